@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Native benchmarks for init data parsing and validation.
+//!
+//! Run with `cargo bench --bench init_data --features session`.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::hint::black_box;
+
+    use criterion::{Criterion, criterion_group};
+    use serde::{Deserialize, Serialize};
+    use telegram_webapp_sdk::{
+        core::init::parse_init_data,
+        messages::{self, WebAppMessage}
+    };
+
+    /// Init data as sent by a current client for a private chat launch.
+    const INIT_DATA: &str = "query_id=AAHdF6IQAAAAAN0XohDhrOrc\
+        &user=%7B%22id%22%3A279058397%2C%22first_name%22%3A%22Vladislav%22%2C%22last_name%22\
+        %3A%22Kibenko%22%2C%22username%22%3A%22vdkfrost%22%2C%22language_code%22%3A%22ru%22\
+        %2C%22is_premium%22%3Atrue%2C%22allows_write_to_pm%22%3Atrue%2C%22photo_url%22%3A\
+        %22https%3A%5C%2F%5C%2Ft.me%5C%2Fi%5C%2Fuserpic%5C%2F320%5C%2Fphoto.svg%22%7D\
+        &chat_instance=-2852264163431012348&chat_type=private&start_param=promo\
+        &auth_date=1700000000&signature=c2lnbmF0dXJl\
+        &hash=89d6079ad6762351f38c6dbbc41bb53048019256a9443988af7a48bcad16ba31";
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum Message {
+        Order { item: String, quantity: u32 }
+    }
+
+    impl WebAppMessage for Message {}
+
+    fn parsing(c: &mut Criterion) {
+        c.bench_function("parse_init_data", |b| {
+            b.iter(|| parse_init_data(black_box(INIT_DATA)).expect("valid init data"));
+        });
+    }
+
+    fn messages(c: &mut Criterion) {
+        let data = messages::encode(&Message::Order {
+            item:     "burger".into(),
+            quantity: 2
+        })
+        .expect("encode");
+        c.bench_function("messages::decode", |b| {
+            b.iter(|| messages::decode::<Message>(black_box(&data)).expect("decode"));
+        });
+    }
+
+    #[cfg(feature = "session")]
+    fn sessions(c: &mut Criterion) {
+        use std::time::Duration;
+
+        use telegram_webapp_sdk::session::{derive_token, verify_token};
+
+        let secret = b"bench-secret";
+        let ttl = Duration::from_secs(3600);
+        c.bench_function("session::derive_token", |b| {
+            b.iter(|| derive_token(black_box(INIT_DATA), secret, ttl).expect("token"));
+        });
+        let token = derive_token(INIT_DATA, secret, ttl).expect("token");
+        c.bench_function("session::verify_token", |b| {
+            b.iter(|| verify_token(black_box(&token), secret).expect("claims"));
+        });
+    }
+
+    #[cfg(not(feature = "session"))]
+    fn sessions(_: &mut Criterion) {}
+
+    criterion_group!(benches, parsing, messages, sessions);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+criterion::criterion_main!(native::benches);
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
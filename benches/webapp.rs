@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Browser micro-benchmarks for the hot `Telegram.WebApp` paths.
+//!
+//! Run with `wasm-bindgen-test-runner` configured as the wasm32 runner:
+//!
+//! ```sh
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!     cargo bench --bench webapp --target wasm32-unknown-unknown
+//! ```
+//!
+//! The `Telegram.WebApp` object is stubbed with no-op methods, so the numbers
+//! measure the SDK side of each call.
+
+#![cfg(target_arch = "wasm32")]
+
+use std::hint::black_box;
+
+use js_sys::{Function, Object, Reflect};
+use telegram_webapp_sdk::{
+    api::accelerometer,
+    core::types::theme_params::TelegramThemeParams,
+    webapp::{BottomButton, BottomButtonParams, TelegramWebApp}
+};
+use wasm_bindgen::{JsCast, prelude::Closure};
+use wasm_bindgen_test::{Criterion, wasm_bindgen_bench, wasm_bindgen_test_configure};
+use web_sys::window;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn setup_webapp() -> TelegramWebApp {
+    let win = window().expect("window");
+    let telegram = Object::new();
+    let webapp = Object::new();
+    let button = Object::new();
+    let noop = Function::new_no_args("");
+    let _ = Reflect::set(&webapp, &"onEvent".into(), &noop);
+    let _ = Reflect::set(&webapp, &"offEvent".into(), &noop);
+    let _ = Reflect::set(&button, &"setParams".into(), &noop);
+    let _ = Reflect::set(&webapp, &"MainButton".into(), &button);
+    let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+    let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+    TelegramWebApp::instance().expect("instance")
+}
+
+#[wasm_bindgen_bench]
+fn event_registration(c: &mut Criterion) {
+    let app = setup_webapp();
+    c.bench_function("on_event + drop", |b| {
+        b.iter(|| drop(app.on_event("viewportChanged", |_| {}).expect("subscribe")));
+    });
+}
+
+#[wasm_bindgen_bench]
+fn set_params(c: &mut Criterion) {
+    let app = setup_webapp();
+    let params = BottomButtonParams {
+        text: Some("Send"),
+        color: Some("#2481cc"),
+        text_color: Some("#ffffff"),
+        is_active: Some(true),
+        is_visible: Some(true),
+        ..Default::default()
+    };
+    c.bench_function("set_bottom_button_params", |b| {
+        b.iter(|| {
+            app.set_bottom_button_params(BottomButton::Main, black_box(&params))
+                .expect("setParams");
+        });
+    });
+}
+
+#[wasm_bindgen_bench]
+fn theme_application(c: &mut Criterion) {
+    let theme = TelegramThemeParams {
+        bg_color: Some("#17212b".into()),
+        text_color: Some("#f5f5f5".into()),
+        hint_color: Some("#708499".into()),
+        link_color: Some("#6ab3f3".into()),
+        button_color: Some("#5288c1".into()),
+        button_text_color: Some("#ffffff".into()),
+        ..Default::default()
+    };
+    c.bench_function("apply_to_root", |b| {
+        b.iter(|| black_box(theme.clone()).apply_to_root().expect("apply"));
+    });
+}
+
+/// Stubs `WebApp.Accelerometer` and returns the `accelerometerChanged`
+/// dispatcher stored by `onEvent`.
+fn setup_accelerometer() -> (Object, impl Fn() -> Function) {
+    setup_webapp();
+    let webapp = Reflect::get(&window().expect("window"), &"Telegram".into())
+        .and_then(|telegram| Reflect::get(&telegram, &"WebApp".into()))
+        .expect("WebApp")
+        .unchecked_into::<Object>();
+    let sensor = Object::new();
+    for (axis, value) in [("x", 0.1), ("y", 9.8), ("z", -0.2)] {
+        let _ = Reflect::set(&sensor, &axis.into(), &value.into());
+    }
+    let _ = Reflect::set(&webapp, &"Accelerometer".into(), &sensor);
+    let on_event = Function::new_with_args("name, cb", "this[name] = cb;");
+    let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+    let source = webapp.clone();
+    let emitter = move || {
+        Reflect::get(&source, &"accelerometerChanged".into())
+            .expect("listener")
+            .unchecked_into()
+    };
+    (webapp, emitter)
+}
+
+#[wasm_bindgen_bench]
+fn sensor_dispatch(c: &mut Criterion) {
+    let (webapp, emitter) = setup_accelerometer();
+    let polled = Closure::<dyn Fn()>::new(|| {
+        black_box(accelerometer::get_acceleration());
+    });
+    accelerometer::on_changed(&polled).expect("on_changed");
+    let emit = emitter();
+    c.bench_function("accelerometer on_changed + Reflect", |b| {
+        b.iter(|| emit.call0(&webapp).expect("dispatch"));
+    });
+
+    let _handle = accelerometer::on_reading(|reading| {
+        black_box(reading);
+    })
+    .expect("on_reading");
+    let emit = emitter();
+    c.bench_function("accelerometer on_reading", |b| {
+        b.iter(|| emit.call0(&webapp).expect("dispatch"));
+    });
+}
@@ -1,6 +1,7 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use telegram_webapp_sdk::router;
 use wasm_bindgen::{JsCast, prelude::Closure};
 use web_sys::{Document, Element, HtmlElement, window};
 
@@ -10,6 +11,9 @@ use web_sys::{Document, Element, HtmlElement, window};
 /// - `label`: основной текст (например, "Init Data")
 /// - `subtitle`: второстепенный текст (может быть `None`)
 /// - `href`: путь, на который должна перейти страница при клике
+///
+/// Clicking the link navigates through the SDK router, which renders the
+/// matching page and records it in the location hash (`#/init-data`).
 pub fn nav_link(label: &str, subtitle: Option<&str>, href: &str) -> Element {
     let document = document();
     let link = document.create_element("div").unwrap();
@@ -29,11 +33,7 @@ pub fn nav_link(label: &str, subtitle: Option<&str>, href: &str) -> Element {
 
     let link_closure = {
         let href = href.to_string();
-        Closure::<dyn FnMut()>::new(move || {
-            if let Some(window) = window() {
-                let _ = window.location().set_href(&href);
-            }
-        })
+        Closure::<dyn FnMut()>::new(move || router::navigate(&href))
     };
 
     let html_elem: HtmlElement = link.clone().dyn_into().unwrap();
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: MIT
 
 use serde::{Deserialize, Serialize};
+use telegram_webapp_sdk::core::payload::WebAppPayload;
 
 /// Order data structure received from the Burger King demo WebApp
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -14,6 +15,8 @@ pub struct OrderData {
     pub price_cents: u32
 }
 
+impl WebAppPayload for OrderData {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
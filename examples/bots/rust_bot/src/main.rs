@@ -2,11 +2,8 @@
 // SPDX-License-Identifier: MIT
 
 use masterror::{AppError, AppErrorKind};
-use teloxide::{
-    prelude::*,
-    types::{InlineKeyboardButton, InlineKeyboardMarkup, WebAppInfo},
-    utils::command::BotCommands
-};
+use telegram_webapp_sdk::teloxide::{WebAppLinks, web_app_data};
+use teloxide::{prelude::*, utils::command::BotCommands};
 use webapp_bot_example::OrderData;
 
 #[tokio::main]
@@ -61,38 +58,13 @@ async fn handle_command(bot: Bot, msg: Message, cmd: Command) -> Result<(), AppE
 
     match cmd {
         Command::Start => {
-            let keyboard = InlineKeyboardMarkup::new(vec![
-                vec![InlineKeyboardButton::web_app(
-                    "Open Burger King Menu",
-                    WebAppInfo {
-                        url: format!("{}#/burger-king", webapp_url)
-                            .parse()
-                            .map_err(|e| {
-                                AppError::new(AppErrorKind::Internal, "Invalid WebApp URL")
-                                    .with_context(e)
-                            })?
-                    }
-                )],
-                vec![InlineKeyboardButton::web_app(
-                    "View Init Data",
-                    WebAppInfo {
-                        url: format!("{}#/init-data", webapp_url).parse().map_err(|e| {
-                            AppError::new(AppErrorKind::Internal, "Invalid WebApp URL")
-                                .with_context(e)
-                        })?
-                    }
-                )],
-                vec![InlineKeyboardButton::web_app(
-                    "Theme Parameters",
-                    WebAppInfo {
-                        url: format!("{}#/theme-params", webapp_url)
-                            .parse()
-                            .map_err(|e| {
-                                AppError::new(AppErrorKind::Internal, "Invalid WebApp URL")
-                                    .with_context(e)
-                            })?
-                    }
-                )],
+            let base = webapp_url.parse().map_err(|e| {
+                AppError::new(AppErrorKind::Internal, "Invalid WebApp URL").with_context(e)
+            })?;
+            let keyboard = WebAppLinks::new(base).keyboard([
+                ("Open Burger King Menu", "/burger-king"),
+                ("View Init Data", "/init-data"),
+                ("Theme Parameters", "/theme-params")
             ]);
 
             bot.send_message(
@@ -128,8 +100,8 @@ async fn handle_command(bot: Bot, msg: Message, cmd: Command) -> Result<(), AppE
 ///
 /// Processes orders from the Burger King demo and sends confirmation messages
 async fn handle_webapp_data(bot: Bot, msg: Message) -> Result<(), AppError> {
-    if let Some(web_app_data) = msg.web_app_data() {
-        let order: OrderData = serde_json::from_str(&web_app_data.data).map_err(|e| {
+    if let Some(order) = web_app_data::<OrderData>(&msg) {
+        let order = order.map_err(|e| {
             AppError::new(AppErrorKind::BadRequest, "Invalid order data format").with_context(e)
         })?;
         let price_dollars = order.price_cents as f64 / 100.0;
@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Expansion of `#[telegram_app]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Error, ItemFn, MetaNameValue, Result, Token, parse::Parser, parse2, punctuated::Punctuated,
+    spanned::Spanned
+};
+
+/// Options accepted by `#[telegram_app(...)]`, mirroring `AppOptions`.
+const OPTIONS: [&str; 6] = [
+    "config",
+    "mock",
+    "ready",
+    "expand",
+    "theme_class",
+    "inject_base_styles"
+];
+
+/// Expands `#[telegram_app]` by delegating to the declarative
+/// `telegram_app!` macro, so both entry point styles behave identically.
+pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let options = Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(attr)?;
+    let mut keys = Vec::new();
+    let mut values = Vec::new();
+    for option in &options {
+        let key = option.path.require_ident()?;
+        if !OPTIONS.iter().any(|name| key == name) {
+            return Err(Error::new(
+                key.span(),
+                format!(
+                    "unknown option `{key}`, expected one of: {}",
+                    OPTIONS.join(", ")
+                )
+            ));
+        }
+        if keys.contains(&key) {
+            return Err(Error::new(key.span(), format!("duplicate option `{key}`")));
+        }
+        keys.push(key);
+        values.push(&option.value);
+    }
+
+    let func: ItemFn = parse2(item)?;
+    let sig = &func.sig;
+    if !sig.inputs.is_empty() {
+        return Err(Error::new(
+            sig.inputs.span(),
+            "the start function cannot take arguments"
+        ));
+    }
+    if !sig.generics.params.is_empty() || sig.generics.where_clause.is_some() {
+        return Err(Error::new(
+            sig.generics.span(),
+            "the start function cannot be generic"
+        ));
+    }
+    if let Some(token) = sig
+        .constness
+        .as_ref()
+        .map(Spanned::span)
+        .or(sig.unsafety.as_ref().map(Spanned::span))
+    {
+        return Err(Error::new(
+            token,
+            "the start function cannot be `const` or `unsafe`"
+        ));
+    }
+
+    Ok(quote! {
+        ::telegram_webapp_sdk::telegram_app! { #(#keys = #values,)* #func }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::expand;
+
+    #[test]
+    fn async_main_is_forwarded() {
+        let tokens = expand(quote! {}, quote! { async fn main() {} }).expect("expands");
+        assert!(tokens.to_string().contains("telegram_app !"));
+    }
+
+    #[test]
+    fn options_are_forwarded() {
+        let attr = quote! { config = "custom.toml", ready = true };
+        let tokens = expand(attr, quote! { fn main() {} })
+            .expect("expands")
+            .to_string();
+        assert!(tokens.contains("config = \"custom.toml\" , ready = true ,"));
+    }
+
+    #[test]
+    fn unknown_and_duplicate_options_are_rejected() {
+        let err = expand(quote! { debug = true }, quote! { fn main() {} }).expect_err("unknown");
+        assert_eq!(
+            err.to_string(),
+            "unknown option `debug`, expected one of: config, mock, ready, expand, theme_class, \
+             inject_base_styles"
+        );
+        let duplicate = quote! { ready = true, ready = false };
+        assert!(expand(duplicate, quote! { fn main() {} }).is_err());
+        assert!(expand(quote! { ready }, quote! { fn main() {} }).is_err());
+    }
+
+    #[test]
+    fn invalid_signatures_are_rejected() {
+        assert!(expand(quote! {}, quote! { fn main(arg: u8) {} }).is_err());
+        assert!(expand(quote! {}, quote! { fn main<T>() {} }).is_err());
+        assert!(expand(quote! {}, quote! { unsafe fn main() {} }).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Expansion of `#[derive(CloudStored)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Ident, LitInt, LitStr, Path, Result, parse2};
+
+/// Maximum key length accepted by CloudStorage.
+const KEY_LIMIT: usize = 128;
+
+/// Expands `#[derive(CloudStored)]` applied to `item`.
+pub(crate) fn expand(item: TokenStream) -> Result<TokenStream> {
+    let input: DeriveInput = parse2(item)?;
+    let mut key: Option<LitStr> = None;
+    let mut prefix: Option<LitStr> = None;
+    let mut version: Option<u32> = None;
+    let mut migrate: Option<Path> = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cloud"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let duplicate = || meta.error("duplicate cloud option");
+            if meta.path.is_ident("key") {
+                if key.is_some() {
+                    return Err(duplicate());
+                }
+                key = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("prefix") {
+                if prefix.is_some() {
+                    return Err(duplicate());
+                }
+                prefix = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("version") {
+                if version.is_some() {
+                    return Err(duplicate());
+                }
+                let lit: LitInt = meta.value()?.parse()?;
+                version = Some(lit.base10_parse()?);
+            } else if meta.path.is_ident("migrate") {
+                if migrate.is_some() {
+                    return Err(duplicate());
+                }
+                migrate = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error(
+                    "unknown cloud option, expected `key`, `prefix`, `version` or `migrate`"
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    let name = &input.ident;
+    let base = key.as_ref().map_or_else(|| snake_case(name), LitStr::value);
+    let full = match &prefix {
+        Some(prefix) => format!("{}_{base}", prefix.value()),
+        None => base
+    };
+    let span = key
+        .as_ref()
+        .or(prefix.as_ref())
+        .map_or_else(|| name.span(), LitStr::span);
+    validate_key(&full).map_err(|msg| Error::new(span, msg))?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let version = version.map(|version| quote! { const VERSION: u32 = #version; });
+    let migrate = migrate.map(|path| {
+        quote! {
+            fn migrate(
+                version: u32,
+                data: ::telegram_webapp_sdk::serde_json::Value
+            ) -> ::core::result::Result<
+                ::core::option::Option<Self>,
+                ::telegram_webapp_sdk::wasm_bindgen::JsValue
+            > {
+                #path(version, data)
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::telegram_webapp_sdk::api::cloud_storage::CloudStored
+            for #name #ty_generics #where_clause
+        {
+            const KEY: &'static str = #full;
+            #version
+            #migrate
+        }
+    })
+}
+
+/// Converts a type name such as `UserSettings` to `user_settings`.
+fn snake_case(ident: &Ident) -> String {
+    let mut out = String::new();
+    for (i, c) in ident.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Checks a key against the CloudStorage key rules.
+fn validate_key(key: &str) -> std::result::Result<(), String> {
+    if key.is_empty() || key.len() > KEY_LIMIT {
+        return Err(format!(
+            "cloud key `{key}` must be 1 to {KEY_LIMIT} characters long"
+        ));
+    }
+    if let Some(c) = key
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_' || *c == '-'))
+    {
+        return Err(format!(
+            "cloud key `{key}` contains `{c}`; only A-Z, a-z, 0-9, `_` and `-` are allowed"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::{format_ident, quote};
+
+    use super::{expand, snake_case, validate_key};
+
+    #[test]
+    fn key_defaults_to_snake_case_name() {
+        assert_eq!(snake_case(&format_ident!("UserSettings")), "user_settings");
+        let tokens = expand(quote! { #[cloud(prefix = "shop")] struct CartItems; })
+            .expect("derive")
+            .to_string();
+        assert!(tokens.contains("\"shop_cart_items\""));
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected() {
+        assert!(validate_key("a-b_C9").is_ok());
+        assert!(validate_key("").is_err());
+        assert!(validate_key(&"k".repeat(129)).is_err());
+        let err = expand(quote! { #[cloud(key = "my key")] struct Settings; })
+            .expect_err("space in key")
+            .to_string();
+        assert!(err.starts_with("cloud key `my key` contains ` `"));
+    }
+
+    #[test]
+    fn unknown_and_duplicate_options_are_rejected() {
+        assert!(expand(quote! { #[cloud(ttl = 5)] struct Settings; }).is_err());
+        assert!(expand(quote! { #[cloud(version = 1, version = 2)] struct Settings; }).is_err());
+    }
+}
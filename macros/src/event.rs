@@ -0,0 +1,129 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Expansion of `#[telegram_event]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, FnArg, ItemFn, LitStr, Result, ReturnType, parse2, spanned::Spanned};
+
+/// Expands `#[telegram_event(name)]` applied to `item`.
+pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let event: LitStr = parse2(attr)?;
+    let func: ItemFn = parse2(item)?;
+    validate_event(&event.value()).map_err(|msg| Error::new(event.span(), msg))?;
+
+    let sig = &func.sig;
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new(
+            sig.generics.span(),
+            "event handlers cannot be generic"
+        ));
+    }
+    if let ReturnType::Type(_, ty) = &sig.output {
+        return Err(Error::new(ty.span(), "event handlers must return `()`"));
+    }
+    if sig.inputs.len() > 1 {
+        return Err(Error::new(
+            sig.inputs.span(),
+            "event handlers take at most one argument, the event payload"
+        ));
+    }
+
+    let name = &sig.ident;
+    let call = match sig.inputs.first() {
+        None => quote! { #name() },
+        Some(FnArg::Receiver(receiver)) => {
+            return Err(Error::new(
+                receiver.span(),
+                "event handlers cannot take `self`"
+            ));
+        }
+        Some(FnArg::Typed(arg)) => {
+            let ty = &arg.ty;
+            quote! {{
+                let ::core::option::Option::Some(payload) =
+                    ::telegram_webapp_sdk::listeners::decode::<#ty>(#event, payload)
+                else {
+                    return;
+                };
+                #name(payload)
+            }}
+        }
+    };
+    let call = if sig.asyncness.is_some() {
+        quote! { ::telegram_webapp_sdk::wasm_bindgen_futures::spawn_local(#call); }
+    } else {
+        quote! { #call; }
+    };
+
+    Ok(quote! {
+        #func
+
+        #[doc(hidden)]
+        const _: () = {
+            #[allow(unused_variables)]
+            fn __telegram_event_handler(payload: ::telegram_webapp_sdk::wasm_bindgen::JsValue) {
+                #call
+            }
+
+            ::telegram_webapp_sdk::inventory::submit! {
+                ::telegram_webapp_sdk::listeners::Listener {
+                    event: #event,
+                    handler: __telegram_event_handler
+                }
+            }
+        };
+    })
+}
+
+/// Checks that `event` looks like a `WebApp` event name such as
+/// `invoiceClosed`.
+fn validate_event(event: &str) -> std::result::Result<(), String> {
+    let valid = event.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && event.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "`{event}` is not a valid event name, expected camelCase like `invoiceClosed`"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::{expand, validate_event};
+
+    #[test]
+    fn event_names_are_validated() {
+        assert!(validate_event("invoiceClosed").is_ok());
+        assert!(validate_event("").is_err());
+        assert!(validate_event("Invoice Closed").is_err());
+    }
+
+    #[test]
+    fn handlers_take_optional_payload() {
+        let unit = quote! { fn on_theme() {} };
+        assert!(expand(quote! { "themeChanged" }, unit).is_ok());
+
+        let typed = quote! { async fn on_invoice(status: Status) {} };
+        let tokens = expand(quote! { "invoiceClosed" }, typed)
+            .expect("typed payload")
+            .to_string();
+        assert!(tokens.contains("decode :: < Status >"));
+        assert!(tokens.contains("spawn_local"));
+    }
+
+    #[test]
+    fn unsupported_signatures_are_rejected() {
+        let two = quote! { fn on_event(a: u8, b: u8) {} };
+        assert!(expand(quote! { "popupClosed" }, two).is_err());
+        let ret = quote! { fn on_event() -> u8 { 0 } };
+        assert!(expand(quote! { "popupClosed" }, ret).is_err());
+        let generic = quote! { fn on_event<T>(value: T) {} };
+        assert!(expand(quote! { "popupClosed" }, generic).is_err());
+    }
+}
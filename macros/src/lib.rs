@@ -0,0 +1,201 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Procedural macros for
+//! [`telegram-webapp-sdk`](https://docs.rs/telegram-webapp-sdk).
+//!
+//! The macros are re-exported by the SDK behind its `macros` feature and
+//! expand to paths under `::telegram_webapp_sdk`, so depend on the SDK rather
+//! than on this crate directly.
+
+#![warn(missing_docs)]
+
+mod app;
+mod cloud;
+mod event;
+mod page;
+mod payload;
+
+use proc_macro::TokenStream;
+
+/// Registers a function as a routable page with typed path parameters.
+///
+/// The attribute takes the route path. Segments starting with `:` are path
+/// parameters and must match a function argument of the same name; every
+/// argument must in turn name a parameter. Arguments may have any type
+/// implementing [`FromStr`](std::str::FromStr). A generated wrapper parses
+/// the parameters from the current route and calls the function, rendering
+/// the router's `not_found` page when a value fails to parse.
+///
+/// `async fn` pages are spawned once their parameters are parsed.
+///
+/// # Examples
+///
+/// ```ignore
+/// use telegram_webapp_sdk::pages::telegram_page;
+///
+/// #[telegram_page("/item/:id")]
+/// fn item(id: u32) {
+///     // render item `id`
+/// }
+///
+/// #[telegram_page("/user/:name/posts")]
+/// async fn posts(name: String) {
+///     // fetch and render posts
+/// }
+/// ```
+///
+/// Mismatches are rejected at compile time:
+///
+/// ```ignore
+/// #[telegram_page("/item/:id")]
+/// fn item(slug: String) {} // error: `slug` is not a parameter of `/item/:id`
+/// ```
+#[proc_macro_attribute]
+pub fn telegram_page(attr: TokenStream, item: TokenStream) -> TokenStream {
+    page::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Registers a function as a handler for a Telegram `WebApp` event.
+///
+/// The attribute takes the event name as passed to `WebApp.onEvent`. The
+/// function may take no arguments or a single argument of any
+/// `serde::de::DeserializeOwned` type, into which the event payload is
+/// deserialized; payloads that fail to deserialize are logged and skipped.
+/// `async fn` handlers are spawned with `wasm_bindgen_futures::spawn_local`.
+///
+/// Handlers are subscribed by `telegram_app!` during startup, or manually
+/// with `listeners::bind()`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde::Deserialize;
+/// use telegram_webapp_sdk::listeners::telegram_event;
+///
+/// #[derive(Deserialize)]
+/// struct InvoiceClosed {
+///     url:    String,
+///     status: String
+/// }
+///
+/// #[telegram_event("invoiceClosed")]
+/// fn on_invoice(event: InvoiceClosed) {
+///     // react to `event.status`
+/// }
+///
+/// #[telegram_event("themeChanged")]
+/// fn on_theme() {
+///     // restyle
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn telegram_event(attr: TokenStream, item: TokenStream) -> TokenStream {
+    event::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Defines the WASM start function with Telegram SDK initialization.
+///
+/// Attribute form of the SDK's `telegram_app!` macro with the same
+/// behavior: the function is exported as the `#[wasm_bindgen(start)]` entry
+/// point, the SDK is initialized (with the debug mock outside Telegram), and
+/// the body runs afterwards. `async fn` bodies are spawned with
+/// `wasm_bindgen_futures::spawn_local` while the start function itself still
+/// returns `Result<(), JsValue>`.
+///
+/// Accepts the same `key = value` options as the declarative macro:
+/// `config`, `mock`, `ready`, `expand`, `theme_class` and
+/// `inject_base_styles`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use telegram_webapp_sdk::core::init::telegram_app;
+/// use wasm_bindgen::JsValue;
+///
+/// #[telegram_app(config = "custom.toml", ready = true, expand = true)]
+/// async fn main() -> Result<(), JsValue> {
+///     // fetch configuration before rendering
+///     telegram_webapp_sdk::telegram_router!();
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn telegram_app(attr: TokenStream, item: TokenStream) -> TokenStream {
+    app::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `WebAppPayload` for data sent with `WebApp.sendData`.
+///
+/// The type must also implement `serde::Serialize`. Optional attributes:
+///
+/// - `#[payload(max_bytes = N)]` on the type lowers the encoded size limit;
+///   values above Telegram's 4096-byte limit are rejected at compile time.
+/// - `#[payload(max_len = N)]` on a struct field rejects values whose `len()`
+///   exceeds `N` when encoding or decoding.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::core::payload::WebAppPayload;
+///
+/// #[derive(Serialize, Deserialize, WebAppPayload)]
+/// #[payload(max_bytes = 1024)]
+/// struct OrderData {
+///     #[payload(max_len = 64)]
+///     item:     String,
+///     quantity: u32
+/// }
+/// ```
+#[proc_macro_derive(WebAppPayload, attributes(payload))]
+pub fn derive_web_app_payload(item: TokenStream) -> TokenStream {
+    payload::expand(item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `CloudStored` to persist a type in Telegram CloudStorage.
+///
+/// The type must also implement `serde::Serialize` and
+/// `serde::Deserialize`. Options are given in `#[cloud(..)]`:
+///
+/// - `key = ".."` — storage key, defaulting to the type name in `snake_case`.
+/// - `prefix = ".."` — prepended to the key with `_`.
+/// - `version = N` — schema version stored with the value (default `1`).
+/// - `migrate = path` — `fn(u32, serde_json::Value) -> Result<Option<Self>,
+///   JsValue>` converting values stored with another version.
+///
+/// The resulting key is checked against CloudStorage rules at compile time.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::api::cloud_storage::CloudStored;
+///
+/// #[derive(Serialize, Deserialize, Default, CloudStored)]
+/// #[cloud(prefix = "shop", version = 2)]
+/// struct Cart {
+///     items: Vec<String>
+/// }
+///
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let mut cart = Cart::load().await?.unwrap_or_default(); // key `shop_cart`
+/// cart.items.push("burger".into());
+/// cart.save().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[proc_macro_derive(CloudStored, attributes(cloud))]
+pub fn derive_cloud_stored(item: TokenStream) -> TokenStream {
+    cloud::expand(item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,177 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Expansion of `#[telegram_page]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, FnArg, Ident, ItemFn, LitStr, Pat, Result, Type, parse2, spanned::Spanned};
+
+/// Expands `#[telegram_page(path)]` applied to `item`.
+pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let path: LitStr = parse2(attr)?;
+    let func: ItemFn = parse2(item)?;
+    let route = path.value();
+    let params = path_params(&route).map_err(|msg| Error::new(path.span(), msg))?;
+
+    if !func.sig.generics.params.is_empty() {
+        return Err(Error::new(
+            func.sig.generics.span(),
+            "page functions cannot be generic"
+        ));
+    }
+
+    let args = arguments(&func)?;
+    for (ident, _) in &args {
+        if !params.contains(&ident.to_string().as_str()) {
+            return Err(Error::new(
+                ident.span(),
+                format!("`{ident}` is not a parameter of `{route}`")
+            ));
+        }
+    }
+    if let Some(missing) = params
+        .iter()
+        .find(|param| !args.iter().any(|(ident, _)| ident == *param))
+    {
+        return Err(Error::new(
+            path.span(),
+            format!(
+                "path parameter `:{missing}` has no matching argument in `{}`",
+                func.sig.ident
+            )
+        ));
+    }
+
+    let name = &func.sig.ident;
+    let parse = args.iter().map(|(ident, ty)| {
+        let key = ident.to_string();
+        quote! {
+            let ::core::option::Option::Some(#ident) = ::telegram_webapp_sdk::router::param(#key)
+                .and_then(|value| <#ty as ::core::str::FromStr>::from_str(&value).ok())
+            else {
+                ::telegram_webapp_sdk::router::fallback();
+                return;
+            };
+        }
+    });
+    let idents = args.iter().map(|(ident, _)| ident);
+    let call = if func.sig.asyncness.is_some() {
+        quote! {
+            ::telegram_webapp_sdk::router::spawn_page(::std::boxed::Box::pin(#name(#(#idents),*)));
+        }
+    } else {
+        quote! { #name(#(#idents),*); }
+    };
+
+    Ok(quote! {
+        #func
+
+        #[doc(hidden)]
+        const _: () = {
+            fn __telegram_page_handler() {
+                #(#parse)*
+                #call
+            }
+
+            ::telegram_webapp_sdk::inventory::submit! {
+                ::telegram_webapp_sdk::pages::Page {
+                    path: #path,
+                    handler: __telegram_page_handler
+                }
+            }
+        };
+    })
+}
+
+/// Collects the argument names and types of a page function.
+fn arguments(func: &ItemFn) -> Result<Vec<(Ident, Type)>> {
+    func.sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            FnArg::Receiver(receiver) => Err(Error::new(
+                receiver.span(),
+                "page functions cannot take `self`"
+            )),
+            FnArg::Typed(arg) => match &*arg.pat {
+                Pat::Ident(pat) => Ok((pat.ident.clone(), (*arg.ty).clone())),
+                other => Err(Error::new(
+                    other.span(),
+                    "page arguments must be plain names matching path parameters"
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Validates `path` and returns the names of its `:param` segments.
+fn path_params(path: &str) -> std::result::Result<Vec<&str>, String> {
+    if !path.starts_with('/') {
+        return Err(format!("page path `{path}` must start with `/`"));
+    }
+    let mut params = Vec::new();
+    for name in path
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix(':'))
+    {
+        let valid = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err(format!("`:{name}` is not a valid parameter name"));
+        }
+        if params.contains(&name) {
+            return Err(format!("parameter `:{name}` appears more than once"));
+        }
+        params.push(name);
+    }
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::{expand, path_params};
+
+    #[test]
+    fn path_params_are_collected() {
+        assert_eq!(path_params("/"), Ok(vec![]));
+        assert_eq!(path_params("/item/:id/:tab"), Ok(vec!["id", "tab"]));
+    }
+
+    #[test]
+    fn invalid_paths_are_rejected() {
+        assert!(path_params("item").is_err());
+        assert!(path_params("/item/:").is_err());
+        assert!(path_params("/item/:1d").is_err());
+        assert!(path_params("/a/:id/b/:id").is_err());
+    }
+
+    #[test]
+    fn arguments_must_match_parameters() {
+        let ok = expand(quote! { "/item/:id" }, quote! { fn item(id: u32) {} });
+        assert!(ok.is_ok());
+
+        let unknown = expand(quote! { "/item/:id" }, quote! { fn item(slug: String) {} });
+        let err = unknown.expect_err("unknown argument").to_string();
+        assert_eq!(err, "`slug` is not a parameter of `/item/:id`");
+
+        let missing = expand(quote! { "/item/:id" }, quote! { fn item() {} });
+        let err = missing.expect_err("missing argument").to_string();
+        assert_eq!(
+            err,
+            "path parameter `:id` has no matching argument in `item`"
+        );
+    }
+
+    #[test]
+    fn unsupported_signatures_are_rejected() {
+        assert!(expand(quote! { "/" }, quote! { fn page<T>() {} }).is_err());
+        let tuple = quote! { fn page((a, b): (u8, u8)) {} };
+        assert!(expand(quote! { "/:a" }, tuple).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Expansion of `#[derive(WebAppPayload)]`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, Index, LitInt, Member, Result, parse2,
+    spanned::Spanned
+};
+
+/// Size limit of `WebApp.sendData`, mirrored from the SDK.
+const SEND_DATA_LIMIT: usize = 4096;
+
+/// Expands `#[derive(WebAppPayload)]` applied to `item`.
+pub(crate) fn expand(item: TokenStream) -> Result<TokenStream> {
+    let input: DeriveInput = parse2(item)?;
+    let max_bytes = container_limit(&input.attrs)?;
+
+    let checks = match &input.data {
+        Data::Struct(data) => field_checks(&data.fields)?,
+        Data::Enum(data) => {
+            for field in data.variants.iter().flat_map(|variant| &variant.fields) {
+                if let Some(attr) = payload_attr(&field.attrs) {
+                    return Err(Error::new(
+                        attr.span(),
+                        "`#[payload(max_len)]` is only supported on struct fields"
+                    ));
+                }
+            }
+            Vec::new()
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "WebAppPayload cannot be derived for unions"
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let max_bytes = max_bytes.map(|limit| quote! { const MAX_BYTES: usize = #limit; });
+    let validate = (!checks.is_empty()).then(|| {
+        quote! {
+            fn validate(
+                &self
+            ) -> ::core::result::Result<(), ::telegram_webapp_sdk::core::payload::PayloadError> {
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::telegram_webapp_sdk::core::payload::WebAppPayload
+            for #name #ty_generics #where_clause
+        {
+            #max_bytes
+            #validate
+        }
+    })
+}
+
+/// Reads `#[payload(max_bytes = N)]` from the container attributes.
+fn container_limit(attrs: &[Attribute]) -> Result<Option<usize>> {
+    let mut limit = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("payload")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("max_bytes") {
+                return Err(meta.error("expected `max_bytes = N` on the payload type"));
+            }
+            if limit.is_some() {
+                return Err(meta.error("duplicate `max_bytes`"));
+            }
+            let lit: LitInt = meta.value()?.parse()?;
+            let value: usize = lit.base10_parse()?;
+            if value == 0 || value > SEND_DATA_LIMIT {
+                return Err(Error::new(
+                    lit.span(),
+                    format!("`max_bytes` must be between 1 and {SEND_DATA_LIMIT}")
+                ));
+            }
+            limit = Some(value);
+            Ok(())
+        })?;
+    }
+    Ok(limit)
+}
+
+/// Builds the `validate` checks for fields marked `#[payload(max_len = N)]`.
+fn field_checks(fields: &Fields) -> Result<Vec<TokenStream>> {
+    let mut checks = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let Some(attr) = payload_attr(&field.attrs) else {
+            continue;
+        };
+        let mut max_len = None;
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("max_len") {
+                return Err(meta.error("expected `max_len = N` on a payload field"));
+            }
+            if max_len.is_some() {
+                return Err(meta.error("duplicate `max_len`"));
+            }
+            let lit: LitInt = meta.value()?.parse()?;
+            let value: usize = lit.base10_parse()?;
+            if value > SEND_DATA_LIMIT {
+                return Err(Error::new(
+                    lit.span(),
+                    format!("`max_len` cannot exceed the {SEND_DATA_LIMIT}-byte payload limit")
+                ));
+            }
+            max_len = Some(value);
+            Ok(())
+        })?;
+        let Some(max) = max_len else {
+            return Err(Error::new(attr.span(), "expected `max_len = N`"));
+        };
+        let (member, label) = match &field.ident {
+            Some(ident) => (Member::Named(ident.clone()), ident.to_string()),
+            None => (
+                Member::Unnamed(Index {
+                    index: index as u32,
+                    span:  Span::call_site()
+                }),
+                index.to_string()
+            )
+        };
+        checks.push(quote! {
+            let len = self.#member.len();
+            if len > #max {
+                return ::core::result::Result::Err(
+                    ::telegram_webapp_sdk::core::payload::PayloadError::FieldTooLong {
+                        field: #label,
+                        len,
+                        max: #max
+                    }
+                );
+            }
+        });
+    }
+    Ok(checks)
+}
+
+/// Returns the `#[payload(..)]` attribute of a field, if any.
+fn payload_attr(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|attr| attr.path().is_ident("payload"))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::expand;
+
+    #[test]
+    fn limits_are_validated() {
+        let ok = quote! {
+            #[payload(max_bytes = 512)]
+            struct Order { #[payload(max_len = 64)] item: String, quantity: u32 }
+        };
+        assert!(expand(ok).is_ok());
+
+        let large = quote! {
+            #[payload(max_bytes = 5000)]
+            struct Order { item: String }
+        };
+        let err = expand(large).expect_err("limit above sendData");
+        assert_eq!(err.to_string(), "`max_bytes` must be between 1 and 4096");
+    }
+
+    #[test]
+    fn unknown_attributes_are_rejected() {
+        let field = quote! { struct Order { #[payload(max_bytes = 8)] item: String } };
+        assert!(expand(field).is_err());
+
+        let container = quote! { #[payload(max_len = 8)] struct Order { item: String } };
+        assert!(expand(container).is_err());
+
+        let variant = quote! { enum Action { Buy { #[payload(max_len = 8)] item: String } } };
+        assert!(expand(variant).is_err());
+    }
+
+    #[test]
+    fn tuple_fields_are_checked_by_index() {
+        let tuple = quote! { struct Code(#[payload(max_len = 6)] String); };
+        let tokens = expand(tuple).expect("tuple struct").to_string();
+        assert!(tokens.contains("self . 0 . len ()"));
+    }
+}
@@ -0,0 +1,364 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Product analytics posted to the mini app's backend.
+//!
+//! [`track`] buffers events in memory. They are posted as one
+//! [`AnalyticsBatch`] once [`Analytics::batch_size`] events were tracked since
+//! the last flush, when the app is `deactivated` and on [`flush`]. Requests go
+//! through [`TelegramFetch`], so every batch carries the raw init data and the
+//! backend can attribute events to a validated user instead of trusting a
+//! client-side id. Failed batches stay queued; a background flush retries them
+//! with [`Analytics::retry_policy`] backoff and at most one runs at a time.
+//!
+//! Nothing is collected until [`Analytics::install`] is called.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration
+};
+
+use serde::{Deserialize, Serialize, de::IgnoredAny};
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+
+use crate::{
+    http::{FetchError, TelegramFetch},
+    logger,
+    utils::{
+        clock::now_ms,
+        future::{RetryPolicy, retry}
+    },
+    webapp::{EventHandle, TelegramWebApp}
+};
+
+/// Events posted in one batch by default.
+pub const DEFAULT_BATCH_SIZE: usize = 20;
+
+/// Events kept while the endpoint is unreachable; the oldest are dropped
+/// beyond this.
+pub const MAX_QUEUED_EVENTS: usize = 1000;
+
+/// Single tracked event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsEvent {
+    /// Event name, e.g. `checkout_started`.
+    pub name:      String,
+    /// Event properties; `null` when none were given.
+    #[serde(default)]
+    pub props:     Value,
+    /// Unix time in milliseconds when the event was tracked.
+    pub timestamp: u64
+}
+
+/// Request body posted to the analytics endpoint.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsBatch {
+    /// Events in the order they were tracked.
+    pub events: Vec<AnalyticsEvent>
+}
+
+/// Analytics configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde_json::json;
+/// use telegram_webapp_sdk::{analytics, http::TelegramFetch};
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// analytics::Analytics::new(TelegramFetch::new("https://api.example.com"), "/analytics")
+///     .batch_size(10)
+///     .install()?;
+///
+/// analytics::track("checkout_started", json!({ "items": 3 }));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Analytics {
+    client:     TelegramFetch,
+    path:       String,
+    batch_size: usize,
+    retry:      RetryPolicy
+}
+
+impl Analytics {
+    /// Posts batches to `path` of the `client` API.
+    pub fn new(client: TelegramFetch, path: impl Into<String>) -> Self {
+        Self {
+            client,
+            path: path.into(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            retry: RetryPolicy::new(4)
+                .initial_delay(Duration::from_secs(1))
+                .max_delay(Duration::from_secs(30))
+        }
+    }
+
+    /// Sets how many queued events trigger a flush; `0` is treated as `1`.
+    pub fn batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size.max(1);
+        self
+    }
+
+    /// Sets the backoff of background flushes; by default four attempts
+    /// waiting 1, 2 and 4 seconds. Events of a flush that runs out of attempts
+    /// stay queued for the next one.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Starts collecting events, replacing a previously installed
+    /// configuration and discarding its queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsValue`] if the `deactivated` handler cannot be
+    /// registered.
+    pub fn install(self) -> Result<(), JsValue> {
+        let deactivated = match TelegramWebApp::instance() {
+            Some(app) => Some(app.on_event("deactivated", |_| flush_detached())?),
+            None => None
+        };
+        let tracker = Tracker {
+            config:       self,
+            queue:        RefCell::new(Vec::new()),
+            unsent:       Cell::new(0),
+            flushing:     Cell::new(false),
+            _deactivated: deactivated
+        };
+        TRACKER.with(|slot| *slot.borrow_mut() = Some(Rc::new(tracker)));
+        Ok(())
+    }
+}
+
+struct Tracker {
+    config:       Analytics,
+    queue:        RefCell<Vec<AnalyticsEvent>>,
+    /// Events tracked since the last background flush started.
+    unsent:       Cell<usize>,
+    /// Whether a background flush is running.
+    flushing:     Cell<bool>,
+    _deactivated: Option<EventHandle<dyn FnMut(JsValue)>>
+}
+
+impl Tracker {
+    /// Queues `event`; returns `true` once a full batch was tracked since the
+    /// last background flush started.
+    ///
+    /// Requeued events do not count, so an unreachable endpoint is retried
+    /// per batch rather than per event.
+    fn push(&self, event: AnalyticsEvent) -> bool {
+        let mut queue = self.queue.borrow_mut();
+        queue.push(event);
+        if queue.len() > MAX_QUEUED_EVENTS {
+            let excess = queue.len() - MAX_QUEUED_EVENTS;
+            queue.drain(..excess);
+        }
+        self.unsent.set(self.unsent.get() + 1);
+        self.unsent.get() >= self.config.batch_size
+    }
+
+    /// Marks a background flush as running; returns `false` if one already
+    /// is.
+    fn start_flush(&self) -> bool {
+        if self.flushing.replace(true) {
+            return false;
+        }
+        self.unsent.set(0);
+        true
+    }
+
+    /// Puts events that failed to send back in front of newer ones.
+    fn requeue(&self, mut events: Vec<AnalyticsEvent>) {
+        let mut queue = self.queue.borrow_mut();
+        events.append(&mut queue);
+        let excess = events.len().saturating_sub(MAX_QUEUED_EVENTS);
+        events.drain(..excess);
+        *queue = events;
+    }
+}
+
+thread_local! {
+    static TRACKER: RefCell<Option<Rc<Tracker>>> = const { RefCell::new(None) };
+}
+
+fn tracker() -> Option<Rc<Tracker>> {
+    TRACKER.with(|slot| slot.borrow().clone())
+}
+
+/// Tracks `event` with `props`, e.g. a `serde_json::json!` object or any
+/// serializable struct.
+///
+/// Does nothing until [`Analytics::install`] is called. Properties that fail
+/// to serialize are recorded as `null`.
+pub fn track(event: &str, props: impl Serialize) {
+    let Some(tracker) = tracker() else {
+        return;
+    };
+    let event = AnalyticsEvent {
+        name:      event.to_owned(),
+        props:     serde_json::to_value(props).unwrap_or(Value::Null),
+        timestamp: now_ms()
+    };
+    if tracker.push(event) {
+        spawn_flush(tracker);
+    }
+}
+
+/// Posts all queued events now.
+///
+/// # Errors
+///
+/// Returns [`FetchError`] if the request fails; the events stay queued.
+pub async fn flush() -> Result<(), FetchError> {
+    let Some(tracker) = tracker() else {
+        return Ok(());
+    };
+    let events = std::mem::take(&mut *tracker.queue.borrow_mut());
+    if events.is_empty() {
+        return Ok(());
+    }
+    let batch = AnalyticsBatch {
+        events
+    };
+    let config = &tracker.config;
+    match config
+        .client
+        .post::<_, IgnoredAny>(&config.path, &batch)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            tracker.requeue(batch.events);
+            Err(err)
+        }
+    }
+}
+
+/// Flushes in the background, retrying with the configured backoff.
+fn spawn_flush(tracker: Rc<Tracker>) {
+    if !tracker.start_flush() {
+        return;
+    }
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(err) = retry(tracker.config.retry, flush).await {
+            logger::warn(&format!("Analytics flush failed: {err}"));
+        }
+        tracker.flushing.set(false);
+    });
+}
+
+/// Posts the queue with `keepalive` so the request survives the WebView
+/// closing.
+fn flush_detached() {
+    let Some(tracker) = tracker() else {
+        return;
+    };
+    let events = std::mem::take(&mut *tracker.queue.borrow_mut());
+    if events.is_empty() {
+        return;
+    }
+    let batch = AnalyticsBatch {
+        events
+    };
+    let config = &tracker.config;
+    if let Err(err) = config.client.post_detached(&config.path, &batch) {
+        tracker.requeue(batch.events);
+        logger::warn(&format!("Analytics flush failed: {err}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker(batch_size: usize) -> Tracker {
+        Tracker {
+            config:       Analytics::new(TelegramFetch::new("https://example.com"), "/a")
+                .batch_size(batch_size),
+            queue:        RefCell::new(Vec::new()),
+            unsent:       Cell::new(0),
+            flushing:     Cell::new(false),
+            _deactivated: None
+        }
+    }
+
+    fn event(name: &str) -> AnalyticsEvent {
+        AnalyticsEvent {
+            name:      name.to_owned(),
+            props:     Value::Null,
+            timestamp: 0
+        }
+    }
+
+    #[test]
+    fn signals_full_batch() {
+        let tracker = tracker(2);
+        assert!(!tracker.push(event("a")));
+        assert!(tracker.push(event("b")));
+    }
+
+    #[test]
+    fn requeued_events_do_not_retrigger() {
+        let tracker = tracker(2);
+        tracker.push(event("a"));
+        assert!(tracker.push(event("b")));
+        assert!(tracker.start_flush());
+        assert!(!tracker.start_flush());
+        let failed = std::mem::take(&mut *tracker.queue.borrow_mut());
+        tracker.requeue(failed);
+        tracker.flushing.set(false);
+        assert!(!tracker.push(event("c")));
+        assert!(tracker.push(event("d")));
+        assert_eq!(tracker.queue.borrow().len(), 4);
+    }
+
+    #[test]
+    fn requeued_events_precede_new_ones() {
+        let tracker = tracker(10);
+        tracker.push(event("new"));
+        tracker.requeue(vec![event("old")]);
+        let names: Vec<_> = tracker
+            .queue
+            .borrow()
+            .iter()
+            .map(|event| event.name.clone())
+            .collect();
+        assert_eq!(names, ["old", "new"]);
+    }
+
+    #[test]
+    fn queue_is_bounded() {
+        let tracker = tracker(usize::MAX);
+        for i in 0..=MAX_QUEUED_EVENTS {
+            tracker.push(event(&i.to_string()));
+        }
+        let queue = tracker.queue.borrow();
+        assert_eq!(queue.len(), MAX_QUEUED_EVENTS);
+        assert_eq!(queue[0].name, "1");
+    }
+
+    #[test]
+    fn batch_round_trips() {
+        let batch = AnalyticsBatch {
+            events: vec![AnalyticsEvent {
+                name:      "open".into(),
+                props:     serde_json::json!({ "source": "menu" }),
+                timestamp: 1
+            }]
+        };
+        let json = serde_json::to_string(&batch).unwrap();
+        assert_eq!(
+            json,
+            r#"{"events":[{"name":"open","props":{"source":"menu"},"timestamp":1}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<AnalyticsBatch>(&json).unwrap(),
+            batch
+        );
+    }
+}
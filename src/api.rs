@@ -8,25 +8,47 @@
 //! information.
 
 /// Accelerometer sensor: three-axis acceleration readings.
+#[cfg(feature = "sensors")]
 pub mod accelerometer;
 /// Biometric manager: fingerprint/face authentication and access requests.
+#[cfg(feature = "biometric")]
 pub mod biometric;
+/// Clipboard: copying text with an `execCommand` fallback.
+pub mod clipboard;
 /// Cloud storage: per-user key-value storage synced across devices.
+#[cfg(feature = "cloud-storage")]
 pub mod cloud_storage;
 /// Device orientation sensor: orientation angles in degrees.
+#[cfg(feature = "sensors")]
 pub mod device_orientation;
 /// Device storage: local key-value storage on the current device.
 pub mod device_storage;
 /// WebApp event subscription helpers (`onEvent`/`offEvent`).
 pub mod events;
+/// Shake detection built on accelerometer readings.
+#[cfg(feature = "sensors")]
+pub mod gestures;
 /// Gyroscope sensor: angular velocity readings.
+#[cfg(feature = "sensors")]
 pub mod gyroscope;
 /// Haptic feedback: impact, notification, and selection vibrations.
 pub mod haptic;
 /// Location manager: initialization and geolocation access.
+#[cfg(feature = "location")]
 pub mod location_manager;
+/// Low-pass, high-pass and gravity separation filters for accelerometer
+/// readings.
+#[cfg(feature = "sensors")]
+pub mod motion_filter;
+/// Screen orientation from the viewport and a landscape lock guard.
+pub mod orientation;
+/// Tilt-driven parallax offsets from device orientation readings.
+#[cfg(feature = "sensors")]
+pub mod parallax;
 /// Secure storage: encrypted key-value storage that survives reinstalls.
 pub mod secure_storage;
+#[cfg(feature = "sensors")]
+mod sensor;
 /// Settings button: control over the WebApp settings button.
 pub mod settings_button;
 /// Theme parameters exposed by the Telegram client.
@@ -5,7 +5,11 @@ use js_sys::{Function, Reflect};
 use wasm_bindgen::{JsCast, prelude::*};
 use web_sys::window;
 
-use super::events;
+use super::{
+    events,
+    sensor::{self, SensorObject}
+};
+use crate::webapp::EventHandle;
 
 /// Three-dimensional acceleration in meters per second squared.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -94,6 +98,42 @@ pub fn on_started(callback: &Closure<dyn Fn()>) -> Result<(), JsValue> {
     events::on_event("accelerometerStarted", callback)
 }
 
+/// Calls `callback` with the acceleration on every `accelerometerChanged`
+/// event.
+///
+/// Unlike polling the getter from [`on_changed`], the sensor object is
+/// resolved once and each event reads the axes as plain numbers, without
+/// allocating JS strings or heap slots. The callback is unregistered when the
+/// returned handle is dropped.
+///
+/// # Errors
+/// Returns [`JsValue`] if the WebApp or the sensor is unavailable.
+///
+/// # Examples
+/// ```no_run
+/// # use telegram_webapp_sdk::api::accelerometer;
+/// accelerometer::start()?;
+/// let _handle = accelerometer::on_reading(|reading| {
+///     let _ = reading.x;
+/// })?;
+/// # Ok::<(), wasm_bindgen::JsValue>(())
+/// ```
+pub fn on_reading<F>(callback: F) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+where
+    F: 'static + FnMut(&Acceleration)
+{
+    sensor::subscribe("Accelerometer", "accelerometerChanged", read, callback)
+}
+
+fn read(sensor: &SensorObject) -> Option<Acceleration> {
+    let (x, y, z) = sensor::triple(sensor.x(), sensor.y(), sensor.z())?;
+    Some(Acceleration {
+        x,
+        y,
+        z
+    })
+}
+
 /// Registers a callback for `accelerometerChanged` event.
 ///
 /// ⚠️ The closure must be kept alive for as long as it is needed.
@@ -209,4 +249,48 @@ mod tests {
         assert!(Reflect::has(&webapp, &"accelerometerFailed".into()).unwrap());
         cb.forget();
     }
+
+    #[wasm_bindgen_test]
+    #[allow(clippy::unused_unit)]
+    fn on_reading_reads_axes() {
+        let (webapp, accel) = setup_accelerometer();
+        let on_event = Function::new_with_args("name, cb", "this[name] = cb;");
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &Function::new_no_args(""));
+        let _ = Reflect::set(&accel, &"x".into(), &JsValue::from_f64(1.0));
+        let _ = Reflect::set(&accel, &"y".into(), &JsValue::from_f64(2.0));
+        let _ = Reflect::set(&accel, &"z".into(), &JsValue::from_f64(3.0));
+
+        let received = std::rc::Rc::new(std::cell::Cell::new(None));
+        let sink = std::rc::Rc::clone(&received);
+        let _handle = on_reading(move |reading| sink.set(Some(*reading))).unwrap();
+        let emit = Reflect::get(&webapp, &"accelerometerChanged".into())
+            .unwrap()
+            .dyn_into::<Function>()
+            .unwrap();
+        let _ = emit.call0(&webapp);
+        assert_eq!(received.get(), Some(Acceleration::new(1.0, 2.0, 3.0)));
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(clippy::unused_unit)]
+    fn on_reading_skips_null_axes() {
+        let (webapp, accel) = setup_accelerometer();
+        let on_event = Function::new_with_args("name, cb", "this[name] = cb;");
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &Function::new_no_args(""));
+        for axis in ["x", "y", "z"] {
+            let _ = Reflect::set(&accel, &axis.into(), &JsValue::NULL);
+        }
+
+        let received = std::rc::Rc::new(std::cell::Cell::new(None));
+        let sink = std::rc::Rc::clone(&received);
+        let _handle = on_reading(move |reading| sink.set(Some(*reading))).unwrap();
+        let emit = Reflect::get(&webapp, &"accelerometerChanged".into())
+            .unwrap()
+            .dyn_into::<Function>()
+            .unwrap();
+        let _ = emit.call0(&webapp);
+        assert_eq!(received.get(), None);
+    }
 }
@@ -1,8 +1,9 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use js_sys::{Function, Reflect};
+use js_sys::{Array, Function, Object, Promise, Reflect};
 use wasm_bindgen::{JsCast, prelude::*};
+use wasm_bindgen_futures::JsFuture;
 use web_sys::window;
 
 /// Calls `Telegram.WebApp.BiometricManager.init()`.
@@ -257,6 +258,94 @@ pub fn device_id() -> Result<String, JsValue> {
         .ok_or_else(|| JsValue::from_str("deviceId not a string"))
 }
 
+/// Snapshot of the `BiometricManager` flags.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BiometricStatus {
+    /// Whether `BiometricManager` finished initializing.
+    pub inited:           bool,
+    /// Whether biometrics are available on the device.
+    pub available:        bool,
+    /// Whether access to biometrics was requested.
+    pub access_requested: bool,
+    /// Whether access to biometrics was granted.
+    pub access_granted:   bool,
+    /// Whether a biometric token is stored on the device.
+    pub token_saved:      bool
+}
+
+impl BiometricStatus {
+    /// Reads the current flags, treating unavailable ones as `false`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use telegram_webapp_sdk::api::biometric::BiometricStatus;
+    ///
+    /// let status = BiometricStatus::current();
+    /// let _ = status.available && status.access_granted;
+    /// ```
+    pub fn current() -> Self {
+        Self {
+            inited:           is_inited().unwrap_or(false),
+            available:        is_biometric_available().unwrap_or(false),
+            access_requested: is_access_requested().unwrap_or(false),
+            access_granted:   is_access_granted().unwrap_or(false),
+            token_saved:      is_biometric_token_saved().unwrap_or(false)
+        }
+    }
+}
+
+/// Outcome of [`authenticate_user`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BiometricAuthResult {
+    /// Whether the user passed biometric authentication.
+    pub authenticated: bool,
+    /// Biometric token stored on the device, if authentication succeeded.
+    pub token:         Option<String>
+}
+
+/// Calls `Telegram.WebApp.BiometricManager.authenticate({ reason },
+/// callback)` and resolves with its outcome.
+///
+/// # Errors
+/// Returns `Err(JsValue)` if `BiometricManager` or the method is unavailable,
+/// or if the call fails.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::biometric::authenticate_user;
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let result = authenticate_user(Some("Unlock your wallet")).await?;
+/// if result.authenticated {
+///     let _ = result.token;
+/// }
+/// # Ok(()) }
+/// ```
+pub async fn authenticate_user(reason: Option<&str>) -> Result<BiometricAuthResult, JsValue> {
+    let biom = biometric_object()?;
+    let func = Reflect::get(&biom, &JsValue::from_str("authenticate"))?.dyn_into::<Function>()?;
+    let params = Object::new();
+    if let Some(reason) = reason {
+        Reflect::set(&params, &"reason".into(), &JsValue::from_str(reason))?;
+    }
+    let mut call = Some((biom, func, params));
+    let promise = Promise::new(&mut |resolve, reject| {
+        let Some((biom, func, params)) = call.take() else {
+            return;
+        };
+        let cb = Closure::once_into_js(move |authenticated: JsValue, token: JsValue| {
+            let _ = resolve.call1(&JsValue::NULL, &Array::of2(&authenticated, &token));
+        });
+        if let Err(err) = func.call2(&biom, &params, &cb) {
+            let _ = reject.call1(&JsValue::NULL, &err);
+        }
+    });
+    let outcome = Array::from(&JsFuture::from(promise).await?);
+    Ok(BiometricAuthResult {
+        authenticated: outcome.get(0).as_bool().unwrap_or(false),
+        token:         outcome.get(1).as_string().filter(|token| !token.is_empty())
+    })
+}
+
 fn biometric_object() -> Result<JsValue, JsValue> {
     let win = window().ok_or_else(|| JsValue::from_str("no window"))?;
     let tg = Reflect::get(&win, &JsValue::from_str("Telegram"))?;
@@ -374,6 +463,23 @@ mod tests {
         assert!(authenticate("abc", None, None).is_err());
     }
 
+    #[wasm_bindgen_test]
+    async fn authenticate_user_resolves_outcome() {
+        let biom = setup_biometric();
+        let func = Function::new_with_args(
+            "params, cb",
+            "this.reason = params.reason; cb(true, 'token');"
+        );
+        let _ = Reflect::set(&biom, &"authenticate".into(), &func);
+        let result = authenticate_user(Some("why")).await.expect("outcome");
+        assert!(result.authenticated);
+        assert_eq!(result.token.as_deref(), Some("token"));
+        assert_eq!(
+            Reflect::get(&biom, &"reason".into()).unwrap().as_string(),
+            Some("why".to_owned())
+        );
+    }
+
     #[wasm_bindgen_test]
     #[allow(dead_code, clippy::unused_unit)]
     fn update_biometric_token_ok() {
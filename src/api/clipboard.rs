@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use js_sys::{Function, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Document, window};
+
+/// Errors produced by [`copy_to_clipboard`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipboardError {
+    /// Neither the Clipboard API nor `document.execCommand` is available.
+    Unavailable,
+    /// The browser refused to write, e.g. without a user gesture.
+    Denied(String)
+}
+
+impl std::fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "Clipboard is not available"),
+            Self::Denied(msg) => write!(f, "Clipboard write denied: {msg}")
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+impl From<ClipboardError> for JsValue {
+    fn from(err: ClipboardError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Copies `text` to the clipboard.
+///
+/// Uses `navigator.clipboard.writeText` and falls back to
+/// `document.execCommand("copy")` on a hidden `textarea` for WebViews
+/// without the Clipboard API or where it rejects. Call it from a user
+/// gesture, such as a click handler, since browsers deny clipboard writes
+/// otherwise.
+///
+/// # Errors
+///
+/// Returns [`ClipboardError::Unavailable`] outside a browser and
+/// [`ClipboardError::Denied`] when both methods fail.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::api::clipboard::copy_to_clipboard;
+///
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// copy_to_clipboard("https://t.me/my_bot/app?startapp=ref_42").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn copy_to_clipboard(text: &str) -> Result<(), ClipboardError> {
+    let win = window().ok_or(ClipboardError::Unavailable)?;
+    let api_error = match write_text(&win, text) {
+        Some(promise) => match JsFuture::from(promise).await {
+            Ok(_) => return Ok(()),
+            Err(err) => Some(describe(&err))
+        },
+        None => None
+    };
+    let document = win.document().ok_or(ClipboardError::Unavailable)?;
+    match exec_copy(&document, text) {
+        Some(true) => Ok(()),
+        Some(false) => {
+            Err(ClipboardError::Denied(api_error.unwrap_or_else(|| {
+                "execCommand(\"copy\") returned false".into()
+            })))
+        }
+        None => Err(api_error.map_or(ClipboardError::Unavailable, ClipboardError::Denied))
+    }
+}
+
+/// Starts `navigator.clipboard.writeText(text)`, if the API exists.
+fn write_text(win: &web_sys::Window, text: &str) -> Option<Promise> {
+    let clipboard = Reflect::get(
+        &Reflect::get(win, &"navigator".into()).ok()?,
+        &"clipboard".into()
+    )
+    .ok()
+    .filter(|clipboard| clipboard.is_object())?;
+    Reflect::get(&clipboard, &"writeText".into())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?
+        .call1(&clipboard, &text.into())
+        .ok()?
+        .dyn_into::<Promise>()
+        .ok()
+}
+
+/// Copies through a temporary off-screen `textarea`; `None` when
+/// `execCommand` is missing.
+fn exec_copy(document: &Document, text: &str) -> Option<bool> {
+    let exec = Reflect::get(document, &"execCommand".into())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+    let body = document.body()?;
+    let area = document.create_element("textarea").ok()?;
+    let _ = area.set_attribute("readonly", "");
+    let _ = area.set_attribute("style", "position: fixed; top: -1000px; opacity: 0;");
+    let _ = Reflect::set(&area, &"value".into(), &text.into());
+    body.append_child(&area).ok()?;
+    let _ = Reflect::get(&area, &"select".into())
+        .ok()
+        .and_then(|select| select.dyn_into::<Function>().ok())
+        .map(|select| select.call0(&area));
+    let copied = exec.call1(document, &"copy".into()).ok()?.as_bool();
+    area.remove();
+    Some(copied.unwrap_or(false))
+}
+
+fn describe(err: &JsValue) -> String {
+    err.as_string()
+        .or_else(|| Reflect::get(err, &"message".into()).ok()?.as_string())
+        .unwrap_or_else(|| "unknown error".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::Object;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn error_messages_are_readable() {
+        assert_eq!(
+            ClipboardError::Denied("NotAllowedError".into()).to_string(),
+            "Clipboard write denied: NotAllowedError"
+        );
+        assert_eq!(
+            ClipboardError::Unavailable.to_string(),
+            "Clipboard is not available"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn uses_clipboard_api_when_available() {
+        let clipboard = Object::new();
+        let write =
+            Function::new_with_args("text", "this.written = text; return Promise.resolve();");
+        let _ = Reflect::set(&clipboard, &"writeText".into(), &write);
+        // `navigator.clipboard` is a read-only getter, so shadow it.
+        Function::new_with_args(
+            "clipboard",
+            "Object.defineProperty(navigator, 'clipboard', { value: clipboard, configurable: true });"
+        )
+        .call1(&JsValue::NULL, &clipboard)
+        .unwrap();
+
+        copy_to_clipboard("invite").await.unwrap();
+        let written = Reflect::get(&clipboard, &"written".into()).unwrap();
+        assert_eq!(written.as_string().as_deref(), Some("invite"));
+        let _ = Function::new_no_args("delete navigator.clipboard;").call0(&JsValue::NULL);
+    }
+}
@@ -1,10 +1,23 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration
+};
+
 use js_sys::{Array, Function, Promise, Reflect};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+#[cfg(feature = "macros")]
+pub use telegram_webapp_sdk_macros::CloudStored;
 use wasm_bindgen::{JsCast, prelude::*};
+use wasm_bindgen_futures::{JsFuture, spawn_local};
 use web_sys::window;
 
+/// Maximum length of a value stored in CloudStorage.
+pub const VALUE_LIMIT: usize = 4096;
+
 /// Returns the `Telegram.WebApp.CloudStorage` object.
 fn cloud_storage_object() -> Result<JsValue, JsValue> {
     let win = window().ok_or_else(|| JsValue::from_str("no window"))?;
@@ -151,6 +164,252 @@ pub fn get_keys() -> Result<Promise, JsValue> {
     func.call0(&storage)?.dyn_into::<Promise>()
 }
 
+type WatchCallback = RefCell<Box<dyn FnMut(Option<String>)>>;
+
+/// Polling state shared between [`Watch`] and its interval callback.
+struct WatchState {
+    key:      String,
+    last:     RefCell<Option<Option<String>>>,
+    busy:     Cell<bool>,
+    stopped:  Cell<bool>,
+    callback: WatchCallback
+}
+
+impl WatchState {
+    /// Reads the key unless a previous read is still running.
+    fn poll(self: &Rc<Self>) {
+        if self.busy.replace(true) {
+            return;
+        }
+        let state = Rc::clone(self);
+        spawn_local(async move {
+            let value = match get_item(&state.key) {
+                Ok(promise) => JsFuture::from(promise).await.ok(),
+                Err(_) => None
+            };
+            state.busy.set(false);
+            if let Some(value) = value {
+                state.update(value.as_string().filter(|raw| !raw.is_empty()));
+            }
+        });
+    }
+
+    /// Records `value`, notifying when it differs from the previous read.
+    fn update(&self, value: Option<String>) {
+        if self.stopped.get() {
+            return;
+        }
+        let previous = self.last.replace(Some(value.clone()));
+        if matches!(previous, Some(previous) if previous != value) {
+            (self.callback.borrow_mut())(value);
+        }
+    }
+}
+
+/// Polling watch created by [`watch`]; polling stops when it is dropped.
+#[must_use = "polling stops when the watch is dropped"]
+pub struct Watch {
+    interval_id: i32,
+    state:       Rc<WatchState>,
+    _tick:       Closure<dyn FnMut()>
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        self.state.stopped.set(true);
+        if let Some(win) = window() {
+            win.clear_interval_with_handle(self.interval_id);
+        }
+    }
+}
+
+/// Polls `key` every `interval` and calls `callback` with the new value
+/// whenever it changes.
+///
+/// CloudStorage has no change events, so this is the way to pick up values
+/// written by the bot or by another session. The first read only records
+/// the current value; `None` means the key is missing or empty. Failed reads
+/// are skipped and retried on the next tick.
+///
+/// # Errors
+/// Returns `Err(JsValue)` if no window is available or the interval cannot
+/// be scheduled.
+///
+/// # Examples
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::api::cloud_storage::watch;
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let settings = watch("settings", Duration::from_secs(30), |value| {
+///     let _ = value; // apply the settings
+/// })?;
+/// # drop(settings);
+/// # Ok(())
+/// # }
+/// ```
+pub fn watch(
+    key: &str,
+    interval: Duration,
+    callback: impl FnMut(Option<String>) + 'static
+) -> Result<Watch, JsValue> {
+    let win = window().ok_or_else(|| JsValue::from_str("no window"))?;
+    let state = Rc::new(WatchState {
+        key:      key.to_owned(),
+        last:     RefCell::new(None),
+        busy:     Cell::new(false),
+        stopped:  Cell::new(false),
+        callback: RefCell::new(Box::new(callback))
+    });
+    let tick = {
+        let state = Rc::clone(&state);
+        Closure::<dyn FnMut()>::new(move || state.poll())
+    };
+    let millis = i32::try_from(interval.as_millis()).unwrap_or(i32::MAX);
+    let interval_id = win.set_interval_with_callback_and_timeout_and_arguments_0(
+        tick.as_ref().unchecked_ref(),
+        millis
+    )?;
+    state.poll();
+    Ok(Watch {
+        interval_id,
+        state,
+        _tick: tick
+    })
+}
+
+/// A type persisted as JSON in Telegram CloudStorage under a fixed key.
+///
+/// Values are stored together with [`Self::VERSION`]. When a stored value
+/// has a different version, [`migrate`](Self::migrate) converts it; the
+/// default implementation discards it and [`load`](Self::load) returns
+/// `None`.
+///
+/// With the `macros` feature the trait can be derived with
+/// `#[cloud(key = "..", prefix = "..", version = N, migrate = path)]`. The key
+/// defaults to the type name in `snake_case` and is joined to the prefix with
+/// `_`; invalid keys are rejected at compile time.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::api::cloud_storage::CloudStored;
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct Settings {
+///     dark: bool
+/// }
+///
+/// impl CloudStored for Settings {
+///     const KEY: &'static str = "app_settings";
+/// }
+///
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let mut settings = Settings::load().await?.unwrap_or_default();
+/// settings.dark = true;
+/// settings.save().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait CloudStored: Serialize + DeserializeOwned {
+    /// Full CloudStorage key, including any prefix.
+    const KEY: &'static str;
+    /// Schema version stored alongside the value.
+    const VERSION: u32 = 1;
+
+    /// Converts a value stored with an older or newer schema `version`.
+    ///
+    /// # Errors
+    /// Implementations may return `Err(JsValue)` if the data cannot be
+    /// converted and should not be discarded.
+    fn migrate(version: u32, data: serde_json::Value) -> Result<Option<Self>, JsValue> {
+        let _ = (version, data);
+        Ok(None)
+    }
+
+    /// Loads the value, returning `None` when nothing is stored.
+    ///
+    /// # Errors
+    /// Returns `Err(JsValue)` if CloudStorage is unavailable or the stored
+    /// data cannot be decoded.
+    fn load() -> impl Future<Output = Result<Option<Self>, JsValue>> {
+        async {
+            let value = JsFuture::from(get_item(Self::KEY)?).await?;
+            match value.as_string() {
+                Some(raw) if !raw.is_empty() => decode(&raw),
+                _ => Ok(None)
+            }
+        }
+    }
+
+    /// Saves the value, replacing any stored one.
+    ///
+    /// # Errors
+    /// Returns `Err(JsValue)` if serialization fails, the encoded value
+    /// exceeds [`VALUE_LIMIT`], or CloudStorage rejects it.
+    fn save(&self) -> impl Future<Output = Result<(), JsValue>> {
+        async move {
+            let raw = encode(self)?;
+            JsFuture::from(set_item(Self::KEY, &raw)?).await?;
+            Ok(())
+        }
+    }
+
+    /// Removes the stored value.
+    ///
+    /// # Errors
+    /// Returns `Err(JsValue)` if CloudStorage is unavailable or the call
+    /// fails.
+    fn delete() -> impl Future<Output = Result<(), JsValue>> {
+        async {
+            JsFuture::from(remove_item(Self::KEY)?).await?;
+            Ok(())
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Envelope<'a, T> {
+    v:    u32,
+    data: &'a T
+}
+
+#[derive(Deserialize)]
+struct StoredEnvelope {
+    v:    u32,
+    data: serde_json::Value
+}
+
+/// Encodes `value` with its schema version.
+fn encode<T: CloudStored>(value: &T) -> Result<String, JsValue> {
+    let raw = serde_json::to_string(&Envelope {
+        v:    T::VERSION,
+        data: value
+    })
+    .map_err(|err| JsValue::from_str(&err.to_string()))?;
+    if raw.chars().count() > VALUE_LIMIT {
+        return Err(JsValue::from_str(&format!(
+            "value for `{}` exceeds {VALUE_LIMIT} characters",
+            T::KEY
+        )));
+    }
+    Ok(raw)
+}
+
+/// Decodes a stored value, migrating it when the version differs.
+fn decode<T: CloudStored>(raw: &str) -> Result<Option<T>, JsValue> {
+    let stored: StoredEnvelope =
+        serde_json::from_str(raw).map_err(|err| JsValue::from_str(&err.to_string()))?;
+    if stored.v != T::VERSION {
+        return T::migrate(stored.v, stored.data);
+    }
+    serde_json::from_value(stored.data)
+        .map(Some)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(dead_code)]
@@ -190,6 +449,33 @@ mod tests {
         );
     }
 
+    #[wasm_bindgen_test(async)]
+    async fn watch_reports_changes_only() {
+        let storage = setup_cloud_storage();
+        let items = Object::new();
+        let _ = Reflect::set(&storage, &"items".into(), &items);
+        let _ = Reflect::set(&items, &"theme".into(), &"dark".into());
+        let func =
+            Function::new_with_args("key", "return Promise.resolve(this.items[key] ?? '');");
+        let _ = Reflect::set(&storage, &"getItem".into(), &func);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&seen);
+        let watch = watch("theme", Duration::from_millis(5), move |value| {
+            sink.borrow_mut().push(value);
+        })
+        .unwrap();
+        crate::utils::future::sleep(Duration::from_millis(30)).await;
+        assert!(seen.borrow().is_empty());
+
+        let _ = Reflect::set(&items, &"theme".into(), &"light".into());
+        crate::utils::future::sleep(Duration::from_millis(30)).await;
+        let _ = Reflect::delete_property(&items, &"theme".into());
+        crate::utils::future::sleep(Duration::from_millis(30)).await;
+        drop(watch);
+        assert_eq!(*seen.borrow(), [Some("light".to_string()), None]);
+    }
+
     #[wasm_bindgen_test]
     fn get_item_err() {
         let _ = setup_cloud_storage();
@@ -308,4 +594,76 @@ mod tests {
         let _ = setup_cloud_storage();
         assert!(get_keys().is_err());
     }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Prefs {
+        dark: bool
+    }
+
+    impl CloudStored for Prefs {
+        const KEY: &'static str = "prefs";
+        const VERSION: u32 = 2;
+
+        fn migrate(version: u32, data: serde_json::Value) -> Result<Option<Self>, JsValue> {
+            Ok((version == 1).then(|| Prefs {
+                dark: data == "dark"
+            }))
+        }
+    }
+
+    #[test]
+    fn stored_value_round_trips() {
+        let raw = encode(&Prefs {
+            dark: true
+        })
+        .unwrap();
+        assert_eq!(raw, r#"{"v":2,"data":{"dark":true}}"#);
+        assert_eq!(
+            decode::<Prefs>(&raw).unwrap(),
+            Some(Prefs {
+                dark: true
+            })
+        );
+    }
+
+    #[test]
+    fn other_versions_are_migrated() {
+        let old = decode::<Prefs>(r#"{"v":1,"data":"dark"}"#).unwrap();
+        assert_eq!(
+            old,
+            Some(Prefs {
+                dark: true
+            })
+        );
+        assert_eq!(decode::<Prefs>(r#"{"v":3,"data":null}"#).unwrap(), None);
+    }
+
+    #[cfg(feature = "macros")]
+    mod derive {
+        use serde::{Deserialize, Serialize};
+        use wasm_bindgen::JsValue;
+
+        use crate::api::cloud_storage::{CloudStored, decode};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize, CloudStored)]
+        #[cloud(prefix = "shop", version = 2, migrate = upgrade)]
+        struct CartItems {
+            items: Vec<String>
+        }
+
+        fn upgrade(version: u32, data: serde_json::Value) -> Result<Option<CartItems>, JsValue> {
+            let item = data.as_str().filter(|_| version == 1);
+            Ok(item.map(|item| CartItems {
+                items: vec![item.to_owned()]
+            }))
+        }
+
+        #[test]
+        fn derive_builds_prefixed_key() {
+            assert_eq!(CartItems::KEY, "shop_cart_items");
+            assert_eq!(CartItems::VERSION, 2);
+            let migrated = decode::<CartItems>(r#"{"v":1,"data":"burger"}"#).unwrap();
+            assert_eq!(migrated.unwrap().items, ["burger"]);
+        }
+    }
 }
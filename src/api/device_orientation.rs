@@ -5,7 +5,11 @@ use js_sys::{Function, Reflect};
 use wasm_bindgen::{JsCast, prelude::*};
 use web_sys::window;
 
-use super::events;
+use super::{
+    events,
+    sensor::{self, SensorObject}
+};
+use crate::webapp::EventHandle;
 
 /// Device orientation angles in degrees.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -91,6 +95,47 @@ pub fn on_started(callback: &Closure<dyn Fn()>) -> Result<(), JsValue> {
     events::on_event("deviceOrientationStarted", callback)
 }
 
+/// Calls `callback` with the orientation on every `deviceOrientationChanged`
+/// event.
+///
+/// Unlike polling the getter from [`on_changed`], the sensor object is
+/// resolved once and each event reads the axes as plain numbers, without
+/// allocating JS strings or heap slots. The callback is unregistered when the
+/// returned handle is dropped.
+///
+/// # Errors
+/// Returns [`JsValue`] if the WebApp or the sensor is unavailable.
+///
+/// # Examples
+/// ```no_run
+/// # use telegram_webapp_sdk::api::device_orientation;
+/// device_orientation::start()?;
+/// let _handle = device_orientation::on_reading(|reading| {
+///     let _ = reading.alpha;
+/// })?;
+/// # Ok::<(), wasm_bindgen::JsValue>(())
+/// ```
+pub fn on_reading<F>(callback: F) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+where
+    F: 'static + FnMut(&Orientation)
+{
+    sensor::subscribe(
+        "DeviceOrientation",
+        "deviceOrientationChanged",
+        read,
+        callback
+    )
+}
+
+fn read(sensor: &SensorObject) -> Option<Orientation> {
+    let (alpha, beta, gamma) = sensor::triple(sensor.alpha(), sensor.beta(), sensor.gamma())?;
+    Some(Orientation {
+        alpha,
+        beta,
+        gamma
+    })
+}
+
 /// Registers a callback for `deviceOrientationChanged` event.
 pub fn on_changed(callback: &Closure<dyn Fn()>) -> Result<(), JsValue> {
     events::on_event("deviceOrientationChanged", callback)
@@ -0,0 +1,195 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Gestures recognized from motion sensor readings.
+
+use std::time::Duration;
+
+use wasm_bindgen::JsValue;
+
+use super::{
+    accelerometer::{self, Acceleration},
+    motion_filter::HighPassFilter
+};
+use crate::webapp::EventHandle;
+
+/// Suggested shake threshold in meters per second squared.
+pub const DEFAULT_SHAKE_THRESHOLD: f64 = 12.0;
+
+/// Peaks needed within [`SHAKE_WINDOW`] to count as a shake.
+const SHAKE_PEAKS: usize = 3;
+/// Time in which the peaks of one shake must occur.
+const SHAKE_WINDOW: Duration = Duration::from_millis(800);
+/// Quiet period after a shake during which readings are ignored.
+const SHAKE_COOLDOWN: Duration = Duration::from_secs(1);
+/// Smoothing factor of the filter removing gravity.
+const GRAVITY_ALPHA: f64 = 0.1;
+
+/// Recognizes shakes in a stream of accelerometer readings.
+///
+/// Gravity is removed with a [`HighPassFilter`]. A shake is three movements
+/// stronger than the threshold within 800 ms; a single bump or a steady push
+/// does not count. After a shake, readings are ignored for one second so a
+/// long shake is reported once.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::api::{accelerometer::Acceleration, gestures::ShakeDetector};
+///
+/// let mut detector = ShakeDetector::new(12.0);
+/// let rest = Acceleration {
+///     x: 0.0,
+///     y: 0.0,
+///     z: 9.8
+/// };
+/// assert!(!detector.feed(rest, 0.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShakeDetector {
+    threshold:  f64,
+    filter:     HighPassFilter,
+    above:      bool,
+    peaks:      Vec<f64>,
+    last_shake: Option<f64>
+}
+
+impl ShakeDetector {
+    /// Creates a detector for movements stronger than `threshold` meters per
+    /// second squared.
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            filter: HighPassFilter::new(GRAVITY_ALPHA),
+            above: false,
+            peaks: Vec::with_capacity(SHAKE_PEAKS),
+            last_shake: None
+        }
+    }
+
+    /// Feeds a reading taken at `now_ms` milliseconds and returns whether it
+    /// completes a shake.
+    pub fn feed(&mut self, reading: Acceleration, now_ms: f64) -> bool {
+        let motion = self.filter.apply(reading);
+        let magnitude = (motion.x * motion.x + motion.y * motion.y + motion.z * motion.z).sqrt();
+        let rising = magnitude > self.threshold && !self.above;
+        self.above = magnitude > self.threshold;
+
+        if let Some(last) = self.last_shake
+            && now_ms - last < SHAKE_COOLDOWN.as_secs_f64() * 1000.0
+        {
+            return false;
+        }
+        if !rising {
+            return false;
+        }
+
+        let window = SHAKE_WINDOW.as_secs_f64() * 1000.0;
+        self.peaks.retain(|peak| now_ms - peak <= window);
+        self.peaks.push(now_ms);
+        if self.peaks.len() < SHAKE_PEAKS {
+            return false;
+        }
+        self.peaks.clear();
+        self.last_shake = Some(now_ms);
+        true
+    }
+
+    /// Forgets previous readings.
+    pub fn reset(&mut self) {
+        self.filter.reset();
+        self.above = false;
+        self.peaks.clear();
+        self.last_shake = None;
+    }
+}
+
+/// Calls `callback` whenever the device is shaken harder than `threshold`
+/// meters per second squared, see [`ShakeDetector`].
+///
+/// The accelerometer must be started with [`accelerometer::start`].
+///
+/// # Errors
+/// Returns [`JsValue`] if the WebApp or the accelerometer is unavailable.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::{
+///     accelerometer,
+///     gestures::{DEFAULT_SHAKE_THRESHOLD, on_shake}
+/// };
+///
+/// accelerometer::start()?;
+/// let _handle = on_shake(DEFAULT_SHAKE_THRESHOLD, || { /* shuffle */ })?;
+/// # Ok::<(), wasm_bindgen::JsValue>(())
+/// ```
+pub fn on_shake<F>(
+    threshold: f64,
+    mut callback: F
+) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+where
+    F: 'static + FnMut()
+{
+    let mut detector = ShakeDetector::new(threshold);
+    accelerometer::on_reading(move |reading| {
+        if detector.feed(*reading, js_sys::Date::now()) {
+            callback();
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REST: Acceleration = Acceleration {
+        x: 0.0,
+        y: 0.0,
+        z: 9.8
+    };
+
+    fn jolt(x: f64) -> Acceleration {
+        Acceleration {
+            x,
+            ..REST
+        }
+    }
+
+    /// Feeds alternating jolts every 100 ms starting at `start_ms` and
+    /// returns the number of detected shakes.
+    fn shake(detector: &mut ShakeDetector, start_ms: f64, jolts: usize) -> usize {
+        let mut detected = 0;
+        for i in 0..jolts {
+            let now = start_ms + i as f64 * 100.0;
+            let x = if i % 2 == 0 { 30.0 } else { -30.0 };
+            detected += usize::from(detector.feed(jolt(x), now));
+            detected += usize::from(detector.feed(REST, now + 50.0));
+        }
+        detected
+    }
+
+    #[test]
+    fn single_bump_is_not_a_shake() {
+        let mut detector = ShakeDetector::new(DEFAULT_SHAKE_THRESHOLD);
+        detector.feed(REST, 0.0);
+        assert!(!detector.feed(jolt(30.0), 100.0));
+        assert!(!detector.feed(REST, 150.0));
+    }
+
+    #[test]
+    fn repeated_jolts_are_one_shake() {
+        let mut detector = ShakeDetector::new(DEFAULT_SHAKE_THRESHOLD);
+        detector.feed(REST, 0.0);
+        assert_eq!(shake(&mut detector, 100.0, 6), 1);
+        assert_eq!(shake(&mut detector, 2000.0, 3), 1);
+    }
+
+    #[test]
+    fn slow_jolts_are_ignored() {
+        let mut detector = ShakeDetector::new(DEFAULT_SHAKE_THRESHOLD);
+        detector.feed(REST, 0.0);
+        for i in 1..=5 {
+            assert!(!detector.feed(jolt(30.0), i as f64 * 1000.0));
+            detector.feed(REST, i as f64 * 1000.0 + 50.0);
+        }
+    }
+}
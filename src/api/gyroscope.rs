@@ -5,7 +5,11 @@ use js_sys::{Function, Reflect};
 use wasm_bindgen::{JsCast, prelude::*};
 use web_sys::window;
 
-use super::events;
+use super::{
+    events,
+    sensor::{self, SensorObject}
+};
+use crate::webapp::EventHandle;
 
 /// Angular velocity around three axes in radians per second.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -91,6 +95,42 @@ pub fn on_started(callback: &Closure<dyn Fn()>) -> Result<(), JsValue> {
     events::on_event("gyroscopeStarted", callback)
 }
 
+/// Calls `callback` with the angular velocity on every `gyroscopeChanged`
+/// event.
+///
+/// Unlike polling the getter from [`on_changed`], the sensor object is
+/// resolved once and each event reads the axes as plain numbers, without
+/// allocating JS strings or heap slots. The callback is unregistered when the
+/// returned handle is dropped.
+///
+/// # Errors
+/// Returns [`JsValue`] if the WebApp or the sensor is unavailable.
+///
+/// # Examples
+/// ```no_run
+/// # use telegram_webapp_sdk::api::gyroscope;
+/// gyroscope::start()?;
+/// let _handle = gyroscope::on_reading(|reading| {
+///     let _ = reading.x;
+/// })?;
+/// # Ok::<(), wasm_bindgen::JsValue>(())
+/// ```
+pub fn on_reading<F>(callback: F) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+where
+    F: 'static + FnMut(&AngularVelocity)
+{
+    sensor::subscribe("Gyroscope", "gyroscopeChanged", read, callback)
+}
+
+fn read(sensor: &SensorObject) -> Option<AngularVelocity> {
+    let (x, y, z) = sensor::triple(sensor.x(), sensor.y(), sensor.z())?;
+    Some(AngularVelocity {
+        x,
+        y,
+        z
+    })
+}
+
 /// Registers a callback for `gyroscopeChanged` event.
 pub fn on_changed(callback: &Closure<dyn Fn()>) -> Result<(), JsValue> {
     events::on_event("gyroscopeChanged", callback)
@@ -1,8 +1,10 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use js_sys::{Function, Reflect};
+use js_sys::{Function, Promise, Reflect};
+use serde::Deserialize;
 use wasm_bindgen::{JsCast, prelude::*};
+use wasm_bindgen_futures::JsFuture;
 use web_sys::window;
 
 /// Initializes `Telegram.WebApp.locationManager`.
@@ -106,6 +108,125 @@ pub fn on_location_requested(callback: &Closure<dyn Fn()>) -> Result<(), JsValue
     add_event_listener("locationRequested", callback)
 }
 
+/// Location reported by `getLocation` and `locationRequested`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct LocationData {
+    /// Latitude in degrees.
+    pub latitude:            f64,
+    /// Longitude in degrees.
+    pub longitude:           f64,
+    /// Altitude above sea level in meters.
+    pub altitude:            Option<f64>,
+    /// Direction of movement in degrees, `0` being north.
+    pub course:              Option<f64>,
+    /// Speed in meters per second.
+    pub speed:               Option<f64>,
+    /// Accuracy of the coordinates in meters.
+    pub horizontal_accuracy: Option<f64>,
+    /// Accuracy of the altitude in meters.
+    pub vertical_accuracy:   Option<f64>,
+    /// Accuracy of the course in degrees.
+    pub course_accuracy:     Option<f64>,
+    /// Accuracy of the speed in meters per second.
+    pub speed_accuracy:      Option<f64>
+}
+
+impl LocationData {
+    /// Decodes a location object, returning `None` for `null` or malformed
+    /// values.
+    pub fn from_js(value: JsValue) -> Option<Self> {
+        if value.is_null() || value.is_undefined() {
+            return None;
+        }
+        serde_wasm_bindgen::from_value(value).ok()
+    }
+
+    /// Extracts the location from a `locationRequested` payload, returning
+    /// `None` when access was denied.
+    pub fn from_event(payload: &JsValue) -> Option<Self> {
+        Reflect::get(payload, &"locationData".into())
+            .ok()
+            .and_then(Self::from_js)
+    }
+}
+
+/// Snapshot of the `LocationManager` flags.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LocationStatus {
+    /// Whether `LocationManager` finished initializing.
+    pub inited:           bool,
+    /// Whether location services are available.
+    pub available:        bool,
+    /// Whether access to the location was requested.
+    pub access_requested: bool,
+    /// Whether access to the location was granted.
+    pub access_granted:   bool
+}
+
+impl LocationStatus {
+    /// Reads the current flags, treating unavailable ones as `false`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use telegram_webapp_sdk::api::location_manager::LocationStatus;
+    ///
+    /// let status = LocationStatus::current();
+    /// let _ = status.available;
+    /// ```
+    pub fn current() -> Self {
+        let flag = |name: &str| {
+            location_manager_object()
+                .and_then(|manager| Reflect::get(&manager, &JsValue::from_str(name)))
+                .ok()
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false)
+        };
+        Self {
+            inited:           flag("isInited"),
+            available:        flag("isLocationAvailable"),
+            access_requested: flag("isAccessRequested"),
+            access_granted:   flag("isAccessGranted")
+        }
+    }
+}
+
+/// Calls `getLocation(callback)` and resolves with the reported location.
+///
+/// Resolves with `None` when the user denied access or the location is
+/// unavailable.
+///
+/// # Errors
+/// Returns `Err(JsValue)` if the JavaScript call fails or `locationManager` is
+/// missing.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::location_manager::request_location;
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// if let Some(location) = request_location().await? {
+///     let _ = (location.latitude, location.longitude);
+/// }
+/// # Ok(()) }
+/// ```
+pub async fn request_location() -> Result<Option<LocationData>, JsValue> {
+    let manager = location_manager_object()?;
+    let func =
+        Reflect::get(&manager, &JsValue::from_str("getLocation"))?.dyn_into::<Function>()?;
+    let mut call = Some((manager, func));
+    let promise = Promise::new(&mut |resolve, reject| {
+        let Some((manager, func)) = call.take() else {
+            return;
+        };
+        let cb = Closure::once_into_js(move |data: JsValue| {
+            let _ = resolve.call1(&JsValue::NULL, &data);
+        });
+        if let Err(err) = func.call1(&manager, &cb) {
+            let _ = reject.call1(&JsValue::NULL, &err);
+        }
+    });
+    Ok(LocationData::from_js(JsFuture::from(promise).await?))
+}
+
 fn add_event_listener(event: &str, callback: &Closure<dyn Fn()>) -> Result<(), JsValue> {
     let webapp = webapp_object()?;
     let on_event = Reflect::get(&webapp, &JsValue::from_str("onEvent"))?.dyn_into::<Function>()?;
@@ -190,6 +311,39 @@ mod tests {
         assert!(get_location().is_err());
     }
 
+    #[wasm_bindgen_test]
+    async fn request_location_resolves_data() {
+        let (_webapp, manager) = setup_location_manager();
+        let func = Function::new_with_args(
+            "cb",
+            "cb({ latitude: 1.5, longitude: 2.5, altitude: null });"
+        );
+        let _ = Reflect::set(&manager, &"getLocation".into(), &func);
+        let location = request_location().await.expect("location");
+        assert_eq!(
+            location,
+            Some(LocationData {
+                latitude: 1.5,
+                longitude: 2.5,
+                ..LocationData::default()
+            })
+        );
+
+        let denied = Function::new_with_args("cb", "cb(null);");
+        let _ = Reflect::set(&manager, &"getLocation".into(), &denied);
+        assert_eq!(request_location().await.expect("no location"), None);
+    }
+
+    #[test]
+    fn location_data_deserializes_telegram_fields() {
+        let data: LocationData = serde_json::from_str(
+            r#"{"latitude":1.0,"longitude":2.0,"speed":3.0,"horizontal_accuracy":null}"#
+        )
+        .expect("location");
+        assert_eq!(data.speed, Some(3.0));
+        assert_eq!(data.horizontal_accuracy, None);
+    }
+
     #[wasm_bindgen_test]
     #[allow(dead_code, clippy::unused_unit)]
     fn open_settings_ok() {
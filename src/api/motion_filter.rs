@@ -0,0 +1,279 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Smoothing filters for raw [`Acceleration`] readings.
+//!
+//! Raw accelerometer samples are noisy and mix gravity with the motion of
+//! the device. [`LowPassFilter`] keeps the slowly changing part,
+//! [`HighPassFilter`] keeps the quick changes and [`GravitySeparator`]
+//! reports both at once.
+
+use super::accelerometer::Acceleration;
+
+/// Standard gravity in meters per second squared.
+pub const STANDARD_GRAVITY: f64 = 9.806_65;
+
+/// Returns the smoothing factor of a first-order low-pass filter with the
+/// given cutoff frequency for samples arriving at `sample_rate_hz`.
+///
+/// The result is clamped to `0.0..=1.0`; a non-positive sample rate yields
+/// `1.0`, i.e. no smoothing.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::api::motion_filter::smoothing_factor;
+///
+/// let alpha = smoothing_factor(5.0, 60.0);
+/// assert!(alpha > 0.3 && alpha < 0.4);
+/// ```
+pub fn smoothing_factor(cutoff_hz: f64, sample_rate_hz: f64) -> f64 {
+    if sample_rate_hz <= 0.0 {
+        return 1.0;
+    }
+    let rc = 1.0 / (2.0 * std::f64::consts::PI * cutoff_hz.max(0.0));
+    let dt = 1.0 / sample_rate_hz;
+    (dt / (rc + dt)).clamp(0.0, 1.0)
+}
+
+/// Exponential low-pass filter passing slow changes such as gravity.
+///
+/// Each reading moves the output towards the input by the smoothing factor
+/// `alpha`: `1.0` passes the input unchanged, values close to `0.0` smooth
+/// heavily. The first reading is passed through as is.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::api::{accelerometer::Acceleration, motion_filter::LowPassFilter};
+///
+/// let mut filter = LowPassFilter::new(0.5);
+/// filter.apply(Acceleration {
+///     x: 0.0,
+///     y: 0.0,
+///     z: 0.0
+/// });
+/// let smooth = filter.apply(Acceleration {
+///     x: 2.0,
+///     y: 0.0,
+///     z: 0.0
+/// });
+/// assert_eq!(smooth.x, 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LowPassFilter {
+    alpha: f64,
+    state: Option<Acceleration>
+}
+
+impl LowPassFilter {
+    /// Creates a filter with smoothing factor `alpha`, clamped to
+    /// `0.0..=1.0`.
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            state: None
+        }
+    }
+
+    /// Creates a filter with the given cutoff frequency, see
+    /// [`smoothing_factor`].
+    pub fn with_cutoff(cutoff_hz: f64, sample_rate_hz: f64) -> Self {
+        Self::new(smoothing_factor(cutoff_hz, sample_rate_hz))
+    }
+
+    /// Returns the smoothing factor.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Feeds a reading and returns the filtered value.
+    pub fn apply(&mut self, reading: Acceleration) -> Acceleration {
+        let output = match self.state {
+            Some(prev) => Acceleration {
+                x: prev.x + self.alpha * (reading.x - prev.x),
+                y: prev.y + self.alpha * (reading.y - prev.y),
+                z: prev.z + self.alpha * (reading.z - prev.z)
+            },
+            None => reading
+        };
+        self.state = Some(output);
+        output
+    }
+
+    /// Returns the last filtered value.
+    pub fn value(&self) -> Option<Acceleration> {
+        self.state
+    }
+
+    /// Forgets previous readings.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// High-pass filter passing quick changes such as shakes and taps.
+///
+/// Returns the difference between the reading and a [`LowPassFilter`] with
+/// the same smoothing factor, so the output settles at zero while the
+/// device is held still.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HighPassFilter {
+    low_pass: LowPassFilter
+}
+
+impl HighPassFilter {
+    /// Creates a filter with smoothing factor `alpha`, clamped to
+    /// `0.0..=1.0`.
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            low_pass: LowPassFilter::new(alpha)
+        }
+    }
+
+    /// Creates a filter with the given cutoff frequency, see
+    /// [`smoothing_factor`].
+    pub fn with_cutoff(cutoff_hz: f64, sample_rate_hz: f64) -> Self {
+        Self::new(smoothing_factor(cutoff_hz, sample_rate_hz))
+    }
+
+    /// Feeds a reading and returns the filtered value.
+    pub fn apply(&mut self, reading: Acceleration) -> Acceleration {
+        let slow = self.low_pass.apply(reading);
+        Acceleration {
+            x: reading.x - slow.x,
+            y: reading.y - slow.y,
+            z: reading.z - slow.z
+        }
+    }
+
+    /// Forgets previous readings.
+    pub fn reset(&mut self) {
+        self.low_pass.reset();
+    }
+}
+
+/// Reading split into gravity and the acceleration caused by the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionComponents {
+    /// Estimated gravity vector.
+    pub gravity: Acceleration,
+    /// Reading with gravity removed.
+    pub linear:  Acceleration
+}
+
+/// Separates gravity from user motion with a low-pass filter.
+///
+/// The default smoothing factor of `0.1` suits readings at 30–60 Hz.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::{accelerometer, motion_filter::GravitySeparator};
+///
+/// let mut separator = GravitySeparator::default();
+/// let handle = accelerometer::on_reading(move |reading| {
+///     let motion = separator.split(*reading);
+///     let _ = motion.linear;
+/// })?;
+/// # drop(handle);
+/// # Ok::<(), wasm_bindgen::JsValue>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GravitySeparator {
+    low_pass: LowPassFilter
+}
+
+impl Default for GravitySeparator {
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl GravitySeparator {
+    /// Creates a separator with smoothing factor `alpha`, clamped to
+    /// `0.0..=1.0`.
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            low_pass: LowPassFilter::new(alpha)
+        }
+    }
+
+    /// Feeds a reading and returns its gravity and linear components.
+    pub fn split(&mut self, reading: Acceleration) -> MotionComponents {
+        let gravity = self.low_pass.apply(reading);
+        MotionComponents {
+            gravity,
+            linear: Acceleration {
+                x: reading.x - gravity.x,
+                y: reading.y - gravity.y,
+                z: reading.z - gravity.z
+            }
+        }
+    }
+
+    /// Returns the current gravity estimate.
+    pub fn gravity(&self) -> Option<Acceleration> {
+        self.low_pass.value()
+    }
+
+    /// Forgets previous readings.
+    pub fn reset(&mut self) {
+        self.low_pass.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STILL: Acceleration = Acceleration {
+        x: 0.0,
+        y: 0.0,
+        z: STANDARD_GRAVITY
+    };
+
+    #[test]
+    fn low_pass_converges_to_constant_input() {
+        let mut filter = LowPassFilter::new(0.2);
+        filter.apply(Acceleration {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0
+        });
+        let mut out = STILL;
+        for _ in 0..100 {
+            out = filter.apply(STILL);
+        }
+        assert!((out.z - STANDARD_GRAVITY).abs() < 1e-6);
+    }
+
+    #[test]
+    fn high_pass_settles_at_zero_and_passes_spikes() {
+        let mut filter = HighPassFilter::new(0.1);
+        for _ in 0..200 {
+            filter.apply(STILL);
+        }
+        let spike = filter.apply(Acceleration {
+            x: 5.0,
+            ..STILL
+        });
+        assert!(spike.x > 4.0);
+        assert!(spike.z.abs() < 1e-6);
+    }
+
+    #[test]
+    fn gravity_separator_removes_gravity() {
+        let mut separator = GravitySeparator::default();
+        for _ in 0..200 {
+            separator.split(STILL);
+        }
+        let motion = separator.split(STILL);
+        assert!((motion.gravity.z - STANDARD_GRAVITY).abs() < 1e-6);
+        assert!(motion.linear.z.abs() < 1e-6);
+    }
+
+    #[test]
+    fn smoothing_factor_is_clamped() {
+        assert_eq!(smoothing_factor(5.0, 0.0), 1.0);
+        assert_eq!(smoothing_factor(0.0, 60.0), 0.0);
+        assert_eq!(LowPassFilter::new(2.0).alpha(), 1.0);
+    }
+}
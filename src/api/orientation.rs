@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::cell::{Cell, RefCell};
+
+use wasm_bindgen::JsValue;
+
+use crate::{
+    bus::{self, Subscription, WebAppEvent},
+    webapp::TelegramWebApp
+};
+
+/// Screen orientation derived from the viewport dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenOrientation {
+    /// The viewport is at least as tall as it is wide.
+    Portrait,
+    /// The viewport is wider than it is tall.
+    Landscape
+}
+
+impl ScreenOrientation {
+    /// Classifies a viewport of `width` by `height` pixels.
+    ///
+    /// # Examples
+    /// ```
+    /// use telegram_webapp_sdk::api::orientation::ScreenOrientation;
+    ///
+    /// assert_eq!(
+    ///     ScreenOrientation::from_size(800.0, 400.0),
+    ///     ScreenOrientation::Landscape
+    /// );
+    /// ```
+    pub fn from_size(width: f64, height: f64) -> Self {
+        if width > height {
+            Self::Landscape
+        } else {
+            Self::Portrait
+        }
+    }
+
+    /// Returns the value passed to `WebApp.lockOrientation`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Portrait => "portrait",
+            Self::Landscape => "landscape"
+        }
+    }
+}
+
+/// Current orientation together with the lock state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrientationState {
+    /// Orientation of the viewport.
+    pub orientation: ScreenOrientation,
+    /// Whether `WebApp.isOrientationLocked` is set.
+    pub locked:      bool
+}
+
+/// Returns the current orientation, or `None` outside Telegram or before the
+/// viewport is known.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::orientation::{ScreenOrientation, current};
+///
+/// if let Some(state) = current() {
+///     let wide = state.orientation == ScreenOrientation::Landscape;
+///     let _ = wide;
+/// }
+/// ```
+pub fn current() -> Option<OrientationState> {
+    let app = TelegramWebApp::instance()?;
+    Some(OrientationState {
+        orientation: ScreenOrientation::from_size(app.viewport_width()?, app.viewport_height()?),
+        locked:      app.is_orientation_locked()
+    })
+}
+
+/// Calls `callback` whenever the orientation flips, until the returned
+/// [`Subscription`] is dropped.
+///
+/// Viewport changes that keep the orientation, such as expanding the app,
+/// are ignored.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::orientation;
+///
+/// let subscription = orientation::on_change(|state| {
+///     let _ = state.orientation; // switch layout
+/// });
+/// # drop(subscription);
+/// ```
+pub fn on_change(callback: impl FnMut(OrientationState) + 'static) -> Subscription {
+    let last = Cell::new(current().map(|state| state.orientation));
+    let callback = RefCell::new(callback);
+    bus::subscribe(move |event| {
+        if !matches!(
+            event,
+            WebAppEvent::ViewportChanged { .. } | WebAppEvent::FullscreenChanged
+        ) {
+            return;
+        }
+        let Some(state) = current() else {
+            return;
+        };
+        if last.replace(Some(state.orientation)) != Some(state.orientation) {
+            (callback.borrow_mut())(state);
+        }
+    })
+}
+
+/// Orientation lock released when dropped.
+///
+/// Only unlocks if the orientation was not already locked when the guard
+/// was created, so nested scenes keep the outer lock.
+#[must_use = "the orientation is unlocked when the guard is dropped"]
+pub struct OrientationLock {
+    app:    TelegramWebApp,
+    unlock: bool
+}
+
+impl OrientationLock {
+    /// Locks the orientation to landscape.
+    ///
+    /// Telegram locks the orientation the device is currently held in, so
+    /// acquire the lock once [`current`] reports
+    /// [`ScreenOrientation::Landscape`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `Telegram.WebApp` is unavailable or the JS call
+    /// fails.
+    pub fn landscape() -> Result<Self, JsValue> {
+        let app = TelegramWebApp::try_instance()?;
+        let unlock = !app.is_orientation_locked();
+        app.lock_orientation(ScreenOrientation::Landscape.as_str())?;
+        Ok(Self {
+            app,
+            unlock
+        })
+    }
+}
+
+impl Drop for OrientationLock {
+    fn drop(&mut self) {
+        if self.unlock {
+            let _ = self.app.unlock_orientation();
+        }
+    }
+}
+
+/// Runs `scene` with the orientation locked to landscape.
+///
+/// The lock is released when `scene` returns or unwinds.
+///
+/// # Errors
+/// Returns [`JsValue`] if the orientation cannot be locked; `scene` is not
+/// run in that case.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::{api::orientation::lock_landscape_for, webapp::TelegramWebApp};
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let app = TelegramWebApp::try_instance()?;
+/// lock_landscape_for(|| app.request_fullscreen())??;
+/// # Ok(())
+/// # }
+/// ```
+pub fn lock_landscape_for<R>(scene: impl FnOnce() -> R) -> Result<R, JsValue> {
+    let _lock = OrientationLock::landscape()?;
+    Ok(scene())
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Function, Object, Reflect};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::window;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn classifies_viewport() {
+        assert_eq!(
+            ScreenOrientation::from_size(390.0, 844.0),
+            ScreenOrientation::Portrait
+        );
+        assert_eq!(
+            ScreenOrientation::from_size(500.0, 500.0),
+            ScreenOrientation::Portrait
+        );
+        assert_eq!(
+            ScreenOrientation::from_size(844.0, 390.0),
+            ScreenOrientation::Landscape
+        );
+    }
+
+    fn setup_webapp() -> Object {
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let _ = Reflect::set(&webapp, &"viewportWidth".into(), &844.into());
+        let _ = Reflect::set(&webapp, &"viewportHeight".into(), &390.into());
+        let _ = Reflect::set(&webapp, &"isOrientationLocked".into(), &JsValue::FALSE);
+        let lock = Function::new_no_args("this.isOrientationLocked = true;");
+        let unlock = Function::new_no_args("this.isOrientationLocked = false;");
+        let _ = Reflect::set(&webapp, &"lockOrientation".into(), &lock);
+        let _ = Reflect::set(&webapp, &"unlockOrientation".into(), &unlock);
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        webapp
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn lock_is_released_after_scene() {
+        let _webapp = setup_webapp();
+        let locked = lock_landscape_for(|| current().unwrap().locked).unwrap();
+        assert!(locked);
+        assert_eq!(
+            current(),
+            Some(OrientationState {
+                orientation: ScreenOrientation::Landscape,
+                locked:      false
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn nested_lock_keeps_outer_lock() {
+        let _webapp = setup_webapp();
+        let outer = OrientationLock::landscape().unwrap();
+        lock_landscape_for(|| ()).unwrap();
+        assert!(current().unwrap().locked);
+        drop(outer);
+        assert!(!current().unwrap().locked);
+    }
+}
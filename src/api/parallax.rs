@@ -0,0 +1,245 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Tilt-driven parallax built on device orientation readings.
+//!
+//! [`TiltMapper`] turns orientation angles into smoothed offsets in
+//! `-1.0..=1.0`. [`on_tilt`] delivers them to a callback, e.g. to update a
+//! framework signal, and [`apply_to`] moves an element directly.
+
+use wasm_bindgen::JsValue;
+use web_sys::HtmlElement;
+
+use super::device_orientation::{self, Orientation};
+use crate::webapp::EventHandle;
+
+/// Default tilt in degrees that maps to a full offset.
+pub const DEFAULT_MAX_ANGLE: f64 = 30.0;
+/// Default smoothing factor applied to consecutive offsets.
+pub const DEFAULT_SMOOTHING: f64 = 0.2;
+
+/// Normalized parallax offset.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TiltOffset {
+    /// Left/right tilt, from `-1.0` (left) to `1.0` (right).
+    pub x: f64,
+    /// Forward/backward tilt, from `-1.0` (top edge away from the user) to
+    /// `1.0` (top edge towards the user).
+    pub y: f64
+}
+
+/// Maps orientation angles to [`TiltOffset`]s.
+///
+/// The first reading is taken as the neutral position, so the offset is
+/// zero for however the user holds the phone when the scene starts;
+/// [`recenter`](Self::recenter) takes the next reading as the new neutral
+/// position.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::api::{device_orientation::Orientation, parallax::TiltMapper};
+///
+/// let mut mapper = TiltMapper::new().max_angle(20.0).smoothing(1.0);
+/// mapper.map(Orientation {
+///     alpha: 0.0,
+///     beta:  40.0,
+///     gamma: 0.0
+/// });
+/// let offset = mapper.map(Orientation {
+///     alpha: 0.0,
+///     beta:  40.0,
+///     gamma: 10.0
+/// });
+/// assert_eq!(offset.x, 0.5);
+/// assert_eq!(offset.y, 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TiltMapper {
+    max_angle: f64,
+    smoothing: f64,
+    neutral:   Option<(f64, f64)>,
+    offset:    TiltOffset
+}
+
+impl Default for TiltMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TiltMapper {
+    /// Creates a mapper with [`DEFAULT_MAX_ANGLE`] and
+    /// [`DEFAULT_SMOOTHING`].
+    pub fn new() -> Self {
+        Self {
+            max_angle: DEFAULT_MAX_ANGLE,
+            smoothing: DEFAULT_SMOOTHING,
+            neutral:   None,
+            offset:    TiltOffset::default()
+        }
+    }
+
+    /// Sets the tilt in degrees that maps to a full offset.
+    pub fn max_angle(mut self, degrees: f64) -> Self {
+        self.max_angle = degrees.abs();
+        self
+    }
+
+    /// Sets how quickly the offset follows the device, from `0.0` (frozen)
+    /// to `1.0` (no smoothing).
+    pub fn smoothing(mut self, alpha: f64) -> Self {
+        self.smoothing = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Feeds a reading and returns the smoothed offset.
+    pub fn map(&mut self, reading: Orientation) -> TiltOffset {
+        let (beta, gamma) = *self.neutral.get_or_insert((reading.beta, reading.gamma));
+        let target = TiltOffset {
+            x: self.normalize(reading.gamma - gamma),
+            y: self.normalize(reading.beta - beta)
+        };
+        self.offset.x += self.smoothing * (target.x - self.offset.x);
+        self.offset.y += self.smoothing * (target.y - self.offset.y);
+        self.offset
+    }
+
+    /// Returns the last offset.
+    pub fn offset(&self) -> TiltOffset {
+        self.offset
+    }
+
+    /// Takes the next reading as the neutral position.
+    pub fn recenter(&mut self) {
+        self.neutral = None;
+    }
+
+    fn normalize(&self, degrees: f64) -> f64 {
+        if self.max_angle == 0.0 {
+            return 0.0;
+        }
+        (degrees / self.max_angle).clamp(-1.0, 1.0)
+    }
+}
+
+/// Calls `callback` with the mapped offset on every orientation reading.
+///
+/// The sensor must be started with [`device_orientation::start`].
+///
+/// # Errors
+/// Returns [`JsValue`] if the WebApp or the sensor is unavailable.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::{
+///     device_orientation,
+///     parallax::{TiltMapper, on_tilt}
+/// };
+///
+/// device_orientation::start()?;
+/// let _handle = on_tilt(TiltMapper::new(), |offset| {
+///     let _ = (offset.x, offset.y); // update a signal
+/// })?;
+/// # Ok::<(), wasm_bindgen::JsValue>(())
+/// ```
+pub fn on_tilt<F>(
+    mut mapper: TiltMapper,
+    mut callback: F
+) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+where
+    F: 'static + FnMut(TiltOffset)
+{
+    device_orientation::on_reading(move |reading| callback(mapper.map(*reading)))
+}
+
+/// Moves `element` by up to `max_shift_px` pixels against the tilt.
+///
+/// Sets the `transform` style to a `translate3d`, replacing any other
+/// transform of the element. The sensor must be started with
+/// [`device_orientation::start`].
+///
+/// # Errors
+/// Returns [`JsValue`] if the WebApp or the sensor is unavailable.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::{
+///     device_orientation,
+///     parallax::{TiltMapper, apply_to}
+/// };
+/// use wasm_bindgen::JsCast;
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let document = web_sys::window().unwrap().document().unwrap();
+/// let background = document
+///     .get_element_by_id("background")
+///     .unwrap()
+///     .dyn_into::<web_sys::HtmlElement>()?;
+/// device_orientation::start()?;
+/// let _handle = apply_to(&background, 24.0, TiltMapper::new())?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn apply_to(
+    element: &HtmlElement,
+    max_shift_px: f64,
+    mapper: TiltMapper
+) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue> {
+    let element = element.clone();
+    on_tilt(mapper, move |offset| {
+        let _ = element
+            .style()
+            .set_property("transform", &transform(offset, max_shift_px));
+    })
+}
+
+/// Returns the CSS transform shifting by `offset` scaled to `max_shift_px`.
+fn transform(offset: TiltOffset, max_shift_px: f64) -> String {
+    format!(
+        "translate3d({:.2}px, {:.2}px, 0)",
+        -offset.x * max_shift_px,
+        -offset.y * max_shift_px
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tilt(beta: f64, gamma: f64) -> Orientation {
+        Orientation {
+            alpha: 0.0,
+            beta,
+            gamma
+        }
+    }
+
+    #[test]
+    fn first_reading_is_neutral() {
+        let mut mapper = TiltMapper::new().smoothing(1.0);
+        assert_eq!(mapper.map(tilt(45.0, 5.0)), TiltOffset::default());
+        let offset = mapper.map(tilt(60.0, -10.0));
+        assert_eq!(offset.x, -0.5);
+        assert_eq!(offset.y, 0.5);
+    }
+
+    #[test]
+    fn offsets_are_clamped_and_smoothed() {
+        let mut mapper = TiltMapper::new().max_angle(10.0).smoothing(0.5);
+        mapper.map(tilt(0.0, 0.0));
+        assert_eq!(mapper.map(tilt(0.0, 90.0)).x, 0.5);
+        assert_eq!(mapper.map(tilt(0.0, 90.0)).x, 0.75);
+
+        mapper.recenter();
+        mapper.map(tilt(0.0, 90.0));
+        assert!(mapper.offset().x < 0.75);
+    }
+
+    #[test]
+    fn transform_moves_against_tilt() {
+        let offset = TiltOffset {
+            x: 0.5, y: -1.0
+        };
+        assert_eq!(transform(offset, 20.0), "translate3d(-10.00px, 20.00px, 0)");
+    }
+}
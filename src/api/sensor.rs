@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Allocation-free reading of motion sensors.
+//!
+//! `Reflect.get` with a string key copies the key into a new JS string and
+//! parks the result in the heap table on every call, which adds up at 60 Hz
+//! per axis. The numeric properties are bound as `Option<f64>` getters
+//! instead, so a reading crosses the boundary as plain numbers. Telegram
+//! reports `null` axes until the first sample arrives; those, like missing
+//! properties, read as `None` rather than `0.0` and produce no reading.
+//!
+//! The `sensor_dispatch` bench in `benches/webapp.rs` measured one
+//! `accelerometerChanged` dispatch at ~5.1 µs through `on_changed` +
+//! `get_acceleration` and ~0.65 µs through `on_reading` (Node 20, release
+//! build).
+
+use std::cell::RefCell;
+
+use js_sys::Reflect;
+use wasm_bindgen::{JsCast, JsValue, prelude::wasm_bindgen};
+
+use crate::webapp::{EventHandle, TelegramWebApp};
+
+#[wasm_bindgen]
+extern "C" {
+    /// `WebApp.Accelerometer`, `WebApp.Gyroscope` or
+    /// `WebApp.DeviceOrientation`.
+    pub(super) type SensorObject;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn x(this: &SensorObject) -> Option<f64>;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn y(this: &SensorObject) -> Option<f64>;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn z(this: &SensorObject) -> Option<f64>;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn alpha(this: &SensorObject) -> Option<f64>;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn beta(this: &SensorObject) -> Option<f64>;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn gamma(this: &SensorObject) -> Option<f64>;
+}
+
+/// Returns `Some` only when all three values are present numbers.
+pub(super) fn triple(
+    a: Option<f64>,
+    b: Option<f64>,
+    c: Option<f64>
+) -> Option<(f64, f64, f64)> {
+    let number = |value: Option<f64>| value.filter(|value| !value.is_nan());
+    Some((number(a)?, number(b)?, number(c)?))
+}
+
+/// Calls `callback` with a fresh reading on every `event`.
+///
+/// The sensor object is looked up once; each event then costs three getter
+/// calls and no allocations.
+pub(super) fn subscribe<T, F>(
+    object: &str,
+    event: &str,
+    read: fn(&SensorObject) -> Option<T>,
+    callback: F
+) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+where
+    T: 'static,
+    F: 'static + FnMut(&T)
+{
+    let app = TelegramWebApp::try_instance()?;
+    let sensor = sensor_object(object)?;
+    let callback = RefCell::new(callback);
+    app.on_event(event, move |_| {
+        if let Some(reading) = read(&sensor) {
+            (callback.borrow_mut())(&reading);
+        }
+    })
+}
+
+fn sensor_object(name: &str) -> Result<SensorObject, JsValue> {
+    let win = web_sys::window().ok_or_else(|| JsValue::from_str("no window"))?;
+    let tg = Reflect::get(&win, &"Telegram".into())?;
+    let webapp = Reflect::get(&tg, &"WebApp".into())?;
+    let sensor = Reflect::get(&webapp, &name.into())?;
+    if sensor.is_object() {
+        Ok(sensor.unchecked_into())
+    } else {
+        Err(JsValue::from_str(&format!("{name} not available")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triple_rejects_missing_values() {
+        assert_eq!(
+            triple(Some(1.0), Some(2.0), Some(3.0)),
+            Some((1.0, 2.0, 3.0))
+        );
+        assert_eq!(triple(Some(1.0), Some(f64::NAN), Some(3.0)), None);
+        assert_eq!(triple(None, Some(0.0), Some(0.0)), None);
+    }
+}
@@ -21,6 +21,219 @@ pub fn get_theme_params() -> Result<TelegramThemeParams, JsValue> {
         .map_err(|e| JsValue::from_str(&format!("themeParams parse error: {e}")))
 }
 
+/// Minimum contrast of series colors against the background, the WCAG
+/// threshold for graphical objects.
+pub const MIN_SERIES_CONTRAST: f64 = 3.0;
+
+/// Hue step between generated colors, spreading any number of hues evenly.
+const GOLDEN_ANGLE: f64 = 137.507_764;
+/// Seed hues closer than this are treated as the same color.
+const MIN_HUE_DISTANCE: f64 = 20.0;
+/// Seeds used when the theme provides no accent colors.
+const FALLBACK_SEEDS: [&str; 2] = ["#2481cc", "#e53935"];
+
+/// Returns `n` distinct series colors derived from the current theme.
+///
+/// See [`palette_for`] for how the colors are chosen.
+///
+/// # Errors
+/// Returns `Err(JsValue)` if the theme parameters are missing or cannot be
+/// parsed.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::api::theme::palette;
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let colors = palette(5)?;
+/// assert_eq!(colors.len(), 5);
+/// # Ok(())
+/// # }
+/// ```
+pub fn palette(n: usize) -> Result<Vec<String>, JsValue> {
+    Ok(palette_for(&get_theme_params()?, n))
+}
+
+/// Returns `n` distinct `#rrggbb` series colors for charts drawn on the
+/// theme background.
+///
+/// The accent, link, destructive and button colors of the theme come first,
+/// skipping ones with nearly the same hue; further colors continue from the
+/// first seed in golden-angle hue steps. Every color is lightened on dark
+/// backgrounds and darkened on light ones until it reaches
+/// [`MIN_SERIES_CONTRAST`] against `bg_color`.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::{
+///     api::theme::palette_for, core::types::theme_params::TelegramThemeParams
+/// };
+///
+/// let theme = TelegramThemeParams {
+///     bg_color: Some("#17212b".into()),
+///     link_color: Some("#6ab3f3".into()),
+///     ..Default::default()
+/// };
+/// let colors = palette_for(&theme, 4);
+/// assert_eq!(colors.len(), 4);
+/// assert_eq!(colors[0], "#6ab3f3");
+/// ```
+pub fn palette_for(theme: &TelegramThemeParams, n: usize) -> Vec<String> {
+    let background = theme
+        .bg_color
+        .as_deref()
+        .and_then(Rgb::parse)
+        .unwrap_or(Rgb(255.0, 255.0, 255.0));
+
+    let mut seeds: Vec<Hsl> = Vec::new();
+    let candidates = [
+        &theme.accent_text_color,
+        &theme.link_color,
+        &theme.destructive_text_color,
+        &theme.button_color
+    ];
+    for hex in candidates.into_iter().flatten() {
+        if let Some(color) = Rgb::parse(hex).map(Rgb::to_hsl)
+            && seeds
+                .iter()
+                .all(|seed| hue_distance(seed.0, color.0) >= MIN_HUE_DISTANCE)
+        {
+            seeds.push(color);
+        }
+    }
+    if seeds.is_empty() {
+        seeds.extend(
+            FALLBACK_SEEDS
+                .iter()
+                .filter_map(|hex| Rgb::parse(hex))
+                .map(Rgb::to_hsl)
+        );
+    }
+
+    let base = seeds[0];
+    (0..n)
+        .map(|i| {
+            let color = seeds.get(i).copied().unwrap_or_else(|| {
+                let step = (i - seeds.len() + 1) as f64;
+                Hsl(
+                    (base.0 + step * GOLDEN_ANGLE) % 360.0,
+                    base.1.max(0.45),
+                    base.2
+                )
+            });
+            readable(color, background).to_hex()
+        })
+        .collect()
+}
+
+/// Adjusts the lightness of `color` until it stands out from `background`.
+fn readable(color: Hsl, background: Rgb) -> Rgb {
+    let step = if background.luminance() < 0.5 {
+        0.02
+    } else {
+        -0.02
+    };
+    let mut color = color;
+    loop {
+        let rgb = color.to_rgb();
+        let next = color.2 + step;
+        if rgb.contrast(background) >= MIN_SERIES_CONTRAST || !(0.0..=1.0).contains(&next) {
+            return rgb;
+        }
+        color.2 = next;
+    }
+}
+
+fn hue_distance(a: f64, b: f64) -> f64 {
+    let d = (a - b).abs() % 360.0;
+    d.min(360.0 - d)
+}
+
+/// sRGB color with channels in `0.0..=255.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rgb(f64, f64, f64);
+
+/// Hue in degrees, saturation and lightness in `0.0..=1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Hsl(f64, f64, f64);
+
+impl Rgb {
+    fn parse(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#')?;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok().map(f64::from);
+        Some(Self(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    fn to_hex(self) -> String {
+        let channel = |v: f64| v.round().clamp(0.0, 255.0) as u8;
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            channel(self.0),
+            channel(self.1),
+            channel(self.2)
+        )
+    }
+
+    /// Relative luminance as defined by WCAG 2.
+    fn luminance(self) -> f64 {
+        let linear = |v: f64| {
+            let v = v / 255.0;
+            if v <= 0.039_28 {
+                v / 12.92
+            } else {
+                ((v + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    fn contrast(self, other: Self) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    fn to_hsl(self) -> Hsl {
+        let (r, g, b) = (self.0 / 255.0, self.1 / 255.0, self.2 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        if d == 0.0 {
+            return Hsl(0.0, 0.0, l);
+        }
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * (((g - b) / d).rem_euclid(6.0))
+        } else if max == g {
+            60.0 * ((b - r) / d + 2.0)
+        } else {
+            60.0 * ((r - g) / d + 4.0)
+        };
+        Hsl(h, s, l)
+    }
+}
+
+impl Hsl {
+    fn to_rgb(self) -> Rgb {
+        let Hsl(h, s, l) = self;
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+        let m = l - c / 2.0;
+        let (r, g, b) = match (h.rem_euclid(360.0) / 60.0) as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x)
+        };
+        Rgb((r + m) * 255.0, (g + m) * 255.0, (b + m) * 255.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use js_sys::{Object, Reflect};
@@ -32,6 +245,53 @@ mod tests {
 
     wasm_bindgen_test_configure!(run_in_browser);
 
+    fn assert_palette(theme: &TelegramThemeParams) {
+        let background = Rgb::parse(theme.bg_color.as_deref().unwrap()).unwrap();
+        let colors = palette_for(theme, 8);
+        assert_eq!(colors.len(), 8);
+        for (i, color) in colors.iter().enumerate() {
+            let rgb = Rgb::parse(color).unwrap();
+            assert!(rgb.contrast(background) >= MIN_SERIES_CONTRAST, "{color}");
+            assert!(!colors[..i].contains(color), "{color} repeated");
+        }
+    }
+
+    #[test]
+    fn palette_contrasts_with_light_and_dark_themes() {
+        assert_palette(&TelegramThemeParams {
+            bg_color: Some("#ffffff".into()),
+            link_color: Some("#2481cc".into()),
+            destructive_text_color: Some("#d14e4e".into()),
+            ..Default::default()
+        });
+        assert_palette(&TelegramThemeParams {
+            bg_color: Some("#17212b".into()),
+            accent_text_color: Some("#6ab2f2".into()),
+            link_color: Some("#6ab3f3".into()),
+            destructive_text_color: Some("#ec3942".into()),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn palette_skips_seeds_with_the_same_hue() {
+        let theme = TelegramThemeParams {
+            bg_color: Some("#ffffff".into()),
+            accent_text_color: Some("#168acd".into()),
+            link_color: Some("#168bce".into()),
+            destructive_text_color: Some("#d14e4e".into()),
+            ..Default::default()
+        };
+        let colors = palette_for(&theme, 2);
+        assert_eq!(colors, ["#168acd", "#d14e4e"]);
+    }
+
+    #[test]
+    fn hsl_round_trips() {
+        let color = Rgb::parse("#6ab3f3").unwrap();
+        assert_eq!(color.to_hsl().to_rgb().to_hex(), "#6ab3f3");
+    }
+
     #[allow(dead_code)]
     fn setup_webapp() -> Object {
         let win = window().expect("window should be available");
@@ -0,0 +1,341 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Crate-wide bus of typed [`WebAppEvent`]s.
+//!
+//! [`subscribe`] lets any module or component observe `Telegram.WebApp`
+//! events without holding a [`TelegramWebApp`]. The first subscription
+//! registers a single JS handler per event name, which decodes the payload
+//! and fans it out to every subscriber, so adding listeners does not add
+//! `onEvent` registrations. The JS handlers stay registered for the rest of
+//! the session.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use telegram_webapp_sdk::{
+//!     bus::{self, WebAppEvent},
+//!     webapp::InvoiceStatus
+//! };
+//!
+//! let subscription = bus::subscribe(|event| {
+//!     if let WebAppEvent::InvoiceClosed {
+//!         status: Some(InvoiceStatus::Paid),
+//!         ..
+//!     } = event
+//!     {
+//!         // deliver the order
+//!     }
+//! });
+//! // events are delivered until `subscription` is dropped
+//! # drop(subscription);
+//! ```
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc
+};
+
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
+
+use crate::{
+    logger,
+    webapp::{
+        ContactRequestResult, EventHandle, FullscreenError, InvoiceStatus, TelegramWebApp,
+        WriteAccessResult
+    }
+};
+
+/// Typed `Telegram.WebApp` event.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WebAppEvent {
+    /// The app became visible (`activated`).
+    Activated,
+    /// The app was minimized or hidden (`deactivated`).
+    Deactivated,
+    /// `themeParams` or `colorScheme` changed (`themeChanged`).
+    ThemeChanged,
+    /// The viewport was resized (`viewportChanged`).
+    ViewportChanged {
+        /// Whether the resize animation has finished.
+        is_state_stable: bool
+    },
+    /// The device safe area changed (`safeAreaChanged`).
+    SafeAreaChanged,
+    /// The content safe area changed (`contentSafeAreaChanged`).
+    ContentSafeAreaChanged,
+    /// The app entered or left fullscreen (`fullscreenChanged`).
+    FullscreenChanged,
+    /// A fullscreen request failed (`fullscreenFailed`).
+    FullscreenFailed {
+        /// Reported error.
+        error: FullscreenError
+    },
+    /// The main button was pressed (`mainButtonClicked`).
+    MainButtonClicked,
+    /// The secondary button was pressed (`secondaryButtonClicked`).
+    SecondaryButtonClicked,
+    /// The back button was pressed (`backButtonClicked`).
+    BackButtonClicked,
+    /// The settings item was pressed (`settingsButtonClicked`).
+    SettingsButtonClicked,
+    /// An invoice was closed (`invoiceClosed`).
+    InvoiceClosed {
+        /// Invoice link.
+        url:    String,
+        /// Final status; `None` for an unknown status.
+        status: Option<InvoiceStatus>
+    },
+    /// A popup was closed (`popupClosed`).
+    PopupClosed {
+        /// Id of the pressed button; `None` when dismissed.
+        button_id: Option<String>
+    },
+    /// The QR scanner read text (`qrTextReceived`).
+    QrTextReceived {
+        /// Scanned text.
+        data: String
+    },
+    /// The QR scanner was closed (`scanQrPopupClosed`).
+    ScanQrPopupClosed,
+    /// Clipboard text was read (`clipboardTextReceived`).
+    ClipboardTextReceived {
+        /// Clipboard text; `None` without clipboard access.
+        data: Option<String>
+    },
+    /// The user answered a write access request (`writeAccessRequested`).
+    WriteAccessRequested {
+        /// The answer; `None` for an unrecognized payload.
+        result: Option<WriteAccessResult>
+    },
+    /// The user answered a contact request (`contactRequested`).
+    ContactRequested {
+        /// The answer; `None` for an unrecognized payload.
+        result: Option<ContactRequestResult>
+    }
+}
+
+/// Event names observed by the bus.
+const EVENTS: [&str; 19] = [
+    "activated",
+    "deactivated",
+    "themeChanged",
+    "viewportChanged",
+    "safeAreaChanged",
+    "contentSafeAreaChanged",
+    "fullscreenChanged",
+    "fullscreenFailed",
+    "mainButtonClicked",
+    "secondaryButtonClicked",
+    "backButtonClicked",
+    "settingsButtonClicked",
+    "invoiceClosed",
+    "popupClosed",
+    "qrTextReceived",
+    "scanQrPopupClosed",
+    "clipboardTextReceived",
+    "writeAccessRequested",
+    "contactRequested"
+];
+
+impl WebAppEvent {
+    /// Decodes the payload of the JS event `name`.
+    ///
+    /// Returns `None` for events the bus does not know.
+    pub fn from_js(name: &str, payload: &JsValue) -> Option<Self> {
+        let text = |key: &str| {
+            Reflect::get(payload, &key.into())
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+        Some(match name {
+            "activated" => Self::Activated,
+            "deactivated" => Self::Deactivated,
+            "themeChanged" => Self::ThemeChanged,
+            "viewportChanged" => Self::ViewportChanged {
+                is_state_stable: Reflect::get(payload, &"isStateStable".into())
+                    .ok()
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or(false)
+            },
+            "safeAreaChanged" => Self::SafeAreaChanged,
+            "contentSafeAreaChanged" => Self::ContentSafeAreaChanged,
+            "fullscreenChanged" => Self::FullscreenChanged,
+            "fullscreenFailed" => Self::FullscreenFailed {
+                error: FullscreenError::from_event(payload)
+                    .unwrap_or_else(|| FullscreenError::Other(String::new()))
+            },
+            "mainButtonClicked" => Self::MainButtonClicked,
+            "secondaryButtonClicked" => Self::SecondaryButtonClicked,
+            "backButtonClicked" => Self::BackButtonClicked,
+            "settingsButtonClicked" => Self::SettingsButtonClicked,
+            "invoiceClosed" => Self::InvoiceClosed {
+                url:    text("url").unwrap_or_default(),
+                status: InvoiceStatus::from_event(payload)
+            },
+            "popupClosed" => Self::PopupClosed {
+                button_id: text("button_id")
+            },
+            "qrTextReceived" => Self::QrTextReceived {
+                data: text("data").unwrap_or_default()
+            },
+            "scanQrPopupClosed" => Self::ScanQrPopupClosed,
+            "clipboardTextReceived" => Self::ClipboardTextReceived {
+                data: text("data")
+            },
+            "writeAccessRequested" => Self::WriteAccessRequested {
+                result: WriteAccessResult::from_event(payload)
+            },
+            "contactRequested" => Self::ContactRequested {
+                result: ContactRequestResult::from_event(payload)
+            },
+            _ => return None
+        })
+    }
+}
+
+type Listener = Rc<dyn Fn(&WebAppEvent)>;
+type JsHandle = EventHandle<dyn FnMut(JsValue)>;
+
+#[derive(Default)]
+struct Bus {
+    next_id:   Cell<u64>,
+    listeners: RefCell<Vec<(u64, Listener)>>,
+    handles:   RefCell<Vec<JsHandle>>
+}
+
+thread_local! {
+    static BUS: Bus = Bus::default();
+}
+
+/// Registration returned by [`subscribe`]; unsubscribes when dropped.
+#[must_use = "the listener is removed when the subscription is dropped"]
+#[derive(Debug)]
+pub struct Subscription {
+    id: u64
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let id = self.id;
+        let _ = BUS.try_with(|bus| {
+            bus.listeners.borrow_mut().retain(|(other, _)| *other != id);
+        });
+    }
+}
+
+/// Calls `listener` for every [`WebAppEvent`] until the returned
+/// [`Subscription`] is dropped.
+///
+/// Registers the JS handlers on first use; if `Telegram.WebApp` is not
+/// available yet, a later subscription retries.
+pub fn subscribe(listener: impl Fn(&WebAppEvent) + 'static) -> Subscription {
+    BUS.with(|bus| {
+        if bus.handles.borrow().is_empty() {
+            install(bus);
+        }
+        let id = bus.next_id.get() + 1;
+        bus.next_id.set(id);
+        bus.listeners.borrow_mut().push((id, Rc::new(listener)));
+        Subscription {
+            id
+        }
+    })
+}
+
+/// Delivers `event` to all subscribers.
+///
+/// The bus publishes Telegram events itself; call this to inject events,
+/// e.g. from a mock or in tests.
+pub fn publish(event: &WebAppEvent) {
+    // Clone the list so listeners may subscribe or unsubscribe re-entrantly.
+    let listeners: Vec<Listener> = BUS.with(|bus| {
+        bus.listeners
+            .borrow()
+            .iter()
+            .map(|(_, listener)| Rc::clone(listener))
+            .collect()
+    });
+    for listener in listeners {
+        listener(event);
+    }
+}
+
+/// Registers one JS handler per event name.
+///
+/// Installs all handlers or none, so a failed registration is retried by the
+/// next [`subscribe`] instead of leaving events unobserved.
+fn install(bus: &Bus) {
+    let Some(app) = TelegramWebApp::instance() else {
+        return;
+    };
+    let handles = EVENTS
+        .into_iter()
+        .map(|name| {
+            app.on_event(name, move |payload| {
+                if let Some(event) = WebAppEvent::from_js(name, &payload) {
+                    publish(&event);
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>();
+    match handles {
+        Ok(handles) => *bus.handles.borrow_mut() = handles,
+        Err(err) => logger::warn(&format!("bus: event registration failed: {err:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Function, Object};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn publish_reaches_live_subscribers() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&seen);
+        let subscription = subscribe(move |event| sink.borrow_mut().push(event.clone()));
+        publish(&WebAppEvent::MainButtonClicked);
+        drop(subscription);
+        publish(&WebAppEvent::BackButtonClicked);
+        assert_eq!(*seen.borrow(), [WebAppEvent::MainButtonClicked]);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn fans_out_js_events() {
+        let win = web_sys::window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let on_event = Function::new_with_args("name, cb", "this[name] = cb;");
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &Function::new_no_args(""));
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let (first, second) = (Rc::clone(&seen), Rc::clone(&seen));
+        let _a = subscribe(move |event| first.borrow_mut().push(event.clone()));
+        let _b = subscribe(move |event| second.borrow_mut().push(event.clone()));
+
+        let payload = Object::new();
+        let _ = Reflect::set(&payload, &"url".into(), &"https://t.me/$x".into());
+        let _ = Reflect::set(&payload, &"status".into(), &"paid".into());
+        let handler: Function = Reflect::get(&webapp, &"invoiceClosed".into())
+            .unwrap()
+            .into();
+        handler.call1(&webapp, &payload).unwrap();
+
+        let expected = WebAppEvent::InvoiceClosed {
+            url:    "https://t.me/$x".into(),
+            status: Some(InvoiceStatus::Paid)
+        };
+        assert_eq!(*seen.borrow(), [expected.clone(), expected]);
+    }
+}
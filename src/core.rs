@@ -7,6 +7,9 @@ pub mod context;
 /// SDK initialization routines that populate the global context from the
 /// running Telegram WebApp environment.
 pub mod init;
+/// [`payload::WebAppPayload`] trait for JSON data sent to the bot with
+/// `WebApp.sendData`, enforcing the 4096-byte limit on both sides.
+pub mod payload;
 /// Fallible accessors for the global context that return a
 /// [`wasm_bindgen::JsValue`] error instead of an [`Option`] when the context is
 /// not initialized.
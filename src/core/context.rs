@@ -1,6 +1,8 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::rc::Rc;
+
 use once_cell::unsync::OnceCell;
 use percent_encoding::{percent_decode, percent_decode_str};
 use wasm_bindgen::JsValue;
@@ -25,7 +27,7 @@ pub struct TelegramContext {
 
 thread_local! {
     /// Thread-local global TelegramContext instance.
-    static CONTEXT: OnceCell<TelegramContext> = const { OnceCell::new() };
+    static CONTEXT: OnceCell<Rc<TelegramContext>> = const { OnceCell::new() };
 }
 
 impl TelegramContext {
@@ -39,11 +41,11 @@ impl TelegramContext {
         raw_init_data: String
     ) -> Result<(), &'static str> {
         CONTEXT.with(|cell| {
-            cell.set(TelegramContext {
+            cell.set(Rc::new(TelegramContext {
                 init_data,
                 theme_params,
                 raw_init_data
-            })
+            }))
             .map_err(|_| "TelegramContext already initialized")
         })
     }
@@ -55,7 +57,27 @@ impl TelegramContext {
     where
         F: FnOnce(&TelegramContext) -> R
     {
-        CONTEXT.with(|cell| cell.get().map(f))
+        CONTEXT.with(|cell| cell.get().map(|ctx| f(ctx)))
+    }
+
+    /// Returns a shared handle to the global context if it has been
+    /// initialized.
+    ///
+    /// Cloning the handle only bumps a reference count, so reactive layers can
+    /// hold on to the context without copying the user, theme and raw init
+    /// data. Use [`get`](Self::get) to read a single field instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::core::context::TelegramContext;
+    ///
+    /// if let Some(ctx) = TelegramContext::shared() {
+    ///     let _ = ctx.init_data.auth_date;
+    /// }
+    /// ```
+    pub fn shared() -> Option<Rc<TelegramContext>> {
+        CONTEXT.with(|cell| cell.get().cloned())
     }
 
     /// Returns the raw initData string as provided by Telegram.
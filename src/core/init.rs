@@ -3,6 +3,11 @@
 
 use js_sys::Reflect;
 use serde_wasm_bindgen::from_value;
+/// Attribute defining the WASM start function with SDK initialization.
+///
+/// See [`telegram_webapp_sdk_macros::telegram_app`] for details.
+#[cfg(feature = "macros")]
+pub use telegram_webapp_sdk_macros::telegram_app;
 use wasm_bindgen::JsValue;
 use web_sys::window;
 
@@ -108,68 +113,90 @@ pub fn try_init_sdk() -> Result<bool, InitError> {
     init_sdk_typed().map(|_| true)
 }
 
-/// Internal typed version of init_sdk for use by try_init_sdk.
-fn init_sdk_typed() -> Result<(), InitError> {
-    let win = window().ok_or(InitError::WindowUnavailable)?;
-    let telegram =
-        Reflect::get(&win, &"Telegram".into()).map_err(|_| InitError::TelegramUnavailable)?;
-
-    if telegram.is_undefined() {
-        return Err(InitError::TelegramUnavailable);
-    }
-
-    let webapp =
-        Reflect::get(&telegram, &"WebApp".into()).map_err(|_| InitError::WebAppUnavailable)?;
-
-    if webapp.is_undefined() {
-        return Err(InitError::WebAppUnavailable);
-    }
-
-    // === 1. Parse initData string ===
-    let init_data_str = Reflect::get(&webapp, &"initData".into())
-        .ok()
-        .and_then(|v| v.as_string())
-        .ok_or_else(|| InitError::InitDataParseFailed("initData is not a string".to_string()))?;
-
-    let raw: TelegramInitDataInternal = serde_urlencoded::from_str(&init_data_str)
+/// Parses a URL-encoded `initData` string, including the embedded `user`,
+/// `receiver` and `chat` JSON fields.
+///
+/// The signature is not checked; validate the raw string on the backend.
+///
+/// # Errors
+///
+/// Returns [`InitError::InitDataParseFailed`] if the string or one of the
+/// embedded JSON fields is malformed.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::core::init::parse_init_data;
+///
+/// let data = parse_init_data("auth_date=1700000000&hash=abc&start_param=promo").unwrap();
+/// assert_eq!(data.auth_date, 1700000000);
+/// assert_eq!(data.start_param.as_deref(), Some("promo"));
+/// ```
+pub fn parse_init_data(raw: &str) -> Result<TelegramInitData, InitError> {
+    let fields: TelegramInitDataInternal = serde_urlencoded::from_str(raw)
         .map_err(|e| InitError::InitDataParseFailed(e.to_string()))?;
 
-    // === 2. Parse embedded JSON fields ===
-    let user: Option<TelegramUser> = raw
+    // Embedded JSON fields
+    let user: Option<TelegramUser> = fields
         .user
         .as_deref()
         .map(serde_json::from_str)
         .transpose()
         .map_err(|e| InitError::InitDataParseFailed(format!("Failed to parse user: {e}")))?;
 
-    let receiver: Option<TelegramUser> = raw
+    let receiver: Option<TelegramUser> = fields
         .receiver
         .as_deref()
         .map(serde_json::from_str)
         .transpose()
         .map_err(|e| InitError::InitDataParseFailed(format!("Failed to parse receiver: {e}")))?;
 
-    let chat: Option<TelegramChat> = raw
+    let chat: Option<TelegramChat> = fields
         .chat
         .as_deref()
         .map(serde_json::from_str)
         .transpose()
         .map_err(|e| InitError::InitDataParseFailed(format!("Failed to parse chat: {e}")))?;
 
-    // === 3. Construct final typed initData ===
-    let init_data = TelegramInitData {
-        query_id: raw.query_id,
+    Ok(TelegramInitData {
+        query_id: fields.query_id,
         user,
         receiver,
         chat,
-        chat_type: raw.chat_type,
-        chat_instance: raw.chat_instance,
-        start_param: raw.start_param,
-        can_send_after: raw.can_send_after,
-        auth_date: raw.auth_date,
-        hash: raw.hash,
-        signature: raw.signature
-    };
+        chat_type: fields.chat_type,
+        chat_instance: fields.chat_instance,
+        start_param: fields.start_param,
+        can_send_after: fields.can_send_after,
+        auth_date: fields.auth_date,
+        hash: fields.hash,
+        signature: fields.signature
+    })
+}
+
+/// Internal typed version of init_sdk for use by try_init_sdk.
+fn init_sdk_typed() -> Result<(), InitError> {
+    let win = window().ok_or(InitError::WindowUnavailable)?;
+    let telegram =
+        Reflect::get(&win, &"Telegram".into()).map_err(|_| InitError::TelegramUnavailable)?;
+
+    if telegram.is_undefined() {
+        return Err(InitError::TelegramUnavailable);
+    }
+
+    let webapp =
+        Reflect::get(&telegram, &"WebApp".into()).map_err(|_| InitError::WebAppUnavailable)?;
+
+    if webapp.is_undefined() {
+        return Err(InitError::WebAppUnavailable);
+    }
+
+    // === 1. Parse initData string ===
+    let init_data_str = Reflect::get(&webapp, &"initData".into())
+        .ok()
+        .and_then(|v| v.as_string())
+        .ok_or_else(|| InitError::InitDataParseFailed("initData is not a string".to_string()))?;
+
+    let init_data = parse_init_data(&init_data_str)?;
 
     // === 4. Parse themeParams ===
     let theme_val = Reflect::get(&webapp, &"themeParams".into())
@@ -216,3 +243,101 @@ fn init_sdk_typed() -> Result<(), InitError> {
 pub fn init_sdk() -> Result<(), JsValue> {
     init_sdk_typed().map_err(Into::into)
 }
+
+/// Startup behavior of [`init_app`], configured through `telegram_app!`
+/// options.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::core::init::AppOptions;
+///
+/// let options = AppOptions {
+///     ready: true,
+///     ..AppOptions::default()
+/// };
+/// assert_eq!(options.config, "telegram-webapp.toml");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AppOptions {
+    /// Path of the mock configuration loaded outside Telegram.
+    pub config:             &'static str,
+    /// Whether the mock environment is installed when Telegram is missing.
+    /// Only effective with the `mock` feature.
+    pub mock:               bool,
+    /// Whether `WebApp.ready()` is called after initialization.
+    pub ready:              bool,
+    /// Whether `WebApp.expand()` is called after initialization.
+    pub expand:             bool,
+    /// Whether `<body>` gets a `tg-dark`/`tg-light` class following the
+    /// color scheme, see [`crate::dom::ThemeClass`].
+    pub theme_class:        bool,
+    /// Whether the [`crate::dom::base_styles`] stylesheet is injected.
+    pub inject_base_styles: bool
+}
+
+impl Default for AppOptions {
+    fn default() -> Self {
+        Self {
+            config:             "telegram-webapp.toml",
+            mock:               cfg!(debug_assertions),
+            ready:              false,
+            expand:             false,
+            theme_class:        false,
+            inject_base_styles: false
+        }
+    }
+}
+
+/// Initializes the SDK as configured by `options`.
+///
+/// Installs the mock environment from [`AppOptions::config`] when running
+/// outside Telegram and mocking is enabled, calls [`init_sdk`], subscribes
+/// the `#[telegram_event]` handlers (with the `macros` feature), then
+/// optionally signals readiness and expands the Mini App. This is what the
+/// `telegram_app!` entry point runs before its body.
+///
+/// # Errors
+/// Returns `Err(JsValue)` if [`init_sdk`] fails, an event handler cannot be
+/// subscribed, `ready`/`expand` cannot be called, or the theme class or base
+/// styles cannot be applied.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::core::init::{AppOptions, init_app};
+///
+/// init_app(&AppOptions {
+///     expand: true,
+///     ..AppOptions::default()
+/// })
+/// .unwrap();
+/// ```
+pub fn init_app(options: &AppOptions) -> Result<(), JsValue> {
+    #[cfg(feature = "mock")]
+    if options.mock
+        && !crate::utils::check_env::is_telegram_env()
+        && let Ok(cfg) = crate::mock::config::MockTelegramConfig::from_file(options.config)
+    {
+        let _ = crate::mock::init::mock_telegram_webapp(cfg);
+    }
+    init_sdk()?;
+    #[cfg(feature = "macros")]
+    crate::listeners::bind()?;
+    if options.ready || options.expand {
+        let app = crate::webapp::TelegramWebApp::instance()
+            .ok_or_else(|| JsValue::from_str("Telegram.WebApp is undefined"))?;
+        if options.ready {
+            app.ready()?;
+        }
+        if options.expand {
+            app.expand()?;
+        }
+    }
+    if options.inject_base_styles {
+        crate::dom::base_styles::inject_base_styles()?;
+    }
+    if options.theme_class {
+        // Keeps following the scheme for the rest of the session.
+        std::mem::forget(crate::dom::ThemeClass::install()?);
+    }
+    Ok(())
+}
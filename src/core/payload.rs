@@ -0,0 +1,239 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use serde::{Serialize, de::DeserializeOwned};
+#[cfg(feature = "macros")]
+pub use telegram_webapp_sdk_macros::WebAppPayload;
+use wasm_bindgen::JsValue;
+
+/// Maximum size in bytes of the data accepted by `WebApp.sendData`.
+pub const SEND_DATA_LIMIT: usize = 4096;
+
+/// Errors produced while encoding or decoding a [`WebAppPayload`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PayloadError {
+    /// JSON serialization or deserialization failed.
+    Json(String),
+    /// The encoded payload exceeds its byte limit.
+    TooLarge {
+        /// Encoded size in bytes.
+        size:  usize,
+        /// Maximum allowed size in bytes.
+        limit: usize
+    },
+    /// A field exceeds the length declared with `#[payload(max_len = ..)]`.
+    FieldTooLong {
+        /// Name of the offending field.
+        field: &'static str,
+        /// Actual length of the field.
+        len:   usize,
+        /// Maximum allowed length.
+        max:   usize
+    }
+}
+
+impl std::fmt::Display for PayloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(msg) => write!(f, "Invalid payload JSON: {msg}"),
+            Self::TooLarge {
+                size,
+                limit
+            } => write!(f, "Payload is {size} bytes, limit is {limit}"),
+            Self::FieldTooLong {
+                field,
+                len,
+                max
+            } => write!(f, "Field `{field}` has length {len}, maximum is {max}")
+        }
+    }
+}
+
+impl std::error::Error for PayloadError {}
+
+impl From<PayloadError> for JsValue {
+    fn from(err: PayloadError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Structured data sent to the bot through `WebApp.sendData`.
+///
+/// Payloads are encoded as JSON and never exceed [`Self::MAX_BYTES`], which
+/// is at most [`SEND_DATA_LIMIT`]. The same type decodes the data on the bot
+/// side with [`from_send_data`](Self::from_send_data), so both ends agree on
+/// the format and the limits.
+///
+/// With the `macros` feature the trait can be derived. The derive accepts
+/// `#[payload(max_bytes = N)]` on the type and `#[payload(max_len = N)]` on
+/// fields with a `len()` method; the attributes are checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::core::payload::WebAppPayload;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct OrderData {
+///     item:     String,
+///     quantity: u32
+/// }
+///
+/// impl WebAppPayload for OrderData {}
+///
+/// let order = OrderData {
+///     item:     "burger".into(),
+///     quantity: 2
+/// };
+/// let data = order.to_send_data().unwrap();
+/// let decoded = OrderData::from_send_data(&data).unwrap();
+/// assert_eq!(decoded.quantity, 2);
+/// ```
+pub trait WebAppPayload: Serialize {
+    /// Maximum encoded size in bytes.
+    const MAX_BYTES: usize = SEND_DATA_LIMIT;
+
+    /// Checks field constraints before encoding and after decoding.
+    ///
+    /// # Errors
+    /// Returns [`PayloadError::FieldTooLong`] when a constraint is violated.
+    fn validate(&self) -> Result<(), PayloadError> {
+        Ok(())
+    }
+
+    /// Encodes the payload as JSON ready for `WebApp.sendData`.
+    ///
+    /// # Errors
+    /// Returns [`PayloadError`] if validation or serialization fails or the
+    /// encoded data exceeds [`Self::MAX_BYTES`].
+    fn to_send_data(&self) -> Result<String, PayloadError> {
+        self.validate()?;
+        let data =
+            serde_json::to_string(self).map_err(|err| PayloadError::Json(err.to_string()))?;
+        check_size(data.len(), Self::MAX_BYTES)?;
+        Ok(data)
+    }
+
+    /// Decodes a payload received by the bot in `web_app_data.data`.
+    ///
+    /// # Errors
+    /// Returns [`PayloadError`] if the data exceeds [`Self::MAX_BYTES`], is
+    /// not valid JSON for `Self`, or fails validation.
+    fn from_send_data(data: &str) -> Result<Self, PayloadError>
+    where
+        Self: DeserializeOwned
+    {
+        check_size(data.len(), Self::MAX_BYTES)?;
+        let payload: Self =
+            serde_json::from_str(data).map_err(|err| PayloadError::Json(err.to_string()))?;
+        payload.validate()?;
+        Ok(payload)
+    }
+}
+
+fn check_size(size: usize, limit: usize) -> Result<(), PayloadError> {
+    if size > limit.min(SEND_DATA_LIMIT) {
+        return Err(PayloadError::TooLarge {
+            size,
+            limit: limit.min(SEND_DATA_LIMIT)
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Note {
+        text: String
+    }
+
+    impl WebAppPayload for Note {
+        const MAX_BYTES: usize = 32;
+    }
+
+    #[test]
+    fn payload_round_trips() {
+        let note = Note {
+            text: "hi".into()
+        };
+        let data = note.to_send_data().expect("encode");
+        assert_eq!(data, r#"{"text":"hi"}"#);
+        assert_eq!(Note::from_send_data(&data).expect("decode").text, "hi");
+    }
+
+    #[test]
+    fn oversized_payload_is_rejected() {
+        let note = Note {
+            text: "x".repeat(64)
+        };
+        assert!(matches!(
+            note.to_send_data(),
+            Err(PayloadError::TooLarge {
+                limit: 32,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Note::from_send_data(&"x".repeat(33)),
+            Err(PayloadError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_json_is_reported() {
+        assert!(matches!(
+            Note::from_send_data("{"),
+            Err(PayloadError::Json(_))
+        ));
+    }
+
+    #[cfg(feature = "macros")]
+    mod derive {
+        use serde::{Deserialize, Serialize};
+
+        use crate::core::payload::{PayloadError, WebAppPayload};
+
+        #[derive(Debug, Serialize, Deserialize, WebAppPayload)]
+        #[payload(max_bytes = 64)]
+        struct OrderData {
+            #[payload(max_len = 8)]
+            item:     String,
+            quantity: u32
+        }
+
+        #[test]
+        fn derived_payload_checks_fields() {
+            assert_eq!(<OrderData as WebAppPayload>::MAX_BYTES, 64);
+            let order = OrderData {
+                item:     "burger".into(),
+                quantity: 1
+            };
+            let data = order.to_send_data().expect("encode");
+            assert_eq!(
+                OrderData::from_send_data(&data).expect("decode").item,
+                "burger"
+            );
+
+            let long = OrderData {
+                item:     "cheeseburger".into(),
+                quantity: 1
+            };
+            assert_eq!(
+                long.to_send_data(),
+                Err(PayloadError::FieldTooLong {
+                    field: "item",
+                    len:   12,
+                    max:   8
+                })
+            );
+            let decoded = OrderData::from_send_data(r#"{"item":"cheeseburger","quantity":1}"#);
+            assert!(matches!(decoded, Err(PayloadError::FieldTooLong { .. })));
+        }
+    }
+}
@@ -1,6 +1,8 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::rc::Rc;
+
 use wasm_bindgen::JsValue;
 
 use crate::core::context::TelegramContext;
@@ -16,5 +18,30 @@ use crate::core::context::TelegramContext;
 /// Returns `Err(JsValue)` if the global context has not been initialized via
 /// [`crate::core::init::init_sdk`].
 pub fn get_context<T>(f: impl FnOnce(&TelegramContext) -> T) -> Result<T, JsValue> {
-    TelegramContext::get(f).ok_or_else(|| JsValue::from_str("TelegramContext is not initialized"))
+    TelegramContext::get(f).ok_or_else(context_missing)
+}
+
+/// Returns a shared handle to the global [`TelegramContext`].
+///
+/// Fallible counterpart to [`TelegramContext::shared`]; prefer it over
+/// `get_context(|c| c.clone())`, which deep-copies the context.
+///
+/// # Errors
+///
+/// Returns `Err(JsValue)` if the global context has not been initialized via
+/// [`crate::core::init::init_sdk`].
+pub fn get_shared_context() -> Result<Rc<TelegramContext>, JsValue> {
+    TelegramContext::shared().ok_or_else(context_missing)
+}
+
+/// Error returned while the context is missing.
+///
+/// Off `wasm32` no JavaScript value can be created, so the error is
+/// `undefined` there.
+pub(crate) fn context_missing() -> JsValue {
+    if cfg!(target_arch = "wasm32") {
+        JsValue::from_str("TelegramContext is not initialized")
+    } else {
+        JsValue::UNDEFINED
+    }
 }
@@ -1,7 +1,8 @@
-// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
 use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
 
 /// Parameters for
 /// [`TelegramWebApp::download_file`](crate::webapp::TelegramWebApp::download_file).
@@ -24,6 +25,131 @@ pub struct DownloadFileParams<'a> {
     pub mime_type: Option<&'a str>
 }
 
+impl<'a> DownloadFileParams<'a> {
+    /// Starts building parameters for downloading `url`.
+    ///
+    /// # Examples
+    /// ```
+    /// use telegram_webapp_sdk::core::types::download_file_params::DownloadFileParams;
+    ///
+    /// let params = DownloadFileParams::builder("https://example.com/files/report.pdf?v=2")
+    ///     .mime_type("application/pdf")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(params.file_name, Some("report.pdf"));
+    /// ```
+    pub fn builder(url: &'a str) -> DownloadFileParamsBuilder<'a> {
+        DownloadFileParamsBuilder {
+            url,
+            file_name: None,
+            mime_type: None
+        }
+    }
+}
+
+/// Validating builder for [`DownloadFileParams`].
+#[derive(Debug, Clone)]
+pub struct DownloadFileParamsBuilder<'a> {
+    url:       &'a str,
+    file_name: Option<&'a str>,
+    mime_type: Option<&'a str>
+}
+
+impl<'a> DownloadFileParamsBuilder<'a> {
+    /// Sets the name of the saved file; inferred from the URL otherwise.
+    pub fn file_name(mut self, file_name: &'a str) -> Self {
+        self.file_name = Some(file_name);
+        self
+    }
+
+    /// Sets the MIME type, e.g. `application/pdf`.
+    pub fn mime_type(mut self, mime_type: &'a str) -> Self {
+        self.mime_type = Some(mime_type);
+        self
+    }
+
+    /// Validates the parameters.
+    ///
+    /// Without an explicit file name the last path segment of the URL is
+    /// used as is, without percent-decoding.
+    ///
+    /// # Errors
+    /// Returns [`DownloadFileError`] when the URL is not `https`, no file
+    /// name is set or can be inferred, or the MIME type is malformed.
+    pub fn build(self) -> Result<DownloadFileParams<'a>, DownloadFileError> {
+        let rest = self
+            .url
+            .strip_prefix("https://")
+            .filter(|rest| !rest.starts_with(['/', '?', '#']) && !rest.is_empty())
+            .ok_or_else(|| DownloadFileError::InsecureUrl(self.url.to_owned()))?;
+        let file_name = match self.file_name {
+            Some(name) if !name.trim().is_empty() => name,
+            Some(_) => return Err(DownloadFileError::MissingFileName),
+            None => infer_file_name(rest).ok_or(DownloadFileError::MissingFileName)?
+        };
+        if let Some(mime_type) = self.mime_type
+            && !is_mime_type(mime_type)
+        {
+            return Err(DownloadFileError::InvalidMimeType(mime_type.to_owned()));
+        }
+        Ok(DownloadFileParams {
+            url:       self.url,
+            file_name: Some(file_name),
+            mime_type: self.mime_type
+        })
+    }
+}
+
+/// Returns the last path segment of `rest`, the URL without its scheme.
+fn infer_file_name(rest: &str) -> Option<&str> {
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    let (_, path) = rest[..end].split_once('/')?;
+    path.rsplit('/').next().filter(|name| !name.is_empty())
+}
+
+/// Checks the `type/subtype` form with RFC 6838 name characters.
+fn is_mime_type(mime_type: &str) -> bool {
+    let is_name = |part: &str| {
+        !part.is_empty()
+            && part.len() <= 127
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+    };
+    mime_type
+        .split_once('/')
+        .is_some_and(|(kind, subtype)| is_name(kind) && is_name(subtype))
+}
+
+/// Errors produced by [`DownloadFileParamsBuilder::build`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadFileError {
+    /// The URL does not use `https` or has no host.
+    InsecureUrl(String),
+    /// No file name was set and none could be inferred from the URL.
+    MissingFileName,
+    /// The MIME type is not of the form `type/subtype`.
+    InvalidMimeType(String)
+}
+
+impl std::fmt::Display for DownloadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InsecureUrl(url) => write!(f, "Download URL must use https: {url}"),
+            Self::MissingFileName => write!(f, "Download file name is missing"),
+            Self::InvalidMimeType(mime) => write!(f, "Invalid MIME type: {mime}")
+        }
+    }
+}
+
+impl std::error::Error for DownloadFileError {}
+
+impl From<DownloadFileError> for JsValue {
+    fn from(err: DownloadFileError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::{from_str, to_string};
@@ -43,4 +169,52 @@ mod tests {
         assert_eq!(parsed.file_name, params.file_name);
         assert_eq!(parsed.mime_type, params.mime_type);
     }
+
+    #[test]
+    fn builder_infers_file_name() {
+        let params = DownloadFileParams::builder("https://cdn.example.com/a/b/photo.jpg#top")
+            .build()
+            .unwrap();
+        assert_eq!(params.file_name, Some("photo.jpg"));
+        assert_eq!(params.mime_type, None);
+        assert_eq!(
+            DownloadFileParams::builder("https://example.com/")
+                .build()
+                .unwrap_err(),
+            DownloadFileError::MissingFileName
+        );
+        let named = DownloadFileParams::builder("https://example.com/")
+            .file_name("export.csv")
+            .build()
+            .unwrap();
+        assert_eq!(named.file_name, Some("export.csv"));
+    }
+
+    #[test]
+    fn builder_rejects_invalid_input() {
+        assert_eq!(
+            DownloadFileParams::builder("http://example.com/a.txt")
+                .build()
+                .unwrap_err(),
+            DownloadFileError::InsecureUrl("http://example.com/a.txt".into())
+        );
+        assert!(
+            DownloadFileParams::builder("https:///a.txt")
+                .build()
+                .is_err()
+        );
+        assert_eq!(
+            DownloadFileParams::builder("https://example.com/a.txt")
+                .mime_type("text")
+                .build()
+                .unwrap_err(),
+            DownloadFileError::InvalidMimeType("text".into())
+        );
+        assert!(
+            DownloadFileParams::builder("https://example.com/a.svg")
+                .mime_type("image/svg+xml")
+                .build()
+                .is_ok()
+        );
+    }
 }
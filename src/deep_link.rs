@@ -0,0 +1,493 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Builder for `t.me` and `tg://` deep links.
+//!
+//! [`DeepLink`] formats the links a bot or Mini App hands out, validating the
+//! bot username, app short name and start parameter against Telegram's
+//! limits so a malformed link fails at build time instead of opening the
+//! wrong screen:
+//!
+//! | Constructor              | `https` form                            |
+//! |--------------------------|-----------------------------------------|
+//! | [`DeepLink::mini_app`]   | `t.me/<bot>/<app>?startapp=<param>`     |
+//! | [`DeepLink::main_app`]   | `t.me/<bot>?startapp=<param>`           |
+//! | [`DeepLink::attach`]     | `t.me/<bot>?startattach=<param>`        |
+//! | [`DeepLink::bot_start`]  | `t.me/<bot>?start=<param>`              |
+//! | [`DeepLink::share`]      | `t.me/share/url?url=<url>&text=<text>`  |
+//!
+//! [`DeepLink::tg_scheme`] produces the equivalent `tg://` link, which opens
+//! the Telegram client directly without a browser redirect.
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+/// Maximum length of a `startapp` parameter.
+pub const MAX_START_APP_PARAM: usize = 512;
+
+/// Maximum length of a `start` or `startattach` parameter.
+pub const MAX_START_PARAM: usize = 64;
+
+/// Characters escaped in share link query values (RFC 3986 unreserved are
+/// kept).
+const QUERY: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Errors produced by [`DeepLink::build`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepLinkError {
+    /// The bot username is not 5-32 letters, digits or underscores.
+    InvalidBot(String),
+    /// The app short name is not 3-30 letters, digits or underscores.
+    InvalidApp(String),
+    /// The start parameter contains characters other than `A-Z`, `a-z`,
+    /// `0-9`, `_` and `-`.
+    InvalidParam(String),
+    /// The start parameter exceeds its length limit.
+    ParamTooLong {
+        /// Actual length.
+        len: usize,
+        /// Allowed length.
+        max: usize
+    },
+    /// The shared URL is empty.
+    EmptyUrl
+}
+
+impl std::fmt::Display for DeepLinkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidBot(bot) => write!(f, "Invalid bot username: {bot}"),
+            Self::InvalidApp(app) => write!(f, "Invalid app short name: {app}"),
+            Self::InvalidParam(param) => write!(f, "Invalid start parameter: {param}"),
+            Self::ParamTooLong {
+                len,
+                max
+            } => write!(
+                f,
+                "Start parameter is {len} characters long, limit is {max}"
+            ),
+            Self::EmptyUrl => write!(f, "Shared URL is empty")
+        }
+    }
+}
+
+impl std::error::Error for DeepLinkError {}
+
+impl From<DeepLinkError> for wasm_bindgen::JsValue {
+    fn from(err: DeepLinkError) -> Self {
+        wasm_bindgen::JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Presentation requested with the `mode` parameter of Mini App links.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchMode {
+    /// Half-height sheet.
+    Compact,
+    /// Full screen.
+    Fullscreen
+}
+
+impl LaunchMode {
+    /// Returns the value of the `mode` parameter.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Compact => "compact",
+            Self::Fullscreen => "fullscreen"
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    MiniApp { bot: String, app: String },
+    MainApp { bot: String },
+    Attach { bot: String },
+    BotStart { bot: String },
+    Share { url: String, text: Option<String> }
+}
+
+/// Deep link builder.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::deep_link::{DeepLink, LaunchMode};
+///
+/// # fn main() -> Result<(), telegram_webapp_sdk::deep_link::DeepLinkError> {
+/// let link = DeepLink::mini_app("shop_bot", "store")
+///     .start_param("ref_42")
+///     .mode(LaunchMode::Compact)
+///     .build()?;
+/// assert_eq!(
+///     link,
+///     "https://t.me/shop_bot/store?startapp=ref_42&mode=compact"
+/// );
+///
+/// let link = DeepLink::mini_app("shop_bot", "store")
+///     .start_param("ref_42")
+///     .tg_scheme()
+///     .build()?;
+/// assert_eq!(
+///     link,
+///     "tg://resolve?domain=shop_bot&appname=store&startapp=ref_42"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeepLink {
+    kind:  Kind,
+    param: Option<String>,
+    mode:  Option<LaunchMode>,
+    tg:    bool
+}
+
+impl DeepLink {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            param: None,
+            mode: None,
+            tg: false
+        }
+    }
+
+    /// Link to the Mini App `app` of `bot` (`startapp`).
+    pub fn mini_app(bot: impl Into<String>, app: impl Into<String>) -> Self {
+        Self::new(Kind::MiniApp {
+            bot: username(bot),
+            app: app.into()
+        })
+    }
+
+    /// Link to the main Mini App of `bot` (`startapp` without an app name).
+    pub fn main_app(bot: impl Into<String>) -> Self {
+        Self::new(Kind::MainApp {
+            bot: username(bot)
+        })
+    }
+
+    /// Link adding `bot` to the attachment menu and opening it
+    /// (`startattach`).
+    pub fn attach(bot: impl Into<String>) -> Self {
+        Self::new(Kind::Attach {
+            bot: username(bot)
+        })
+    }
+
+    /// Link starting a chat with `bot` (`start`).
+    pub fn bot_start(bot: impl Into<String>) -> Self {
+        Self::new(Kind::BotStart {
+            bot: username(bot)
+        })
+    }
+
+    /// Link opening the chat picker to share `url`.
+    pub fn share(url: impl Into<String>) -> Self {
+        Self::new(Kind::Share {
+            url:  url.into(),
+            text: None
+        })
+    }
+
+    /// Sets the start parameter passed to the bot or Mini App.
+    ///
+    /// Ignored by share links.
+    pub fn start_param(mut self, param: impl Into<String>) -> Self {
+        self.param = Some(param.into());
+        self
+    }
+
+    /// Sets the message text of a share link.
+    ///
+    /// Ignored by other links.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        if let Kind::Share {
+            text: slot, ..
+        } = &mut self.kind
+        {
+            *slot = Some(text.into());
+        }
+        self
+    }
+
+    /// Requests a presentation mode for Mini App links.
+    ///
+    /// Ignored by attachment, bot and share links.
+    pub fn mode(mut self, mode: LaunchMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Builds a `tg://` link instead of an `https://t.me` one.
+    pub fn tg_scheme(mut self) -> Self {
+        self.tg = true;
+        self
+    }
+
+    /// Validates the parts and formats the link.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeepLinkError`] when the bot username, app short name or
+    /// start parameter violates Telegram's limits, or a share URL is empty.
+    pub fn build(&self) -> Result<String, DeepLinkError> {
+        let (path, mut query) = match &self.kind {
+            Kind::MiniApp {
+                bot,
+                app
+            } => {
+                validate_bot(bot)?;
+                validate_app(app)?;
+                let mut query = Vec::new();
+                self.push_param(&mut query, "startapp", MAX_START_APP_PARAM)?;
+                self.push_mode(&mut query);
+                if self.tg {
+                    query.insert(0, ("appname", app.clone()));
+                    query.insert(0, ("domain", bot.clone()));
+                    ("resolve".to_owned(), query)
+                } else {
+                    (format!("{bot}/{app}"), query)
+                }
+            }
+            Kind::MainApp {
+                bot
+            } => {
+                validate_bot(bot)?;
+                let mut query = Vec::new();
+                match &self.param {
+                    Some(_) => self.push_param(&mut query, "startapp", MAX_START_APP_PARAM)?,
+                    None => query.push(("startapp", String::new()))
+                }
+                self.push_mode(&mut query);
+                self.resolve(bot, query)
+            }
+            Kind::Attach {
+                bot
+            } => {
+                validate_bot(bot)?;
+                let mut query = Vec::new();
+                match &self.param {
+                    Some(_) => self.push_param(&mut query, "startattach", MAX_START_PARAM)?,
+                    None => query.push(("startattach", String::new()))
+                }
+                self.resolve(bot, query)
+            }
+            Kind::BotStart {
+                bot
+            } => {
+                validate_bot(bot)?;
+                let mut query = Vec::new();
+                self.push_param(&mut query, "start", MAX_START_PARAM)?;
+                self.resolve(bot, query)
+            }
+            Kind::Share {
+                url,
+                text
+            } => {
+                if url.is_empty() {
+                    return Err(DeepLinkError::EmptyUrl);
+                }
+                let mut query = vec![("url", encode(url))];
+                if let Some(text) = text {
+                    query.push(("text", encode(text)));
+                }
+                let path = if self.tg { "msg_url" } else { "share/url" };
+                (path.to_owned(), query)
+            }
+        };
+        let base = if self.tg { "tg://" } else { "https://t.me/" };
+        let mut link = format!("{base}{path}");
+        for (i, (key, value)) in query.drain(..).enumerate() {
+            link.push(if i == 0 { '?' } else { '&' });
+            link.push_str(key);
+            if !value.is_empty() {
+                link.push('=');
+                link.push_str(&value);
+            }
+        }
+        Ok(link)
+    }
+
+    /// Returns the path and query for a link addressing `bot` only.
+    fn resolve(
+        &self,
+        bot: &str,
+        mut query: Vec<(&'static str, String)>
+    ) -> (String, Vec<(&'static str, String)>) {
+        if self.tg {
+            query.insert(0, ("domain", bot.to_owned()));
+            ("resolve".to_owned(), query)
+        } else {
+            (bot.to_owned(), query)
+        }
+    }
+
+    fn push_param(
+        &self,
+        query: &mut Vec<(&'static str, String)>,
+        key: &'static str,
+        max: usize
+    ) -> Result<(), DeepLinkError> {
+        if let Some(param) = &self.param {
+            validate_param(param, max)?;
+            query.push((key, param.clone()));
+        }
+        Ok(())
+    }
+
+    fn push_mode(&self, query: &mut Vec<(&'static str, String)>) {
+        if let Some(mode) = self.mode {
+            query.push(("mode", mode.as_str().to_owned()));
+        }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Drops a leading `@` from a username.
+fn username(bot: impl Into<String>) -> String {
+    let bot = bot.into();
+    match bot.strip_prefix('@') {
+        Some(name) => name.to_owned(),
+        None => bot
+    }
+}
+
+fn validate_bot(bot: &str) -> Result<(), DeepLinkError> {
+    if (5..=32).contains(&bot.len()) && bot.chars().all(is_name_char) {
+        Ok(())
+    } else {
+        Err(DeepLinkError::InvalidBot(bot.to_owned()))
+    }
+}
+
+fn validate_app(app: &str) -> Result<(), DeepLinkError> {
+    if (3..=30).contains(&app.len()) && app.chars().all(is_name_char) {
+        Ok(())
+    } else {
+        Err(DeepLinkError::InvalidApp(app.to_owned()))
+    }
+}
+
+fn validate_param(param: &str, max: usize) -> Result<(), DeepLinkError> {
+    if !param.chars().all(|c| is_name_char(c) || c == '-') {
+        return Err(DeepLinkError::InvalidParam(param.to_owned()));
+    }
+    if param.len() > max {
+        return Err(DeepLinkError::ParamTooLong {
+            len: param.len(),
+            max
+        });
+    }
+    Ok(())
+}
+
+fn encode(value: &str) -> String {
+    utf8_percent_encode(value, QUERY).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_https_links() {
+        assert_eq!(
+            DeepLink::main_app("shop_bot").build().unwrap(),
+            "https://t.me/shop_bot?startapp"
+        );
+        assert_eq!(
+            DeepLink::attach("shop_bot")
+                .start_param("promo")
+                .build()
+                .unwrap(),
+            "https://t.me/shop_bot?startattach=promo"
+        );
+        assert_eq!(
+            DeepLink::bot_start("shop_bot").build().unwrap(),
+            "https://t.me/shop_bot"
+        );
+        assert_eq!(
+            DeepLink::mini_app("shop_bot", "store")
+                .mode(LaunchMode::Fullscreen)
+                .build()
+                .unwrap(),
+            "https://t.me/shop_bot/store?mode=fullscreen"
+        );
+    }
+
+    #[test]
+    fn builds_tg_links() {
+        assert_eq!(
+            DeepLink::main_app("shop_bot")
+                .start_param("x")
+                .tg_scheme()
+                .build()
+                .unwrap(),
+            "tg://resolve?domain=shop_bot&startapp=x"
+        );
+        assert_eq!(
+            DeepLink::bot_start("shop_bot")
+                .start_param("hi")
+                .tg_scheme()
+                .build()
+                .unwrap(),
+            "tg://resolve?domain=shop_bot&start=hi"
+        );
+    }
+
+    #[test]
+    fn encodes_share_links() {
+        let link = DeepLink::share("https://t.me/shop_bot/store?startapp=ref_1")
+            .text("Join me & save 10%");
+        assert_eq!(
+            link.build().unwrap(),
+            "https://t.me/share/url?url=https%3A%2F%2Ft.me%2Fshop_bot%2Fstore%3Fstartapp%3Dref_1\
+             &text=Join%20me%20%26%20save%2010%25"
+        );
+        assert!(
+            link.tg_scheme()
+                .build()
+                .unwrap()
+                .starts_with("tg://msg_url?url=")
+        );
+        assert_eq!(DeepLink::share("").build(), Err(DeepLinkError::EmptyUrl));
+    }
+
+    #[test]
+    fn validates_parts() {
+        assert_eq!(
+            DeepLink::main_app("bot").build(),
+            Err(DeepLinkError::InvalidBot("bot".into()))
+        );
+        assert_eq!(
+            DeepLink::mini_app("shop_bot", "a-b").build(),
+            Err(DeepLinkError::InvalidApp("a-b".into()))
+        );
+        assert_eq!(
+            DeepLink::main_app("shop_bot").start_param("a b").build(),
+            Err(DeepLinkError::InvalidParam("a b".into()))
+        );
+        assert_eq!(
+            DeepLink::bot_start("shop_bot")
+                .start_param("x".repeat(65))
+                .build(),
+            Err(DeepLinkError::ParamTooLong {
+                len: 65,
+                max: MAX_START_PARAM
+            })
+        );
+        let long = DeepLink::main_app("@shop_bot")
+            .start_param("x".repeat(512))
+            .build()
+            .unwrap();
+        assert!(long.starts_with("https://t.me/shop_bot?startapp=x"));
+    }
+}
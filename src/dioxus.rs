@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+/// [`bottom_button::BottomButton`] component driving the main/secondary button.
+pub mod bottom_button;
+/// [`safe_area::use_safe_area`] hook exposing safe-area insets reactively.
+pub mod safe_area;
+/// [`theme::use_theme`] hook exposing Telegram theme parameters reactively.
+pub mod theme;
+/// [`viewport::use_viewport`] hook exposing viewport size and state reactively.
+pub mod viewport;
+
+use std::rc::Rc;
+
+pub use bottom_button::BottomButton;
+use dioxus::prelude::{provide_context, try_use_context};
+pub use safe_area::{SafeAreaState, use_safe_area};
+pub use theme::{ThemeState, use_theme};
+pub use viewport::{ViewportState, use_viewport};
+use wasm_bindgen::JsValue;
+
+use crate::core::{context::TelegramContext, safe_context::get_shared_context};
+
+/// Provides the [`TelegramContext`] to the current component and its
+/// descendants.
+///
+/// # Errors
+///
+/// Returns an error if the global context has not been initialized with
+/// [`TelegramContext::init`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use telegram_webapp_sdk::dioxus::{provide_telegram_context, use_shared_telegram_context};
+///
+/// #[component]
+/// fn App() -> Element {
+///     provide_telegram_context().expect("context");
+///     let ctx = use_shared_telegram_context().expect("context");
+///     rsx! { span { "{ctx.init_data.auth_date}" } }
+/// }
+/// ```
+pub fn provide_telegram_context() -> Result<(), JsValue> {
+    provide_context(get_shared_context()?);
+    Ok(())
+}
+
+/// Returns a copy of the [`TelegramContext`] provided by an ancestor,
+/// falling back to the global context.
+///
+/// Prefer [`use_shared_telegram_context`], which avoids the copy.
+///
+/// # Errors
+///
+/// Returns an error if no ancestor called [`provide_telegram_context`] and
+/// the global context has not been initialized.
+pub fn use_telegram_context() -> Result<TelegramContext, JsValue> {
+    use_shared_telegram_context().map(|ctx| (*ctx).clone())
+}
+
+/// Returns a shared handle to the [`TelegramContext`] provided by
+/// an ancestor, falling back to the global context.
+///
+/// # Errors
+///
+/// Returns an error if no ancestor called [`provide_telegram_context`] and
+/// the global context has not been initialized.
+pub fn use_shared_telegram_context() -> Result<Rc<TelegramContext>, JsValue> {
+    match try_use_context::<Rc<TelegramContext>>() {
+        Some(ctx) => Ok(ctx),
+        None => get_shared_context()
+    }
+}
@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::{
+    logger,
+    webapp::{BottomButton as WebBottomButton, BottomButtonParams, TelegramWebApp}
+};
+
+/// Dioxus component that controls a Telegram bottom button.
+///
+/// The component shows the selected bottom button and keeps its text, colors
+/// and state in sync with its props, which are reactive. The click callback is
+/// removed and the button hidden when the component unmounts.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use telegram_webapp_sdk::{
+///     dioxus::BottomButton,
+///     webapp::{BottomButton as Btn, TelegramWebApp}
+/// };
+///
+/// #[component]
+/// fn App() -> Element {
+///     let sending = use_signal(|| false);
+///     rsx! {
+///         BottomButton {
+///             button: Btn::Main,
+///             text: "Send",
+///             progress: sending(),
+///             on_click: move |_| {
+///                 if let Some(app) = TelegramWebApp::instance() {
+///                     let _ = app.send_data("clicked");
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn BottomButton(
+    /// Text label displayed on the button.
+    text: ReadSignal<String>,
+    /// Optional background color as a `#RRGGBB` hex string.
+    color: ReadSignal<Option<String>>,
+    /// Optional text color as a `#RRGGBB` hex string.
+    text_color: ReadSignal<Option<String>>,
+    /// Optional flag showing the loading indicator; the button is inactive
+    /// while it is shown.
+    progress: ReadSignal<Option<bool>>,
+    /// Optional flag; `false` disables the button.
+    enabled: ReadSignal<Option<bool>>,
+    /// Optional flag enabling the shine animation.
+    shine: ReadSignal<Option<bool>>,
+    /// Optional callback invoked when the button is clicked.
+    on_click: Option<EventHandler>,
+    /// Which bottom button to control; defaults to the main button.
+    #[props(default = WebBottomButton::Main)]
+    button: WebBottomButton
+) -> Element {
+    // Show button on mount.
+    use_effect(move || {
+        if let Some(app) = TelegramWebApp::instance() {
+            if let Err(err) = app.show_bottom_button(button) {
+                logger::error(&format!("show_bottom_button failed: {err:?}"));
+            }
+        } else {
+            logger::error("TelegramWebApp instance not available");
+        }
+    });
+
+    // Update text when the signal changes.
+    use_effect(move || {
+        let text = text.read();
+        if let Some(app) = TelegramWebApp::instance()
+            && let Err(err) = app.set_bottom_button_text(button, &text)
+        {
+            logger::error(&format!("set_bottom_button_text failed: {err:?}"));
+        }
+    });
+
+    // Update button color.
+    use_effect(move || {
+        let Some(color) = color.cloned() else {
+            return;
+        };
+        if let Some(app) = TelegramWebApp::instance()
+            && let Err(err) = app.set_bottom_button_color(button, &color)
+        {
+            logger::error(&format!("set_bottom_button_color failed: {err:?}"));
+        }
+    });
+
+    // Update text color.
+    use_effect(move || {
+        let Some(text_color) = text_color.cloned() else {
+            return;
+        };
+        if let Some(app) = TelegramWebApp::instance()
+            && let Err(err) = app.set_bottom_button_text_color(button, &text_color)
+        {
+            logger::error(&format!("set_bottom_button_text_color failed: {err:?}"));
+        }
+    });
+
+    // Toggle the loading indicator.
+    use_effect(move || {
+        let Some(progress) = progress() else {
+            return;
+        };
+        let Some(app) = TelegramWebApp::instance() else {
+            return;
+        };
+        let result = if progress {
+            app.show_bottom_button_progress(button, false)
+        } else {
+            app.hide_bottom_button_progress(button)
+        };
+        if let Err(err) = result {
+            logger::error(&format!("bottom button progress update failed: {err:?}"));
+        }
+    });
+
+    // Enable or disable the button.
+    use_effect(move || {
+        let Some(enabled) = enabled() else {
+            return;
+        };
+        let Some(app) = TelegramWebApp::instance() else {
+            return;
+        };
+        let result = if enabled {
+            app.enable_bottom_button(button)
+        } else {
+            app.disable_bottom_button(button)
+        };
+        if let Err(err) = result {
+            logger::error(&format!("bottom button enable/disable failed: {err:?}"));
+        }
+    });
+
+    // Update the shine effect.
+    use_effect(move || {
+        let Some(shine) = shine() else {
+            return;
+        };
+        let params = BottomButtonParams {
+            has_shine_effect: Some(shine),
+            ..Default::default()
+        };
+        if let Some(app) = TelegramWebApp::instance()
+            && let Err(err) = app.set_bottom_button_params(button, &params)
+        {
+            logger::error(&format!("set_bottom_button_params failed: {err:?}"));
+        }
+    });
+
+    // Register the click callback once and keep its handle for cleanup.
+    let handle = use_hook(|| {
+        let handle = on_click.and_then(|on_click| {
+            let app = TelegramWebApp::instance()?;
+            app.set_bottom_button_callback(button, move || on_click.call(()))
+                .inspect_err(|err| {
+                    logger::error(&format!("set_bottom_button_callback failed: {err:?}"));
+                })
+                .ok()
+        });
+        Rc::new(RefCell::new(handle))
+    });
+
+    // Cleanup: remove callback and hide button when the component unmounts.
+    use_drop(move || {
+        let Some(app) = TelegramWebApp::instance() else {
+            return;
+        };
+        if let Some(handle) = handle.borrow_mut().take()
+            && let Err(err) = app.remove_bottom_button_callback(handle)
+        {
+            logger::error(&format!("remove_bottom_button_callback failed: {err:?}"));
+        }
+        if let Err(err) = app.hide_bottom_button(button) {
+            logger::error(&format!("hide_bottom_button failed: {err:?}"));
+        }
+    });
+
+    // Component renders no DOM nodes.
+    rsx! {}
+}
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::webapp::{SafeAreaInset, TelegramWebApp};
+
+/// Snapshot of `Telegram.WebApp` safe-area insets.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct SafeAreaState {
+    /// `WebApp.safeAreaInset`.
+    pub area:    Option<SafeAreaInset>,
+    /// `WebApp.contentSafeAreaInset`.
+    pub content: Option<SafeAreaInset>
+}
+
+impl SafeAreaState {
+    /// Returns the combined device and content insets, treating missing ones
+    /// as zero.
+    pub fn total(&self) -> SafeAreaInset {
+        self.area
+            .unwrap_or_default()
+            .combine(self.content.unwrap_or_default())
+    }
+
+    fn snapshot(app: Option<&TelegramWebApp>) -> Self {
+        match app {
+            Some(app) => Self {
+                area:    app.safe_area_inset(),
+                content: app.content_safe_area_inset()
+            },
+            None => Self::default()
+        }
+    }
+}
+
+/// Dioxus reactive hook over the safe-area insets.
+///
+/// Updates on both `safeAreaChanged` and `contentSafeAreaChanged`. The
+/// subscriptions are removed when the component unmounts.
+///
+/// # Examples
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use telegram_webapp_sdk::dioxus::use_safe_area;
+///
+/// #[component]
+/// fn Content() -> Element {
+///     let safe = use_safe_area();
+///     let top = safe.read().total().top;
+///     rsx! { div { padding_top: "{top}px" } }
+/// }
+/// ```
+pub fn use_safe_area() -> ReadSignal<SafeAreaState> {
+    let (state, _handles) = use_hook(|| {
+        let app = TelegramWebApp::instance();
+        let state = Signal::new(SafeAreaState::snapshot(app.as_ref()));
+        let handles = app.map(|app| {
+            let app_area = app.clone();
+            let area = app
+                .on_safe_area_changed(move || {
+                    let mut writer = state;
+                    writer.set(SafeAreaState::snapshot(Some(&app_area)));
+                })
+                .ok();
+            let app_content = app.clone();
+            let content = app
+                .on_content_safe_area_changed(move || {
+                    let mut writer = state;
+                    writer.set(SafeAreaState::snapshot(Some(&app_content)));
+                })
+                .ok();
+            (area, content)
+        });
+        (ReadSignal::new(state), Rc::new(handles))
+    });
+    state
+}
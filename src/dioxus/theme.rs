@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{
+    api::theme::get_theme_params, core::types::theme_params::TelegramThemeParams,
+    webapp::TelegramWebApp
+};
+
+/// Snapshot of `Telegram.WebApp` theme state.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ThemeState {
+    /// `"light"` or `"dark"`.
+    pub color_scheme: Option<String>,
+    /// Parsed theme palette.
+    pub params:       TelegramThemeParams
+}
+
+impl ThemeState {
+    fn snapshot(app: Option<&TelegramWebApp>) -> Self {
+        let color_scheme = app.and_then(|a| a.color_scheme());
+        let params = app
+            .and_then(|_| get_theme_params().ok())
+            .unwrap_or_default();
+        Self {
+            color_scheme,
+            params
+        }
+    }
+}
+
+/// Dioxus reactive hook over `Telegram.WebApp` theme state.
+///
+/// Updates on `themeChanged`. The subscription is removed when the component
+/// unmounts.
+///
+/// # Examples
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use telegram_webapp_sdk::dioxus::use_theme;
+///
+/// #[component]
+/// fn ThemeBadge() -> Element {
+///     let theme = use_theme();
+///     let scheme = theme.read().color_scheme.clone().unwrap_or_default();
+///     rsx! { span { "{scheme}" } }
+/// }
+/// ```
+pub fn use_theme() -> ReadSignal<ThemeState> {
+    // The handle lives in the hook list, so it is dropped, and the
+    // subscription removed, together with the component.
+    let (state, _handle) = use_hook(|| {
+        let app = TelegramWebApp::instance();
+        let state = Signal::new(ThemeState::snapshot(app.as_ref()));
+        let handle = app.and_then(|app| {
+            let app_for_handler = app.clone();
+            app.on_theme_changed(move || {
+                let mut writer = state;
+                writer.set(ThemeState::snapshot(Some(&app_for_handler)));
+            })
+            .ok()
+        });
+        (ReadSignal::new(state), Rc::new(handle))
+    });
+    state
+}
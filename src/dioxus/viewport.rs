@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::webapp::TelegramWebApp;
+
+/// Snapshot of `Telegram.WebApp`'s viewport-related properties.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ViewportState {
+    /// Current visible viewport height in CSS pixels.
+    pub height:        f64,
+    /// Stable viewport height (does not change while the user pulls the chat).
+    pub stable_height: f64,
+    /// Whether the mini app is currently expanded.
+    pub is_expanded:   bool
+}
+
+impl ViewportState {
+    fn snapshot(app: Option<&TelegramWebApp>) -> Self {
+        match app {
+            Some(app) => Self {
+                height:        app.viewport_height().unwrap_or(0.0),
+                stable_height: app.viewport_stable_height().unwrap_or(0.0),
+                is_expanded:   app.is_expanded()
+            },
+            None => Self::default()
+        }
+    }
+}
+
+/// Dioxus reactive hook over `Telegram.WebApp` viewport state.
+///
+/// The returned [`ReadSignal`] starts with a snapshot taken at mount time and
+/// updates whenever Telegram fires `viewportChanged`. The subscription is
+/// removed when the component unmounts.
+///
+/// # Examples
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use telegram_webapp_sdk::dioxus::use_viewport;
+///
+/// #[component]
+/// fn ViewportBadge() -> Element {
+///     let viewport = use_viewport();
+///     rsx! { span { "{viewport.read().height}" } }
+/// }
+/// ```
+pub fn use_viewport() -> ReadSignal<ViewportState> {
+    let (state, _handle) = use_hook(|| {
+        let app = TelegramWebApp::instance();
+        let state = Signal::new(ViewportState::snapshot(app.as_ref()));
+        let handle = app.and_then(|app| {
+            let app_for_handler = app.clone();
+            app.on_viewport_changed(move || {
+                let mut writer = state;
+                writer.set(ViewportState::snapshot(Some(&app_for_handler)));
+            })
+            .ok()
+        });
+        (ReadSignal::new(state), Rc::new(handle))
+    });
+    state
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use wasm_bindgen::JsValue;
+
+use super::document::Doc;
+
+/// Stylesheet giving plain HTML the look of a native Mini App.
+///
+/// Styles `body` and links, and provides the `tg-button` (with
+/// `tg-button--secondary` and `tg-button--destructive` modifiers),
+/// `tg-input`, `tg-section`, `tg-section-header`, `tg-cell`, `tg-hint`,
+/// `tg-subtitle` and `tg-accent` classes. Colors come from the
+/// `--tg-theme-*` variables, so they follow theme changes without code, and
+/// every selector is wrapped in `:where()` so that any app rule overrides it.
+pub const BASE_STYLESHEET: &str = include_str!("base.css");
+
+/// Id of the `<style>` element added by [`inject_base_styles`].
+pub const BASE_STYLES_ID: &str = "tg-base-styles";
+
+/// Appends [`BASE_STYLESHEET`] to the document head.
+///
+/// Does nothing if the stylesheet was already injected. The
+/// `inject_base_styles` option of [`AppOptions`](crate::core::init::AppOptions)
+/// calls this at startup.
+///
+/// # Errors
+///
+/// Returns `Err` when the document or its head is unavailable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::dom::base_styles::inject_base_styles;
+///
+/// # fn example() -> Result<(), wasm_bindgen::JsValue> {
+/// inject_base_styles()?;
+/// // <button class="tg-button">Continue</button> now looks native
+/// # Ok(())
+/// # }
+/// ```
+pub fn inject_base_styles() -> Result<(), JsValue> {
+    if Doc.get_element_by_id(BASE_STYLES_ID).is_some() {
+        return Ok(());
+    }
+    let head = web_sys::window()
+        .and_then(|win| win.document())
+        .and_then(|doc| doc.head())
+        .ok_or_else(|| JsValue::from_str("document head not available"))?;
+    let style = Doc.create_element("style")?;
+    style.set_id(BASE_STYLES_ID);
+    style.set_text_content(Some(BASE_STYLESHEET));
+    // Prepend so stylesheets linked by the app come later and win ties.
+    head.prepend_with_node_1(&style)
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn stylesheet_uses_theme_variables() {
+        assert!(BASE_STYLESHEET.contains(".tg-button"));
+        assert!(BASE_STYLESHEET.contains("var(--tg-theme-button-color"));
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn injects_once() {
+        inject_base_styles().unwrap();
+        inject_base_styles().unwrap();
+        let count = js_sys::Function::new_with_args(
+            "id",
+            "return document.querySelectorAll('#' + id).length;"
+        )
+        .call1(&JsValue::NULL, &BASE_STYLES_ID.into())
+        .unwrap();
+        assert_eq!(count.as_f64(), Some(1.0));
+    }
+}
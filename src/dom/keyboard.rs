@@ -0,0 +1,235 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::RefCell, rc::Rc};
+
+use js_sys::{Function, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{Element, Event, EventTarget, window};
+
+use crate::webapp::{EventHandle, TelegramWebApp};
+
+#[derive(Default)]
+struct State {
+    focused:  Option<Element>,
+    scroll_y: f64
+}
+
+/// Keeps focused inputs visible above the on-screen keyboard.
+///
+/// While installed, focusing an `input`, `textarea`, `select` or
+/// `contenteditable` element remembers the scroll position; every following
+/// `viewportChanged` event (a `resize` outside Telegram) scrolls the element
+/// back into the visible viewport, and blurring it restores the scroll
+/// position. This works around WebViews where the keyboard covers the input.
+///
+/// Listeners are removed when the value is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::dom::KeyboardAvoider;
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let avoider = KeyboardAvoider::install()?;
+/// // keep `avoider` alive as long as the form is shown
+/// # drop(avoider);
+/// # Ok(())
+/// # }
+/// ```
+pub struct KeyboardAvoider {
+    document:  EventTarget,
+    focus_in:  Closure<dyn FnMut(Event)>,
+    focus_out: Closure<dyn FnMut(Event)>,
+    resize:    Resize
+}
+
+/// Source of viewport updates.
+enum Resize {
+    /// Unsubscribes from `viewportChanged` when dropped.
+    Telegram {
+        _handle: EventHandle<dyn FnMut()>
+    },
+    Window(EventTarget, Closure<dyn FnMut()>)
+}
+
+impl KeyboardAvoider {
+    /// Starts tracking focused inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the window or document is unavailable or a listener
+    /// cannot be registered.
+    pub fn install() -> Result<Self, JsValue> {
+        let win = window().ok_or_else(|| JsValue::from_str("window not available"))?;
+        let document: EventTarget = win
+            .document()
+            .ok_or_else(|| JsValue::from_str("document not available"))?
+            .into();
+        let state = Rc::new(RefCell::new(State::default()));
+
+        let focus_in = {
+            let state = Rc::clone(&state);
+            let win = win.clone();
+            Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+                let Some(element) = event.target().and_then(|t| t.dyn_into::<Element>().ok())
+                else {
+                    return;
+                };
+                if !is_editable(&element) {
+                    return;
+                }
+                let mut state = state.borrow_mut();
+                // Moving between fields keeps the position saved by the first.
+                if state.focused.is_none() {
+                    state.scroll_y = win.scroll_y().unwrap_or_default();
+                }
+                reveal(&element);
+                state.focused = Some(element);
+            })
+        };
+        let focus_out = {
+            let state = Rc::clone(&state);
+            let win = win.clone();
+            Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+                // `relatedTarget` is the element receiving focus, if any.
+                let next = Reflect::get(&event, &"relatedTarget".into())
+                    .ok()
+                    .and_then(|t| t.dyn_into::<Element>().ok());
+                if next.as_ref().is_some_and(is_editable) {
+                    return;
+                }
+                let mut state = state.borrow_mut();
+                if state.focused.take().is_some() {
+                    win.scroll_to_with_x_and_y(0.0, state.scroll_y);
+                }
+            })
+        };
+        document.add_event_listener_with_callback("focusin", focus_in.as_ref().unchecked_ref())?;
+        document
+            .add_event_listener_with_callback("focusout", focus_out.as_ref().unchecked_ref())?;
+
+        let on_resize = move || {
+            if let Some(element) = &state.borrow().focused {
+                reveal(element);
+            }
+        };
+        let resize = match TelegramWebApp::instance() {
+            Some(app) => Resize::Telegram {
+                _handle: app.on_viewport_changed(on_resize)?
+            },
+            None => {
+                let target: EventTarget = win.into();
+                let closure = Closure::<dyn FnMut()>::new(on_resize);
+                target.add_event_listener_with_callback(
+                    "resize",
+                    closure.as_ref().unchecked_ref()
+                )?;
+                Resize::Window(target, closure)
+            }
+        };
+
+        Ok(Self {
+            document,
+            focus_in,
+            focus_out,
+            resize
+        })
+    }
+}
+
+impl Drop for KeyboardAvoider {
+    fn drop(&mut self) {
+        let _ = self.document.remove_event_listener_with_callback(
+            "focusin",
+            self.focus_in.as_ref().unchecked_ref()
+        );
+        let _ = self.document.remove_event_listener_with_callback(
+            "focusout",
+            self.focus_out.as_ref().unchecked_ref()
+        );
+        if let Resize::Window(target, closure) = &self.resize {
+            let _ = target
+                .remove_event_listener_with_callback("resize", closure.as_ref().unchecked_ref());
+        }
+    }
+}
+
+fn is_editable(element: &Element) -> bool {
+    matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+        || Reflect::get(element, &"isContentEditable".into())
+            .ok()
+            .and_then(|editable| editable.as_bool())
+            .unwrap_or(false)
+}
+
+/// Scrolls `element` to the middle of the visible viewport when it is
+/// outside of it.
+fn reveal(element: &Element) {
+    let visible = TelegramWebApp::instance()
+        .and_then(|app| app.viewport_height())
+        .or_else(|| window()?.inner_height().ok()?.as_f64());
+    let Some(visible) = visible else {
+        return;
+    };
+    let rect = call(element, "getBoundingClientRect", &JsValue::UNDEFINED);
+    let edge = |name: &str| {
+        rect.as_ref()
+            .and_then(|rect| Reflect::get(rect, &name.into()).ok())
+            .and_then(|value| value.as_f64())
+    };
+    let (Some(top), Some(bottom)) = (edge("top"), edge("bottom")) else {
+        return;
+    };
+    if top >= 0.0 && bottom <= visible {
+        return;
+    }
+    let options = Object::new();
+    let _ = Reflect::set(&options, &"block".into(), &"center".into());
+    let _ = Reflect::set(&options, &"behavior".into(), &"smooth".into());
+    let _ = call(element, "scrollIntoView", &options);
+}
+
+fn call(element: &Element, method: &str, arg: &JsValue) -> Option<JsValue> {
+    Reflect::get(element, &method.into())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?
+        .call1(element, arg)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::dom::Document;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn restores_scroll_after_blur() {
+        let win = window().unwrap();
+        let _ = Reflect::delete_property(&win, &"Telegram".into());
+        let body = Document.body().unwrap();
+        let spacer = Document.create_element("div").unwrap();
+        spacer.set_attribute("style", "height: 5000px").unwrap();
+        let input = Document.create_element("input").unwrap();
+        body.append_child(&spacer).unwrap();
+        body.append_child(&input).unwrap();
+        win.scroll_to_with_x_and_y(0.0, 0.0);
+
+        let avoider = KeyboardAvoider::install().unwrap();
+        let html: &web_sys::HtmlElement = input.unchecked_ref();
+        html.focus().unwrap();
+        html.blur().unwrap();
+        assert_eq!(win.scroll_y().unwrap(), 0.0);
+
+        drop(avoider);
+        spacer.remove();
+        input.remove();
+    }
+}
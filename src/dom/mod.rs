@@ -3,13 +3,27 @@
 
 //! Thin ergonomic wrappers over `web-sys` for DOM access.
 //!
-//! Provides a [`Document`] handle for resolving the current document and an
-//! [`ElementExt`] trait with convenience methods for manipulating elements.
+//! Provides a [`Document`] handle for resolving the current document, an
+//! [`ElementExt`] trait with convenience methods for manipulating elements,
+//! a [`KeyboardAvoider`] keeping focused inputs visible, a [`ThemeClass`]
+//! exposing the color scheme to CSS, an injectable [`base_styles`] sheet and
+//! themed
+//! [`widgets`] for apps built without a UI framework.
 
+/// Injectable stylesheet with a native Telegram look.
+pub mod base_styles;
 /// Document access helpers.
 pub mod document;
 /// Element extension trait.
 pub mod element;
+/// Keeping focused inputs visible above the on-screen keyboard.
+pub mod keyboard;
+/// `tg-dark`/`tg-light` class on `<body>` following the color scheme.
+pub mod theme_class;
+/// Themed header, list cell, skeleton and toast widgets.
+pub mod widgets;
 
 pub use document::Doc as Document;
 pub use element::ElementExt;
+pub use keyboard::KeyboardAvoider;
+pub use theme_class::ThemeClass;
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use wasm_bindgen::JsValue;
+use web_sys::window;
+
+use crate::{
+    bus::{self, Subscription, WebAppEvent},
+    webapp::TelegramWebApp
+};
+
+/// Class set on `<body>` while `colorScheme` is `dark`.
+pub const DARK_CLASS: &str = "tg-dark";
+
+/// Class set on `<body>` while `colorScheme` is `light`.
+pub const LIGHT_CLASS: &str = "tg-light";
+
+/// Mirrors `WebApp.colorScheme` as a `tg-dark`/`tg-light` class on `<body>`.
+///
+/// The class is applied on install and updated on every `themeChanged`
+/// event, so plain CSS can target the theme:
+///
+/// ```css
+/// body.tg-dark .logo { filter: invert(1); }
+/// ```
+///
+/// Updates stop when the value is dropped; the class is left as is. The
+/// `theme_class` option of [`AppOptions`](crate::core::init::AppOptions)
+/// installs it for the whole session.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::dom::ThemeClass;
+///
+/// # fn example() -> Result<(), wasm_bindgen::JsValue> {
+/// let theme_class = ThemeClass::install()?;
+/// # drop(theme_class);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "the class stops updating when dropped"]
+#[derive(Debug)]
+pub struct ThemeClass {
+    _subscription: Subscription
+}
+
+impl ThemeClass {
+    /// Applies the current scheme and follows its changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when `Telegram.WebApp` or the document is unavailable.
+    pub fn install() -> Result<Self, JsValue> {
+        apply()?;
+        let subscription = bus::subscribe(|event| {
+            if *event == WebAppEvent::ThemeChanged {
+                let _ = apply();
+            }
+        });
+        Ok(Self {
+            _subscription: subscription
+        })
+    }
+}
+
+/// Sets the class matching the current `colorScheme` and removes the other.
+fn apply() -> Result<(), JsValue> {
+    let app = TelegramWebApp::try_instance()?;
+    let body = window()
+        .and_then(|win| win.document())
+        .and_then(|doc| doc.body())
+        .ok_or_else(|| JsValue::from_str("document body not available"))?;
+    let dark = app.color_scheme().as_deref() == Some("dark");
+    let (add, remove) = if dark {
+        (DARK_CLASS, LIGHT_CLASS)
+    } else {
+        (LIGHT_CLASS, DARK_CLASS)
+    };
+    let classes = body.class_list();
+    classes.remove_1(remove)?;
+    classes.add_1(add)
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Function, Object, Reflect};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn follows_color_scheme() {
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let _ = Reflect::set(&webapp, &"colorScheme".into(), &"dark".into());
+        let on_event = Function::new_with_args("name, cb", "this[name] = cb;");
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &Function::new_no_args(""));
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        let body = win.document().unwrap().body().unwrap();
+
+        let theme_class = ThemeClass::install().unwrap();
+        assert!(body.class_list().contains(DARK_CLASS));
+
+        let _ = Reflect::set(&webapp, &"colorScheme".into(), &"light".into());
+        bus::publish(&WebAppEvent::ThemeChanged);
+        assert!(body.class_list().contains(LIGHT_CLASS));
+        assert!(!body.class_list().contains(DARK_CLASS));
+        drop(theme_class);
+    }
+}
@@ -0,0 +1,284 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::Cell, time::Duration};
+
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::HtmlElement;
+
+use super::document::Doc;
+
+/// Class of the elements returned by [`skeleton`].
+pub const SKELETON_CLASS: &str = "tg-skeleton";
+
+/// Rules that cannot be expressed with inline styles.
+const STYLESHEET: &str = "@keyframes tg-skeleton-pulse { 50% { opacity: 0.4; } } \
+                          .tg-skeleton { animation: tg-skeleton-pulse 1.2s ease-in-out infinite; }";
+
+thread_local! {
+    static STYLE_INJECTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Creates a sticky page header with a title and an optional subtitle.
+///
+/// The header uses the theme header and text colors and is padded by the top
+/// safe-area insets (`--tg-safe-area-inset-top` and
+/// `--tg-content-safe-area-inset-top`), so it clears the Telegram controls
+/// in fullscreen mode.
+///
+/// # Errors
+///
+/// Returns `Err` when the document is unavailable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::dom::{Document, ElementExt, widgets};
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let header = widgets::header("Orders", Some("3 active"))?;
+/// Document.body()?.prepend(&header)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn header(title: &str, subtitle: Option<&str>) -> Result<HtmlElement, JsValue> {
+    let element = styled(
+        "header",
+        "position: sticky; top: 0; z-index: 10; \
+         padding: calc(12px + var(--tg-safe-area-inset-top, 0px) \
+         + var(--tg-content-safe-area-inset-top, 0px)) 16px 12px; \
+         background: var(--tg-theme-header-bg-color, var(--tg-theme-bg-color)); \
+         color: var(--tg-theme-text-color);"
+    )?;
+    let heading = styled("h1", "margin: 0; font-size: 20px; font-weight: 600;")?;
+    heading.set_text_content(Some(title));
+    element.append_child(&heading)?;
+    if let Some(subtitle) = subtitle {
+        let hint = styled(
+            "p",
+            "margin: 2px 0 0; font-size: 14px; color: var(--tg-theme-subtitle-text-color, \
+             var(--tg-theme-hint-color));"
+        )?;
+        hint.set_text_content(Some(subtitle));
+        element.append_child(&hint)?;
+    }
+    Ok(element)
+}
+
+/// Creates a list cell with a title, an optional subtitle below it and an
+/// optional value on the right.
+///
+/// # Errors
+///
+/// Returns `Err` when the document is unavailable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::dom::{Document, ElementExt, widgets};
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let list = Document.create_element("div")?;
+/// let burger = widgets::list_cell("Burger", Some("Beef, cheese"), Some("$5"))?;
+/// let fries = widgets::list_cell("Fries", None, Some("$2"))?;
+/// list.append(&burger)?;
+/// list.append(&fries)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn list_cell(
+    title: &str,
+    subtitle: Option<&str>,
+    value: Option<&str>
+) -> Result<HtmlElement, JsValue> {
+    let element = styled(
+        "div",
+        "display: flex; align-items: center; gap: 12px; padding: 10px 16px; \
+         background: var(--tg-theme-section-bg-color, var(--tg-theme-bg-color)); \
+         color: var(--tg-theme-text-color); \
+         border-bottom: 1px solid var(--tg-theme-section-separator-color, \
+         var(--tg-theme-hint-color));"
+    )?;
+    let body = styled("div", "flex: 1; min-width: 0;")?;
+    let heading = styled("div", "font-size: 16px;")?;
+    heading.set_text_content(Some(title));
+    body.append_child(&heading)?;
+    if let Some(subtitle) = subtitle {
+        let hint = styled(
+            "div",
+            "margin-top: 2px; font-size: 14px; color: var(--tg-theme-subtitle-text-color, \
+             var(--tg-theme-hint-color));"
+        )?;
+        hint.set_text_content(Some(subtitle));
+        body.append_child(&hint)?;
+    }
+    element.append_child(&body)?;
+    if let Some(value) = value {
+        let trailing = styled("div", "color: var(--tg-theme-hint-color);")?;
+        trailing.set_text_content(Some(value));
+        element.append_child(&trailing)?;
+    }
+    Ok(element)
+}
+
+/// Creates a pulsing placeholder block of the given CSS `width` and `height`,
+/// shown while content loads.
+///
+/// The pulse animation is injected into the document head on first use.
+///
+/// # Errors
+///
+/// Returns `Err` when the document is unavailable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::dom::{Document, ElementExt, widgets};
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let placeholder = widgets::skeleton("100%", "48px")?;
+/// Document.body()?.append(&placeholder)?;
+/// // Replace `placeholder` once the data has arrived.
+/// # Ok(())
+/// # }
+/// ```
+pub fn skeleton(width: &str, height: &str) -> Result<HtmlElement, JsValue> {
+    inject_stylesheet();
+    let element = styled(
+        "div",
+        &format!(
+            "width: {width}; height: {height}; border-radius: 8px; \
+             background: var(--tg-theme-secondary-bg-color, var(--tg-theme-hint-color));"
+        )
+    )?;
+    element.set_class_name(SKELETON_CLASS);
+    element.set_attribute("aria-busy", "true")?;
+    Ok(element)
+}
+
+/// Shows `message` in a toast above the bottom safe-area insets and removes
+/// it after `duration`.
+///
+/// Returns the toast element so it can be removed earlier.
+///
+/// # Errors
+///
+/// Returns `Err` when the document or its body is unavailable or the timer
+/// cannot be scheduled.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::dom::widgets;
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// widgets::show_toast("Saved", Duration::from_secs(2))?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn show_toast(message: &str, duration: Duration) -> Result<HtmlElement, JsValue> {
+    let element = styled(
+        "div",
+        "position: fixed; left: 16px; right: 16px; z-index: 1000; \
+         bottom: calc(16px + var(--tg-safe-area-inset-bottom, 0px) \
+         + var(--tg-content-safe-area-inset-bottom, 0px)); \
+         padding: 12px 16px; border-radius: 12px; text-align: center; \
+         background: var(--tg-theme-text-color); color: var(--tg-theme-bg-color);"
+    )?;
+    element.set_attribute("role", "status")?;
+    element.set_text_content(Some(message));
+    Doc.body()?.append_child(&element)?;
+
+    let toast = element.clone();
+    let remove = Closure::once_into_js(move || toast.remove());
+    let timeout = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+    web_sys::window()
+        .ok_or_else(|| JsValue::from_str("window not available"))?
+        .set_timeout_with_callback_and_timeout_and_arguments_0(remove.unchecked_ref(), timeout)?;
+    Ok(element)
+}
+
+/// Creates an element of `tag` with the inline `style`.
+fn styled(tag: &str, style: &str) -> Result<HtmlElement, JsValue> {
+    let element = Doc.create_element(tag)?;
+    element.set_attribute("style", style)?;
+    element.dyn_into::<HtmlElement>().map_err(JsValue::from)
+}
+
+/// Appends [`STYLESHEET`] to the document head once.
+fn inject_stylesheet() {
+    if STYLE_INJECTED.with(Cell::get) {
+        return;
+    }
+    let Some(document) = web_sys::window().and_then(|win| win.document()) else {
+        return;
+    };
+    if let (Some(head), Ok(style)) = (document.head(), document.create_element("style")) {
+        style.set_text_content(Some(STYLESHEET));
+        if head.append_child(&style).is_ok() {
+            STYLE_INJECTED.with(|injected| injected.set(true));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn header_renders_title_and_subtitle() {
+        let el = header("Orders", Some("3 active")).expect("header");
+        assert_eq!(el.tag_name(), "HEADER");
+        assert_eq!(el.child_element_count(), 2);
+        assert_eq!(el.text_content().as_deref(), Some("Orders3 active"));
+        assert_eq!(
+            header("Orders", None)
+                .expect("header")
+                .child_element_count(),
+            1
+        );
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn list_cell_renders_optional_parts() {
+        let full = list_cell("Burger", Some("Beef"), Some("$5")).expect("cell");
+        assert_eq!(full.child_element_count(), 2);
+        assert_eq!(full.text_content().as_deref(), Some("BurgerBeef$5"));
+
+        let plain = list_cell("Fries", None, None).expect("cell");
+        assert_eq!(plain.child_element_count(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn skeleton_sets_size_and_injects_animation() {
+        let el = skeleton("50%", "20px").expect("skeleton");
+        assert_eq!(el.class_name(), SKELETON_CLASS);
+        let style = el.get_attribute("style").unwrap_or_default();
+        assert!(style.contains("width: 50%"));
+        assert!(style.contains("height: 20px"));
+        assert!(STYLE_INJECTED.with(Cell::get));
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn toast_is_attached_above_safe_area() {
+        let el = show_toast("Saved", Duration::from_secs(60)).expect("toast");
+        assert!(el.parent_element().is_some());
+        let style = el.get_attribute("style").unwrap_or_default();
+        assert!(style.contains("--tg-safe-area-inset-bottom"));
+        el.remove();
+    }
+}
@@ -0,0 +1,246 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Error reports posted to the mini app's backend.
+//!
+//! Once a [`ReportConfig`] is installed, [`capture_error`],
+//! [`capture_message`] and [`capture_panic`] post an [`ErrorReport`] enriched
+//! with the client platform, Bot API version and user id. Reports are sent
+//! immediately with `keepalive` through [`TelegramFetch`], so they carry the
+//! raw init data and survive a panic or the WebView closing. A sliding
+//! window rate limit keeps an error in a render loop from flooding the
+//! endpoint; reports over the limit are dropped.
+//!
+//! Panics are captured by passing [`capture_panic`] to
+//! [`PanicHook::on_panic`](crate::logger::PanicHook::on_panic).
+
+use std::{cell::RefCell, collections::VecDeque, error::Error, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::context::TelegramContext, http::TelegramFetch, logger, utils::clock::now_ms,
+    webapp::TelegramWebApp
+};
+
+/// Reports allowed per [`DEFAULT_RATE_WINDOW`] by default.
+pub const DEFAULT_RATE_LIMIT: usize = 10;
+
+/// Window of the default rate limit.
+pub const DEFAULT_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// What produced an [`ErrorReport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportKind {
+    /// An error value passed to [`capture_error`].
+    Error,
+    /// A panic captured by [`capture_panic`].
+    Panic,
+    /// A message passed to [`capture_message`].
+    Message
+}
+
+/// Request body posted to the error endpoint.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ErrorReport {
+    /// Source of the report.
+    pub kind:      ReportKind,
+    /// Error or panic message.
+    pub message:   String,
+    /// Messages of the error's `source()` chain, outermost first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes:    Vec<String>,
+    /// `WebApp.platform`, e.g. `ios`.
+    pub platform:  Option<String>,
+    /// `WebApp.version`, the Bot API version of the client.
+    pub version:   Option<String>,
+    /// Id of the current user from the init data.
+    pub user_id:   Option<u64>,
+    /// Unix time in milliseconds when the report was captured.
+    pub timestamp: u64
+}
+
+/// Error reporting configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::{
+///     error_report::{self, ReportConfig},
+///     http::TelegramFetch,
+///     logger::PanicHook
+/// };
+///
+/// ReportConfig::new(TelegramFetch::new("https://api.example.com"), "/errors")
+///     .rate_limit(5, Duration::from_secs(60))
+///     .install();
+/// PanicHook::new()
+///     .alert("Something went wrong")
+///     .on_panic(error_report::capture_panic)
+///     .install();
+///
+/// error_report::capture_message("payment widget failed to load");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReportConfig {
+    client: TelegramFetch,
+    path:   String,
+    limit:  usize,
+    window: Duration
+}
+
+impl ReportConfig {
+    /// Posts reports to `path` of the `client` API.
+    pub fn new(client: TelegramFetch, path: impl Into<String>) -> Self {
+        Self {
+            client,
+            path: path.into(),
+            limit: DEFAULT_RATE_LIMIT,
+            window: DEFAULT_RATE_WINDOW
+        }
+    }
+
+    /// Allows at most `limit` reports per `window`.
+    pub fn rate_limit(mut self, limit: usize, window: Duration) -> Self {
+        self.limit = limit;
+        self.window = window;
+        self
+    }
+
+    /// Starts submitting captured reports, replacing a previously installed
+    /// configuration.
+    pub fn install(self) {
+        REPORTER.with(|slot| {
+            *slot.borrow_mut() = Some(State {
+                config: self,
+                sent:   VecDeque::new()
+            });
+        });
+    }
+}
+
+struct State {
+    config: ReportConfig,
+    /// Send times within the current window, oldest first.
+    sent:   VecDeque<u64>
+}
+
+impl State {
+    /// Records a send at `now` if the rate limit allows it.
+    fn admit(&mut self, now: u64) -> bool {
+        let window = u64::try_from(self.config.window.as_millis()).unwrap_or(u64::MAX);
+        while self
+            .sent
+            .front()
+            .is_some_and(|sent| now.saturating_sub(*sent) >= window)
+        {
+            self.sent.pop_front();
+        }
+        if self.sent.len() >= self.config.limit {
+            return false;
+        }
+        self.sent.push_back(now);
+        true
+    }
+}
+
+thread_local! {
+    static REPORTER: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// Reports `err` together with its `source()` chain.
+///
+/// Accepts every SDK error type, such as
+/// [`FetchError`](crate::http::FetchError) or
+/// [`ShareError`](crate::share::ShareError).
+pub fn capture_error(err: &dyn Error) {
+    let mut causes = Vec::new();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+    submit(ReportKind::Error, err.to_string(), causes);
+}
+
+/// Reports a custom `message`.
+pub fn capture_message(message: &str) {
+    submit(ReportKind::Message, message.to_owned(), Vec::new());
+}
+
+/// Reports a panic `message`; pass it to
+/// [`PanicHook::on_panic`](crate::logger::PanicHook::on_panic).
+pub fn capture_panic(message: &str) {
+    submit(ReportKind::Panic, message.to_owned(), Vec::new());
+}
+
+fn submit(kind: ReportKind, message: String, causes: Vec<String>) {
+    let now = now_ms();
+    // `try_with` and `try_borrow_mut` keep a panic raised while reporting
+    // from panicking again inside the hook.
+    let config = REPORTER
+        .try_with(|slot| {
+            let mut slot = slot.try_borrow_mut().ok()?;
+            let state = slot.as_mut()?;
+            state.admit(now).then(|| state.config.clone())
+        })
+        .ok()
+        .flatten();
+    let Some(config) = config else {
+        return;
+    };
+    let app = TelegramWebApp::instance();
+    let report = ErrorReport {
+        kind,
+        message,
+        causes,
+        platform: app.as_ref().and_then(TelegramWebApp::platform),
+        version: app.as_ref().and_then(TelegramWebApp::raw_version),
+        user_id: TelegramContext::get(|ctx| ctx.init_data.user.as_ref().map(|user| user.id))
+            .flatten(),
+        timestamp: now
+    };
+    if let Err(err) = config.client.post_detached(&config.path, &report) {
+        logger::warn(&format!("Error report not sent: {err}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_uses_sliding_window() {
+        let mut state = State {
+            config: ReportConfig::new(TelegramFetch::new("https://example.com"), "/errors")
+                .rate_limit(2, Duration::from_secs(1)),
+            sent:   VecDeque::new()
+        };
+        assert!(state.admit(0));
+        assert!(state.admit(500));
+        assert!(!state.admit(900));
+        assert!(state.admit(1000));
+        assert!(!state.admit(1400));
+        assert!(state.admit(1500));
+    }
+
+    #[test]
+    fn report_serializes_kind_in_snake_case() {
+        let report = ErrorReport {
+            kind:      ReportKind::Panic,
+            message:   "boom".into(),
+            causes:    Vec::new(),
+            platform:  Some("ios".into()),
+            version:   None,
+            user_id:   Some(7),
+            timestamp: 1
+        };
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"kind":"panic","message":"boom","platform":"ios","version":null,"user_id":7,"timestamp":1}"#
+        );
+    }
+}
@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Browser shims for previewing a Mini App outside Telegram.
+//!
+//! [`install_browser_fallback`] installs a minimal `Telegram.WebApp` when the
+//! page is opened in a plain browser tab, so the same build keeps working
+//! with degraded behavior instead of failing on the first SDK call. The
+//! official `telegram-web-app.js` defines `Telegram.WebApp` in any tab, so a
+//! page counts as outside Telegram when it carries no init data and reports
+//! the `unknown` platform; that stub is then replaced:
+//!
+//! | WebApp API                         | Fallback                         |
+//! |------------------------------------|----------------------------------|
+//! | `openLink`, `openTelegramLink`     | `window.open` in a new tab       |
+//! | `readTextFromClipboard`            | `navigator.clipboard.readText`   |
+//! | `showAlert`                        | `window.alert`                   |
+//! | `showConfirm`, `showPopup`         | `window.confirm`                 |
+//! | `CloudStorage`                     | `localStorage`, prefixed keys    |
+//!
+//! Other methods are no-ops and the init data is empty. Unlike the `mock`
+//! feature no user or theme is faked, which makes the fallback suitable for
+//! production builds.
+
+use js_sys::{Function, Reflect};
+use wasm_bindgen::JsValue;
+use web_sys::window;
+
+use crate::logger;
+
+/// Prefix of the `localStorage` keys backing the `CloudStorage` fallback.
+pub const STORAGE_PREFIX: &str = "tg-cloud:";
+
+/// Builds the shim `WebApp` object; `prefix` is the storage key prefix.
+const SHIM: &str = r#"
+const noop = () => {};
+const done = (cb, ...args) => { if (typeof cb === 'function') cb(...args); };
+const storage = (run) => (arg, cb) => {
+    try {
+        const value = run(arg);
+        done(cb, null, value);
+        return Promise.resolve(value);
+    } catch (e) {
+        done(cb, String(e));
+        return Promise.reject(e);
+    }
+};
+const keys = () => Object.keys(localStorage)
+    .filter((key) => key.startsWith(prefix))
+    .map((key) => key.slice(prefix.length));
+const open = (url) => { window.open(url, '_blank'); };
+return {
+    initData: '',
+    initDataUnsafe: {},
+    version: '6.0',
+    platform: 'unknown',
+    colorScheme: window.matchMedia && window.matchMedia('(prefers-color-scheme: dark)').matches
+        ? 'dark' : 'light',
+    themeParams: {},
+    isExpanded: true,
+    viewportHeight: window.innerHeight,
+    viewportStableHeight: window.innerHeight,
+    isVersionAtLeast: () => false,
+    ready: noop,
+    expand: noop,
+    close: () => window.close(),
+    onEvent: noop,
+    offEvent: noop,
+    sendData: noop,
+    openLink: open,
+    openTelegramLink: open,
+    readTextFromClipboard: (cb) => {
+        const read = navigator.clipboard && navigator.clipboard.readText
+            ? navigator.clipboard.readText() : Promise.reject();
+        read.then((text) => done(cb, text), () => done(cb, null));
+    },
+    showAlert: (message, cb) => { window.alert(message); done(cb); },
+    showConfirm: (message, cb) => { done(cb, window.confirm(message)); },
+    showPopup: (params, cb) => {
+        const buttons = params.buttons && params.buttons.length
+            ? params.buttons : [{ id: '', type: 'close' }];
+        const text = params.title ? params.title + '\n\n' + params.message : params.message;
+        if (buttons.length === 1) {
+            window.alert(text);
+            done(cb, buttons[0].id || '');
+        } else {
+            const ok = window.confirm(text);
+            done(cb, (ok ? buttons[0] : buttons[buttons.length - 1]).id || '');
+        }
+    },
+    CloudStorage: {
+        setItem: (key, value, cb) =>
+            storage(() => { localStorage.setItem(prefix + key, value); return true; })(key, cb),
+        getItem: storage((key) => localStorage.getItem(prefix + key) ?? ''),
+        getItems: storage((list) => Object.fromEntries(
+            list.map((key) => [key, localStorage.getItem(prefix + key) ?? '']))),
+        removeItem: storage((key) => { localStorage.removeItem(prefix + key); return true; }),
+        removeItems: storage((list) => {
+            list.forEach((key) => localStorage.removeItem(prefix + key));
+            return true;
+        }),
+        getKeys: (cb) => storage(keys)(undefined, cb)
+    }
+};
+"#;
+
+/// Installs the browser fallback unless the page runs inside Telegram.
+///
+/// Call it before [`crate::core::init::init_sdk`]. Returns `true` if the shim
+/// was installed and `false` inside Telegram, where nothing is changed.
+///
+/// # Errors
+///
+/// Returns [`JsValue`] if the global window object is unavailable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::{core::init::init_sdk, fallback::install_browser_fallback};
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// install_browser_fallback()?;
+/// init_sdk()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn install_browser_fallback() -> Result<bool, JsValue> {
+    let win = window().ok_or_else(|| JsValue::from_str("window not available"))?;
+    if launched_by_telegram(&win) {
+        return Ok(false);
+    }
+    let webapp =
+        Function::new_with_args("prefix", SHIM).call1(&JsValue::NULL, &STORAGE_PREFIX.into())?;
+    let telegram = Reflect::get(&win, &"Telegram".into())?;
+    let telegram = if telegram.is_object() {
+        telegram
+    } else {
+        let telegram = js_sys::Object::new().into();
+        Reflect::set(&win, &"Telegram".into(), &telegram)?;
+        telegram
+    };
+    Reflect::set(&telegram, &"WebApp".into(), &webapp)?;
+    logger::info("Telegram not detected, using browser fallback");
+    Ok(true)
+}
+
+/// Returns `true` if `Telegram.WebApp` carries init data or a real platform.
+fn launched_by_telegram(win: &web_sys::Window) -> bool {
+    let Ok(webapp) = Reflect::get(win, &"Telegram".into())
+        .and_then(|telegram| Reflect::get(&telegram, &"WebApp".into()))
+    else {
+        return false;
+    };
+    if !webapp.is_object() {
+        return false;
+    }
+    let field = |name: &str| {
+        Reflect::get(&webapp, &name.into())
+            .ok()
+            .and_then(|value| value.as_string())
+            .unwrap_or_default()
+    };
+    let platform = field("platform");
+    !field("initData").is_empty() || !(platform.is_empty() || platform == "unknown")
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::Object;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn cleanup() {
+        let win = window().unwrap();
+        let _ = Reflect::delete_property(&win, &"Telegram".into());
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code)]
+    fn keeps_real_telegram() {
+        cleanup();
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let _ = Reflect::set(&webapp, &"initData".into(), &"query_id=1".into());
+        let _ = Reflect::set(&webapp, &"platform".into(), &"ios".into());
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        assert!(!install_browser_fallback().unwrap());
+        let installed = Reflect::get(&telegram, &"WebApp".into()).unwrap();
+        assert_eq!(installed, JsValue::from(webapp));
+        cleanup();
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code)]
+    fn replaces_stub_without_init_data() {
+        cleanup();
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let _ = Reflect::set(&webapp, &"initData".into(), &"".into());
+        let _ = Reflect::set(&webapp, &"platform".into(), &"unknown".into());
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        assert!(install_browser_fallback().unwrap());
+        let installed = Reflect::get(&telegram, &"WebApp".into()).unwrap();
+        assert_ne!(installed, JsValue::from(webapp));
+        assert!(Reflect::has(&installed, &"CloudStorage".into()).unwrap());
+        cleanup();
+    }
+
+    #[cfg(feature = "cloud-storage")]
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn cloud_storage_uses_local_storage() {
+        use wasm_bindgen_futures::JsFuture;
+
+        use crate::api::cloud_storage::{get_item, set_item};
+
+        cleanup();
+        assert!(install_browser_fallback().unwrap());
+        JsFuture::from(set_item("fallback-test", "42").unwrap())
+            .await
+            .unwrap();
+        let value = get_item("fallback-test").unwrap();
+        assert_eq!(
+            JsFuture::from(value).await.unwrap().as_string().as_deref(),
+            Some("42")
+        );
+        let stored = Function::new_with_args("key", "return localStorage.getItem(key);")
+            .call1(
+                &JsValue::NULL,
+                &format!("{STORAGE_PREFIX}fallback-test").into()
+            )
+            .unwrap();
+        assert_eq!(stored.as_string().as_deref(), Some("42"));
+        cleanup();
+    }
+}
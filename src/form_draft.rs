@@ -0,0 +1,270 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! [`FormDraft`] autosaving partially filled forms to CloudStorage.
+//!
+//! Long order or checkout forms are often abandoned halfway when the user
+//! switches chats. A draft saves the form state shortly after each change,
+//! restores it on the next launch and is cleared once the form is
+//! submitted.
+
+use std::{
+    cell::{OnceCell, RefCell},
+    marker::PhantomData,
+    rc::Rc,
+    time::Duration
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::{
+    api::cloud_storage::{VALUE_LIMIT, get_item, remove_item, set_item},
+    bus::{self, Subscription, WebAppEvent},
+    webapp::debounce::Debouncer
+};
+
+/// Default quiet period before a change is saved.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_secs(1);
+
+/// Serialized state waiting to be written under `key`.
+struct Pending {
+    key:   String,
+    value: RefCell<Option<String>>
+}
+
+impl Pending {
+    /// Starts writing the pending value without waiting for the result.
+    fn save_detached(&self) {
+        if let Some(raw) = self.value.borrow_mut().take() {
+            let _ = set_item(&self.key, &raw);
+        }
+    }
+}
+
+/// Form state autosaved to CloudStorage under a fixed key.
+///
+/// [`update`](Self::update) records the latest state and saves it once the
+/// form has been quiet for the debounce delay. Pending changes are also
+/// saved when the app is minimized and when the draft is dropped.
+/// [`restore`](Self::restore) loads the state on the next launch and
+/// [`clear`](Self::clear) removes it after a successful submit.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::form_draft::FormDraft;
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct Checkout {
+///     address: String,
+///     comment: String
+/// }
+///
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let draft = FormDraft::<Checkout>::new("checkout_draft");
+/// let mut form = draft.restore().await?.unwrap_or_default();
+///
+/// form.address = "Baker Street 221b".into();
+/// draft.update(&form)?;
+///
+/// // after the order was accepted
+/// draft.clear().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FormDraft<T> {
+    pending:      Rc<Pending>,
+    delay:        Duration,
+    debouncer:    OnceCell<Debouncer>,
+    _deactivated: Subscription,
+    _marker:      PhantomData<fn(T) -> T>
+}
+
+impl<T: Serialize + DeserializeOwned> FormDraft<T> {
+    /// Creates a draft stored under the CloudStorage `key`, saved
+    /// [`DEFAULT_DEBOUNCE`] after the last change.
+    pub fn new(key: impl Into<String>) -> Self {
+        let pending = Rc::new(Pending {
+            key:   key.into(),
+            value: RefCell::new(None)
+        });
+        let on_deactivated = Rc::clone(&pending);
+        let deactivated = bus::subscribe(move |event| {
+            if *event == WebAppEvent::Deactivated {
+                on_deactivated.save_detached();
+            }
+        });
+        Self {
+            pending,
+            delay: DEFAULT_DEBOUNCE,
+            debouncer: OnceCell::new(),
+            _deactivated: deactivated,
+            _marker: PhantomData
+        }
+    }
+
+    /// Sets the quiet period before a change is saved.
+    pub fn debounce(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the CloudStorage key of the draft.
+    pub fn key(&self) -> &str {
+        &self.pending.key
+    }
+
+    /// Loads the saved draft.
+    ///
+    /// A draft that no longer matches `T`, e.g. after the form changed
+    /// between releases, is treated as missing.
+    ///
+    /// # Errors
+    /// Returns `Err(JsValue)` if CloudStorage is unavailable or the call
+    /// fails.
+    pub async fn restore(&self) -> Result<Option<T>, JsValue> {
+        let value = JsFuture::from(get_item(&self.pending.key)?).await?;
+        Ok(value
+            .as_string()
+            .and_then(|raw| serde_json::from_str(&raw).ok()))
+    }
+
+    /// Records `value` and schedules saving it.
+    ///
+    /// # Errors
+    /// Returns `Err(JsValue)` if serialization fails, the encoded value
+    /// exceeds [`VALUE_LIMIT`] or no timer is available.
+    pub fn update(&self, value: &T) -> Result<(), JsValue> {
+        let raw = encode(value)?;
+        *self.pending.value.borrow_mut() = Some(raw);
+        let debouncer = match self.debouncer.get() {
+            Some(debouncer) => debouncer,
+            None => {
+                let pending = Rc::clone(&self.pending);
+                let debouncer = Debouncer::new(self.delay, move || pending.save_detached())?;
+                self.debouncer.get_or_init(|| debouncer)
+            }
+        };
+        debouncer.trigger();
+        Ok(())
+    }
+
+    /// Saves a pending change immediately.
+    ///
+    /// # Errors
+    /// Returns `Err(JsValue)` if CloudStorage is unavailable or rejects the
+    /// value.
+    pub async fn flush(&self) -> Result<(), JsValue> {
+        self.cancel();
+        let raw = self.pending.value.borrow_mut().take();
+        if let Some(raw) = raw {
+            JsFuture::from(set_item(&self.pending.key, &raw)?).await?;
+        }
+        Ok(())
+    }
+
+    /// Drops any pending change and removes the saved draft.
+    ///
+    /// # Errors
+    /// Returns `Err(JsValue)` if CloudStorage is unavailable or the call
+    /// fails.
+    pub async fn clear(&self) -> Result<(), JsValue> {
+        self.cancel();
+        self.pending.value.borrow_mut().take();
+        JsFuture::from(remove_item(&self.pending.key)?).await?;
+        Ok(())
+    }
+
+    fn cancel(&self) {
+        if let Some(debouncer) = self.debouncer.get() {
+            debouncer.cancel();
+        }
+    }
+}
+
+impl<T> Drop for FormDraft<T> {
+    fn drop(&mut self) {
+        self.pending.save_detached();
+    }
+}
+
+/// Serializes `value`, enforcing the CloudStorage value limit.
+fn encode<T: Serialize>(value: &T) -> Result<String, JsValue> {
+    let raw = serde_json::to_string(value).map_err(|err| JsValue::from_str(&err.to_string()))?;
+    if raw.chars().count() > VALUE_LIMIT {
+        return Err(JsValue::from_str(&format!(
+            "form draft exceeds {VALUE_LIMIT} characters"
+        )));
+    }
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Function, Object, Reflect};
+    use serde::Deserialize;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::utils::future::sleep;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Checkout {
+        address: String
+    }
+
+    #[test]
+    fn encode_serializes_json() {
+        let form = Checkout {
+            address: "Main St".into()
+        };
+        assert_eq!(encode(&form).unwrap(), r#"{"address":"Main St"}"#);
+    }
+
+    fn setup_storage() -> Object {
+        let win = web_sys::window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let storage = Object::new();
+        let items = Object::new();
+        let _ = Reflect::set(&storage, &"items".into(), &items);
+        let get = Function::new_with_args("key", "return Promise.resolve(this.items[key] ?? '');");
+        let set = Function::new_with_args(
+            "key, value",
+            "this.items[key] = value; return Promise.resolve(true);"
+        );
+        let remove = Function::new_with_args(
+            "key",
+            "delete this.items[key]; return Promise.resolve(true);"
+        );
+        let _ = Reflect::set(&storage, &"getItem".into(), &get);
+        let _ = Reflect::set(&storage, &"setItem".into(), &set);
+        let _ = Reflect::set(&storage, &"removeItem".into(), &remove);
+        let _ = Reflect::set(&webapp, &"CloudStorage".into(), &storage);
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        items
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn saves_after_debounce_and_clears() {
+        let items = setup_storage();
+        let draft = FormDraft::<Checkout>::new("draft").debounce(Duration::from_millis(5));
+        let form = Checkout {
+            address: "Main St".into()
+        };
+        draft.update(&form).unwrap();
+        assert!(!Reflect::has(&items, &"draft".into()).unwrap());
+        sleep(Duration::from_millis(20)).await;
+        assert_eq!(draft.restore().await.unwrap(), Some(form));
+
+        draft.clear().await.unwrap();
+        assert_eq!(draft.restore().await.unwrap(), None);
+    }
+}
@@ -0,0 +1,355 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! [`GameLoop`] driven by `requestAnimationFrame`.
+//!
+//! The loop pauses while the mini app is minimized (`deactivated`) or the
+//! page is hidden, and resumes on `activated` or when the page becomes
+//! visible again. Time spent paused is never fed to the update function.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+    time::Duration
+};
+
+use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
+use web_sys::{Document, window};
+
+use crate::bus::{self, Subscription, WebAppEvent};
+
+/// Longest frame fed to the update function.
+///
+/// Longer gaps, e.g. after a stalled tab, are clamped so that a fixed
+/// timestep loop does not try to catch up with hundreds of steps.
+pub const MAX_FRAME_TIME: Duration = Duration::from_millis(250);
+
+/// Accumulates frame times into variable or fixed update steps.
+#[derive(Debug, Default)]
+struct Timestep {
+    step:        Option<Duration>,
+    last:        Option<f64>,
+    accumulator: Duration
+}
+
+impl Timestep {
+    /// Forgets the previous frame so the next one starts from zero.
+    fn reset(&mut self) {
+        self.last = None;
+        self.accumulator = Duration::ZERO;
+    }
+
+    /// Runs the updates due at `now` (milliseconds) and returns the
+    /// interpolation factor for rendering.
+    fn frame(&mut self, now: f64, update: &mut dyn FnMut(Duration)) -> f64 {
+        let Some(last) = self.last.replace(now) else {
+            return if self.step.is_some() { 0.0 } else { 1.0 };
+        };
+        let elapsed =
+            Duration::from_secs_f64(((now - last) / 1000.0).max(0.0)).min(MAX_FRAME_TIME);
+        let Some(step) = self.step else {
+            update(elapsed);
+            return 1.0;
+        };
+        self.accumulator += elapsed;
+        while self.accumulator >= step {
+            update(step);
+            self.accumulator -= step;
+        }
+        self.accumulator.as_secs_f64() / step.as_secs_f64()
+    }
+}
+
+type Update = Box<dyn FnMut(Duration)>;
+type Render = Box<dyn FnMut(f64)>;
+type FrameCallback = Closure<dyn FnMut(f64)>;
+
+struct Inner {
+    update:   RefCell<Update>,
+    render:   RefCell<Option<Render>>,
+    timestep: RefCell<Timestep>,
+    started:  Cell<bool>,
+    inactive: Cell<bool>,
+    hidden:   Cell<bool>,
+    frame:    Cell<Option<i32>>,
+    callback: RefCell<Option<FrameCallback>>
+}
+
+impl Inner {
+    fn should_run(&self) -> bool {
+        self.started.get() && !self.inactive.get() && !self.hidden.get()
+    }
+
+    /// Requests or cancels the next frame to match the current state.
+    fn sync(&self) {
+        if self.should_run() {
+            if self.frame.get().is_some() {
+                return;
+            }
+            let callback = self.callback.borrow();
+            if let (Some(win), Some(callback)) = (window(), callback.as_ref())
+                && let Ok(id) = win.request_animation_frame(callback.as_ref().unchecked_ref())
+            {
+                self.frame.set(Some(id));
+            }
+        } else {
+            if let Some(id) = self.frame.take()
+                && let Some(win) = window()
+            {
+                let _ = win.cancel_animation_frame(id);
+            }
+            self.timestep.borrow_mut().reset();
+        }
+    }
+
+    fn tick(&self, now: f64) {
+        self.frame.set(None);
+        if !self.should_run() {
+            return;
+        }
+        // Moved out while `update` runs, which may stop or drop the loop; the
+        // `sync` below then resets the restored timestep.
+        let mut timestep = self.timestep.take();
+        let alpha = timestep.frame(now, &mut **self.update.borrow_mut());
+        *self.timestep.borrow_mut() = timestep;
+        if let Some(render) = self.render.borrow_mut().as_mut() {
+            render(alpha);
+        }
+        self.sync();
+    }
+}
+
+/// Visibility listener registered on the document by [`GameLoop::start`].
+struct Visibility {
+    document: Document,
+    closure:  Closure<dyn FnMut()>
+}
+
+/// Frame loop built on `requestAnimationFrame`.
+///
+/// By default the update function receives the time elapsed since the
+/// previous frame. With [`fixed_timestep`](Self::fixed_timestep) it is
+/// instead called zero or more times per frame with exactly the step, and
+/// the [`on_render`](Self::on_render) callback receives how far the
+/// simulation is into the next step, for interpolation.
+///
+/// The loop pauses on `deactivated` and when the page is hidden, and stops
+/// for good when dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::game_loop::GameLoop;
+///
+/// # fn example() -> Result<(), wasm_bindgen::JsValue> {
+/// let game = GameLoop::new(|dt| {
+///     // advance the simulation by `dt`
+///     let _ = dt;
+/// })
+/// .fixed_timestep(Duration::from_secs_f64(1.0 / 60.0))
+/// .on_render(|alpha| {
+///     // draw, interpolating by `alpha`
+///     let _ = alpha;
+/// });
+/// game.start()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct GameLoop {
+    inner:         Rc<Inner>,
+    visibility:    RefCell<Option<Visibility>>,
+    _subscription: Subscription
+}
+
+impl GameLoop {
+    /// Creates a stopped loop calling `update` with the frame time.
+    pub fn new(update: impl FnMut(Duration) + 'static) -> Self {
+        let inner = Rc::new(Inner {
+            update:   RefCell::new(Box::new(update)),
+            render:   RefCell::new(None),
+            timestep: RefCell::new(Timestep::default()),
+            started:  Cell::new(false),
+            inactive: Cell::new(false),
+            hidden:   Cell::new(false),
+            frame:    Cell::new(None),
+            callback: RefCell::new(None)
+        });
+        let weak = Rc::downgrade(&inner);
+        *inner.callback.borrow_mut() = Some(Closure::new(move |now: f64| {
+            if let Some(inner) = weak.upgrade() {
+                inner.tick(now);
+            }
+        }));
+        let weak: Weak<Inner> = Rc::downgrade(&inner);
+        let subscription = bus::subscribe(move |event| {
+            let Some(inner) = weak.upgrade() else {
+                return;
+            };
+            match event {
+                WebAppEvent::Activated => inner.inactive.set(false),
+                WebAppEvent::Deactivated => inner.inactive.set(true),
+                _ => return
+            }
+            inner.sync();
+        });
+        Self {
+            inner,
+            visibility: RefCell::new(None),
+            _subscription: subscription
+        }
+    }
+
+    /// Calls the update function with exactly `step` instead of the frame
+    /// time; a zero step keeps the variable timestep.
+    pub fn fixed_timestep(self, step: Duration) -> Self {
+        self.inner.timestep.borrow_mut().step = (!step.is_zero()).then_some(step);
+        self
+    }
+
+    /// Calls `render` once per frame after the updates.
+    ///
+    /// It receives the fraction of a fixed step accumulated but not yet
+    /// simulated, in `0.0..1.0`, or `1.0` with a variable timestep.
+    pub fn on_render(self, render: impl FnMut(f64) + 'static) -> Self {
+        *self.inner.render.borrow_mut() = Some(Box::new(render));
+        self
+    }
+
+    /// Starts or resumes the loop.
+    ///
+    /// Frames are only requested while the app is active and the page is
+    /// visible.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the document is unavailable.
+    pub fn start(&self) -> Result<(), JsValue> {
+        if self.visibility.borrow().is_none() {
+            let document = window()
+                .and_then(|win| win.document())
+                .ok_or_else(|| JsValue::from_str("document not available"))?;
+            let weak = Rc::downgrade(&self.inner);
+            let target = document.clone();
+            let closure = Closure::<dyn FnMut()>::new(move || {
+                if let Some(inner) = weak.upgrade() {
+                    inner.hidden.set(target.hidden());
+                    inner.sync();
+                }
+            });
+            document.add_event_listener_with_callback(
+                "visibilitychange",
+                closure.as_ref().unchecked_ref()
+            )?;
+            self.inner.hidden.set(document.hidden());
+            *self.visibility.borrow_mut() = Some(Visibility {
+                document,
+                closure
+            });
+        }
+        self.inner.started.set(true);
+        self.inner.sync();
+        Ok(())
+    }
+
+    /// Stops the loop until [`start`](Self::start) is called again.
+    pub fn stop(&self) {
+        self.inner.started.set(false);
+        self.inner.sync();
+    }
+
+    /// Returns whether frames are currently being requested.
+    pub fn is_running(&self) -> bool {
+        self.inner.should_run()
+    }
+}
+
+impl Drop for GameLoop {
+    fn drop(&mut self) {
+        self.stop();
+        if let Some(visibility) = self.visibility.borrow_mut().take() {
+            let _ = visibility.document.remove_event_listener_with_callback(
+                "visibilitychange",
+                visibility.closure.as_ref().unchecked_ref()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn run(timestep: &mut Timestep, now: f64) -> (Vec<Duration>, f64) {
+        let mut steps = Vec::new();
+        let alpha = timestep.frame(now, &mut |dt| steps.push(dt));
+        (steps, alpha)
+    }
+
+    #[test]
+    fn fixed_timestep_accumulates_frames() {
+        let step = Duration::from_millis(10);
+        let mut timestep = Timestep {
+            step: Some(step),
+            ..Timestep::default()
+        };
+        assert_eq!(run(&mut timestep, 1000.0), (vec![], 0.0));
+        let (steps, alpha) = run(&mut timestep, 1025.0);
+        assert_eq!(steps, [step, step]);
+        assert!((alpha - 0.5).abs() < 1e-9);
+        let (steps, _) = run(&mut timestep, 1030.0);
+        assert_eq!(steps, [step]);
+    }
+
+    #[test]
+    fn long_frames_are_clamped_and_reset_forgets_time() {
+        let mut timestep = Timestep::default();
+        run(&mut timestep, 0.0);
+        assert_eq!(run(&mut timestep, 5000.0).0, [MAX_FRAME_TIME]);
+        timestep.reset();
+        assert_eq!(run(&mut timestep, 9000.0), (vec![], 1.0));
+        assert_eq!(run(&mut timestep, 9016.0).0, [Duration::from_millis(16)]);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn pauses_while_deactivated() {
+        let game = GameLoop::new(|_| {});
+        game.start().unwrap();
+        game.inner.hidden.set(false);
+        assert!(game.is_running());
+        bus::publish(&WebAppEvent::Deactivated);
+        assert!(!game.is_running());
+        bus::publish(&WebAppEvent::Activated);
+        assert!(game.is_running());
+        game.stop();
+        assert!(!game.is_running());
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn update_may_stop_the_loop() {
+        let slot: Rc<RefCell<Option<GameLoop>>> = Rc::new(RefCell::new(None));
+        let game = GameLoop::new({
+            let slot = Rc::clone(&slot);
+            move |_| {
+                if let Some(game) = slot.borrow().as_ref() {
+                    game.stop();
+                }
+            }
+        });
+        game.start().unwrap();
+        game.inner.hidden.set(false);
+        let inner = Rc::clone(&game.inner);
+        *slot.borrow_mut() = Some(game);
+        inner.tick(0.0);
+        inner.tick(16.0);
+        assert!(!inner.should_run());
+        assert_eq!(inner.timestep.borrow().last, None);
+    }
+}
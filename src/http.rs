@@ -0,0 +1,329 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Headers, Request, RequestInit, Response};
+
+use crate::core::context::TelegramContext;
+
+/// Header carrying the init data by default.
+pub const DEFAULT_AUTH_HEADER: &str = "Authorization";
+
+/// Scheme prefixed to the init data by default (`Authorization: tma <raw>`).
+pub const DEFAULT_AUTH_SCHEME: &str = "tma";
+
+/// Errors produced by [`TelegramFetch`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    /// The SDK is not initialized and no init data was set explicitly.
+    InitDataUnavailable,
+    /// The request could not be built or sent.
+    Network(String),
+    /// The server answered with a non-success status.
+    Status {
+        /// HTTP status code.
+        status: u16,
+        /// Response body as text.
+        body:   String
+    },
+    /// The request body could not be encoded or the response decoded.
+    Json(String)
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InitDataUnavailable => write!(f, "Init data is not available"),
+            Self::Network(msg) => write!(f, "Request failed: {msg}"),
+            Self::Status {
+                status,
+                body
+            } => write!(f, "Server responded with {status}: {body}"),
+            Self::Json(msg) => write!(f, "Invalid JSON: {msg}")
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<FetchError> for JsValue {
+    fn from(err: FetchError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+impl From<JsValue> for FetchError {
+    fn from(err: JsValue) -> Self {
+        Self::Network(err.as_string().unwrap_or_else(|| format!("{err:?}")))
+    }
+}
+
+/// JSON client for the mini app's backend that authenticates every request
+/// with the raw init data.
+///
+/// Each request carries `Authorization: tma <initData>` by default, which the
+/// backend validates against the bot token. The header name and scheme are
+/// configurable. Bodies and responses are JSON; an empty response decodes as
+/// `null`, so `()` can be used for endpoints without content.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::http::{FetchError, TelegramFetch};
+///
+/// #[derive(Serialize)]
+/// struct NewOrder {
+///     item: String
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Order {
+///     id: u64
+/// }
+///
+/// # async fn run() -> Result<(), FetchError> {
+/// let api = TelegramFetch::new("https://api.example.com");
+/// let order: Order = api
+///     .post(
+///         "/orders",
+///         &NewOrder {
+///             item: "burger".into()
+///         }
+///     )
+///     .await?;
+/// let orders: Vec<Order> = api.get("/orders").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TelegramFetch {
+    base_url:  String,
+    header:    String,
+    scheme:    Option<String>,
+    init_data: Option<String>
+}
+
+impl TelegramFetch {
+    /// Creates a client for the API at `base_url`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url:  base_url.into(),
+            header:    DEFAULT_AUTH_HEADER.to_owned(),
+            scheme:    Some(DEFAULT_AUTH_SCHEME.to_owned()),
+            init_data: None
+        }
+    }
+
+    /// Sets the header carrying the init data.
+    pub fn header(mut self, name: impl Into<String>) -> Self {
+        self.header = name.into();
+        self
+    }
+
+    /// Sets the scheme prefixed to the init data; `None` sends the bare init
+    /// data.
+    pub fn scheme(mut self, scheme: Option<&str>) -> Self {
+        self.scheme = scheme.map(str::to_owned);
+        self
+    }
+
+    /// Uses `raw` instead of the init data captured by
+    /// [`TelegramContext::init`].
+    pub fn init_data(mut self, raw: impl Into<String>) -> Self {
+        self.init_data = Some(raw.into());
+        self
+    }
+
+    /// Sends a `GET` request to `path` and decodes the JSON response.
+    ///
+    /// # Errors
+    /// See [`request`](Self::request).
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, FetchError> {
+        self.request::<(), T>("GET", path, None).await
+    }
+
+    /// Sends `body` as JSON with a `POST` request to `path` and decodes the
+    /// JSON response.
+    ///
+    /// # Errors
+    /// See [`request`](Self::request).
+    pub async fn post<B, T>(&self, path: &str, body: &B) -> Result<T, FetchError>
+    where
+        B: Serialize,
+        T: DeserializeOwned
+    {
+        self.request("POST", path, Some(body)).await
+    }
+
+    /// Sends `body` as JSON with a `PUT` request to `path` and decodes the
+    /// JSON response.
+    ///
+    /// # Errors
+    /// See [`request`](Self::request).
+    pub async fn put<B, T>(&self, path: &str, body: &B) -> Result<T, FetchError>
+    where
+        B: Serialize,
+        T: DeserializeOwned
+    {
+        self.request("PUT", path, Some(body)).await
+    }
+
+    /// Sends a `DELETE` request to `path` and decodes the JSON response.
+    ///
+    /// # Errors
+    /// See [`request`](Self::request).
+    pub async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T, FetchError> {
+        self.request::<(), T>("DELETE", path, None).await
+    }
+
+    /// Sends a `method` request to `path` with an optional JSON `body` and
+    /// decodes the JSON response.
+    ///
+    /// # Errors
+    /// Returns [`FetchError::InitDataUnavailable`] without init data,
+    /// [`FetchError::Network`] when the request fails,
+    /// [`FetchError::Status`] for non-2xx responses and [`FetchError::Json`]
+    /// when encoding or decoding fails.
+    pub async fn request<B, T>(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&B>
+    ) -> Result<T, FetchError>
+    where
+        B: Serialize,
+        T: DeserializeOwned
+    {
+        let request = self.build_request(method, path, body, false)?;
+        let window = web_sys::window()
+            .ok_or_else(|| FetchError::Network("window not available".to_owned()))?;
+        let response: Response = JsFuture::from(window.fetch_with_request(&request))
+            .await?
+            .dyn_into()?;
+        let text = JsFuture::from(response.text()?)
+            .await?
+            .as_string()
+            .unwrap_or_default();
+
+        if !response.ok() {
+            return Err(FetchError::Status {
+                status: response.status(),
+                body:   text
+            });
+        }
+        decode(&text)
+    }
+
+    /// Starts a `POST` of `body` to `path` without waiting for the response.
+    ///
+    /// The request uses `keepalive`, so it completes even if the WebView is
+    /// closing or the calling code has panicked. Network failures are only
+    /// logged.
+    #[cfg(any(feature = "analytics", feature = "error-report"))]
+    pub(crate) fn post_detached<B: Serialize>(
+        &self,
+        path: &str,
+        body: &B
+    ) -> Result<(), FetchError> {
+        let request = self.build_request("POST", path, Some(body), true)?;
+        let window = web_sys::window()
+            .ok_or_else(|| FetchError::Network("window not available".to_owned()))?;
+        let response = JsFuture::from(window.fetch_with_request(&request));
+        // Awaiting the promise handles its rejection instead of leaving an
+        // `Uncaught (in promise)` error behind.
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(err) = response.await {
+                crate::logger::warn(&format!("detached POST failed: {err:?}"));
+            }
+        });
+        Ok(())
+    }
+
+    /// Builds an authenticated request with an optional JSON `body`.
+    fn build_request<B: Serialize>(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&B>,
+        keepalive: bool
+    ) -> Result<Request, FetchError> {
+        let headers = Headers::new()?;
+        headers.set(&self.header, &self.auth_value()?)?;
+        headers.set("Accept", "application/json")?;
+
+        let init = RequestInit::new();
+        init.set_method(method);
+        if let Some(body) = body {
+            let json =
+                serde_json::to_string(body).map_err(|err| FetchError::Json(err.to_string()))?;
+            headers.set("Content-Type", "application/json")?;
+            init.set_body(&JsValue::from_str(&json));
+        }
+        init.set_headers(&headers);
+        if keepalive {
+            js_sys::Reflect::set(&init, &"keepalive".into(), &JsValue::TRUE)?;
+        }
+
+        Ok(Request::new_with_str_and_init(&self.url(path), &init)?)
+    }
+
+    /// Joins `path` to the base URL.
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// Returns the value of the auth header.
+    fn auth_value(&self) -> Result<String, FetchError> {
+        let raw = match &self.init_data {
+            Some(raw) => raw.clone(),
+            None => TelegramContext::get_raw_init_data()
+                .map_err(|_| FetchError::InitDataUnavailable)?
+        };
+        Ok(match &self.scheme {
+            Some(scheme) => format!("{scheme} {raw}"),
+            None => raw
+        })
+    }
+}
+
+/// Decodes a response body, treating an empty body as `null`.
+fn decode<T: DeserializeOwned>(text: &str) -> Result<T, FetchError> {
+    let text = if text.trim().is_empty() { "null" } else { text };
+    serde_json::from_str(text).map_err(|err| FetchError::Json(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_joins_base_and_path() {
+        let api = TelegramFetch::new("https://api.example.com/");
+        assert_eq!(api.url("/orders"), "https://api.example.com/orders");
+        assert_eq!(api.url("orders/1"), "https://api.example.com/orders/1");
+    }
+
+    #[test]
+    fn auth_value_uses_scheme_and_init_data() {
+        let api = TelegramFetch::new("https://api.example.com").init_data("query_id=1");
+        assert_eq!(api.auth_value().unwrap(), "tma query_id=1");
+        assert_eq!(
+            api.scheme(None).auth_value().unwrap(),
+            "query_id=1".to_owned()
+        );
+    }
+
+    #[test]
+    fn decode_handles_empty_bodies() {
+        decode::<()>("").unwrap();
+        assert_eq!(decode::<Vec<u8>>("[1]").unwrap(), vec![1]);
+        assert!(matches!(decode::<u8>("x"), Err(FetchError::Json(_))));
+    }
+}
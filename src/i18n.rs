@@ -0,0 +1,512 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Translations selected from the Telegram user's `language_code`.
+//!
+//! An [`I18n`] catalog maps locales to flat `key → message` tables. Messages
+//! may contain `{name}` placeholders, and plural messages are stored under
+//! `key.one`, `key.few`, `key.many` and `key.other` following the CLDR
+//! categories of the locale they were found in.
+//!
+//! Lookups walk a fallback chain: the active locale (`pt-br`), its base
+//! language (`pt`), any fallbacks registered with [`I18n::with_fallback`] and
+//! finally the default locale. A missing key renders as the key itself.
+//!
+//! [`install`] makes a catalog global so the [`t!`](crate::t) macro can be
+//! used anywhere in the app.
+//!
+//! # Examples
+//!
+//! ```
+//! use telegram_webapp_sdk::{i18n::I18n, t};
+//!
+//! telegram_webapp_sdk::i18n::install(
+//!     I18n::new("en")
+//!         .with_messages(
+//!             "en",
+//!             [
+//!                 ("greeting", "Hello, {name}!"),
+//!                 ("cart.one", "{count} item"),
+//!                 ("cart.other", "{count} items")
+//!             ]
+//!         )
+//!         .with_messages(
+//!             "ru",
+//!             [
+//!                 ("greeting", "Привет, {name}!"),
+//!                 ("cart.one", "{count} товар"),
+//!                 ("cart.few", "{count} товара"),
+//!                 ("cart.many", "{count} товаров")
+//!             ]
+//!         )
+//!         .with_locale("ru-RU")
+//! );
+//!
+//! assert_eq!(t!("greeting", name = "Alice"), "Привет, Alice!");
+//! assert_eq!(t!("cart", count = 5), "5 товаров");
+//! ```
+
+use std::{cell::RefCell, collections::HashMap, fmt::Display};
+
+use crate::core::context::TelegramContext;
+
+/// Named arguments substituted into `{name}` placeholders.
+pub type Args<'a> = &'a [(&'a str, &'a dyn Display)];
+
+/// CLDR plural category of a count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    /// Exactly zero, in languages distinguishing it (e.g. Arabic).
+    Zero,
+    /// Singular form.
+    One,
+    /// Dual form (e.g. Arabic, Hebrew).
+    Two,
+    /// Paucal form (e.g. Russian `2..4`).
+    Few,
+    /// Form for larger counts (e.g. Russian `5..20`).
+    Many,
+    /// General form; always present.
+    Other
+}
+
+impl PluralCategory {
+    /// Returns the key suffix used for this category.
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other"
+        }
+    }
+
+    /// Selects the category of `count` for `locale`.
+    ///
+    /// Only the base language is considered; languages without a dedicated
+    /// rule use the English `one`/`other` split.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use telegram_webapp_sdk::i18n::PluralCategory;
+    ///
+    /// assert_eq!(PluralCategory::of("ru", 3), PluralCategory::Few);
+    /// assert_eq!(PluralCategory::of("en", 3), PluralCategory::Other);
+    /// ```
+    pub fn of(locale: &str, count: u64) -> Self {
+        let (n10, n100) = (count % 10, count % 100);
+        match base_language(locale) {
+            "ja" | "ko" | "zh" | "vi" | "th" | "id" | "ms" | "my" | "lo" | "km" => Self::Other,
+            "fr" | "pt" | "hy" | "kab" if count <= 1 => Self::One,
+            "fr" | "pt" | "hy" | "kab" => Self::Other,
+            "ru" | "uk" | "be" | "sr" | "hr" | "bs" => {
+                if n10 == 1 && n100 != 11 {
+                    Self::One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    Self::Few
+                } else {
+                    Self::Many
+                }
+            }
+            "pl" => {
+                if count == 1 {
+                    Self::One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    Self::Few
+                } else {
+                    Self::Many
+                }
+            }
+            "cs" | "sk" => match count {
+                1 => Self::One,
+                2..=4 => Self::Few,
+                _ => Self::Other
+            },
+            "ar" => match count {
+                0 => Self::Zero,
+                1 => Self::One,
+                2 => Self::Two,
+                _ if (3..=10).contains(&n100) => Self::Few,
+                _ if n100 >= 11 => Self::Many,
+                _ => Self::Other
+            },
+            "he" | "iw" => match count {
+                1 => Self::One,
+                2 => Self::Two,
+                _ => Self::Other
+            },
+            _ if count == 1 => Self::One,
+            _ => Self::Other
+        }
+    }
+}
+
+/// Translation catalog with an active locale.
+#[derive(Debug, Clone, Default)]
+pub struct I18n {
+    default:   String,
+    locale:    String,
+    messages:  HashMap<String, HashMap<String, String>>,
+    fallbacks: HashMap<String, Vec<String>>
+}
+
+impl I18n {
+    /// Creates an empty catalog whose active and last-resort locale is
+    /// `default_locale`.
+    pub fn new(default_locale: &str) -> Self {
+        let default = normalize(default_locale);
+        Self {
+            locale: default.clone(),
+            default,
+            ..Self::default()
+        }
+    }
+
+    /// Adds messages for `locale`, replacing existing keys.
+    pub fn with_messages<K, V>(
+        mut self,
+        locale: &str,
+        messages: impl IntoIterator<Item = (K, V)>
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>
+    {
+        self.messages
+            .entry(normalize(locale))
+            .or_default()
+            .extend(messages.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Tries `fallback` when a key is missing in `locale`, before the
+    /// default locale.
+    pub fn with_fallback(mut self, locale: &str, fallback: &str) -> Self {
+        self.fallbacks
+            .entry(normalize(locale))
+            .or_default()
+            .push(normalize(fallback));
+        self
+    }
+
+    /// Sets the active locale, see [`set_locale`](Self::set_locale).
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.set_locale(locale);
+        self
+    }
+
+    /// Sets the active locale from an IETF tag such as `en` or `pt_BR`.
+    pub fn set_locale(&mut self, locale: &str) {
+        self.locale = normalize(locale);
+    }
+
+    /// Uses the current user's `language_code` as the active locale.
+    ///
+    /// Returns `false` and keeps the locale when the SDK is not initialized
+    /// or Telegram did not report a language.
+    pub fn detect_locale(&mut self) -> bool {
+        let code = TelegramContext::get(|ctx| {
+            ctx.init_data
+                .user
+                .as_ref()
+                .and_then(|user| user.language_code.clone())
+        })
+        .flatten();
+        match code {
+            Some(code) => {
+                self.set_locale(&code);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// Returns the active locale, lowercased with `-` separators.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Returns the locales searched for a key, most specific first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use telegram_webapp_sdk::i18n::I18n;
+    ///
+    /// let i18n = I18n::new("en")
+    ///     .with_fallback("uk", "ru")
+    ///     .with_locale("uk-UA");
+    /// assert_eq!(i18n.chain(), ["uk-ua", "uk", "ru", "en"]);
+    /// ```
+    pub fn chain(&self) -> Vec<&str> {
+        fn push<'a>(locale: &'a str, chain: &mut Vec<&'a str>) {
+            if !chain.contains(&locale) {
+                chain.push(locale);
+            }
+        }
+        let mut chain = Vec::new();
+        let mut tag = self.locale.as_str();
+        loop {
+            push(tag, &mut chain);
+            for fallback in self.fallbacks.get(tag).into_iter().flatten() {
+                push(fallback, &mut chain);
+            }
+            match tag.rfind('-') {
+                Some(idx) => tag = &self.locale[..idx],
+                None => break
+            }
+        }
+        push(&self.default, &mut chain);
+        chain
+    }
+
+    /// Translates `key`, substituting `args` into `{name}` placeholders.
+    ///
+    /// Returns `key` itself when no locale in the [`chain`](Self::chain)
+    /// defines it.
+    pub fn translate(&self, key: &str, args: Args<'_>) -> String {
+        self.chain()
+            .into_iter()
+            .find_map(|locale| self.lookup(locale, key))
+            .map_or_else(|| key.to_owned(), |message| interpolate(message, args))
+    }
+
+    /// Translates the plural form of `key` matching `count`.
+    ///
+    /// Looks up `key.<category>` and then `key.other` in each locale of the
+    /// chain, with the category chosen by that locale's rules. `count` is
+    /// available as the `{count}` placeholder.
+    pub fn translate_plural(&self, key: &str, count: u64, args: Args<'_>) -> String {
+        let message = self.chain().into_iter().find_map(|locale| {
+            let category = PluralCategory::of(locale, count);
+            self.lookup(locale, &format!("{key}.{}", category.suffix()))
+                .or_else(|| self.lookup(locale, &format!("{key}.other")))
+        });
+        let Some(message) = message else {
+            return key.to_owned();
+        };
+        let mut all: Vec<(&str, &dyn Display)> = Vec::with_capacity(args.len() + 1);
+        all.push(("count", &count));
+        all.extend_from_slice(args);
+        interpolate(message, &all)
+    }
+
+    fn lookup(&self, locale: &str, key: &str) -> Option<&str> {
+        self.messages.get(locale)?.get(key).map(String::as_str)
+    }
+}
+
+thread_local! {
+    static GLOBAL: RefCell<Option<I18n>> = const { RefCell::new(None) };
+}
+
+/// Makes `i18n` the catalog used by [`t!`](crate::t), replacing any
+/// previous one.
+pub fn install(i18n: I18n) {
+    GLOBAL.with(|global| *global.borrow_mut() = Some(i18n));
+}
+
+/// Runs `f` with the installed catalog, if any.
+///
+/// Useful for switching the language at runtime:
+///
+/// ```
+/// use telegram_webapp_sdk::i18n::{self, I18n};
+///
+/// i18n::install(I18n::new("en"));
+/// i18n::with(|i18n| i18n.set_locale("de"));
+/// assert_eq!(
+///     i18n::with(|i18n| i18n.locale().to_owned()).as_deref(),
+///     Some("de")
+/// );
+/// ```
+pub fn with<R>(f: impl FnOnce(&mut I18n) -> R) -> Option<R> {
+    GLOBAL.with(|global| global.borrow_mut().as_mut().map(f))
+}
+
+/// Translates `key` with the installed catalog; used by [`t!`](crate::t).
+///
+/// Returns `key` when no catalog is installed.
+pub fn translate(key: &str, args: Args<'_>) -> String {
+    with(|i18n| i18n.translate(key, args)).unwrap_or_else(|| key.to_owned())
+}
+
+/// Translates a plural `key` with the installed catalog; used by
+/// [`t!`](crate::t).
+pub fn translate_plural(key: &str, count: u64, args: Args<'_>) -> String {
+    with(|i18n| i18n.translate_plural(key, count, args)).unwrap_or_else(|| key.to_owned())
+}
+
+/// Translates a key with the catalog set by
+/// [`i18n::install`](crate::i18n::install).
+///
+/// Accepts named arguments for `{name}` placeholders; a leading
+/// `count = n` argument selects the plural form.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::{i18n::I18n, t};
+///
+/// telegram_webapp_sdk::i18n::install(I18n::new("en").with_messages(
+///     "en",
+///     [
+///         ("title", "Shop"),
+///         ("welcome", "Welcome, {name}"),
+///         ("left.one", "{count} day left"),
+///         ("left.other", "{count} days left")
+///     ]
+/// ));
+///
+/// assert_eq!(t!("title"), "Shop");
+/// assert_eq!(t!("welcome", name = "Bob"), "Welcome, Bob");
+/// assert_eq!(t!("left", count = 1), "1 day left");
+/// assert_eq!(t!("missing"), "missing");
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:expr, count = $count:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::i18n::translate_plural(
+            $key,
+            $count as u64,
+            &[$((stringify!($name), &$value as &dyn ::core::fmt::Display)),*]
+        )
+    };
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::i18n::translate(
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::core::fmt::Display)),*]
+        )
+    };
+}
+
+/// Lowercases a tag and uses `-` as the subtag separator.
+fn normalize(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+fn base_language(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or(locale)
+}
+
+/// Replaces `{name}` placeholders; unknown ones are left untouched.
+fn interpolate(message: &str, args: Args<'_>) -> String {
+    if args.is_empty() {
+        return message.to_owned();
+    }
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}').and_then(|end| {
+            let name = &after[..end];
+            args.iter()
+                .find(|(arg, _)| *arg == name)
+                .map(|(_, value)| (end, value))
+        });
+        match value {
+            Some((end, value)) => {
+                out.push_str(&value.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog() -> I18n {
+        I18n::new("en")
+            .with_messages(
+                "en",
+                [
+                    ("hello", "Hello, {name}!"),
+                    ("only_en", "English"),
+                    ("apples.one", "{count} apple"),
+                    ("apples.other", "{count} apples")
+                ]
+            )
+            .with_messages(
+                "ru",
+                [
+                    ("hello", "Привет, {name}!"),
+                    ("apples.one", "{count} яблоко"),
+                    ("apples.few", "{count} яблока"),
+                    ("apples.many", "{count} яблок")
+                ]
+            )
+            .with_messages("pt", [("hello", "Olá, {name}!")])
+    }
+
+    #[test]
+    fn falls_back_through_chain() {
+        let i18n = catalog().with_locale("pt_BR");
+        assert_eq!(i18n.chain(), ["pt-br", "pt", "en"]);
+        assert_eq!(i18n.translate("hello", &[("name", &"Ana")]), "Olá, Ana!");
+        assert_eq!(i18n.translate("only_en", &[]), "English");
+        assert_eq!(i18n.translate("nope", &[]), "nope");
+    }
+
+    #[test]
+    fn explicit_fallback_precedes_default() {
+        let i18n = catalog().with_fallback("uk", "ru").with_locale("uk");
+        assert_eq!(i18n.translate("hello", &[("name", &"Оля")]), "Привет, Оля!");
+    }
+
+    #[test]
+    fn plural_forms_follow_locale_rules() {
+        let ru = catalog().with_locale("ru");
+        let forms: Vec<String> = [1, 3, 5, 11, 21, 22]
+            .into_iter()
+            .map(|n| ru.translate_plural("apples", n, &[]))
+            .collect();
+        assert_eq!(
+            forms,
+            [
+                "1 яблоко",
+                "3 яблока",
+                "5 яблок",
+                "11 яблок",
+                "21 яблоко",
+                "22 яблока"
+            ]
+        );
+        let en = catalog();
+        assert_eq!(en.translate_plural("apples", 1, &[]), "1 apple");
+        assert_eq!(en.translate_plural("apples", 0, &[]), "0 apples");
+    }
+
+    #[test]
+    fn plural_categories() {
+        assert_eq!(PluralCategory::of("pl", 22), PluralCategory::Few);
+        assert_eq!(PluralCategory::of("pl", 21), PluralCategory::Many);
+        assert_eq!(PluralCategory::of("fr", 0), PluralCategory::One);
+        assert_eq!(PluralCategory::of("ar", 2), PluralCategory::Two);
+        assert_eq!(PluralCategory::of("zh-hans", 1), PluralCategory::Other);
+    }
+
+    #[test]
+    fn interpolation_keeps_unknown_placeholders() {
+        assert_eq!(interpolate("{a} and {b} {", &[("a", &1)]), "1 and {b} {");
+    }
+
+    #[test]
+    fn macro_uses_installed_catalog() {
+        assert_eq!(crate::t!("hello", name = "x"), "hello");
+        install(catalog().with_locale("ru"));
+        assert_eq!(crate::t!("hello", name = "Иван"), "Привет, Иван!");
+        assert_eq!(crate::t!("apples", count = 2), "2 яблока");
+    }
+}
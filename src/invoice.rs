@@ -0,0 +1,440 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Invoice payments.
+//!
+//! The backend creates an invoice link with the Bot API `createInvoiceLink`
+//! (see [`CreateInvoiceLink`]) and hands it to the mini app, which opens it
+//! with [`pay`] and awaits the outcome. The serde types are shared by both
+//! halves.
+//!
+//! Only one invoice may be open at a time; [`pay`] and
+//! [`PaymentFlow`](crate::payments::PaymentFlow) share the guard and fail
+//! with [`InvoiceError::InProgress`] instead of stacking invoices.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    str::FromStr,
+    time::Duration
+};
+
+use js_sys::{Function, Promise};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use wasm_bindgen_futures::JsFuture;
+
+use crate::webapp::{InvoiceStatus, TelegramWebApp};
+
+/// Hosts serving invoice links.
+const INVOICE_HOSTS: [&str; 2] = ["https://t.me/", "https://telegram.me/"];
+
+/// Currency code of Telegram Stars.
+pub const STARS_CURRENCY: &str = "XTR";
+
+/// Invoice to open with `WebApp.openInvoice`.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::invoice::InvoiceSource;
+///
+/// let source: InvoiceSource = "https://t.me/$AbC123".parse().unwrap();
+/// assert_eq!(source, InvoiceSource::Slug("AbC123".into()));
+/// assert_eq!(source.to_url(), "https://t.me/$AbC123");
+/// assert!("https://example.com/pay".parse::<InvoiceSource>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvoiceSource {
+    /// Full invoice link, e.g. `https://t.me/$slug`.
+    Url(String),
+    /// Invoice slug, the part after `$` or `invoice/` in the link.
+    Slug(String)
+}
+
+impl InvoiceSource {
+    /// Checks that the link points at Telegram or that the slug is well
+    /// formed.
+    ///
+    /// # Errors
+    /// Returns [`InvoiceError::InvalidSource`] otherwise.
+    pub fn validate(&self) -> Result<(), InvoiceError> {
+        let valid = match self {
+            Self::Url(url) => INVOICE_HOSTS
+                .iter()
+                .any(|host| url.strip_prefix(host).is_some_and(|rest| !rest.is_empty())),
+            Self::Slug(slug) => is_slug(slug)
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(InvoiceError::InvalidSource(self.to_url()))
+        }
+    }
+
+    /// Returns the link passed to `WebApp.openInvoice`.
+    pub fn to_url(&self) -> String {
+        match self {
+            Self::Url(url) => url.clone(),
+            Self::Slug(slug) => format!("https://t.me/${slug}")
+        }
+    }
+}
+
+impl FromStr for InvoiceSource {
+    type Err = InvoiceError;
+
+    /// Parses an invoice link (`https://t.me/$slug` or
+    /// `https://t.me/invoice/slug`) or a bare slug.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = match INVOICE_HOSTS.iter().find_map(|host| s.strip_prefix(host)) {
+            Some(rest) => match rest
+                .strip_prefix('$')
+                .or_else(|| rest.strip_prefix("invoice/"))
+            {
+                Some(slug) if is_slug(slug) => Self::Slug(slug.to_owned()),
+                _ => Self::Url(s.to_owned())
+            },
+            None if s.contains("://") => return Err(InvoiceError::InvalidSource(s.to_owned())),
+            None => Self::Slug(s.trim_start_matches('$').to_owned())
+        };
+        source.validate()?;
+        Ok(source)
+    }
+}
+
+fn is_slug(slug: &str) -> bool {
+    !slug.is_empty()
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Errors produced by [`pay`] and
+/// [`PaymentFlow::run`](crate::payments::PaymentFlow::run).
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvoiceError {
+    /// The invoice link or slug is malformed.
+    InvalidSource(String),
+    /// `Telegram.WebApp` is not available.
+    Unavailable,
+    /// No outcome was reported within the timeout.
+    Timeout,
+    /// Another invoice is already open.
+    InProgress,
+    /// Telegram reported an unknown status.
+    UnknownStatus(String),
+    /// The underlying JS call failed.
+    Js(String)
+}
+
+impl std::fmt::Display for InvoiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSource(source) => write!(f, "Invalid invoice: {source}"),
+            Self::Unavailable => write!(f, "WebApp not available"),
+            Self::Timeout => write!(f, "Invoice was not closed in time"),
+            Self::InProgress => write!(f, "Another invoice is already open"),
+            Self::UnknownStatus(status) => write!(f, "Unknown invoice status: {status}"),
+            Self::Js(msg) => write!(f, "openInvoice failed: {msg}")
+        }
+    }
+}
+
+impl std::error::Error for InvoiceError {}
+
+impl From<InvoiceError> for JsValue {
+    fn from(err: InvoiceError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+impl From<JsValue> for InvoiceError {
+    fn from(err: JsValue) -> Self {
+        Self::Js(err.as_string().unwrap_or_else(|| format!("{err:?}")))
+    }
+}
+
+/// Opens the invoice and resolves with its outcome.
+///
+/// The outcome is taken from whichever comes first: the `openInvoice`
+/// callback, the `invoiceClosed` event or, when `timeout` is set, the timer,
+/// which resolves with [`InvoiceError::Timeout`]. [`InvoiceStatus::Pending`]
+/// means the payment is still being processed; confirm it on the backend.
+///
+/// # Errors
+/// Returns [`InvoiceError`] when the source is invalid, the WebApp is
+/// unavailable, another invoice is open, the timeout elapses or the JS call
+/// fails.
+///
+/// # Examples
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::{
+///     invoice::{InvoiceError, pay},
+///     webapp::InvoiceStatus
+/// };
+///
+/// # async fn run(link: &str) -> Result<(), InvoiceError> {
+/// match pay(&link.parse()?, Some(Duration::from_secs(300))).await? {
+///     InvoiceStatus::Paid => { /* deliver */ }
+///     InvoiceStatus::Pending => { /* poll the backend */ }
+///     InvoiceStatus::Cancelled | InvoiceStatus::Failed => {}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn pay(
+    source: &InvoiceSource,
+    timeout: Option<Duration>
+) -> Result<InvoiceStatus, InvoiceError> {
+    open(source, timeout, || {})
+        .await?
+        .ok_or(InvoiceError::Timeout)
+}
+
+thread_local! {
+    static IN_FLIGHT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks an invoice as open for as long as it lives.
+struct InFlight;
+
+impl InFlight {
+    fn acquire() -> Result<Self, InvoiceError> {
+        if IN_FLIGHT.with(|open| open.replace(true)) {
+            return Err(InvoiceError::InProgress);
+        }
+        Ok(Self)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        let _ = IN_FLIGHT.try_with(|open| open.set(false));
+    }
+}
+
+/// Opens the invoice, calls `opened` once it is on screen and resolves with
+/// its status, or `None` when `timeout` elapses first.
+///
+/// Shared by [`pay`] and [`PaymentFlow`](crate::payments::PaymentFlow).
+pub(crate) async fn open(
+    source: &InvoiceSource,
+    timeout: Option<Duration>,
+    opened: impl FnOnce()
+) -> Result<Option<InvoiceStatus>, InvoiceError> {
+    source.validate()?;
+    let app = TelegramWebApp::instance().ok_or(InvoiceError::Unavailable)?;
+    let _in_flight = InFlight::acquire()?;
+
+    let settle: Rc<RefCell<Option<Function>>> = Rc::new(RefCell::new(None));
+    let promise = {
+        let settle = Rc::clone(&settle);
+        Promise::new(&mut |resolve, _reject| {
+            *settle.borrow_mut() = Some(resolve);
+        })
+    };
+    let resolve = move |settle: &RefCell<Option<Function>>, value: &JsValue| {
+        if let Some(resolve) = settle.borrow_mut().take() {
+            let _ = resolve.call1(&JsValue::NULL, value);
+        }
+    };
+
+    let closed = {
+        let settle = Rc::clone(&settle);
+        app.on_event("invoiceClosed", move |payload| {
+            let status =
+                InvoiceStatus::from_event(&payload).map_or("unknown", InvoiceStatus::as_str);
+            resolve(&settle, &status.into());
+        })?
+    };
+    {
+        let settle = Rc::clone(&settle);
+        app.open_invoice_with_callback(&source.to_url(), move |status| {
+            resolve(&settle, &status.into());
+        })?;
+    }
+    opened();
+    let _timer = match (timeout, web_sys::window()) {
+        (Some(timeout), Some(win)) => {
+            let settle = Rc::clone(&settle);
+            Some(Timer::start(win, timeout, move || {
+                resolve(&settle, &JsValue::UNDEFINED)
+            })?)
+        }
+        _ => None
+    };
+
+    let outcome = JsFuture::from(promise).await;
+    drop(closed);
+    status_from(outcome?)
+}
+
+/// Pending `setTimeout` owning its callback; dropping it clears the timer
+/// and frees the callback, also when the payment future is dropped early.
+struct Timer {
+    window:  web_sys::Window,
+    id:      i32,
+    _expire: Closure<dyn FnMut()>
+}
+
+impl Timer {
+    fn start(
+        window: web_sys::Window,
+        timeout: Duration,
+        expire: impl FnOnce() + 'static
+    ) -> Result<Self, JsValue> {
+        let expire = Closure::once(expire);
+        let millis = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+        let id = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            expire.as_ref().unchecked_ref(),
+            millis
+        )?;
+        Ok(Self {
+            window,
+            id,
+            _expire: expire
+        })
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.window.clear_timeout_with_handle(self.id);
+    }
+}
+
+/// Interprets the value the payment promise resolved with; `undefined`
+/// marks the timeout.
+fn status_from(value: JsValue) -> Result<Option<InvoiceStatus>, InvoiceError> {
+    let Some(status) = value.as_string() else {
+        return Ok(None);
+    };
+    InvoiceStatus::from_status(&status)
+        .map(Some)
+        .ok_or(InvoiceError::UnknownStatus(status))
+}
+
+/// Price component of an invoice, mirroring the Bot API `LabeledPrice`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabeledPrice {
+    /// Portion label.
+    pub label:  String,
+    /// Price in the smallest units of the currency (or in Stars for `XTR`).
+    pub amount: u64
+}
+
+/// Parameters of the Bot API `createInvoiceLink` call, built by the backend.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::invoice::CreateInvoiceLink;
+///
+/// let request = CreateInvoiceLink::stars("Boost", "Extra lives", "order-42", 50);
+/// assert_eq!(request.currency, "XTR");
+/// assert_eq!(request.prices[0].amount, 50);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateInvoiceLink {
+    /// Product name, 1-32 characters.
+    pub title:          String,
+    /// Product description, 1-255 characters.
+    pub description:    String,
+    /// Bot-defined payload, 1-128 bytes, not shown to the user.
+    pub payload:        String,
+    /// Payment provider token; omitted for payments in Telegram Stars.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, or `XTR` for Telegram Stars.
+    pub currency:       String,
+    /// Price breakdown.
+    pub prices:         Vec<LabeledPrice>
+}
+
+impl CreateInvoiceLink {
+    /// Creates an invoice for `amount` Telegram Stars.
+    pub fn stars(
+        title: impl Into<String>,
+        description: impl Into<String>,
+        payload: impl Into<String>,
+        amount: u64
+    ) -> Self {
+        let title = title.into();
+        Self {
+            prices: vec![LabeledPrice {
+                label: title.clone(),
+                amount
+            }],
+            title,
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: None,
+            currency: STARS_CURRENCY.to_owned()
+        }
+    }
+}
+
+/// Invoice link returned by the backend to the mini app.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvoiceLink {
+    /// Link created with `createInvoiceLink`.
+    pub url: String
+}
+
+impl InvoiceLink {
+    /// Parses the link into an [`InvoiceSource`].
+    ///
+    /// # Errors
+    /// Returns [`InvoiceError::InvalidSource`] when the link is malformed.
+    pub fn source(&self) -> Result<InvoiceSource, InvoiceError> {
+        self.url.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_parse_links_and_slugs() {
+        assert_eq!(
+            "https://t.me/invoice/abc_1".parse(),
+            Ok(InvoiceSource::Slug("abc_1".into()))
+        );
+        assert_eq!("$abc".parse(), Ok(InvoiceSource::Slug("abc".into())));
+        assert_eq!(
+            "https://t.me/other/path".parse(),
+            Ok(InvoiceSource::Url("https://t.me/other/path".into()))
+        );
+        assert!(
+            "https://evil.example/$abc"
+                .parse::<InvoiceSource>()
+                .is_err()
+        );
+        assert!("bad slug".parse::<InvoiceSource>().is_err());
+        assert!(
+            InvoiceSource::Url("https://t.me/".into())
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn only_one_invoice_in_flight() {
+        let first = InFlight::acquire().unwrap();
+        assert_eq!(InFlight::acquire().err(), Some(InvoiceError::InProgress));
+        drop(first);
+        assert!(InFlight::acquire().is_ok());
+    }
+
+    #[test]
+    fn stars_invoice_serializes_without_provider() {
+        let json = serde_json::to_value(CreateInvoiceLink::stars("A", "B", "p", 5)).unwrap();
+        assert_eq!(json["currency"], "XTR");
+        assert!(json.get("provider_token").is_none());
+        assert_eq!(json["prices"][0]["amount"], 5);
+    }
+}
@@ -1,33 +1,85 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+/// [`active::use_is_active`] signal tracking the active/background state.
+pub mod active;
 /// [`back_button::BackButton`] component driving `WebApp.BackButton`.
 pub mod back_button;
+/// [`biometric::use_biometric`] primitive over `WebApp.BiometricManager`.
+#[cfg(feature = "biometric")]
+pub mod biometric;
 /// [`bottom_button::BottomButton`] component driving the main/secondary button.
 pub mod bottom_button;
+/// [`cloud_storage::use_cloud_storage`] binding a CloudStorage key to a
+/// signal.
+#[cfg(feature = "cloud-storage")]
+pub mod cloud_storage;
+/// [`dialogs::TelegramConfirm`] and other components driving native popups.
+#[cfg(feature = "popups")]
+pub mod dialogs;
+/// [`event::use_telegram_event`] signal over raw or typed event payloads.
+pub mod event;
+/// [`form::TextInput`], [`form::Select`], [`form::Switch`] and
+/// [`form::Section`] components styled from the Telegram theme.
+pub mod form;
+/// [`haptic::use_haptic`] helper returning haptic feedback callbacks.
+pub mod haptic;
+/// [`invoice::use_invoice`] primitive opening invoices and tracking their
+/// status.
+pub mod invoice;
+/// [`location::use_location`] primitive over `WebApp.LocationManager`.
+#[cfg(feature = "location")]
+pub mod location;
+/// [`router::PagePath`] bridging registered pages to `leptos_router`.
+#[cfg(feature = "leptos-router")]
+pub mod router;
 /// [`safe_area::use_safe_area`] hook exposing safe-area insets reactively.
 pub mod safe_area;
+/// [`safe_area_view::SafeAreaView`] component padding its children by the
+/// safe-area insets.
+pub mod safe_area_view;
 /// [`settings_button::SettingsButton`] component driving
 /// `WebApp.SettingsButton`.
 pub mod settings_button;
-/// [`theme::use_theme`] hook exposing Telegram theme parameters reactively.
+/// [`theme::use_theme`] hook and [`theme::ThemeProvider`] component exposing
+/// Telegram theme parameters reactively.
 pub mod theme;
 /// [`viewport::use_viewport`] hook exposing viewport size and state reactively.
 pub mod viewport;
 
+use std::rc::Rc;
+
+pub use active::use_is_active;
 pub use back_button::BackButton;
+#[cfg(feature = "biometric")]
+pub use biometric::{BiometricHandle, use_biometric};
 pub use bottom_button::BottomButton;
-use leptos::prelude::provide_context;
+#[cfg(feature = "cloud-storage")]
+pub use cloud_storage::{CloudStorageSignal, use_cloud_storage};
+#[cfg(feature = "popups")]
+pub use dialogs::{TelegramAlert, TelegramConfirm, TelegramPopup};
+pub use event::{use_telegram_event, use_telegram_event_as};
+pub use form::{Section, Select, Switch, TextInput};
+pub use haptic::{Haptic, use_haptic};
+pub use invoice::{InvoiceState, use_invoice};
+use leptos::prelude::{provide_context, use_context};
+#[cfg(feature = "location")]
+pub use location::{LocationHandle, use_location};
 pub use safe_area::{SafeAreaState, use_safe_area};
+pub use safe_area_view::SafeAreaView;
+use send_wrapper::SendWrapper;
 pub use settings_button::SettingsButton;
-pub use theme::{ThemeState, use_theme};
+pub use theme::{ThemeProvider, ThemeState, use_theme, use_theme_params};
 pub use viewport::{ViewportState, use_viewport};
 use wasm_bindgen::JsValue;
 
-use crate::core::{context::TelegramContext, safe_context::get_context};
+use crate::core::{context::TelegramContext, safe_context::get_shared_context};
 
 /// Provides the [`TelegramContext`] to the Leptos reactive system.
 ///
+/// The context is shared, not copied; read it with
+/// [`use_shared_telegram_context`].
+///
 /// # Errors
 ///
 /// Returns an error if the global context has not been initialized with
@@ -37,17 +89,43 @@ use crate::core::{context::TelegramContext, safe_context::get_context};
 ///
 /// ```no_run
 /// use leptos::prelude::*;
-/// use telegram_webapp_sdk::{core::context::TelegramContext, leptos::provide_telegram_context};
+/// use telegram_webapp_sdk::leptos::{provide_telegram_context, use_shared_telegram_context};
 ///
 /// #[component]
 /// fn App() -> impl IntoView {
 ///     provide_telegram_context().expect("context");
-///     let ctx = use_context::<TelegramContext>().expect("context");
+///     let ctx = use_shared_telegram_context().expect("context");
 ///     view! { <span>{ ctx.init_data.auth_date }</span> }
 /// }
 /// ```
 pub fn provide_telegram_context() -> Result<(), JsValue> {
-    let ctx: TelegramContext = get_context(|c| c.clone())?;
-    provide_context(ctx);
+    provide_context(SendWrapper::new(get_shared_context()?));
     Ok(())
 }
+
+/// Returns a copy of the [`TelegramContext`] provided by an ancestor, falling
+/// back to the global context.
+///
+/// Prefer [`use_shared_telegram_context`], which avoids the copy.
+///
+/// # Errors
+///
+/// Returns an error if no ancestor called [`provide_telegram_context`] and
+/// the global context has not been initialized.
+pub fn use_telegram_context() -> Result<TelegramContext, JsValue> {
+    use_shared_telegram_context().map(|ctx| (*ctx).clone())
+}
+
+/// Returns a shared handle to the [`TelegramContext`] provided by an
+/// ancestor, falling back to the global context.
+///
+/// # Errors
+///
+/// Returns an error if no ancestor called [`provide_telegram_context`] and
+/// the global context has not been initialized.
+pub fn use_shared_telegram_context() -> Result<Rc<TelegramContext>, JsValue> {
+    match use_context::<SendWrapper<Rc<TelegramContext>>>() {
+        Some(ctx) => Ok(ctx.take()),
+        None => get_shared_context()
+    }
+}
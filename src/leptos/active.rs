@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+
+use super::event::subscribe;
+use crate::webapp::TelegramWebApp;
+
+/// Reads `WebApp.isActive`.
+///
+/// Clients older than Bot API 8.0 and non-Telegram environments have no
+/// background state and are reported as active.
+fn snapshot(app: Option<&TelegramWebApp>) -> bool {
+    app.is_none_or(|app| app.is_active() || !app.is_version_at_least("8.0").unwrap_or(false))
+}
+
+/// Leptos signal reporting whether the Mini App is active.
+///
+/// Updates on `activated` and `deactivated`, so effects can pause polling,
+/// animations and timers while the app is in the background. The
+/// subscriptions are removed on scope disposal.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::use_is_active;
+///
+/// #[component]
+/// fn Clock() -> impl IntoView {
+///     let active = use_is_active();
+///     Effect::new(move |_| {
+///         if active.get() { /* resume ticking */
+///         } else { /* pause */
+///         }
+///     });
+///     view! { <span>{move || if active.get() { "Live" } else { "Paused" }}</span> }
+/// }
+/// ```
+pub fn use_is_active() -> Signal<bool> {
+    let app = TelegramWebApp::instance();
+    let (active, set_active) = signal(snapshot(app.as_ref()));
+    if app.is_some() {
+        subscribe("activated", move |_| set_active.set(true));
+        subscribe("deactivated", move |_| set_active.set(false));
+    }
+    active.into()
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+
+use super::event::subscribe;
+use crate::{
+    api::biometric::{self, BiometricAuthResult, BiometricStatus},
+    logger,
+    webapp::TelegramWebApp
+};
+
+/// `BiometricManager` events that change its flags.
+const EVENTS: [&str; 3] = [
+    "biometricManagerUpdated",
+    "biometricAuthRequested",
+    "biometricTokenUpdated"
+];
+
+/// Handle returned by [`use_biometric`].
+#[derive(Clone, Copy, Debug)]
+pub struct BiometricHandle {
+    /// Current `BiometricManager` flags.
+    pub status:         Signal<BiometricStatus>,
+    /// Whether biometrics are available on the device.
+    pub available:      Signal<bool>,
+    /// Whether access to biometrics was granted.
+    pub access_granted: Signal<bool>,
+    set_status:         WriteSignal<BiometricStatus>
+}
+
+impl BiometricHandle {
+    /// Prompts the user for biometric authentication.
+    ///
+    /// [`BiometricHandle::status`] is refreshed once the prompt closes.
+    ///
+    /// # Errors
+    /// The future fails if `BiometricManager` is unavailable or the call
+    /// fails.
+    pub fn authenticate(
+        &self,
+        reason: Option<&str>
+    ) -> impl Future<Output = Result<BiometricAuthResult, JsValue>> + 'static {
+        let reason = reason.map(str::to_owned);
+        let set_status = self.set_status;
+        async move {
+            let result = biometric::authenticate_user(reason.as_deref()).await;
+            set_status.set(BiometricStatus::current());
+            result
+        }
+    }
+
+    /// Opens the biometric settings, e.g. after access was denied.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `BiometricManager` is unavailable or the call
+    /// fails.
+    pub fn open_settings(&self) -> Result<(), JsValue> {
+        biometric::open_settings()
+    }
+}
+
+/// Leptos primitive over `Telegram.WebApp.BiometricManager`.
+///
+/// Initializes the manager and updates on `biometricManagerUpdated`,
+/// `biometricAuthRequested` and `biometricTokenUpdated`. The subscriptions
+/// are removed on scope disposal.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::{prelude::*, task::spawn_local};
+/// use telegram_webapp_sdk::leptos::use_biometric;
+///
+/// #[component]
+/// fn Unlock() -> impl IntoView {
+///     let biometric = use_biometric();
+///     let unlock = move |_| {
+///         let unlock = biometric.authenticate(Some("Unlock your wallet"));
+///         spawn_local(async move {
+///             let _ = unlock.await;
+///         });
+///     };
+///     view! {
+///         <button disabled=move || !biometric.available.get() on:click=unlock>
+///             "Unlock"
+///         </button>
+///     }
+/// }
+/// ```
+pub fn use_biometric() -> BiometricHandle {
+    let app = TelegramWebApp::instance();
+    let initial = app
+        .as_ref()
+        .map(|_| BiometricStatus::current())
+        .unwrap_or_default();
+    let (status, set_status) = signal(initial);
+
+    if app.is_some() {
+        for event in EVENTS {
+            subscribe(event, move |_| set_status.set(BiometricStatus::current()));
+        }
+        if !biometric::is_inited().unwrap_or(true)
+            && let Err(err) = biometric::init()
+        {
+            logger::error(&format!("BiometricManager.init failed: {err:?}"));
+        }
+    }
+
+    BiometricHandle {
+        status: status.into(),
+        available: Signal::derive(move || status.get().available),
+        access_granted: Signal::derive(move || status.get().access_granted),
+        set_status
+    }
+}
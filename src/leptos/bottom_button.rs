@@ -7,7 +7,7 @@ use leptos::prelude::*;
 
 use crate::{
     logger,
-    webapp::{BottomButton as WebBottomButton, EventHandle, TelegramWebApp}
+    webapp::{BottomButton as WebBottomButton, BottomButtonParams, EventHandle, TelegramWebApp}
 };
 
 thread_local! {
@@ -59,6 +59,16 @@ pub fn BottomButton<F>(
     /// Optional reactive text color as a `#RRGGBB` hex string.
     #[prop(optional, into)]
     text_color: Option<Signal<String>>,
+    /// Optional reactive flag showing the loading indicator; the button is
+    /// inactive while it is shown.
+    #[prop(optional, into)]
+    progress: Option<Signal<bool>>,
+    /// Optional reactive flag; `false` disables the button.
+    #[prop(optional, into)]
+    enabled: Option<Signal<bool>>,
+    /// Optional reactive flag enabling the shine animation.
+    #[prop(optional, into)]
+    shine: Option<Signal<bool>>,
     /// Optional callback invoked when the button is clicked.
     #[prop(optional)]
     on_click: Option<F>,
@@ -111,6 +121,55 @@ where
         });
     }
 
+    // Toggle the loading indicator.
+    if let Some(progress) = progress {
+        Effect::new(move |_| {
+            let Some(app) = TelegramWebApp::instance() else {
+                return;
+            };
+            let result = if progress.get() {
+                app.show_bottom_button_progress(button, false)
+            } else {
+                app.hide_bottom_button_progress(button)
+            };
+            if let Err(err) = result {
+                logger::error(&format!("bottom button progress update failed: {err:?}"));
+            }
+        });
+    }
+
+    // Enable or disable the button.
+    if let Some(enabled) = enabled {
+        Effect::new(move |_| {
+            let Some(app) = TelegramWebApp::instance() else {
+                return;
+            };
+            let result = if enabled.get() {
+                app.enable_bottom_button(button)
+            } else {
+                app.disable_bottom_button(button)
+            };
+            if let Err(err) = result {
+                logger::error(&format!("bottom button enable/disable failed: {err:?}"));
+            }
+        });
+    }
+
+    // Update the shine effect.
+    if let Some(shine) = shine {
+        Effect::new(move |_| {
+            let params = BottomButtonParams {
+                has_shine_effect: Some(shine.get()),
+                ..Default::default()
+            };
+            if let Some(app) = TelegramWebApp::instance()
+                && let Err(err) = app.set_bottom_button_params(button, &params)
+            {
+                logger::error(&format!("set_bottom_button_params failed: {err:?}"));
+            }
+        });
+    }
+
     // Register click callback if provided and keep handle for cleanup.
     if let Some(cb) = on_click {
         if let Some(app) = TelegramWebApp::instance() {
@@ -278,4 +337,66 @@ mod tests {
         let stored = texts.borrow();
         assert_eq!(stored.as_slice(), ["Start", "Next"]);
     }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code)]
+    fn bottom_button_tracks_progress_enabled_and_shine() {
+        let _ = setup_webapp();
+        let button: Object = Reflect::get(
+            &Reflect::get(
+                &Reflect::get(&window().unwrap(), &"Telegram".into()).unwrap(),
+                &"WebApp".into()
+            )
+            .unwrap(),
+            &"MainButton".into()
+        )
+        .unwrap()
+        .unchecked_into();
+        let calls = js_sys::Array::new();
+        Reflect::set(&button, &"calls".into(), &calls).unwrap();
+        for (method, body) in [
+            ("showProgress", "this.calls.push('showProgress:' + a);"),
+            ("hideProgress", "this.calls.push('hideProgress');"),
+            ("enable", "this.calls.push('enable');"),
+            ("disable", "this.calls.push('disable');"),
+            (
+                "setParams",
+                "this.calls.push('shine:' + a.has_shine_effect);"
+            )
+        ] {
+            Reflect::set(&button, &method.into(), &Function::new_with_args("a", body)).unwrap();
+        }
+
+        let owner = Owner::new();
+        owner.set();
+        let progress = RwSignal::new(true);
+        let enabled = RwSignal::new(false);
+        let shine = RwSignal::new(true);
+        let _view = view! {
+            <BottomButton
+                text="Pay"
+                progress
+                enabled
+                shine
+                on_click=|| {}
+            />
+        };
+        progress.set(false);
+        enabled.set(true);
+        drop(owner);
+
+        let calls: Vec<String> = calls.iter().filter_map(|call| call.as_string()).collect();
+        for expected in [
+            "showProgress:false",
+            "hideProgress",
+            "disable",
+            "enable",
+            "shine:true"
+        ] {
+            assert!(
+                calls.iter().any(|call| call == expected),
+                "missing {expected}"
+            );
+        }
+    }
 }
@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use leptos::prelude::*;
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::{JsFuture, spawn_local};
+
+use crate::api::cloud_storage::{VALUE_LIMIT, get_item, set_item};
+
+/// Delay after the last change before a value is written back.
+pub const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// CloudStorage entry returned by [`use_cloud_storage`].
+#[derive(Debug)]
+pub struct CloudStorageSignal<T: Send + Sync + 'static> {
+    /// Current value; changes are saved after [`SAVE_DEBOUNCE`].
+    pub value:   RwSignal<T>,
+    /// `true` until the stored value has been loaded.
+    pub loading: ReadSignal<bool>,
+    /// Last load or save error, cleared by the next successful save.
+    pub error:   ReadSignal<Option<String>>
+}
+
+impl<T: Send + Sync + 'static> Clone for CloudStorageSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for CloudStorageSignal<T> {}
+
+/// Leptos primitive binding a CloudStorage key to a signal.
+///
+/// Starts with `default`, replaces it with the stored JSON value once loaded
+/// and writes every later change back as JSON, debounced by
+/// [`SAVE_DEBOUNCE`]. Changes made while [`CloudStorageSignal::loading`] is
+/// `true` are overwritten by the stored value. A pending save still runs
+/// after the scope is disposed.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::use_cloud_storage;
+///
+/// #[component]
+/// fn Counter() -> impl IntoView {
+///     let counter = use_cloud_storage("counter", 0_u32);
+///     view! {
+///         <button
+///             disabled=move || counter.loading.get()
+///             on:click=move |_| counter.value.update(|n| *n += 1)
+///         >
+///             { move || counter.value.get() }
+///         </button>
+///     }
+/// }
+/// ```
+pub fn use_cloud_storage<T>(key: &str, default: T) -> CloudStorageSignal<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static
+{
+    let value = RwSignal::new(default);
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+    let saved = StoredValue::new(None::<String>);
+    let pending = StoredValue::new(None::<TimeoutHandle>);
+
+    // On the server the value stays at `default` and `loading` stays `true`,
+    // matching the first render in the browser.
+    if cfg!(target_arch = "wasm32") {
+        let key = key.to_owned();
+        spawn_local(async move {
+            match load::<T>(&key).await {
+                Ok(Some((raw, stored))) => {
+                    saved.set_value(Some(raw));
+                    value.set(stored);
+                }
+                Ok(None) => {}
+                Err(err) => error.set(Some(err))
+            }
+            loading.set(false);
+        });
+    }
+
+    let key = key.to_owned();
+    Effect::new(move |_| {
+        let encoded = value.with(serde_json::to_string);
+        if loading.get_untracked() {
+            return;
+        }
+        if let Some(handle) = pending.get_value() {
+            handle.clear();
+            pending.set_value(None);
+        }
+        let raw = match encoded {
+            Ok(raw) => raw,
+            Err(err) => {
+                error.set(Some(err.to_string()));
+                return;
+            }
+        };
+        if saved.with_value(|saved| saved.as_deref() == Some(raw.as_str())) {
+            return;
+        }
+        let key = key.clone();
+        let scheduled = set_timeout_with_handle(
+            move || {
+                pending.set_value(None);
+                spawn_local(async move {
+                    match save(&key, &raw).await {
+                        Ok(()) => {
+                            saved.set_value(Some(raw));
+                            error.set(None);
+                        }
+                        Err(err) => error.set(Some(err))
+                    }
+                });
+            },
+            SAVE_DEBOUNCE
+        );
+        match scheduled {
+            Ok(handle) => pending.set_value(Some(handle)),
+            Err(err) => error.set(Some(format!("{err:?}")))
+        }
+    });
+
+    CloudStorageSignal {
+        value,
+        loading: loading.read_only(),
+        error: error.read_only()
+    }
+}
+
+/// Reads `key`, returning the raw JSON and the decoded value.
+async fn load<T: DeserializeOwned>(key: &str) -> Result<Option<(String, T)>, String> {
+    let stored = JsFuture::from(get_item(key).map_err(describe)?)
+        .await
+        .map_err(describe)?;
+    match stored.as_string() {
+        Some(raw) if !raw.is_empty() => {
+            let value = serde_json::from_str(&raw).map_err(|err| err.to_string())?;
+            Ok(Some((raw, value)))
+        }
+        _ => Ok(None)
+    }
+}
+
+/// Writes `raw` under `key`.
+async fn save(key: &str, raw: &str) -> Result<(), String> {
+    if raw.chars().count() > VALUE_LIMIT {
+        return Err(format!(
+            "value for `{key}` exceeds {VALUE_LIMIT} characters"
+        ));
+    }
+    JsFuture::from(set_item(key, raw).map_err(describe)?)
+        .await
+        .map_err(describe)?;
+    Ok(())
+}
+
+/// Converts a JavaScript error into a message.
+fn describe(err: JsValue) -> String {
+    err.as_string().unwrap_or_else(|| format!("{err:?}"))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use js_sys::{Function, Object, Reflect};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::window;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn setup_cloud_storage(get_item: &str) -> Object {
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let storage = Object::new();
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&webapp, &"CloudStorage".into(), &storage);
+        let _ = Reflect::set(
+            &storage,
+            &"getItem".into(),
+            &Function::new_with_args("key", get_item)
+        );
+        storage
+    }
+
+    #[wasm_bindgen_test(async)]
+    async fn load_decodes_json() {
+        let _ = setup_cloud_storage("return Promise.resolve('[1,2]');");
+        let loaded = load::<Vec<u8>>("list").await.unwrap();
+        assert_eq!(loaded, Some(("[1,2]".to_owned(), vec![1, 2])));
+    }
+
+    #[wasm_bindgen_test(async)]
+    async fn load_treats_empty_as_missing() {
+        let _ = setup_cloud_storage("return Promise.resolve('');");
+        assert_eq!(load::<u32>("count").await.unwrap(), None);
+    }
+
+    #[wasm_bindgen_test(async)]
+    async fn load_reports_invalid_json() {
+        let _ = setup_cloud_storage("return Promise.resolve('nope');");
+        assert!(load::<u32>("count").await.is_err());
+    }
+
+    #[wasm_bindgen_test(async)]
+    async fn save_rejects_oversized_values() {
+        let storage = setup_cloud_storage("return Promise.resolve('');");
+        let _ = Reflect::set(
+            &storage,
+            &"setItem".into(),
+            &Function::new_with_args("key, value", "return Promise.resolve();")
+        );
+        let raw = "x".repeat(VALUE_LIMIT + 1);
+        assert!(save("big", &raw).await.is_err());
+        assert!(save("small", "1").await.is_ok());
+    }
+}
@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+
+use crate::{
+    logger,
+    webapp::{PopupParams, TelegramWebApp}
+};
+
+/// Runs `show` each time `open` switches to `true`.
+fn on_open(open: Signal<bool>, show: impl Fn(TelegramWebApp) + 'static) {
+    Effect::new(move |was_open: Option<bool>| {
+        let is_open = open.get();
+        if is_open
+            && was_open != Some(true)
+            && let Some(app) = TelegramWebApp::instance()
+        {
+            show(app);
+        }
+        is_open
+    });
+}
+
+/// Leptos component showing `WebApp.showAlert` while `open` is `true`.
+///
+/// The alert is shown each time `open` switches to `true`; reset it from
+/// `on_close`.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::TelegramAlert;
+///
+/// #[component]
+/// fn Saved() -> impl IntoView {
+///     let open = RwSignal::new(true);
+///     view! { <TelegramAlert open message="Saved" on_close=move || open.set(false) /> }
+/// }
+/// ```
+#[component]
+pub fn TelegramAlert(
+    /// Shows the alert when it becomes `true`.
+    #[prop(into)]
+    open: Signal<bool>,
+    /// Alert message.
+    #[prop(into)]
+    message: Signal<String>,
+    /// Called once the alert is closed.
+    #[prop(optional, into)]
+    on_close: Option<Callback<()>>
+) -> impl IntoView {
+    on_open(open, move |app| {
+        let closed = move || {
+            if let Some(on_close) = on_close {
+                on_close.run(());
+            }
+        };
+        if let Err(err) = app.show_alert_with_callback(&message.get_untracked(), closed) {
+            logger::error(&format!("showAlert failed: {err:?}"));
+        }
+    });
+}
+
+/// Leptos component showing `WebApp.showConfirm` while `open` is `true`.
+///
+/// The confirmation is shown each time `open` switches to `true`; reset it
+/// from `on_result`.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::TelegramConfirm;
+///
+/// #[component]
+/// fn Logout() -> impl IntoView {
+///     let open = RwSignal::new(false);
+///     view! {
+///         <button on:click=move |_| open.set(true)>"Log out"</button>
+///         <TelegramConfirm
+///             open
+///             message="Log out?"
+///             on_result=move |confirmed: bool| {
+///                 open.set(false);
+///                 if confirmed { /* log out */ }
+///             }
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn TelegramConfirm(
+    /// Shows the confirmation when it becomes `true`.
+    #[prop(into)]
+    open: Signal<bool>,
+    /// Question shown to the user.
+    #[prop(into)]
+    message: Signal<String>,
+    /// Called with the user's answer.
+    #[prop(optional, into)]
+    on_result: Option<Callback<bool>>
+) -> impl IntoView {
+    on_open(open, move |app| {
+        let answered = move |confirmed| {
+            if let Some(on_result) = on_result {
+                on_result.run(confirmed);
+            }
+        };
+        if let Err(err) = app.show_confirm_with_callback(&message.get_untracked(), answered) {
+            logger::error(&format!("showConfirm failed: {err:?}"));
+        }
+    });
+}
+
+/// Leptos component showing `WebApp.showPopup` while `open` is `true`.
+///
+/// The popup is shown each time `open` switches to `true`; reset it from
+/// `on_result`, which receives the pressed button id or `None` if the popup
+/// was dismissed.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::{
+///     leptos::TelegramPopup,
+///     webapp::{PopupButton, PopupButtonKind, PopupParams}
+/// };
+///
+/// #[component]
+/// fn DeleteOrder() -> impl IntoView {
+///     let open = RwSignal::new(true);
+///     let params = PopupParams {
+///         title:   Some("Delete order".into()),
+///         message: "This cannot be undone".into(),
+///         buttons: vec![
+///             PopupButton::new("delete", "Delete").kind(PopupButtonKind::Destructive),
+///             PopupButton::default().kind(PopupButtonKind::Cancel),
+///         ]
+///     };
+///     let on_result = move |id: Option<String>| {
+///         open.set(false);
+///         if id.as_deref() == Some("delete") { /* delete */ }
+///     };
+///     view! { <TelegramPopup open params on_result /> }
+/// }
+/// ```
+#[component]
+pub fn TelegramPopup(
+    /// Shows the popup when it becomes `true`.
+    #[prop(into)]
+    open: Signal<bool>,
+    /// Title, message and buttons of the popup.
+    #[prop(into)]
+    params: Signal<PopupParams>,
+    /// Called with the id of the pressed button, or `None` if the popup was
+    /// dismissed.
+    #[prop(optional, into)]
+    on_result: Option<Callback<Option<String>>>
+) -> impl IntoView {
+    on_open(open, move |app| {
+        let result = params.get_untracked().to_js().and_then(|params| {
+            app.show_popup_with_callback(&params, move |id| {
+                if let Some(on_result) = on_result {
+                    on_result.run(Some(id).filter(|id| !id.is_empty()));
+                }
+            })
+        });
+        if let Err(err) = result {
+            logger::error(&format!("showPopup failed: {err:?}"));
+        }
+    });
+}
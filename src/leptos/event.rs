@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::JsValue;
+
+use crate::{logger, webapp::TelegramWebApp};
+
+/// Leptos signal holding the latest payload of a Telegram event.
+///
+/// Starts as `None` and is set on every `event`; events without data yield
+/// `Some(JsValue::UNDEFINED)`. The subscription is removed on scope disposal.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::use_telegram_event;
+///
+/// #[component]
+/// fn Clipboard() -> impl IntoView {
+///     let received = use_telegram_event("clipboardTextReceived");
+///     view! { <span>{ move || received.get().map(|_| "Received") }</span> }
+/// }
+/// ```
+pub fn use_telegram_event(event: &str) -> ReadSignal<Option<JsValue>, LocalStorage> {
+    let signal = RwSignal::new_local(None);
+    subscribe(event, move |payload| signal.set(Some(payload)));
+    signal.read_only()
+}
+
+/// Leptos signal holding the latest payload of a Telegram event, decoded
+/// into `T`.
+///
+/// Payloads that fail to decode are logged and leave the signal unchanged.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use serde::Deserialize;
+/// use telegram_webapp_sdk::leptos::use_telegram_event_as;
+///
+/// #[derive(Clone, Deserialize)]
+/// struct InvoiceClosed {
+///     url:    String,
+///     status: String
+/// }
+///
+/// #[component]
+/// fn LastInvoice() -> impl IntoView {
+///     let closed = use_telegram_event_as::<InvoiceClosed>("invoiceClosed");
+///     view! { <span>{ move || closed.get().map(|event| event.status) }</span> }
+/// }
+/// ```
+pub fn use_telegram_event_as<T>(event: &str) -> ReadSignal<Option<T>>
+where
+    T: DeserializeOwned + Send + Sync + 'static
+{
+    let signal = RwSignal::new(None);
+    let name = event.to_owned();
+    subscribe(event, move |payload| {
+        match serde_wasm_bindgen::from_value(payload) {
+            Ok(value) => signal.set(Some(value)),
+            Err(err) => logger::error(&format!("Invalid `{name}` payload: {err}"))
+        }
+    });
+    signal.read_only()
+}
+
+/// Registers `handler` for `event` until the current scope is disposed.
+pub(super) fn subscribe(event: &str, handler: impl Fn(JsValue) + 'static) {
+    let Some(app) = TelegramWebApp::instance() else {
+        return;
+    };
+    match app.on_event(event, handler) {
+        Ok(handle) => {
+            let wrapped = SendWrapper::new(handle);
+            on_cleanup(move || {
+                drop(wrapped);
+            });
+        }
+        Err(err) => logger::error(&format!("`{event}` subscription failed: {err:?}"))
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use js_sys::{Function, Object, Reflect};
+    use serde::Deserialize;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::window;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn setup_webapp() -> Object {
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(
+            &webapp,
+            &"onEvent".into(),
+            &Function::new_with_args("name, cb", "this[name] = cb;")
+        );
+        let _ = Reflect::set(
+            &webapp,
+            &"offEvent".into(),
+            &Function::new_with_args("name", "delete this[name];")
+        );
+        webapp
+    }
+
+    fn fire(webapp: &Object, event: &str, payload: &JsValue) {
+        let handler = Reflect::get(webapp, &event.into()).unwrap();
+        let _ = Function::from(handler).call1(&JsValue::NULL, payload);
+    }
+
+    #[wasm_bindgen_test]
+    fn event_signal_tracks_payload_until_disposed() {
+        let webapp = setup_webapp();
+        let owner = Owner::new();
+        let signal = owner.with(|| use_telegram_event("customEvent"));
+        assert!(signal.get_untracked().is_none());
+
+        fire(&webapp, "customEvent", &JsValue::from_str("data"));
+        assert_eq!(
+            signal.get_untracked().and_then(|value| value.as_string()),
+            Some("data".to_owned())
+        );
+
+        owner.cleanup();
+        assert!(!Reflect::has(&webapp, &"customEvent".into()).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn typed_event_signal_decodes_payload() {
+        #[derive(Clone, Debug, PartialEq, Deserialize)]
+        struct Payload {
+            status: String
+        }
+
+        let webapp = setup_webapp();
+        let owner = Owner::new();
+        let signal = owner.with(|| use_telegram_event_as::<Payload>("invoiceClosed"));
+
+        let payload = Object::new();
+        let _ = Reflect::set(&payload, &"status".into(), &"paid".into());
+        fire(&webapp, "invoiceClosed", &payload);
+        assert_eq!(
+            signal.get_untracked(),
+            Some(Payload {
+                status: "paid".into()
+            })
+        );
+
+        fire(&webapp, "invoiceClosed", &JsValue::from_f64(1.0));
+        assert_eq!(
+            signal.get_untracked().map(|p| p.status),
+            Some("paid".into())
+        );
+    }
+}
@@ -0,0 +1,248 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+
+use super::use_safe_area;
+use crate::utils::form_style::{field_style, inject_stylesheet};
+
+/// Text field styled from the Telegram theme.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::TextInput;
+///
+/// #[component]
+/// fn Name() -> impl IntoView {
+///     let name = RwSignal::new(String::new());
+///     view! {
+///         <TextInput
+///             value=name
+///             placeholder="Your name"
+///             destructive=Signal::derive(move || name.with(String::is_empty))
+///             on_input=move |value| name.set(value)
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn TextInput(
+    /// Current value.
+    #[prop(optional, into)]
+    value: Signal<String>,
+    /// Placeholder rendered in the theme hint color.
+    #[prop(optional, into)]
+    placeholder: MaybeProp<String>,
+    /// HTML input type, e.g. `"email"` or `"number"`; defaults to `"text"`.
+    #[prop(optional, into)]
+    input_type: MaybeProp<String>,
+    /// Called with the new value on every edit.
+    #[prop(optional, into)]
+    on_input: Option<Callback<String>>,
+    /// Highlights the field with the destructive color, e.g. on validation
+    /// errors.
+    #[prop(optional, into)]
+    destructive: Signal<bool>,
+    /// Whether the field is disabled.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+    /// Extra classes for the `input`.
+    #[prop(optional, into)]
+    class: MaybeProp<String>
+) -> impl IntoView {
+    Effect::new(|_| inject_stylesheet());
+    view! {
+        <input
+            class=move || format!("tg-text-input {}", class.get().unwrap_or_default())
+            type=move || input_type.get().unwrap_or_else(|| "text".to_owned())
+            prop:value=move || value.get()
+            placeholder=move || placeholder.get()
+            disabled=move || disabled.get()
+            style=move || field_style(destructive.get(), disabled.get())
+            on:input=move |event| {
+                if let Some(on_input) = on_input {
+                    on_input.run(event_target_value(&event));
+                }
+            }
+        />
+    }
+}
+
+/// Drop-down styled from the Telegram theme.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::Select;
+///
+/// #[component]
+/// fn Currency() -> impl IntoView {
+///     let currency = RwSignal::new("usd".to_owned());
+///     let options = vec![
+///         ("usd".to_owned(), "US Dollar".to_owned()),
+///         ("eur".to_owned(), "Euro".to_owned()),
+///     ];
+///     view! { <Select options value=currency on_change=move |value| currency.set(value) /> }
+/// }
+/// ```
+#[component]
+pub fn Select(
+    /// Options as `(value, label)` pairs.
+    #[prop(into)]
+    options: Signal<Vec<(String, String)>>,
+    /// Value of the selected option.
+    #[prop(optional, into)]
+    value: Signal<String>,
+    /// Called with the value of the newly selected option.
+    #[prop(optional, into)]
+    on_change: Option<Callback<String>>,
+    /// Highlights the field with the destructive color.
+    #[prop(optional, into)]
+    destructive: Signal<bool>,
+    /// Whether the field is disabled.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+    /// Extra classes for the `select`.
+    #[prop(optional, into)]
+    class: MaybeProp<String>
+) -> impl IntoView {
+    view! {
+        <select
+            class=move || class.get()
+            disabled=move || disabled.get()
+            style=move || field_style(destructive.get(), disabled.get())
+            on:change=move |event| {
+                if let Some(on_change) = on_change {
+                    on_change.run(event_target_value(&event));
+                }
+            }
+        >
+            {move || {
+                let selected = value.get();
+                options
+                    .get()
+                    .into_iter()
+                    .map(|(option, label)| {
+                        let is_selected = option == selected;
+                        view! {
+                            <option value=option selected=is_selected>
+                                {label}
+                            </option>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </select>
+    }
+}
+
+/// Labelled toggle tinted with the theme button color.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::Switch;
+///
+/// #[component]
+/// fn Notifications() -> impl IntoView {
+///     let enabled = RwSignal::new(true);
+///     view! {
+///         <Switch checked=enabled label="Notifications" on_toggle=move |on| enabled.set(on) />
+///     }
+/// }
+/// ```
+#[component]
+pub fn Switch(
+    /// Whether the switch is on.
+    #[prop(into)]
+    checked: Signal<bool>,
+    /// Label shown next to the switch.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    /// Called with the new state when toggled.
+    #[prop(optional, into)]
+    on_toggle: Option<Callback<bool>>,
+    /// Whether the switch is disabled.
+    #[prop(optional, into)]
+    disabled: Signal<bool>
+) -> impl IntoView {
+    view! {
+        <label style="display: flex; align-items: center; justify-content: space-between; \
+                      gap: 12px; padding: 10px 0; color: var(--tg-theme-text-color);">
+            <span>{move || label.get()}</span>
+            <input
+                type="checkbox"
+                role="switch"
+                prop:checked=move || checked.get()
+                disabled=move || disabled.get()
+                style="width: 20px; height: 20px; accent-color: var(--tg-theme-button-color);"
+                on:change=move |event| {
+                    if let Some(on_toggle) = on_toggle {
+                        on_toggle.run(event_target_checked(&event));
+                    }
+                }
+            />
+        </label>
+    }
+}
+
+/// Settings-style group with a header and footer in theme colors.
+///
+/// The group is inset by the horizontal safe-area insets, so it stays clear
+/// of notches in landscape.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::{Section, Switch};
+///
+/// #[component]
+/// fn Settings() -> impl IntoView {
+///     view! {
+///         <Section title="Privacy" footer="Visible to your contacts only.">
+///             <Switch checked=true label="Show phone number" />
+///         </Section>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Section(
+    /// Section content.
+    children: Children,
+    /// Header shown above the section.
+    #[prop(optional, into)]
+    title: MaybeProp<String>,
+    /// Hint shown below the section.
+    #[prop(optional, into)]
+    footer: MaybeProp<String>
+) -> impl IntoView {
+    let safe_area = use_safe_area();
+    let style = move || {
+        let inset = safe_area.get().total();
+        format!(
+            "margin: 12px 0; padding: 0 {}px 0 {}px;",
+            inset.right, inset.left
+        )
+    };
+    view! {
+        <section style=style>
+            {move || title.get().map(|title| view! {
+                <h3 style="margin: 0 16px 6px; font-size: 13px; text-transform: uppercase; \
+                           color: var(--tg-theme-section-header-text-color);">
+                    {title}
+                </h3>
+            })}
+            <div style="padding: 4px 16px; border-radius: 12px; \
+                        background: var(--tg-theme-section-bg-color, var(--tg-theme-bg-color));">
+                {children()}
+            </div>
+            {move || footer.get().map(|footer| view! {
+                <p style="margin: 6px 16px 0; font-size: 13px; \
+                          color: var(--tg-theme-hint-color);">
+                    {footer}
+                </p>
+            })}
+        </section>
+    }
+}
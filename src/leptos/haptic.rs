@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+
+use crate::api::haptic::{
+    HapticImpactStyle, HapticNotificationType, impact_occurred, notification_occurred,
+    selection_changed
+};
+
+/// Haptic feedback callbacks returned by [`use_haptic`].
+///
+/// The callbacks are no-ops outside Telegram.
+#[derive(Clone, Copy, Debug)]
+pub struct Haptic {
+    /// Triggers an impact of the given style.
+    pub impact:    Callback<HapticImpactStyle>,
+    /// Triggers a notification of the given type.
+    pub notify:    Callback<HapticNotificationType>,
+    /// Signals a selection change.
+    pub selection: Callback<()>
+}
+
+/// Leptos helper returning haptic feedback callbacks.
+///
+/// The callbacks are `Copy`, so they can be moved into any number of event
+/// handlers.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::{api::haptic::HapticImpactStyle, leptos::use_haptic};
+///
+/// #[component]
+/// fn Tap() -> impl IntoView {
+///     let haptic = use_haptic();
+///     view! {
+///         <button on:click=move |_| haptic.impact.run(HapticImpactStyle::Light)>"Tap"</button>
+///     }
+/// }
+/// ```
+pub fn use_haptic() -> Haptic {
+    Haptic {
+        impact:    Callback::new(|style| {
+            let _ = impact_occurred(style);
+        }),
+        notify:    Callback::new(|ty| {
+            let _ = notification_occurred(ty);
+        }),
+        selection: Callback::new(|()| {
+            let _ = selection_changed();
+        })
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+
+use super::event::subscribe;
+use crate::{
+    logger,
+    webapp::{InvoiceStatus, TelegramWebApp}
+};
+
+/// Handle returned by [`use_invoice`].
+#[derive(Clone, Copy, Debug)]
+pub struct InvoiceState {
+    /// Status of the last closed invoice; `None` until one is closed.
+    pub status:  Signal<Option<InvoiceStatus>>,
+    /// Whether an invoice opened with [`InvoiceState::open`] is still shown.
+    pub pending: Signal<bool>,
+    set_status:  WriteSignal<Option<InvoiceStatus>>,
+    set_pending: WriteSignal<bool>
+}
+
+impl InvoiceState {
+    /// Opens the invoice at `url` and clears the previous status.
+    ///
+    /// Does nothing outside Telegram.
+    pub fn open(&self, url: &str) {
+        let Some(app) = TelegramWebApp::instance() else {
+            return;
+        };
+        self.set_status.set(None);
+        self.set_pending.set(true);
+        if let Err(err) = app.open_invoice_with_callback(url, |_| {}) {
+            logger::error(&format!("openInvoice failed: {err:?}"));
+            self.set_pending.set(false);
+        }
+    }
+}
+
+/// Leptos primitive opening invoices and tracking their outcome.
+///
+/// The status updates on `invoiceClosed`. The subscription is removed on
+/// scope disposal.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::{leptos::use_invoice, webapp::InvoiceStatus};
+///
+/// #[component]
+/// fn Checkout() -> impl IntoView {
+///     let invoice = use_invoice();
+///     view! {
+///         <button
+///             disabled=move || invoice.pending.get()
+///             on:click=move |_| invoice.open("https://t.me/$invoice")
+///         >
+///             "Pay"
+///         </button>
+///         <Show when=move || invoice.status.get() == Some(InvoiceStatus::Paid)>
+///             <p>"Thank you!"</p>
+///         </Show>
+///     }
+/// }
+/// ```
+pub fn use_invoice() -> InvoiceState {
+    let (status, set_status) = signal(None);
+    let (pending, set_pending) = signal(false);
+
+    subscribe("invoiceClosed", move |payload| {
+        set_status.set(InvoiceStatus::from_event(&payload));
+        set_pending.set(false);
+    });
+
+    InvoiceState {
+        status: status.into(),
+        pending: pending.into(),
+        set_status,
+        set_pending
+    }
+}
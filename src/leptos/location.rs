@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+
+use super::event::subscribe;
+use crate::{
+    api::location_manager::{self, LocationData, LocationStatus},
+    logger,
+    webapp::TelegramWebApp
+};
+
+/// Handle returned by [`use_location`].
+#[derive(Clone, Copy, Debug)]
+pub struct LocationHandle {
+    /// Current `LocationManager` flags.
+    pub status:    Signal<LocationStatus>,
+    /// Whether location services are available.
+    pub available: Signal<bool>,
+    /// Latest reported location.
+    pub location:  Signal<Option<LocationData>>,
+    set_status:    WriteSignal<LocationStatus>,
+    set_location:  WriteSignal<Option<LocationData>>
+}
+
+impl LocationHandle {
+    /// Asks Telegram for the current location.
+    ///
+    /// [`LocationHandle::location`] updates once the location arrives; it
+    /// becomes `None` if the user denies access.
+    pub fn request_location(&self) {
+        let set_status = self.set_status;
+        let set_location = self.set_location;
+        spawn_local(async move {
+            match location_manager::request_location().await {
+                Ok(data) => set_location.set(data),
+                Err(err) => logger::error(&format!("getLocation failed: {err:?}"))
+            }
+            set_status.set(LocationStatus::current());
+        });
+    }
+
+    /// Opens the location settings, e.g. after access was denied.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `LocationManager` is unavailable or the call
+    /// fails.
+    pub fn open_settings(&self) -> Result<(), JsValue> {
+        location_manager::open_settings()
+    }
+}
+
+/// Leptos primitive over `Telegram.WebApp.LocationManager`.
+///
+/// Initializes the manager, refreshes the flags on `locationManagerUpdated`
+/// and stores locations delivered by `locationRequested`. The subscriptions
+/// are removed on scope disposal.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::use_location;
+///
+/// #[component]
+/// fn Nearby() -> impl IntoView {
+///     let location = use_location();
+///     view! {
+///         <button
+///             disabled=move || !location.available.get()
+///             on:click=move |_| location.request_location()
+///         >
+///             "Locate me"
+///         </button>
+///         <p>
+///             {move || location.location.get().map(|data| {
+///                 format!("{}, {}", data.latitude, data.longitude)
+///             })}
+///         </p>
+///     }
+/// }
+/// ```
+pub fn use_location() -> LocationHandle {
+    let app = TelegramWebApp::instance();
+    let initial = app
+        .as_ref()
+        .map(|_| LocationStatus::current())
+        .unwrap_or_default();
+    let (status, set_status) = signal(initial);
+    let (location, set_location) = signal(None);
+
+    if app.is_some() {
+        subscribe("locationManagerUpdated", move |_| {
+            set_status.set(LocationStatus::current());
+        });
+        subscribe("locationRequested", move |payload| {
+            let data = LocationData::from_event(&payload);
+            set_location.set(data);
+            set_status.set(LocationStatus::current());
+        });
+        if !LocationStatus::current().inited
+            && let Err(err) = location_manager::init()
+        {
+            logger::error(&format!("LocationManager.init failed: {err:?}"));
+        }
+    }
+
+    LocationHandle {
+        status: status.into(),
+        available: Signal::derive(move || status.get().available),
+        location: location.into(),
+        set_status,
+        set_location
+    }
+}
@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::borrow::Cow;
+
+use leptos::prelude::*;
+use leptos_router::{PartialPathMatch, PathSegment, PossibleRouteMatch, hooks::use_location};
+
+use crate::{leptos::BackButton, pages};
+
+/// `leptos_router` path built from an SDK route pattern such as
+/// `/item/:id`.
+///
+/// Lets routes registered with `telegram_page!` or `#[telegram_page]` be
+/// mounted into `<Routes>` without restating them with `path!`. Parameters
+/// are available through `use_params_map` as usual.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use leptos_router::components::{Route, Router, Routes};
+/// use telegram_webapp_sdk::leptos::router::{PagePath, RouterBackButton};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Router>
+///             <RouterBackButton />
+///             <Routes fallback=|| "Not found">
+///                 <Route path=PagePath::new("/") view=|| "Home" />
+///                 <Route path=PagePath::new("/item/:id") view=|| "Item" />
+///             </Routes>
+///         </Router>
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PagePath(&'static str);
+
+impl PagePath {
+    /// Creates a path from an SDK route pattern.
+    pub const fn new(pattern: &'static str) -> Self {
+        Self(pattern)
+    }
+
+    /// Returns the paths of all registered pages.
+    pub fn registered() -> Vec<Self> {
+        pages::iter()
+            .into_iter()
+            .map(|page| Self(page.path))
+            .collect()
+    }
+
+    /// Returns the route pattern.
+    pub const fn pattern(&self) -> &'static str {
+        self.0
+    }
+
+    /// Returns whether a page is registered for this pattern.
+    pub fn is_registered(&self) -> bool {
+        pages::is_registered(self.0)
+    }
+
+    fn segments(&self) -> impl Iterator<Item = &'static str> {
+        self.0.split('/').filter(|segment| !segment.is_empty())
+    }
+}
+
+impl PossibleRouteMatch for PagePath {
+    fn optional(&self) -> bool {
+        false
+    }
+
+    fn test<'a>(&self, path: &'a str) -> Option<PartialPathMatch<'a>> {
+        let mut rest = path;
+        let mut params = Vec::new();
+        for expected in self.segments() {
+            let tail = rest.strip_prefix('/')?;
+            let end = tail.find('/').unwrap_or(tail.len());
+            let (segment, remaining) = tail.split_at(end);
+            match expected.strip_prefix(':') {
+                Some(name) if !segment.is_empty() => {
+                    params.push((Cow::Borrowed(name), segment.to_owned()));
+                }
+                None if expected == segment => {}
+                _ => return None
+            }
+            rest = remaining;
+        }
+        let matched = &path[..path.len() - rest.len()];
+        Some(PartialPathMatch::new(rest, params, matched))
+    }
+
+    fn generate_path(&self, path: &mut Vec<PathSegment>) {
+        let start = path.len();
+        path.extend(
+            self.segments()
+                .map(|segment| match segment.strip_prefix(':') {
+                    Some(name) => PathSegment::Param(name.into()),
+                    None => PathSegment::Static(segment.into())
+                })
+        );
+        if path.len() == start {
+            path.push(PathSegment::Unit);
+        }
+    }
+}
+
+/// Visited paths used to derive the back-navigation depth.
+///
+/// Visiting the previous path counts as going back, mirroring the SDK
+/// router's history handling.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct PathStack(Vec<String>);
+
+impl PathStack {
+    fn visit(&mut self, path: String) {
+        let len = self.0.len();
+        if len >= 2 && self.0[len - 2] == path {
+            self.0.pop();
+        } else if self.0.last() != Some(&path) {
+            self.0.push(path);
+        }
+    }
+
+    fn can_go_back(&self) -> bool {
+        self.0.len() > 1
+    }
+}
+
+/// Keeps `WebApp.BackButton` in sync with the `leptos_router` history.
+///
+/// Must be rendered inside a `<Router>`. The button is shown once the user
+/// navigated away from the first location and steps back through the browser
+/// history when pressed.
+#[component]
+pub fn RouterBackButton() -> impl IntoView {
+    let location = use_location();
+    let stack = RwSignal::new(PathStack::default());
+    Effect::new(move |_| {
+        let path = location.pathname.get();
+        stack.update(|stack| stack.visit(path));
+    });
+    let visible = Signal::derive(move || stack.with(PathStack::can_go_back));
+    let on_click = move || {
+        if let Some(history) = web_sys::window().and_then(|win| win.history().ok()) {
+            let _ = history.back();
+        }
+    };
+    view! { <BackButton visible on_click /> }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos_router::{PathSegment, PossibleRouteMatch};
+
+    use super::{PagePath, PathStack};
+
+    #[test]
+    fn page_path_matches_sdk_patterns() {
+        let item = PagePath::new("/item/:id");
+        let matched = item.test("/item/7").expect("match");
+        assert!(matched.is_complete());
+        assert_eq!(matched.params(), [("id".into(), "7".to_owned())]);
+        assert!(item.test("/item").is_none());
+        assert!(item.test("/items/7").is_none());
+
+        let nested = item.test("/item/7/tab").expect("prefix match");
+        assert_eq!(nested.remaining(), "/tab");
+        assert!(PagePath::new("/").test("/").expect("root").is_complete());
+    }
+
+    #[test]
+    fn page_path_generates_segments() {
+        let mut segments = Vec::new();
+        PagePath::new("/item/:id").generate_path(&mut segments);
+        assert_eq!(
+            segments,
+            [
+                PathSegment::Static("item".into()),
+                PathSegment::Param("id".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn path_stack_tracks_depth() {
+        let mut stack = PathStack::default();
+        stack.visit("/".into());
+        assert!(!stack.can_go_back());
+        stack.visit("/a".into());
+        stack.visit("/a/b".into());
+        assert!(stack.can_go_back());
+        stack.visit("/a".into());
+        stack.visit("/".into());
+        assert!(!stack.can_go_back());
+    }
+}
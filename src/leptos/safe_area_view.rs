@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use leptos::prelude::*;
+
+use super::use_safe_area;
+
+/// Leptos component padding its children by the safe-area insets.
+///
+/// The padding is the sum of `WebApp.safeAreaInset` and
+/// `WebApp.contentSafeAreaInset` and follows `safeAreaChanged` and
+/// `contentSafeAreaChanged`.
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::SafeAreaView;
+///
+/// #[component]
+/// fn Screen() -> impl IntoView {
+///     view! {
+///         <SafeAreaView class="screen">
+///             <h1>"Settings"</h1>
+///         </SafeAreaView>
+///     }
+/// }
+/// ```
+#[component]
+pub fn SafeAreaView(
+    /// Content rendered inside the safe area.
+    children: Children,
+    /// Extra classes for the wrapping `div`.
+    #[prop(optional, into)]
+    class: MaybeProp<String>
+) -> impl IntoView {
+    let safe_area = use_safe_area();
+    view! {
+        <div
+            class=move || class.get()
+            style=move || safe_area.get().total().to_padding_css()
+        >
+            {children()}
+        </div>
+    }
+}
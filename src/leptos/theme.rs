@@ -5,10 +5,15 @@ use leptos::prelude::*;
 use send_wrapper::SendWrapper;
 
 use crate::{
-    api::theme::get_theme_params, core::types::theme_params::TelegramThemeParams,
+    api::theme::get_theme_params, core::types::theme_params::TelegramThemeParams, logger,
     webapp::TelegramWebApp
 };
 
+/// Class set on `<body>` by [`ThemeProvider`] while the dark scheme is active.
+pub const DARK_CLASS: &str = "tg-theme-dark";
+/// Class set on `<body>` by [`ThemeProvider`] while the light scheme is active.
+pub const LIGHT_CLASS: &str = "tg-theme-light";
+
 /// Snapshot of `Telegram.WebApp` theme state.
 #[derive(Clone, Debug, PartialEq, Default)]
 pub struct ThemeState {
@@ -21,7 +26,9 @@ pub struct ThemeState {
 impl ThemeState {
     fn snapshot(app: Option<&TelegramWebApp>) -> Self {
         let color_scheme = app.and_then(|a| a.color_scheme());
-        let params = get_theme_params().unwrap_or_default();
+        let params = app
+            .and_then(|_| get_theme_params().ok())
+            .unwrap_or_default();
         Self {
             color_scheme,
             params
@@ -63,3 +70,78 @@ pub fn use_theme() -> ReadSignal<ThemeState> {
 
     signal.read_only()
 }
+
+/// Leptos component keeping the document in sync with the Telegram theme.
+///
+/// Applies the `--tg-theme-*` CSS variables to `:root`, toggles
+/// [`DARK_CLASS`] or [`LIGHT_CLASS`] on `<body>` and re-applies both on
+/// `themeChanged`. Descendants read the palette with [`use_theme_params`].
+///
+/// # Examples
+/// ```no_run
+/// use leptos::prelude::*;
+/// use telegram_webapp_sdk::leptos::{ThemeProvider, use_theme_params};
+///
+/// #[component]
+/// fn Accent() -> impl IntoView {
+///     let params = use_theme_params();
+///     view! { <span>{ move || params.get().accent_text_color }</span> }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <ThemeProvider>
+///             <Accent />
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeProvider(
+    /// Content rendered inside the provider.
+    children: Children
+) -> impl IntoView {
+    let theme = use_theme();
+    provide_context(Signal::derive(move || theme.get().params));
+
+    Effect::new(move |_| {
+        if TelegramWebApp::instance().is_none() {
+            return;
+        }
+        let ThemeState {
+            color_scheme,
+            params
+        } = theme.get();
+        if let Err(err) = params.apply_to_root() {
+            logger::error(&format!("Failed to apply theme: {err:?}"));
+        }
+        set_body_class(color_scheme.as_deref());
+    });
+
+    children()
+}
+
+/// Returns the palette provided by the nearest [`ThemeProvider`].
+///
+/// Without a provider the palette comes from [`use_theme`].
+pub fn use_theme_params() -> Signal<TelegramThemeParams> {
+    use_context::<Signal<TelegramThemeParams>>().unwrap_or_else(|| {
+        let theme = use_theme();
+        Signal::derive(move || theme.get().params)
+    })
+}
+
+/// Marks `<body>` with the class matching `color_scheme`.
+fn set_body_class(color_scheme: Option<&str>) {
+    let Some(body) = web_sys::window()
+        .and_then(|win| win.document())
+        .and_then(|document| document.body())
+    else {
+        return;
+    };
+    let classes = body.class_list();
+    let dark = color_scheme == Some("dark");
+    let _ = classes.toggle_with_force(DARK_CLASS, dark);
+    let _ = classes.toggle_with_force(LIGHT_CLASS, color_scheme.is_some() && !dark);
+}
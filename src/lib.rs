@@ -5,25 +5,74 @@
 #![cfg_attr(all(docsrs, has_doc_cfg), feature(doc_cfg))]
 #![cfg_attr(all(docsrs, not(has_doc_cfg), has_doc_auto_cfg), feature(doc_auto_cfg))]
 
+/// Opt-in [`analytics::track`] batching events to the backend.
+#[cfg(feature = "analytics")]
+pub mod analytics;
 /// High-level, ergonomic wrappers over the Telegram WebApp JavaScript API.
 pub mod api;
+/// Crate-wide bus of typed [`bus::WebAppEvent`]s.
+pub mod bus;
 /// Core primitives: launch parameters, init data, theme parameters and the
 /// global [`core::context::TelegramContext`].
 pub mod core;
+/// [`deep_link::DeepLink`] builder for `t.me` and `tg://` links.
+pub mod deep_link;
 /// Thin helpers for interacting with the browser DOM from WebAssembly.
 pub mod dom;
+/// Error reports submitted with [`error_report::capture_error`].
+#[cfg(feature = "error-report")]
+pub mod error_report;
+/// Browser shims for previewing the app outside Telegram.
+#[cfg(feature = "browser-fallback")]
+pub mod fallback;
+/// [`form_draft::FormDraft`] autosaving form state to CloudStorage.
+#[cfg(feature = "cloud-storage")]
+pub mod form_draft;
+/// [`game_loop::GameLoop`] that pauses while the app is inactive.
+pub mod game_loop;
+/// [`http::TelegramFetch`] JSON client authenticating requests with the init
+/// data.
+pub mod http;
+/// [`i18n::I18n`] translations selected from the user's `language_code`.
+pub mod i18n;
+/// [`invoice::pay`] flow and invoice types shared with the backend.
+pub mod invoice;
 /// Logging helpers that forward messages to the browser console.
 pub mod logger;
+/// [`messages::WebAppMessage`] schema shared by the mini app and the bot.
+pub mod messages;
 
 #[cfg(feature = "mock")]
 pub mod mock;
+/// [`payments::PaymentFlow`] state machine around invoice payments.
+pub mod payments;
+/// [`share::share_prepared`] flow for sharing prepared inline messages.
+pub mod share;
 /// Utility helpers, including environment detection for the Telegram WebApp.
 pub mod utils;
 /// Safe Rust bindings for `window.Telegram.WebApp` and its sub-objects.
 pub mod webapp;
+/// [`ws::TelegramSocket`] `WebSocket` client authenticating with the init
+/// data.
+pub mod ws;
 #[cfg(feature = "macros")]
 pub use inventory;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use serde_json;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use wasm_bindgen;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use wasm_bindgen_futures;
+// Lets macro expansions refer to `::telegram_webapp_sdk` inside this crate.
+#[cfg(feature = "macros")]
+extern crate self as telegram_webapp_sdk;
 pub use webapp::TelegramWebApp;
+/// Registry of event handlers collected via the `#[telegram_event]` macro.
+#[cfg(feature = "macros")]
+pub mod listeners;
 #[cfg(feature = "macros")]
 mod macros;
 /// Registry of routable pages collected via the `#[page]` macro.
@@ -41,3 +90,19 @@ pub mod yew;
 /// Leptos components and hooks for building Telegram mini apps.
 #[cfg(feature = "leptos")]
 pub mod leptos;
+
+/// Dioxus components and hooks for building Telegram mini apps.
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
+
+/// Sycamore components and hooks for building Telegram mini apps.
+#[cfg(feature = "sycamore")]
+pub mod sycamore;
+
+/// Bot-side teloxide helpers sharing payload types with the mini app.
+#[cfg(all(feature = "teloxide", not(target_arch = "wasm32")))]
+pub mod teloxide;
+
+/// Short-lived session tokens issued by backends after validating init data.
+#[cfg(all(feature = "session", not(target_arch = "wasm32")))]
+pub mod session;
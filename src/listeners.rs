@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::cell::RefCell;
+
+use inventory::collect;
+use serde::de::DeserializeOwned;
+/// Attribute registering a function as a Telegram event handler.
+///
+/// See [`telegram_webapp_sdk_macros::telegram_event`] for details.
+pub use telegram_webapp_sdk_macros::telegram_event;
+use wasm_bindgen::JsValue;
+
+use crate::{
+    logger,
+    webapp::{EventHandle, TelegramWebApp}
+};
+
+/// Represents a single registered event handler.
+#[derive(Copy, Clone)]
+pub struct Listener {
+    /// Name of the `WebApp` event, e.g. `"invoiceClosed"`.
+    pub event:   &'static str,
+    /// Callback invoked with the raw event payload.
+    pub handler: fn(JsValue)
+}
+
+collect!(Listener);
+
+/// Subscriptions created by [`bind`], kept alive for the page lifetime.
+type Bound = Option<Vec<EventHandle<dyn FnMut(JsValue)>>>;
+
+thread_local! {
+    static BOUND: RefCell<Bound> = const { RefCell::new(None) };
+}
+
+/// Returns iterator over registered listeners.
+pub fn iter() -> inventory::iter<Listener> {
+    inventory::iter::<Listener>
+}
+
+/// Subscribes every registered listener to its `WebApp` event.
+///
+/// Called by `telegram_app!` during startup; calling it again is a no-op.
+/// The subscriptions stay active for the lifetime of the page.
+///
+/// # Errors
+/// Returns [`JsValue`] if `Telegram.WebApp` is unavailable or a subscription
+/// fails.
+pub fn bind() -> Result<(), JsValue> {
+    if BOUND.with(|bound| bound.borrow().is_some()) {
+        return Ok(());
+    }
+    let app = TelegramWebApp::try_instance()?;
+    let handles = iter()
+        .into_iter()
+        .map(|listener| app.on_event(listener.event, listener.handler))
+        .collect::<Result<Vec<_>, _>>()?;
+    BOUND.with(|bound| *bound.borrow_mut() = Some(handles));
+    Ok(())
+}
+
+/// Deserializes an event payload, logging and returning `None` on failure.
+///
+/// Used by the code generated for `#[telegram_event]`.
+#[doc(hidden)]
+pub fn decode<T: DeserializeOwned>(event: &str, payload: JsValue) -> Option<T> {
+    match serde_wasm_bindgen::from_value(payload) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            logger::error(&format!("Invalid `{event}` payload: {err}"));
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use wasm_bindgen::JsValue;
+
+    use crate::listeners::telegram_event;
+
+    thread_local! {
+        static THEME_CHANGES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[telegram_event("themeChanged")]
+    fn on_theme() {
+        THEME_CHANGES.with(|count| count.set(count.get() + 1));
+    }
+
+    #[test]
+    fn attribute_registers_listener() {
+        let listener = super::iter()
+            .into_iter()
+            .find(|listener| listener.event == "themeChanged")
+            .expect("registered listener");
+        (listener.handler)(JsValue::UNDEFINED);
+        assert_eq!(THEME_CHANGES.with(Cell::get), 1);
+    }
+}
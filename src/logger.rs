@@ -1,46 +1,389 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-#[cfg(debug_assertions)]
+use std::{
+    str::FromStr,
+    sync::{
+        RwLock,
+        atomic::{AtomicU8, Ordering}
+    }
+};
+
+#[cfg(target_arch = "wasm32")]
 use web_sys::console;
 
-/// Internal helper for styled log output.
-#[cfg_attr(not(debug_assertions), allow(unused_variables))]
-fn styled_log(level: &str, emoji: &str, color: &str, msg: &str) {
-    #[cfg(debug_assertions)]
+#[cfg(feature = "log")]
+mod facade;
+mod panic;
+#[cfg(feature = "tracing")]
+mod subscriber;
+
+#[cfg(feature = "log")]
+pub use facade::init_log;
+pub use panic::{DEFAULT_PANIC_ALERT, PanicHook, install_panic_hook};
+#[cfg(feature = "tracing")]
+pub use subscriber::{ConsoleSubscriber, init_tracing};
+
+/// Severity of a log message, ordered from most to least verbose.
+///
+/// Used both for messages and as the filter passed to [`set_level`], where
+/// [`Level::Off`] silences all output.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger::Level;
+///
+/// assert!(Level::Debug < Level::Warn);
+/// assert_eq!("warn".parse::<Level>(), Ok(Level::Warn));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Very detailed diagnostics.
+    Trace,
+    /// Diagnostics useful while developing.
+    Debug,
+    /// Notable events.
+    Info,
+    /// Recoverable problems.
+    Warn,
+    /// Failures.
+    Error,
+    /// Filter value disabling all output.
+    Off
+}
+
+impl Level {
+    /// Returns the lowercase name of the level.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Off => "off"
+        }
+    }
+
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Trace,
+            1 => Self::Debug,
+            2 => Self::Info,
+            3 => Self::Warn,
+            4 => Self::Error,
+            _ => Self::Off
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    /// Parses a level name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Self::Trace,
+            Self::Debug,
+            Self::Info,
+            Self::Warn,
+            Self::Error,
+            Self::Off
+        ]
+        .into_iter()
+        .find(|level| level.as_str().eq_ignore_ascii_case(s))
+        .ok_or_else(|| format!("unknown log level: {s}"))
+    }
+}
+
+/// Everything is logged in debug builds; release builds stay silent unless
+/// [`set_level`] is called.
+const DEFAULT_LEVEL: Level = if cfg!(debug_assertions) {
+    Level::Trace
+} else {
+    Level::Off
+};
+
+static LEVEL: AtomicU8 = AtomicU8::new(DEFAULT_LEVEL as u8);
+
+/// Sets the minimum level that is written; messages below it are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger::{self, Level};
+///
+/// logger::set_level(Level::Warn);
+/// assert!(!logger::enabled(Level::Debug));
+/// assert!(logger::enabled(Level::Error));
+/// ```
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    #[cfg(feature = "log")]
+    ::log::set_max_level(facade::level_filter(level));
+}
+
+/// Returns the current minimum level.
+pub fn level() -> Level {
+    Level::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+/// Returns whether a message at `level` would be written.
+///
+/// Check it before formatting expensive messages.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level >= self::level()
+}
+
+/// Custom destination receiving every written message.
+pub type Sink = fn(Level, &str);
+
+static SINK: RwLock<Option<Sink>> = RwLock::new(None);
+
+/// Registers `sink` to receive every message that passes the filter.
+///
+/// Messages are still written to the console; the sink gets them in addition,
+/// prefixed with their target and followed by any `key=value` fields. Use it
+/// to mirror logs to a backend or an in-app debug console. Registering a new
+/// sink replaces the previous one.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger::{self, Level};
+///
+/// fn mirror(level: Level, line: &str) {
+///     // e.g. push `line` into a buffer shown by an in-app debug panel
+///     let _ = (level, line);
+/// }
+///
+/// logger::set_sink(mirror);
+/// logger::info("mirrored");
+/// logger::clear_sink();
+/// ```
+pub fn set_sink(sink: Sink) {
+    *SINK.write().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+}
+
+/// Removes the sink registered with [`set_sink`].
+pub fn clear_sink() {
+    *SINK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Logs `msg` at `level`.
+pub fn log(level: Level, msg: &str) {
+    write(level, style(level), None, msg, &[]);
+}
+
+/// Logs `msg` at `level`, prefixed with `target`, e.g. a module name.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger::{self, Level};
+///
+/// logger::log_target(Level::Info, "checkout", "order submitted");
+/// ```
+pub fn log_target(level: Level, target: &str, msg: &str) {
+    write(level, style(level), Some(target), msg, &[]);
+}
+
+/// Logs `name` at [`Level::Info`] with structured `fields`.
+///
+/// In the browser the fields are passed as an object, so the inspector shows
+/// them expandable next to the message.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger;
+///
+/// logger::event("checkout", &[("step", "2"), ("total", "599")]);
+/// ```
+pub fn event(name: &str, fields: &[(&str, &str)]) {
+    write(Level::Info, style(Level::Info), None, name, fields);
+}
+
+/// Runs `f` inside a console group labelled `name` and returns its result.
+///
+/// Messages logged by `f` are nested under the group in the inspector. The
+/// group is only opened when [`Level::Info`] passes the filter.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger;
+///
+/// let total = logger::group("checkout", || {
+///     logger::event("cart", &[("items", "3")]);
+///     599
+/// });
+/// assert_eq!(total, 599);
+/// ```
+pub fn group<R>(name: &str, f: impl FnOnce() -> R) -> R {
+    if !enabled(Level::Info) {
+        return f();
+    }
+    group_start(name);
+    let result = f();
+    group_end();
+    result
+}
+
+/// Writes `msg` if `level` passes the filter.
+///
+/// `fields` are appended as `key=value` pairs and, in the browser, passed as
+/// an object so the inspector shows them as structured data.
+fn write(
+    level: Level,
+    (label, emoji, color): Style,
+    target: Option<&str>,
+    msg: &str,
+    fields: &[(&str, &str)]
+) {
+    if !enabled(level) {
+        return;
+    }
+    if let Some(sink) = *SINK.read().unwrap_or_else(|e| e.into_inner()) {
+        let target = target.map(|t| format!("{t}: ")).unwrap_or_default();
+        let fields: String = fields.iter().map(|(k, v)| format!(" {k}={v}")).collect();
+        sink(level, &format!("{target}{msg}{fields}"));
+    }
+    let target = target.map(|t| format!(" {t}:")).unwrap_or_default();
+    #[cfg(target_arch = "wasm32")]
+    {
+        let prefix = format!("%c[SDK] {emoji} {}{target}", label.to_uppercase());
+        let style = format!("color: {color}; font-weight: bold");
+        if fields.is_empty() {
+            console::log_3(&prefix.into(), &style.into(), &msg.into());
+        } else {
+            let data = js_sys::Object::new();
+            for (key, value) in fields {
+                let _ = js_sys::Reflect::set(&data, &(*key).into(), &(*value).into());
+            }
+            console::log_4(&prefix.into(), &style.into(), &msg.into(), &data);
+        }
+    }
+    // There is no console off `wasm32`, e.g. during server-side rendering.
+    #[cfg(not(target_arch = "wasm32"))]
     {
-        let prefix = format!("%c[SDK] {} {}", emoji, level.to_uppercase());
-        let style = format!("color: {}; font-weight: bold", color);
-        console::log_3(&prefix.into(), &style.into(), &msg.into());
+        let _ = color;
+        let fields: String = fields.iter().map(|(k, v)| format!(" {k}={v}")).collect();
+        eprintln!(
+            "[SDK] {emoji} {}{target} {msg}{fields}",
+            label.to_uppercase()
+        );
     }
 }
 
-/// Logs a success message (✅ Green).
+/// Opens a console group labelled `label`.
+fn group_start(label: &str) {
+    #[cfg(target_arch = "wasm32")]
+    console::group_1(&label.into());
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = label;
+}
+
+/// Closes the innermost console group.
+fn group_end() {
+    #[cfg(target_arch = "wasm32")]
+    console::group_end();
+}
+
+/// Label, emoji and CSS color of a console line.
+type Style = (&'static str, &'static str, &'static str);
+
+const SUCCESS: Style = ("success", "✅", "lightgreen");
+
+const fn style(level: Level) -> Style {
+    let (emoji, color) = match level {
+        Level::Trace => ("📍", "#aaa"),
+        Level::Debug => ("🔧", "#888"),
+        Level::Info => ("ℹ️", "#3399ff"),
+        Level::Warn => ("⚠️", "orange"),
+        Level::Error | Level::Off => ("❌", "red")
+    };
+    (level.as_str(), emoji, color)
+}
+
+/// Logs a success message (✅ Green) at [`Level::Info`].
 pub fn success(msg: &str) {
-    styled_log("success", "✅", "lightgreen", msg);
+    write(Level::Info, SUCCESS, None, msg, &[]);
 }
 
 /// Logs an error message (❌ Red).
 pub fn error(msg: &str) {
-    styled_log("error", "❌", "red", msg);
+    log(Level::Error, msg);
 }
 
 /// Logs a warning message (⚠️ Orange).
 pub fn warn(msg: &str) {
-    styled_log("warn", "⚠️", "orange", msg);
+    log(Level::Warn, msg);
 }
 
 /// Logs an info message (ℹ️ Blue).
 pub fn info(msg: &str) {
-    styled_log("info", "ℹ️", "#3399ff", msg);
+    log(Level::Info, msg);
 }
 
 /// Logs a debug message (🔧 Gray).
 pub fn debug(msg: &str) {
-    styled_log("debug", "🔧", "#888", msg);
+    log(Level::Debug, msg);
 }
 
 /// Logs a trace message (📍 Light Gray).
 pub fn trace(msg: &str) {
-    styled_log("trace", "📍", "#aaa", msg);
+    log(Level::Trace, msg);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_parse_and_order() {
+        assert_eq!("ERROR".parse(), Ok(Level::Error));
+        assert!("verbose".parse::<Level>().is_err());
+        assert!(Level::Trace < Level::Error && Level::Error < Level::Off);
+    }
+
+    #[test]
+    fn filter_follows_set_level() {
+        let previous = level();
+        set_level(Level::Info);
+        assert!(!enabled(Level::Debug));
+        assert!(enabled(Level::Warn));
+        set_level(Level::Off);
+        assert!(!enabled(Level::Error));
+        set_level(previous);
+    }
+
+    #[test]
+    fn sink_receives_formatted_lines() {
+        use std::sync::Mutex;
+
+        static LINES: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+        fn sink(level: Level, line: &str) {
+            LINES.lock().unwrap().push((level, line.to_owned()));
+        }
+
+        let previous = level();
+        set_level(Level::Trace);
+        set_sink(sink);
+        write(
+            Level::Warn,
+            style(Level::Warn),
+            Some("cart"),
+            "slow",
+            &[("ms", "900")]
+        );
+        clear_sink();
+        write(Level::Warn, style(Level::Warn), None, "dropped", &[]);
+        set_level(previous);
+
+        let lines = LINES.lock().unwrap();
+        assert!(lines.contains(&(Level::Warn, "cart: slow ms=900".to_owned())));
+        assert!(!lines.iter().any(|(_, line)| line == "dropped"));
+    }
 }
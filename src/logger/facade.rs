@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! [`log`] facade backed by the console logger.
+
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use super::Level;
+
+/// Forwards `log` records to [`super::log_target`].
+struct ConsoleLogger;
+
+static LOGGER: ConsoleLogger = ConsoleLogger;
+
+impl Log for ConsoleLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        super::enabled(level(metadata.level()))
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let level = level(record.level());
+        if super::enabled(level) {
+            super::log_target(level, record.target(), &record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the console logger as the global [`log`] logger.
+///
+/// `log::info!` and friends, including calls made by dependencies, are then
+/// written with the same styling as [`super::info`] and filtered by
+/// [`super::set_level`].
+///
+/// # Errors
+///
+/// Returns [`SetLoggerError`] if another logger has already been installed.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger;
+///
+/// logger::init_log().expect("no other logger installed");
+/// log::info!(target: "checkout", "order submitted");
+/// ```
+pub fn init_log() -> Result<(), SetLoggerError> {
+    log::set_logger(&LOGGER)?;
+    log::set_max_level(level_filter(super::level()));
+    Ok(())
+}
+
+const fn level(level: log::Level) -> Level {
+    match level {
+        log::Level::Error => Level::Error,
+        log::Level::Warn => Level::Warn,
+        log::Level::Info => Level::Info,
+        log::Level::Debug => Level::Debug,
+        log::Level::Trace => Level::Trace
+    }
+}
+
+/// Maps a filter set with [`super::set_level`] to the `log` max level.
+pub(super) const fn level_filter(level: Level) -> LevelFilter {
+    match level {
+        Level::Trace => LevelFilter::Trace,
+        Level::Debug => LevelFilter::Debug,
+        Level::Info => LevelFilter::Info,
+        Level::Warn => LevelFilter::Warn,
+        Level::Error => LevelFilter::Error,
+        Level::Off => LevelFilter::Off
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_map_both_ways() {
+        assert_eq!(level(log::Level::Warn), Level::Warn);
+        assert_eq!(level_filter(Level::Off), LevelFilter::Off);
+        assert_eq!(level_filter(level(log::Level::Trace)), LevelFilter::Trace);
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Panic hook reporting panics instead of freezing silently.
+
+use std::{any::Any, panic};
+
+/// Alert shown by [`install_panic_hook`].
+pub const DEFAULT_PANIC_ALERT: &str = "Something went wrong";
+
+type PanicCallback = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Configurable panic hook.
+///
+/// Every panic is logged through [`super::error`]. Optionally a native
+/// `WebApp.showAlert` is shown and a callback receives the panic message,
+/// e.g. to report it to a backend. The previously installed hook still runs
+/// afterwards.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::logger::PanicHook;
+///
+/// PanicHook::new()
+///     .alert("The app crashed, please reopen it")
+///     .on_panic(|message| {
+///         // send `message` to the backend
+///         let _ = message;
+///     })
+///     .install();
+/// ```
+#[derive(Default)]
+pub struct PanicHook {
+    alert:    Option<String>,
+    on_panic: Option<PanicCallback>
+}
+
+impl PanicHook {
+    /// Creates a hook that only logs panics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shows `message` in a native alert when a panic occurs.
+    pub fn alert(mut self, message: impl Into<String>) -> Self {
+        self.alert = Some(message.into());
+        self
+    }
+
+    /// Invokes `callback` with the panic message.
+    pub fn on_panic(mut self, callback: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.on_panic = Some(Box::new(callback));
+        self
+    }
+
+    /// Installs the hook, chaining to the previously installed one.
+    pub fn install(self) {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let message = payload_message(info.payload());
+            match info.location() {
+                Some(location) => super::error(&format!("Panic at {location}: {message}")),
+                None => super::error(&format!("Panic: {message}"))
+            }
+            if let Some(alert) = &self.alert {
+                let _ = show_alert(alert);
+            }
+            if let Some(callback) = &self.on_panic {
+                callback(message);
+            }
+            previous(info);
+        }));
+    }
+}
+
+/// Installs a [`PanicHook`] that logs panics and shows
+/// [`DEFAULT_PANIC_ALERT`].
+///
+/// # Examples
+///
+/// ```no_run
+/// telegram_webapp_sdk::logger::install_panic_hook();
+/// ```
+pub fn install_panic_hook() {
+    PanicHook::new().alert(DEFAULT_PANIC_ALERT).install();
+}
+
+/// Extracts the message passed to `panic!`.
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>")
+}
+
+/// Calls `WebApp.showAlert` directly, since the SDK state may be unusable
+/// after a panic.
+#[cfg(target_arch = "wasm32")]
+fn show_alert(message: &str) -> Result<(), wasm_bindgen::JsValue> {
+    use js_sys::{Function, Reflect};
+    use wasm_bindgen::{JsCast, JsValue};
+
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("no window"))?;
+    let telegram = Reflect::get(&window, &"Telegram".into())?;
+    let webapp = Reflect::get(&telegram, &"WebApp".into())?;
+    let show_alert = Reflect::get(&webapp, &"showAlert".into())?.dyn_into::<Function>()?;
+    show_alert.call1(&webapp, &message.into())?;
+    Ok(())
+}
+
+/// There is no WebApp to alert through off `wasm32`.
+#[cfg(not(target_arch = "wasm32"))]
+fn show_alert(_message: &str) -> Result<(), ()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_message_handles_str_and_string() {
+        let literal: Box<dyn Any + Send> = Box::new("boom");
+        let formatted: Box<dyn Any + Send> = Box::new(format!("bad {}", 42));
+        let other: Box<dyn Any + Send> = Box::new(7_u8);
+        assert_eq!(payload_message(literal.as_ref()), "boom");
+        assert_eq!(payload_message(formatted.as_ref()), "bad 42");
+        assert_eq!(payload_message(other.as_ref()), "Box<dyn Any>");
+    }
+}
@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! [`tracing`] subscriber backed by the console logger.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Write as _},
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering}
+    }
+};
+
+use tracing::{
+    Event, Metadata, Subscriber,
+    dispatcher::SetGlobalDefaultError,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    subscriber::Interest
+};
+
+use super::Level;
+
+/// Wasm-friendly [`Subscriber`] writing through the console logger.
+///
+/// Events are written like [`super::log_target`] with their fields attached
+/// as an object, and entered spans open console groups, so nested work is
+/// collapsible in the WebView inspector. Filtering follows
+/// [`super::set_level`], also for levels changed at runtime.
+#[derive(Default)]
+pub struct ConsoleSubscriber {
+    next_id: AtomicU64,
+    spans:   Mutex<HashMap<u64, SpanData>>,
+    /// Whether each entered span opened a group, innermost last.
+    entered: Mutex<Vec<bool>>
+}
+
+struct SpanData {
+    level: Level,
+    label: String,
+    refs:  usize
+}
+
+/// Installs a [`ConsoleSubscriber`] as the global default.
+///
+/// # Errors
+///
+/// Returns [`SetGlobalDefaultError`] if a global subscriber is already set.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::logger;
+///
+/// logger::init_tracing().expect("no other subscriber installed");
+/// let span = tracing::info_span!("checkout", step = 2);
+/// let _guard = span.enter();
+/// tracing::info!(total = 599, "order submitted");
+/// ```
+pub fn init_tracing() -> Result<(), SetGlobalDefaultError> {
+    tracing::subscriber::set_global_default(ConsoleSubscriber::default())
+}
+
+impl Subscriber for ConsoleSubscriber {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        // The filter can change at runtime, so never cache a decision.
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        super::enabled(level(metadata.level()))
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        let mut label = span.metadata().name().to_owned();
+        fields.append_to(&mut label);
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        lock(&self.spans).insert(
+            id,
+            SpanData {
+                level: level(span.metadata().level()),
+                label,
+                refs: 1
+            }
+        );
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut fields = Fields::default();
+        values.record(&mut fields);
+        if let Some(data) = lock(&self.spans).get_mut(&span.into_u64()) {
+            fields.append_to(&mut data.label);
+        }
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let metadata = event.metadata();
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let pairs: Vec<(&str, &str)> = fields
+            .values
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect();
+        let level = level(metadata.level());
+        super::write(
+            level,
+            super::style(level),
+            Some(metadata.target()),
+            &fields.message,
+            &pairs
+        );
+    }
+
+    fn enter(&self, span: &Id) {
+        let label = lock(&self.spans)
+            .get(&span.into_u64())
+            .filter(|data| super::enabled(data.level))
+            .map(|data| data.label.clone());
+        if let Some(label) = &label {
+            super::group_start(label);
+        }
+        lock(&self.entered).push(label.is_some());
+    }
+
+    fn exit(&self, _: &Id) {
+        if lock(&self.entered).pop() == Some(true) {
+            super::group_end();
+        }
+    }
+
+    fn clone_span(&self, span: &Id) -> Id {
+        if let Some(data) = lock(&self.spans).get_mut(&span.into_u64()) {
+            data.refs += 1;
+        }
+        span.clone()
+    }
+
+    fn try_close(&self, span: Id) -> bool {
+        let mut spans = lock(&self.spans);
+        let Some(data) = spans.get_mut(&span.into_u64()) else {
+            return false;
+        };
+        data.refs -= 1;
+        if data.refs > 0 {
+            return false;
+        }
+        spans.remove(&span.into_u64());
+        true
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn level(level: &tracing::Level) -> Level {
+    match *level {
+        tracing::Level::ERROR => Level::Error,
+        tracing::Level::WARN => Level::Warn,
+        tracing::Level::INFO => Level::Info,
+        tracing::Level::DEBUG => Level::Debug,
+        _ => Level::Trace
+    }
+}
+
+/// Message and fields recorded from a span or event.
+#[derive(Default)]
+struct Fields {
+    message: String,
+    values:  Vec<(&'static str, String)>
+}
+
+impl Fields {
+    /// Appends the fields as `{key=value ...}`.
+    fn append_to(&self, label: &mut String) {
+        if self.values.is_empty() {
+            return;
+        }
+        label.push_str(" {");
+        for (i, (key, value)) in self.values.iter().enumerate() {
+            let sep = if i == 0 { "" } else { " " };
+            let _ = write!(label, "{sep}{key}={value}");
+        }
+        label.push('}');
+    }
+}
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            value.clone_into(&mut self.message);
+        } else {
+            self.values.push((field.name(), value.to_owned()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.values.push((field.name(), format!("{value:?}")));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_are_reference_counted() {
+        let subscriber = ConsoleSubscriber::default();
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("checkout", step = 2);
+            let copy = span.clone();
+            drop(span);
+            copy.in_scope(|| tracing::info!(total = 599, "order submitted"));
+        });
+    }
+
+    #[test]
+    fn fields_render_as_pairs() {
+        let fields = Fields {
+            message: String::new(),
+            values:  vec![("step", "2".into()), ("total", "599".into())]
+        };
+        let mut label = String::from("checkout");
+        fields.append_to(&mut label);
+        assert_eq!(label, "checkout {step=2 total=599}");
+    }
+}
@@ -101,7 +101,9 @@
 ///
 /// ### Handler signature
 ///
-/// The handler must be a plain function `fn()` with no arguments. If you need
+/// The handler must be a plain function `fn()` or `async fn()` with no
+/// arguments. Async pages are spawned through
+/// [`crate::router::spawn_page`] when their route is rendered. If you need
 /// state or context, encapsulate it externally (e.g. closures, singletons, DI),
 /// not as handler parameters.
 ///
@@ -117,9 +119,38 @@
 ///         // render about page
 ///     }
 /// );
+///
+/// telegram_page!(
+///     "/orders",
+///     /// Orders page loading its data first.
+///     pub async fn orders() {
+///         // fetch and render orders
+///     }
+/// );
 /// ```
 #[macro_export]
 macro_rules! telegram_page {
+    ($path:literal, $(#[$meta:meta])* $vis:vis async fn $name:ident $($rest:tt)*) => {
+        $(#[$meta])*
+        $vis async fn $name $($rest)*
+
+        #[doc(hidden)]
+        mod __telegram_page_register {
+            // Keep handler reachable while hiding helper names.
+            use super::$name as __handler;
+
+            fn __spawn() {
+                $crate::router::spawn_page(::std::boxed::Box::pin(__handler()));
+            }
+
+            #[allow(non_upper_case_globals)]
+            const _: () = {
+                $crate::inventory::submit! {
+                    $crate::pages::Page { path: $path, handler: __spawn }
+                }
+            };
+        }
+    };
     ($path:literal, $(#[$meta:meta])* $vis:vis fn $name:ident $($rest:tt)*) => {
         $(#[$meta])*
         $vis fn $name $($rest)*
@@ -144,15 +175,41 @@ macro_rules! telegram_page {
 /// It performs:
 ///
 /// * Environment detection via `utils::check_env::is_telegram_env()`
-/// * Debug-only mock initialization when not in Telegram
-/// * SDK initialization via `core::init::init_sdk()?`
+/// * Debug-only mock initialization when not in Telegram (requires the `mock`
+///   feature)
+/// * SDK initialization via `core::init::init_app()?`
 ///
 /// After these steps, the provided function body is executed.
 ///
+/// ### Options
+///
+/// Leading `key = value` pairs configure [`crate::core::init::AppOptions`]:
+///
+/// * `config` - mock configuration file, `"telegram-webapp.toml"` by default
+/// * `mock` - load the mock outside Telegram, defaults to debug builds only
+/// * `ready` - call `WebApp.ready()` after initialization
+/// * `expand` - call `WebApp.expand()` after initialization
+/// * `theme_class` - keep a `tg-dark`/`tg-light` class on `<body>`
+/// * `inject_base_styles` - add the native-looking base stylesheet
+///
+/// ```ignore
+/// telegram_app!(config = "custom.toml", ready = true, expand = true, fn main() -> Result<(), JsValue> {
+///     Ok(())
+/// });
+/// ```
+///
 /// ### Return type
 ///
 /// The function may return either `()` or `Result<(), wasm_bindgen::JsValue>`.
 ///
+/// ### Async entry points
+///
+/// An `async fn` is accepted as well. The generated start function still
+/// returns `Result<(), JsValue>` and reports initialization errors
+/// synchronously; the body is then spawned with
+/// `wasm_bindgen_futures::spawn_local`. An `Err` returned by an async body is
+/// logged to the console because nothing can await it.
+///
 /// ### Example
 ///
 /// ```ignore
@@ -166,23 +223,69 @@ macro_rules! telegram_page {
 ///         Ok(())
 ///     }
 /// );
+///
+/// telegram_app!(
+///     /// Async entry point.
+///     pub async fn main() -> Result<(), JsValue> {
+///         // load remote configuration, then start the router
+///         telegram_webapp_sdk::telegram_router!();
+///         Ok(())
+///     }
+/// );
 /// ```
 #[macro_export]
 macro_rules! telegram_app {
-    ($(#[$meta:meta])* $vis:vis fn $name:ident($($arg:tt)*) $(-> $ret:ty)? $body:block) => {
+    (@start { $($key:ident: $value:expr),* }
+        $(#[$meta:meta])* $vis:vis async fn $name:ident() -> $ret:ty $body:block) => {
+        $crate::telegram_app!(
+            @spawn { $($key: $value),* } $(#[$meta])* $vis fn $name() {
+                let result: $ret = async move $body.await;
+                if let Err(err) = result {
+                    $crate::logger::error(&format!("telegram_app: {err:?}"));
+                }
+            }
+        );
+    };
+    (@start { $($key:ident: $value:expr),* }
+        $(#[$meta:meta])* $vis:vis async fn $name:ident() $body:block) => {
+        $crate::telegram_app!(
+            @spawn { $($key: $value),* } $(#[$meta])* $vis fn $name() { async move $body.await; }
+        );
+    };
+    (@spawn { $($key:ident: $value:expr),* } $(#[$meta:meta])* $vis:vis fn $name:ident() $body:block) => {
+        $crate::telegram_app!(
+            @start { $($key: $value),* }
+            $(#[$meta])*
+            $vis fn $name() -> Result<(), wasm_bindgen::JsValue> {
+                $crate::wasm_bindgen_futures::spawn_local(async move $body);
+                Ok(())
+            }
+        );
+    };
+    (@start { $($key:ident: $value:expr),* }
+        $(#[$meta:meta])* $vis:vis fn $name:ident($($arg:tt)*) $(-> $ret:ty)? $body:block) => {
         $(#[$meta])*
         #[wasm_bindgen::prelude::wasm_bindgen(start)]
         $vis fn $name($($arg)*) $(-> $ret)? {
-            if !$crate::utils::check_env::is_telegram_env() {
-                #[cfg(debug_assertions)]
-                if let Ok(cfg) = $crate::mock::config::MockTelegramConfig::from_file("telegram-webapp.toml") {
-                    let _ = $crate::mock::init::mock_telegram_webapp(cfg);
+            $crate::core::init::init_app(&$crate::core::init::AppOptions {
+                $($key: $value,)*
+                ..$crate::core::init::AppOptions {
+                    mock: cfg!(debug_assertions),
+                    ..::core::default::Default::default()
                 }
-            }
-            $crate::core::init::init_sdk()?;
+            })?;
             $body
         }
     };
+    (@options { $($key:ident: $value:expr),* } $next:ident = $next_value:expr, $($item:tt)+) => {
+        $crate::telegram_app!(@options { $($key: $value,)* $next: $next_value } $($item)+);
+    };
+    (@options { $($key:ident: $value:expr),* } $($item:tt)+) => {
+        $crate::telegram_app!(@start { $($key: $value),* } $($item)+);
+    };
+    ($($item:tt)+) => {
+        $crate::telegram_app!(@options {} $($item)+);
+    };
 }
 
 /// Build and start a router from all registered pages.
@@ -194,6 +297,19 @@ macro_rules! telegram_app {
 /// * `fn register(self, path: &str, handler: fn()) -> Self`
 /// * `fn start(self)`
 ///
+/// ### Options
+///
+/// Optional `key = value` pairs after the router type (or on their own)
+/// configure the router before it starts:
+///
+/// * `not_found = handler` - page rendered for unknown paths, see
+///   [`crate::router::Router::not_found`]
+/// * `middleware = [first, second]` - callbacks run on every route change, see
+///   [`crate::router::Router::middleware`]
+///
+/// A custom router type only needs the matching `not_found`/`middleware`
+/// builder methods when the options are used.
+///
 /// ### Examples
 ///
 /// Using the default router:
@@ -219,16 +335,40 @@ macro_rules! telegram_app {
 ///
 /// telegram_router!(CustomRouter);
 /// ```
+///
+/// Passing options:
+///
+/// ```ignore
+/// use telegram_webapp_sdk::{router::Transition, telegram_router};
+///
+/// fn missing() {}
+/// fn track(_transition: &Transition<'_>) {}
+///
+/// telegram_router!(not_found = missing, middleware = [track]);
+/// ```
 #[macro_export]
 macro_rules! telegram_router {
+    (@apply $router:ident $(,)?) => {};
+    (@apply $router:ident, not_found = $handler:expr $(, $($rest:tt)*)?) => {
+        $router = $router.not_found($handler);
+        $crate::telegram_router!(@apply $router $(, $($rest)*)?);
+    };
+    (@apply $router:ident, middleware = [$($middleware:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $($router = $router.middleware($middleware);)*
+        $crate::telegram_router!(@apply $router $(, $($rest)*)?);
+    };
     () => {
         $crate::telegram_router!($crate::router::Router);
     };
-    ($router:ty) => {{
+    ($key:ident = $($options:tt)*) => {
+        $crate::telegram_router!($crate::router::Router, $key = $($options)*);
+    };
+    ($router:ty $(, $($options:tt)*)?) => {{
         let mut router = <$router>::new();
         for page in $crate::pages::iter() {
             router = router.register(page.path, page.handler);
         }
+        $crate::telegram_router!(@apply router $(, $($options)*)?);
         router.start();
     }};
 }
@@ -313,3 +453,209 @@ macro_rules! telegram_image {
         }()
     }};
 }
+
+/// Create an `<input>` element styled with Telegram theme variables.
+///
+/// Generates a [`web_sys::HtmlInputElement`] with the provided placeholder,
+/// optional CSS class and additional attributes. Colors come from the
+/// `--tg-theme-*` CSS variables, so the field follows the user's theme. Like
+/// [`telegram_button!`], this macro yields a `Result`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use telegram_webapp_sdk::telegram_input;
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let document = web_sys::window()
+///     .and_then(|w| w.document())
+///     .ok_or_else(|| JsValue::from_str("no document"))?;
+/// let input = telegram_input!(document, "Your name", class = "name", "type" = "text")?;
+/// assert_eq!(input.tag_name(), "INPUT");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! telegram_input {
+    ($doc:expr, $placeholder:expr $(, class = $class:expr)? $(, $attr:literal = $value:expr)* $(,)?) => {{
+        || -> Result<web_sys::HtmlInputElement, wasm_bindgen::JsValue> {
+            use wasm_bindgen::JsCast;
+            let element = $doc.create_element("input")?;
+            element.set_attribute(
+                "style",
+                "box-sizing: border-box; width: 100%; padding: 10px 12px; \
+                 border: 1px solid var(--tg-theme-hint-color); border-radius: 10px; \
+                 background: var(--tg-theme-secondary-bg-color); \
+                 color: var(--tg-theme-text-color); font: inherit;"
+            )?;
+            element.set_attribute("placeholder", $placeholder)?;
+            $(element.set_class_name($class);)?
+            $(
+                element.set_attribute($attr, $value)?;
+            )*
+            element
+                .dyn_into::<web_sys::HtmlInputElement>()
+                .map_err(wasm_bindgen::JsValue::from)
+        }()
+    }};
+}
+
+/// Create a card container styled with Telegram theme variables.
+///
+/// Generates a `<div>` with the section background and text colors of the
+/// current theme and appends the given child elements in order. Accepts an
+/// optional CSS class and additional attributes, and yields a
+/// `Result<web_sys::HtmlElement, wasm_bindgen::JsValue>`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use telegram_webapp_sdk::{telegram_button, telegram_card};
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let document = web_sys::window()
+///     .and_then(|w| w.document())
+///     .ok_or_else(|| JsValue::from_str("no document"))?;
+/// let buy = telegram_button!(document, "Buy")?;
+/// let card = telegram_card!(document, [buy], class = "product")?;
+/// assert_eq!(card.child_element_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! telegram_card {
+    ($doc:expr, [$($child:expr),* $(,)?] $(, class = $class:expr)? $(, $attr:literal = $value:expr)* $(,)?) => {{
+        || -> Result<web_sys::HtmlElement, wasm_bindgen::JsValue> {
+            use wasm_bindgen::JsCast;
+            let element = $doc.create_element("div")?;
+            element.set_attribute(
+                "style",
+                "padding: 12px 16px; border-radius: 12px; \
+                 background: var(--tg-theme-section-bg-color, var(--tg-theme-secondary-bg-color)); \
+                 color: var(--tg-theme-text-color);"
+            )?;
+            $(element.set_class_name($class);)?
+            $(
+                element.set_attribute($attr, $value)?;
+            )*
+            $(
+                element.append_child(&$child)?;
+            )*
+            element
+                .dyn_into::<web_sys::HtmlElement>()
+                .map_err(wasm_bindgen::JsValue::from)
+        }()
+    }};
+}
+
+/// Create a `<ul>` list styled with Telegram theme variables.
+///
+/// Generates a list with one `<li>` per item of `$items`, which may be any
+/// iterator of string-like values. Rows are separated with the theme's
+/// separator color. Accepts an optional CSS class and additional attributes,
+/// and yields a `Result<web_sys::HtmlElement, wasm_bindgen::JsValue>`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use telegram_webapp_sdk::telegram_list;
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let document = web_sys::window()
+///     .and_then(|w| w.document())
+///     .ok_or_else(|| JsValue::from_str("no document"))?;
+/// let list = telegram_list!(document, ["Burger", "Fries"], class = "menu")?;
+/// assert_eq!(list.child_element_count(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! telegram_list {
+    ($doc:expr, $items:expr $(, class = $class:expr)? $(, $attr:literal = $value:expr)* $(,)?) => {{
+        || -> Result<web_sys::HtmlElement, wasm_bindgen::JsValue> {
+            use wasm_bindgen::JsCast;
+            let element = $doc.create_element("ul")?;
+            element.set_attribute(
+                "style",
+                "list-style: none; margin: 0; padding: 0; \
+                 background: var(--tg-theme-section-bg-color, var(--tg-theme-secondary-bg-color)); \
+                 color: var(--tg-theme-text-color);"
+            )?;
+            $(element.set_class_name($class);)?
+            $(
+                element.set_attribute($attr, $value)?;
+            )*
+            for item in $items {
+                let row = $doc.create_element("li")?;
+                row.set_attribute(
+                    "style",
+                    "padding: 12px 16px; \
+                     border-bottom: 1px solid var(--tg-theme-section-separator-color, var(--tg-theme-hint-color));"
+                )?;
+                row.set_text_content(Some(::core::convert::AsRef::<str>::as_ref(&item)));
+                element.append_child(&row)?;
+            }
+            element
+                .dyn_into::<web_sys::HtmlElement>()
+                .map_err(wasm_bindgen::JsValue::from)
+        }()
+    }};
+}
+
+/// Create a titled `<section>` styled with Telegram theme variables.
+///
+/// Generates a section with a header in the theme's section header color
+/// followed by the given child elements. Accepts an optional CSS class and
+/// additional attributes, and yields a
+/// `Result<web_sys::HtmlElement, wasm_bindgen::JsValue>`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use telegram_webapp_sdk::{telegram_input, telegram_section};
+/// use wasm_bindgen::JsValue;
+///
+/// # fn example() -> Result<(), JsValue> {
+/// let document = web_sys::window()
+///     .and_then(|w| w.document())
+///     .ok_or_else(|| JsValue::from_str("no document"))?;
+/// let name = telegram_input!(document, "Name")?;
+/// let section = telegram_section!(document, "Delivery", [name])?;
+/// assert_eq!(section.child_element_count(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! telegram_section {
+    ($doc:expr, $title:expr, [$($child:expr),* $(,)?] $(, class = $class:expr)? $(, $attr:literal = $value:expr)* $(,)?) => {{
+        || -> Result<web_sys::HtmlElement, wasm_bindgen::JsValue> {
+            use wasm_bindgen::JsCast;
+            let element = $doc.create_element("section")?;
+            element.set_attribute(
+                "style",
+                "margin: 16px 0; color: var(--tg-theme-text-color);"
+            )?;
+            let header = $doc.create_element("h3")?;
+            header.set_attribute(
+                "style",
+                "margin: 0 16px 8px; font-size: 13px; text-transform: uppercase; \
+                 color: var(--tg-theme-section-header-text-color, var(--tg-theme-hint-color));"
+            )?;
+            header.set_text_content(Some($title));
+            element.append_child(&header)?;
+            $(element.set_class_name($class);)?
+            $(
+                element.set_attribute($attr, $value)?;
+            )*
+            $(
+                element.append_child(&$child)?;
+            )*
+            element
+                .dyn_into::<web_sys::HtmlElement>()
+                .map_err(wasm_bindgen::JsValue::from)
+        }()
+    }};
+}
@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Message schema shared by the mini app and the bot.
+//!
+//! Define the messages once, usually as a serde enum in a crate used by both
+//! sides, and implement [`WebAppMessage`] for it. The mini app sends them with
+//! [`send_to_bot`]; the bot decodes `web_app_data` with [`decode`] (or
+//! `teloxide::web_app_message` with the `teloxide` feature).
+//!
+//! Messages travel in an envelope tagged with
+//! [`WebAppMessage::SCHEMA_VERSION`], so a bot rejects data sent by an
+//! outdated mini app instead of misreading it.
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+
+use crate::{
+    core::payload::{PayloadError, WebAppPayload},
+    webapp::TelegramWebApp
+};
+
+/// Message exchanged between the mini app and the bot.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::messages::{self, WebAppMessage};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// #[serde(tag = "type", rename_all = "snake_case")]
+/// enum Request {
+///     Order { item: String, quantity: u32 },
+///     Cancel { order_id: u64 }
+/// }
+///
+/// impl WebAppMessage for Request {
+///     const SCHEMA_VERSION: u32 = 2;
+/// }
+///
+/// let data = messages::encode(&Request::Cancel {
+///     order_id: 7
+/// })
+/// .unwrap();
+/// assert_eq!(data, r#"{"v":2,"msg":{"type":"cancel","order_id":7}}"#);
+/// assert_eq!(
+///     messages::decode::<Request>(&data).unwrap(),
+///     Request::Cancel {
+///         order_id: 7
+///     }
+/// );
+/// ```
+pub trait WebAppMessage: Serialize + DeserializeOwned {
+    /// Version written into every envelope; bump it on breaking changes.
+    const SCHEMA_VERSION: u32 = 1;
+}
+
+/// Errors produced while encoding or decoding a [`WebAppMessage`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageError {
+    /// The envelope could not be encoded or decoded.
+    Payload(PayloadError),
+    /// The message was sent with another schema version.
+    VersionMismatch {
+        /// Version expected by the receiver.
+        expected: u32,
+        /// Version found in the envelope.
+        found:    u32
+    }
+}
+
+impl std::fmt::Display for MessageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Payload(err) => err.fmt(f),
+            Self::VersionMismatch {
+                expected,
+                found
+            } => write!(f, "Message schema version {found}, expected {expected}")
+        }
+    }
+}
+
+impl std::error::Error for MessageError {}
+
+impl From<PayloadError> for MessageError {
+    fn from(err: PayloadError) -> Self {
+        Self::Payload(err)
+    }
+}
+
+impl From<MessageError> for JsValue {
+    fn from(err: MessageError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Versioned wrapper around a message.
+#[derive(Serialize, Deserialize)]
+struct Envelope<T> {
+    v:   u32,
+    msg: T
+}
+
+impl<T: Serialize> WebAppPayload for Envelope<T> {}
+
+/// Encodes `msg` in a versioned envelope for `WebApp.sendData`.
+///
+/// # Errors
+/// Returns [`MessageError::Payload`] if serialization fails or the encoded
+/// data exceeds [`SEND_DATA_LIMIT`](crate::core::payload::SEND_DATA_LIMIT).
+pub fn encode<T: WebAppMessage>(msg: &T) -> Result<String, MessageError> {
+    Ok(Envelope {
+        v: T::SCHEMA_VERSION,
+        msg
+    }
+    .to_send_data()?)
+}
+
+/// Decodes a message received by the bot in `web_app_data.data`.
+///
+/// # Errors
+/// Returns [`MessageError::VersionMismatch`] if the envelope carries another
+/// schema version and [`MessageError::Payload`] if the data is oversized or
+/// not a valid `T`.
+pub fn decode<T: WebAppMessage>(data: &str) -> Result<T, MessageError> {
+    let envelope = Envelope::<Value>::from_send_data(data)?;
+    if envelope.v != T::SCHEMA_VERSION {
+        return Err(MessageError::VersionMismatch {
+            expected: T::SCHEMA_VERSION,
+            found:    envelope.v
+        });
+    }
+    serde_json::from_value(envelope.msg).map_err(|err| PayloadError::Json(err.to_string()).into())
+}
+
+/// Encodes `msg` and sends it to the bot with `WebApp.sendData`.
+///
+/// Telegram closes the mini app after the data is sent.
+///
+/// # Errors
+/// Returns [`JsValue`] if the WebApp is unavailable, encoding fails or the
+/// underlying JS call fails.
+///
+/// # Examples
+/// ```no_run
+/// # use serde::{Deserialize, Serialize};
+/// # use telegram_webapp_sdk::messages::WebAppMessage;
+/// # #[derive(Serialize, Deserialize)]
+/// # enum Request { Ping }
+/// # impl WebAppMessage for Request {}
+/// use telegram_webapp_sdk::messages::send_to_bot;
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// send_to_bot(&Request::Ping)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn send_to_bot<T: WebAppMessage>(msg: &T) -> Result<(), JsValue> {
+    let app =
+        TelegramWebApp::instance().ok_or_else(|| JsValue::from_str("WebApp not available"))?;
+    app.send_data(&encode(msg)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum Request {
+        Order { item: String },
+        Ping
+    }
+
+    impl WebAppMessage for Request {}
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NextRequest;
+
+    impl WebAppMessage for NextRequest {
+        const SCHEMA_VERSION: u32 = 2;
+    }
+
+    #[test]
+    fn messages_round_trip_in_envelope() {
+        let data = encode(&Request::Order {
+            item: "burger".into()
+        })
+        .expect("encode");
+        assert_eq!(data, r#"{"v":1,"msg":{"type":"order","item":"burger"}}"#);
+        assert_eq!(
+            decode::<Request>(&data).expect("decode"),
+            Request::Order {
+                item: "burger".into()
+            }
+        );
+    }
+
+    #[test]
+    fn version_mismatch_is_reported() {
+        let data = encode(&Request::Ping).expect("encode");
+        assert_eq!(
+            decode::<NextRequest>(&data),
+            Err(MessageError::VersionMismatch {
+                expected: 2,
+                found:    1
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_messages_are_rejected() {
+        assert!(matches!(
+            decode::<Request>(r#"{"v":1,"msg":{"type":"refund"}}"#),
+            Err(MessageError::Payload(PayloadError::Json(_)))
+        ));
+        assert!(matches!(
+            decode::<Request>(r#"{"type":"ping"}"#),
+            Err(MessageError::Payload(PayloadError::Json(_)))
+        ));
+    }
+}
@@ -2,6 +2,10 @@
 // SPDX-License-Identifier: MIT
 
 use inventory::collect;
+/// Attribute registering a page with typed path parameters.
+///
+/// See [`telegram_webapp_sdk_macros::telegram_page`] for details.
+pub use telegram_webapp_sdk_macros::telegram_page;
 
 /// Represents a single routable page.
 #[derive(Copy, Clone)]
@@ -14,7 +18,97 @@ pub struct Page {
 
 collect!(Page);
 
+/// Describes a registered route for introspection.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RouteInfo {
+    /// Path pattern the page is registered at, e.g. `/item/:id`.
+    pub path:   &'static str,
+    /// Names of the `:param` segments in declaration order.
+    pub params: Vec<&'static str>
+}
+
+impl RouteInfo {
+    fn new(path: &'static str) -> Self {
+        let params = path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix(':'))
+            .collect();
+        Self {
+            path,
+            params
+        }
+    }
+
+    /// Returns whether the path contains parameters.
+    pub fn is_dynamic(&self) -> bool {
+        !self.params.is_empty()
+    }
+}
+
 /// Returns iterator over registered pages.
 pub fn iter() -> inventory::iter<Page> {
     inventory::iter::<Page>
 }
+
+/// Returns every registered route sorted by path.
+///
+/// Intended for dev overlays, debug pages and tests asserting that the
+/// expected routes are linked into the binary.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::pages;
+///
+/// for route in pages::routes() {
+///     println!("{} {:?}", route.path, route.params);
+/// }
+/// ```
+pub fn routes() -> Vec<RouteInfo> {
+    let mut routes: Vec<_> = iter()
+        .into_iter()
+        .map(|page| RouteInfo::new(page.path))
+        .collect();
+    routes.sort_by_key(|route| route.path);
+    routes
+}
+
+/// Returns whether a page is registered at the path pattern `path`.
+pub fn is_registered(path: &str) -> bool {
+    iter().into_iter().any(|page| page.path == path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RouteInfo, is_registered, routes};
+    use crate::pages::telegram_page;
+
+    #[telegram_page("/registry/:section/:id")]
+    fn registry_item(section: String, id: u32) {
+        let _ = (section, id);
+    }
+
+    #[telegram_page("/registry")]
+    fn registry_home() {}
+
+    #[test]
+    fn routes_describe_registered_pages() {
+        let routes = routes();
+        let item = routes
+            .iter()
+            .find(|route| route.path == "/registry/:section/:id")
+            .expect("registered route");
+        assert_eq!(item.params, ["section", "id"]);
+        assert!(item.is_dynamic());
+        assert!(routes.contains(&RouteInfo {
+            path:   "/registry",
+            params: Vec::new()
+        }));
+        assert!(routes.windows(2).all(|pair| pair[0].path <= pair[1].path));
+    }
+
+    #[test]
+    fn is_registered_checks_patterns() {
+        assert!(is_registered("/registry"));
+        assert!(!is_registered("/registry/books/1"));
+    }
+}
@@ -0,0 +1,235 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! [`PaymentFlow`] state machine for checkout screens.
+//!
+//! Builds on the same invoice handling as [`invoice::pay`]: it opens the
+//! invoice, awaits the `openInvoice` callback, the `invoiceClosed` event or
+//! an optional timeout, and refuses to open a second invoice while one is on
+//! screen. On top of that it reports every [`PaymentState`] change.
+
+use std::{cell::Cell, time::Duration};
+
+use crate::{
+    invoice::{self, InvoiceError, InvoiceSource},
+    webapp::InvoiceStatus
+};
+
+/// State of a [`PaymentFlow`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PaymentState {
+    /// The invoice has not been opened yet.
+    Idle,
+    /// The invoice is shown to the user.
+    Opened,
+    /// Telegram is still processing the payment; confirm it on the backend.
+    Pending,
+    /// The invoice was paid.
+    Paid,
+    /// The payment failed.
+    Failed,
+    /// The user closed the invoice without paying.
+    Cancelled,
+    /// No outcome was reported within the timeout.
+    TimedOut
+}
+
+impl PaymentState {
+    /// Returns whether the flow has finished in this state.
+    pub fn is_final(self) -> bool {
+        !matches!(self, Self::Idle | Self::Opened)
+    }
+}
+
+impl From<InvoiceStatus> for PaymentState {
+    fn from(status: InvoiceStatus) -> Self {
+        match status {
+            InvoiceStatus::Paid => Self::Paid,
+            InvoiceStatus::Cancelled => Self::Cancelled,
+            InvoiceStatus::Failed => Self::Failed,
+            InvoiceStatus::Pending => Self::Pending
+        }
+    }
+}
+
+type StateListener = Box<dyn Fn(PaymentState)>;
+
+/// Awaitable payment of one invoice.
+///
+/// [`run`](Self::run) opens the invoice and resolves with the final state,
+/// taken from whichever comes first: the `openInvoice` callback, the
+/// `invoiceClosed` event or the timeout. Only one invoice may be open at a
+/// time across all flows and [`invoice::pay`]; a second `run` fails with
+/// [`InvoiceError::InProgress`] instead of stacking invoices. A flow can be
+/// run again, e.g. to retry after [`PaymentState::Cancelled`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::{
+///     invoice::InvoiceError,
+///     payments::{PaymentFlow, PaymentState}
+/// };
+///
+/// # async fn run(link: &str) -> Result<(), InvoiceError> {
+/// let flow = PaymentFlow::new(link.parse()?)
+///     .timeout(Duration::from_secs(300))
+///     .on_state(|state| {
+///         let _ = state; // update the checkout screen
+///     });
+/// match flow.run().await? {
+///     PaymentState::Paid => { /* deliver */ }
+///     PaymentState::Pending | PaymentState::TimedOut => { /* ask the backend */ }
+///     _ => {}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PaymentFlow {
+    source:   InvoiceSource,
+    timeout:  Option<Duration>,
+    state:    Cell<PaymentState>,
+    listener: Option<StateListener>
+}
+
+impl PaymentFlow {
+    /// Creates an idle flow for `source` without a timeout.
+    pub fn new(source: InvoiceSource) -> Self {
+        Self {
+            source,
+            timeout: None,
+            state: Cell::new(PaymentState::Idle),
+            listener: None
+        }
+    }
+
+    /// Gives up waiting after `timeout`, finishing in
+    /// [`PaymentState::TimedOut`].
+    ///
+    /// The invoice may still be paid afterwards; confirm the order on the
+    /// backend.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Calls `listener` on every state change.
+    pub fn on_state(mut self, listener: impl Fn(PaymentState) + 'static) -> Self {
+        self.listener = Some(Box::new(listener));
+        self
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> PaymentState {
+        self.state.get()
+    }
+
+    /// Opens the invoice and resolves with the final state.
+    ///
+    /// # Errors
+    /// Returns [`InvoiceError`] when the source is invalid, the WebApp is
+    /// unavailable, another invoice is open, Telegram reports an unknown
+    /// status or the JS call fails. The state is left unchanged when the
+    /// invoice could not be opened.
+    pub async fn run(&self) -> Result<PaymentState, InvoiceError> {
+        let opened = || self.set_state(PaymentState::Opened);
+        let state = match invoice::open(&self.source, self.timeout, opened).await {
+            Ok(Some(status)) => PaymentState::from(status),
+            Ok(None) => PaymentState::TimedOut,
+            Err(err @ InvoiceError::UnknownStatus(_)) => {
+                self.set_state(PaymentState::Failed);
+                return Err(err);
+            }
+            Err(err) => return Err(err)
+        };
+        self.set_state(state);
+        Ok(state)
+    }
+
+    fn set_state(&self, state: PaymentState) {
+        self.state.set(state);
+        if let Some(listener) = &self.listener {
+            listener(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use js_sys::{Function, Object, Reflect};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::utils::future::sleep;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn final_states() {
+        assert!(!PaymentState::Opened.is_final());
+        assert!(PaymentState::from(InvoiceStatus::Pending).is_final());
+        assert_eq!(
+            PaymentState::from(InvoiceStatus::Cancelled),
+            PaymentState::Cancelled
+        );
+    }
+
+    fn setup_webapp(open_invoice: &str) {
+        let win = web_sys::window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let open = Function::new_with_args("url, cb", open_invoice);
+        let _ = Reflect::set(&webapp, &"openInvoice".into(), &open);
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &Function::new_no_args(""));
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &Function::new_no_args(""));
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn reports_states_until_paid() {
+        setup_webapp("setTimeout(() => cb('paid'), 0);");
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&seen);
+        let flow = PaymentFlow::new("$abc".parse().unwrap())
+            .on_state(move |state| sink.borrow_mut().push(state));
+        assert_eq!(flow.run().await, Ok(PaymentState::Paid));
+        assert_eq!(*seen.borrow(), [PaymentState::Opened, PaymentState::Paid]);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn times_out_without_outcome() {
+        setup_webapp("");
+        let flow = PaymentFlow::new("$abc".parse().unwrap()).timeout(Duration::from_millis(10));
+        assert_eq!(flow.run().await, Ok(PaymentState::TimedOut));
+        assert_eq!(flow.state(), PaymentState::TimedOut);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn pay_shares_the_open_invoice_guard() {
+        setup_webapp("");
+        wasm_bindgen_futures::spawn_local(async {
+            let flow =
+                PaymentFlow::new("$abc".parse().unwrap()).timeout(Duration::from_millis(50));
+            let _ = flow.run().await;
+        });
+        sleep(Duration::from_millis(10)).await;
+        let source = "$def".parse().unwrap();
+        assert_eq!(
+            invoice::pay(&source, None).await,
+            Err(InvoiceError::InProgress)
+        );
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(
+            invoice::pay(&source, Some(Duration::from_millis(10))).await,
+            Err(InvoiceError::Timeout)
+        );
+    }
+}
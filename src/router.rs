@@ -1,35 +1,289 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-//! Simple in-memory page router.
+//! Hash-based page router.
 //!
-//! Collects page definitions and executes their handlers in registration
-//! order. Used by the `telegram_router!` macro by default.
+//! Collects page definitions and renders the one matching the current
+//! location hash (`#/settings` maps to `/settings`). Used by the
+//! `telegram_router!` macro by default. Deployments whose hosting serves the
+//! app for every path can switch to [`RouterMode::History`] instead.
+//!
+//! Segments starting with `:` capture path parameters: `/item/:id` matches
+//! `/item/42` and the page reads the value with [`param`]. Static routes take
+//! precedence over parameterized ones.
+//!
+//! Routers can be composed: a feature module builds its own [`Router`] and the
+//! application mounts it under a prefix with [`Router::mount`].
+//!
+//! Once started, the router keeps a navigation history. Call [`navigate`] to
+//! open a page and [`back`] to return to the previous one. With
+//! [`Router::with_back_button`] the Telegram BackButton is driven by that
+//! history: it is shown whenever there is a page to return to, pops the
+//! current route on click, and is hidden at the root.
+//!
+//! Routes registered with [`Router::register_guarded`] consult a guard before
+//! rendering. The guard inspects the [`TelegramContext`] and either allows the
+//! page or redirects elsewhere, keeping access rules in one place.
+//!
+//! Pages react to route changes through [`Router::on_enter`] and
+//! [`Router::on_leave`] hooks, while [`Router::middleware`] observes every
+//! [`Transition`], e.g. to record analytics.
+//!
+//! [`navigate_with_state`] passes typed data to the next page, which reads it
+//! back with [`state`].
+//!
+//! Heavy pages can be registered with [`Router::register_async`] to fetch data
+//! before rendering, or with [`Router::register_lazy`] to load their handler
+//! on first visit. Neither blocks [`Router::start`].
+//!
+//! Per-screen chrome (document title, header color, closing confirmation)
+//! is declared next to the route with [`Router::with_meta`] and applied on
+//! every navigation.
+//!
+//! With [`Router::with_start_param`] a deep link such as
+//! `t.me/bot/app?startapp=order_42` opens `/order/42` on launch.
+//!
+//! Paths without a registered page, such as outdated deep links, render the
+//! handler set with [`Router::not_found`].
 //!
 //! # Examples
 //!
 //! ```no_run
-//! use telegram_webapp_sdk::router::Router;
+//! use telegram_webapp_sdk::router::{self, Router};
 //!
 //! fn index() {}
+//! fn profile() {}
+//!
+//! let settings = Router::new().register("/profile", profile);
 //!
-//! Router::new().register("/", index).start();
+//! Router::new()
+//!     .register("/", index)
+//!     .mount("/settings", settings)
+//!     .with_back_button()
+//!     .start();
+//!
+//! router::navigate("/settings/profile");
 //! ```
 
-#[cfg(feature = "macros")]
-use crate::pages::Page;
-#[cfg(not(feature = "macros"))]
-#[derive(Copy, Clone)]
-struct Page {
-    #[allow(dead_code)]
-    path:    &'static str,
-    handler: fn()
+mod browser;
+mod history;
+
+use std::{cell::RefCell, future::Future, pin::Pin};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use self::history::History;
+use crate::{core::context::TelegramContext, webapp::EventHandle};
+
+/// Maximum number of guard redirects followed while rendering one location.
+const MAX_REDIRECTS: usize = 8;
+
+/// Decision returned by a route guard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GuardOutcome {
+    /// Render the guarded page.
+    Allow,
+    /// Replace the current location with the given path.
+    Redirect(String)
+}
+
+impl GuardOutcome {
+    /// Creates a [`GuardOutcome::Redirect`] to `path`.
+    pub fn redirect(path: impl Into<String>) -> Self {
+        Self::Redirect(path.into())
+    }
+}
+
+/// Guard deciding whether a route may be rendered.
+pub type Guard = fn(&TelegramContext) -> GuardOutcome;
+
+/// Route change passed to [`Router::middleware`] callbacks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Transition<'a> {
+    /// Previously rendered path, `None` for the first render.
+    pub from: Option<&'a str>,
+    /// Path being rendered.
+    pub to:   &'a str
+}
+
+/// Callback observing every [`Transition`] of the router.
+pub type Middleware = fn(&Transition<'_>);
+
+/// Screen configuration applied when a route becomes active.
+///
+/// Unset fields leave the current value untouched.
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::router::RouteMeta;
+///
+/// let meta = RouteMeta {
+///     title: Some("Checkout"),
+///     closing_confirmation: Some(true),
+///     ..RouteMeta::default()
+/// };
+/// assert_eq!(meta.header_color, None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RouteMeta {
+    /// Document title.
+    pub title:                Option<&'static str>,
+    /// Header color passed to `WebApp.setHeaderColor`, either a `#RRGGBB`
+    /// value or a theme key such as `"secondary_bg_color"`.
+    pub header_color:         Option<&'static str>,
+    /// Whether closing the Mini App asks the user for confirmation.
+    pub closing_confirmation: Option<bool>
+}
+
+/// Boxed future returned by asynchronous and lazy page handlers.
+pub type PageFuture<T = ()> = Pin<Box<dyn Future<Output = T>>>;
+
+/// How a route renders its page.
+#[derive(Clone, Copy)]
+enum Handler {
+    /// Plain handler called synchronously.
+    Sync(fn()),
+    /// Handler whose future is spawned on each visit.
+    Async(fn() -> PageFuture),
+    /// Loader resolving the real handler on the first visit.
+    Lazy(fn() -> PageFuture<fn()>)
+}
+
+/// Single route entry owned by a [`Router`].
+struct Route {
+    path:     String,
+    handler:  Handler,
+    guard:    Option<Guard>,
+    meta:     Option<RouteMeta>,
+    on_enter: Option<fn()>,
+    on_leave: Option<fn()>
+}
+
+/// Path parameters captured while matching a route.
+type Params = Vec<(String, String)>;
+
+impl Route {
+    fn new(path: String, handler: Handler, guard: Option<Guard>) -> Self {
+        Self {
+            path,
+            handler,
+            guard,
+            meta: None,
+            on_enter: None,
+            on_leave: None
+        }
+    }
+
+    /// Matches `path` against this route's pattern and returns the captured
+    /// parameters.
+    fn matches(&self, path: &str) -> Option<Params> {
+        match_pattern(&self.path, path)
+    }
+
+    fn is_static(&self) -> bool {
+        !self.path.split('/').any(|segment| segment.starts_with(':'))
+    }
+}
+
+/// Matches `path` against a route `pattern` with `:param` segments and
+/// returns the captured parameters.
+pub(crate) fn match_pattern(pattern: &str, path: &str) -> Option<Params> {
+    let mut pattern = pattern.split('/');
+    let mut segments = path.split('/');
+    let mut params = Params::new();
+    loop {
+        match (pattern.next(), segments.next()) {
+            (None, None) => return Some(params),
+            (Some(expected), Some(segment)) => match expected.strip_prefix(':') {
+                Some(name) if !segment.is_empty() => {
+                    params.push((name.to_owned(), segment.to_owned()));
+                }
+                None if expected == segment => {}
+                _ => return None
+            },
+            _ => return None
+        }
+    }
+}
+
+/// Finds the route rendering `path`, preferring static routes over
+/// parameterized ones, and returns its index with the captured parameters.
+fn find_route(routes: &[Route], path: &str) -> Option<(usize, Params)> {
+    let exact = routes
+        .iter()
+        .position(|route| route.is_static() && route.path == path);
+    if let Some(idx) = exact {
+        return Some((idx, Params::new()));
+    }
+    routes
+        .iter()
+        .enumerate()
+        .find_map(|(idx, route)| route.matches(path).map(|params| (idx, params)))
 }
 
-/// Sequential router executing registered page handlers.
+/// Page router rendering registered handlers by path.
 #[derive(Default)]
 pub struct Router {
-    pages: Vec<Page>
+    routes:      Vec<Route>,
+    not_found:   Option<fn()>,
+    middleware:  Vec<Middleware>,
+    start_param: Option<StartParamMapping>,
+    mode:        RouterMode,
+    back_button: bool
+}
+
+/// Where the router keeps the current path in the browser location.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RouterMode {
+    /// Paths live in the location hash (`/app#/settings`). Works with any
+    /// static hosting.
+    #[default]
+    Hash,
+    /// Paths live in the location pathname (`/settings`) and are changed with
+    /// `history.pushState`. The server must serve the app for every route.
+    History
+}
+
+/// How `start_param` is turned into the initial route.
+#[derive(Clone, Copy)]
+enum StartParamMapping {
+    /// Underscores become path separators: `order_42` opens `/order/42`.
+    Direct,
+    /// User callback returning the path to open, if any.
+    Custom(fn(&str) -> Option<String>)
+}
+
+/// State of the router after [`Router::start`].
+struct ActiveRouter {
+    routes:      Vec<Route>,
+    not_found:   Option<fn()>,
+    middleware:  Vec<Middleware>,
+    rendered:    Option<String>,
+    params:      Params,
+    history:     History,
+    mode:        RouterMode,
+    back_button: bool,
+    /// How the next render writes its path into the browser location.
+    location:    LocationUpdate,
+    _back_click: Option<EventHandle<dyn FnMut()>>
+}
+
+/// Browser history operation mirroring the last router history change.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum LocationUpdate {
+    /// Push a new entry unless the location already shows the path.
+    #[default]
+    Push,
+    /// Overwrite the current entry, e.g. after a guard refused a path the
+    /// browser already shows.
+    Replace,
+    /// Step back one entry after the router popped its history.
+    Back
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<ActiveRouter>> = const { RefCell::new(None) };
 }
 
 impl Router {
@@ -41,33 +295,800 @@ impl Router {
     /// Adds a page handler associated with `path` and returns the updated
     /// router.
     pub fn register(mut self, path: &'static str, handler: fn()) -> Self {
-        self.pages.push(Page {
-            path,
-            handler
-        });
+        self.routes.push(Route::new(
+            normalize_path(path),
+            Handler::Sync(handler),
+            None
+        ));
+        self
+    }
+
+    /// Adds an asynchronous page handler associated with `path` and returns
+    /// the updated router.
+    ///
+    /// The future is spawned on every visit, after hooks and metadata were
+    /// applied, so the page can fetch data before drawing itself without
+    /// blocking the router.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::{PageFuture, Router};
+    ///
+    /// async fn orders() {
+    ///     // fetch and render orders
+    /// }
+    ///
+    /// fn orders_page() -> PageFuture {
+    ///     Box::pin(orders())
+    /// }
+    ///
+    /// let _router = Router::new().register_async("/orders", orders_page);
+    /// ```
+    pub fn register_async(mut self, path: &'static str, handler: fn() -> PageFuture) -> Self {
+        self.routes.push(Route::new(
+            normalize_path(path),
+            Handler::Async(handler),
+            None
+        ));
+        self
+    }
+
+    /// Adds a page whose handler is produced by `loader` on the first visit
+    /// and returns the updated router.
+    ///
+    /// The loaded handler is cached and rendered if the route is still
+    /// current once loading finishes; later visits call it directly.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::{PageFuture, Router};
+    ///
+    /// fn reports() {}
+    ///
+    /// fn load_reports() -> PageFuture<fn()> {
+    ///     Box::pin(async {
+    ///         // fetch translations, import code, ...
+    ///         reports as fn()
+    ///     })
+    /// }
+    ///
+    /// let _router = Router::new().register_lazy("/reports", load_reports);
+    /// ```
+    pub fn register_lazy(mut self, path: &'static str, loader: fn() -> PageFuture<fn()>) -> Self {
+        self.routes.push(Route::new(
+            normalize_path(path),
+            Handler::Lazy(loader),
+            None
+        ));
+        self
+    }
+
+    /// Adds a page handler protected by `guard` and returns the updated
+    /// router.
+    ///
+    /// The guard runs every time the route is about to render. A
+    /// [`GuardOutcome::Redirect`] replaces the current history entry, so the
+    /// BackButton never leads back to the refused page. When the
+    /// [`TelegramContext`] has not been initialized the guard cannot run and
+    /// the route is treated as missing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::{
+    ///     core::context::TelegramContext,
+    ///     router::{GuardOutcome, Router}
+    /// };
+    ///
+    /// fn index() {}
+    /// fn premium() {}
+    ///
+    /// fn premium_only(ctx: &TelegramContext) -> GuardOutcome {
+    ///     let is_premium = ctx
+    ///         .init_data
+    ///         .user
+    ///         .as_ref()
+    ///         .and_then(|user| user.is_premium)
+    ///         .unwrap_or(false);
+    ///     if is_premium {
+    ///         GuardOutcome::Allow
+    ///     } else {
+    ///         GuardOutcome::redirect("/")
+    ///     }
+    /// }
+    ///
+    /// Router::new()
+    ///     .register("/", index)
+    ///     .register_guarded("/premium", premium, premium_only)
+    ///     .start();
+    /// ```
+    pub fn register_guarded(mut self, path: &'static str, handler: fn(), guard: Guard) -> Self {
+        self.routes.push(Route::new(
+            normalize_path(path),
+            Handler::Sync(handler),
+            Some(guard)
+        ));
+        self
+    }
+
+    /// Mounts all routes of `router` under `prefix` and returns the updated
+    /// router.
+    ///
+    /// Paths are joined segment-wise, so `mount("/settings", ..)` combined with
+    /// a nested `"/"` route yields `"/settings"` and a nested `"/profile"`
+    /// route yields `"/settings/profile"`. Mounted routes keep their relative
+    /// registration order and are appended after the routes already present.
+    /// Hooks stay attached to their routes. Middleware of `router` is appended
+    /// and observes every transition, and its
+    /// [`not_found`](Self::not_found) handler is adopted only when this router
+    /// has none.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::Router;
+    ///
+    /// fn general() {}
+    /// fn privacy() {}
+    ///
+    /// let settings = Router::new()
+    ///     .register("/", general)
+    ///     .register("/privacy", privacy);
+    /// let _router = Router::new().mount("/settings", settings);
+    /// ```
+    pub fn mount(mut self, prefix: &str, router: Router) -> Self {
+        self.routes
+            .extend(router.routes.into_iter().map(|route| Route {
+                path: join_paths(prefix, &route.path),
+                ..route
+            }));
+        self.middleware.extend(router.middleware);
+        self.not_found = self.not_found.or(router.not_found);
+        self
+    }
+
+    /// Runs `hook` each time the route registered at `path` becomes active.
+    ///
+    /// Enter hooks run before the page handler. Call this after registering
+    /// the route; hooks for unknown paths are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::Router;
+    ///
+    /// fn map() {}
+    /// fn start_location() {}
+    /// fn stop_location() {}
+    ///
+    /// let _router = Router::new()
+    ///     .register("/map", map)
+    ///     .on_enter("/map", start_location)
+    ///     .on_leave("/map", stop_location);
+    /// ```
+    pub fn on_enter(mut self, path: &str, hook: fn()) -> Self {
+        if let Some(route) = self.route_mut(path) {
+            route.on_enter = Some(hook);
+        }
+        self
+    }
+
+    /// Runs `hook` each time the route registered at `path` stops being
+    /// active.
+    ///
+    /// Leave hooks run before the enter hooks of the next route. Call this
+    /// after registering the route; hooks for unknown paths are ignored.
+    pub fn on_leave(mut self, path: &str, hook: fn()) -> Self {
+        if let Some(route) = self.route_mut(path) {
+            route.on_leave = Some(hook);
+        }
+        self
+    }
+
+    /// Attaches `meta` to the route registered at `path`.
+    ///
+    /// The metadata is applied through [`TelegramWebApp`] before the enter
+    /// hook and handler run. Call this after registering the route; metadata
+    /// for unknown paths is ignored.
+    ///
+    /// [`TelegramWebApp`]: crate::webapp::TelegramWebApp
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::{RouteMeta, Router};
+    ///
+    /// fn checkout() {}
+    ///
+    /// let _router = Router::new().register("/checkout", checkout).with_meta(
+    ///     "/checkout",
+    ///     RouteMeta {
+    ///         title:                Some("Checkout"),
+    ///         header_color:         Some("#1c1c1e"),
+    ///         closing_confirmation: Some(true)
+    ///     }
+    /// );
+    /// ```
+    pub fn with_meta(mut self, path: &str, meta: RouteMeta) -> Self {
+        if let Some(route) = self.route_mut(path) {
+            route.meta = Some(meta);
+        }
         self
     }
 
-    /// Starts the router, invoking handlers in order of registration.
+    /// Adds a callback observing every route change.
+    ///
+    /// Middleware runs in registration order after the leave hook of the
+    /// previous route and before the enter hook of the next one, including
+    /// the initial render.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::{Router, Transition};
+    ///
+    /// fn track(transition: &Transition<'_>) {
+    ///     let _ = (transition.from, transition.to);
+    /// }
+    ///
+    /// let _router = Router::new().middleware(track);
+    /// ```
+    pub fn middleware(mut self, middleware: Middleware) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Sets the handler rendered when no registered route matches the current
+    /// path.
+    ///
+    /// Without it unknown paths render nothing and only a warning is logged.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::Router;
+    ///
+    /// fn index() {}
+    /// fn missing() {}
+    ///
+    /// Router::new()
+    ///     .register("/", index)
+    ///     .not_found(missing)
+    ///     .start();
+    /// ```
+    pub fn not_found(mut self, handler: fn()) -> Self {
+        self.not_found = Some(handler);
+        self
+    }
+
+    /// Lets the router manage the Telegram BackButton.
+    ///
+    /// The button is shown while the history holds more than one entry, pops
+    /// the current route when clicked and is hidden again at the root. Apps
+    /// using this option should not register their own back button callback.
+    pub fn with_back_button(mut self) -> Self {
+        self.back_button = true;
+        self
+    }
+
+    /// Selects how paths are stored in the browser location.
+    ///
+    /// Defaults to [`RouterMode::Hash`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::{Router, RouterMode};
+    ///
+    /// fn index() {}
+    ///
+    /// Router::new()
+    ///     .register("/", index)
+    ///     .with_mode(RouterMode::History)
+    ///     .start();
+    /// ```
+    pub fn with_mode(mut self, mode: RouterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Opens the route encoded in `start_param` on launch.
+    ///
+    /// Telegram only allows `A-Z`, `a-z`, `0-9`, `_` and `-` in
+    /// `start_param`, so underscores act as path separators:
+    /// `t.me/bot/app?startapp=order_42` renders `/order/42`. The mapped route
+    /// is pushed on top of the initial `/` entry so the BackButton leads to the
+    /// home page. The parameter is ignored when the location already points
+    /// to a page (e.g. after a reload) or the [`TelegramContext`] is not
+    /// initialized.
+    pub fn with_start_param(mut self) -> Self {
+        self.start_param = Some(StartParamMapping::Direct);
+        self
+    }
+
+    /// Opens the route returned by `map` for the launch `start_param`.
+    ///
+    /// Behaves like [`with_start_param`](Self::with_start_param) but lets the
+    /// application decode the parameter. Returning `None` keeps the initial
+    /// location.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use telegram_webapp_sdk::router::Router;
+    ///
+    /// fn order() {}
+    ///
+    /// fn decode(param: &str) -> Option<String> {
+    ///     param.strip_prefix("o").map(|id| format!("/order/{id}"))
+    /// }
+    ///
+    /// Router::new()
+    ///     .register("/order/42", order)
+    ///     .with_start_param_map(decode)
+    ///     .start();
+    /// ```
+    pub fn with_start_param_map(mut self, map: fn(&str) -> Option<String>) -> Self {
+        self.start_param = Some(StartParamMapping::Custom(map));
+        self
+    }
+
+    /// Returns the paths of all registered routes in registration order.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.routes.iter().map(|route| route.path.as_str())
+    }
+
+    fn route_mut(&mut self, path: &str) -> Option<&mut Route> {
+        let path = normalize_path(path);
+        self.routes
+            .iter_mut()
+            .rev()
+            .find(|route| route.path == path)
+    }
+
+    /// Starts the router and renders the page matching the current location.
+    ///
+    /// The started router replaces any previously active one. On wasm targets
+    /// the initial path is read from the location according to the
+    /// [`RouterMode`], and later `hashchange`/`popstate` events (links such as
+    /// `<a href="#/about">`, browser back) are followed.
     pub fn start(self) {
-        for page in self.pages {
-            (page.handler)();
+        let back_click = if self.back_button {
+            browser::bind_back_button(handle_back_button)
+        } else {
+            None
+        };
+        let mut history = History::new(browser::initial_path(self.mode));
+        if history.current() == "/"
+            && let Some(path) = self.start_param.and_then(start_route)
+        {
+            history.push(path);
         }
+        ACTIVE.with(|active| {
+            *active.borrow_mut() = Some(ActiveRouter {
+                routes: self.routes,
+                not_found: self.not_found,
+                middleware: self.middleware,
+                rendered: None,
+                params: Params::new(),
+                history,
+                mode: self.mode,
+                back_button: self.back_button,
+                location: LocationUpdate::Push,
+                _back_click: back_click
+            });
+        });
+        browser::listen(self.mode, handle_location_change);
+        refresh();
+    }
+}
+
+/// Navigates the active router to `path`, pushing it onto the history.
+///
+/// Does nothing when no router is running or `path` is already current.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::router;
+///
+/// router::navigate("/settings");
+/// ```
+pub fn navigate(path: &str) {
+    let path = normalize_path(path);
+    let changed = with_active(|router| router.history.push(path)).unwrap_or(false);
+    if changed {
+        refresh();
     }
 }
 
+/// Navigates the active router to `path` carrying `state`.
+///
+/// The state is stored with the new history entry and read by the target page
+/// through [`state`]. Returning with [`back`] restores the state of the
+/// previous entry. Navigating to the current path replaces its state and
+/// renders the page again.
+///
+/// # Errors
+/// Returns [`serde_json::Error`] if `state` fails to serialize.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::Serialize;
+/// use telegram_webapp_sdk::router;
+///
+/// #[derive(Serialize)]
+/// struct Order {
+///     id: u64
+/// }
+///
+/// router::navigate_with_state(
+///     "/order",
+///     &Order {
+///         id: 42
+///     }
+/// )
+/// .unwrap();
+/// ```
+pub fn navigate_with_state<S>(path: &str, state: &S) -> Result<(), serde_json::Error>
+where
+    S: Serialize + ?Sized
+{
+    let state = serde_json::to_value(state)?;
+    let path = normalize_path(path);
+    if with_active(|router| router.history.push_with_state(path, state)).is_some() {
+        refresh();
+    }
+    Ok(())
+}
+
+/// Returns the state the current page was opened with.
+///
+/// Returns `Ok(None)` when no router is running or the page was opened without
+/// state.
+///
+/// # Errors
+/// Returns [`serde_json::Error`] if the stored state does not deserialize into
+/// `T`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::Deserialize;
+/// use telegram_webapp_sdk::router;
+///
+/// #[derive(Deserialize)]
+/// struct Order {
+///     id: u64
+/// }
+///
+/// fn order_page() {
+///     if let Ok(Some(order)) = router::state::<Order>() {
+///         let _ = order.id;
+///     }
+/// }
+/// ```
+pub fn state<T: DeserializeOwned>() -> Result<Option<T>, serde_json::Error> {
+    with_active(|router| router.history.state().cloned())
+        .flatten()
+        .map(serde_json::from_value)
+        .transpose()
+}
+
+/// Returns the path parameter `name` captured for the current page.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::router::{self, Router};
+///
+/// fn item() {
+///     let id: Option<u32> = router::param("id").and_then(|id| id.parse().ok());
+///     let _ = id;
+/// }
+///
+/// Router::new().register("/item/:id", item).start();
+/// ```
+pub fn param(name: &str) -> Option<String> {
+    with_active(|router| {
+        router
+            .params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    })
+    .flatten()
+}
+
+/// Renders the `not_found` handler of the active router.
+///
+/// Pages call this when their parameters turn out to be invalid, e.g. a
+/// non-numeric `:id`. Only logs a warning when no fallback is set.
+pub fn fallback() {
+    match with_active(|router| router.not_found).flatten() {
+        Some(handler) => handler(),
+        None => browser::warn("router: no not_found handler registered")
+    }
+}
+
+/// Returns to the previous page of the active router.
+///
+/// Returns `false` when the router is already at its root or not running.
+pub fn back() -> bool {
+    let changed = with_active(|router| {
+        let popped = router.history.pop();
+        if popped {
+            router.location = LocationUpdate::Back;
+        }
+        popped
+    })
+    .unwrap_or(false);
+    if changed {
+        refresh();
+    }
+    changed
+}
+
+/// Returns the path currently rendered by the active router.
+pub fn current_path() -> Option<String> {
+    with_active(|router| router.history.current().to_owned())
+}
+
+/// Returns the number of entries in the active router's history.
+///
+/// Returns `0` when no router is running.
+pub fn history_depth() -> usize {
+    with_active(|router| router.history.depth()).unwrap_or(0)
+}
+
+fn with_active<R>(f: impl FnOnce(&mut ActiveRouter) -> R) -> Option<R> {
+    ACTIVE.with(|active| active.borrow_mut().as_mut().map(f))
+}
+
+fn handle_back_button() {
+    back();
+}
+
+/// Reacts to a location change made outside of [`navigate`]/[`back`].
+fn handle_location_change(mode: RouterMode, path: String) {
+    let changed = with_active(|router| {
+        if router.mode != mode || router.history.current() == path {
+            false
+        } else if router.history.previous() == Some(path.as_str()) {
+            router.history.pop()
+        } else {
+            router.history.push(path)
+        }
+    })
+    .unwrap_or(false);
+    if changed {
+        refresh();
+    }
+}
+
+/// Syncs browser state with the history and renders the current route.
+///
+/// Guards and the handler run after the router state is released so they may
+/// navigate again.
+fn refresh() {
+    for _ in 0..MAX_REDIRECTS {
+        let Some((path, route)) = with_active(|router| {
+            let path = router.history.current().to_owned();
+            let (route, params) = match find_route(&router.routes, &path) {
+                Some((idx, params)) => {
+                    let route = &router.routes[idx];
+                    (Some((route.handler, route.guard)), params)
+                }
+                None => (None, Params::new())
+            };
+            router.params = params;
+            (path, route)
+        }) else {
+            return;
+        };
+
+        let handler = match route {
+            Some((handler, None)) => Some(handler),
+            Some((handler, Some(guard))) => match TelegramContext::get(guard) {
+                Some(GuardOutcome::Allow) => Some(handler),
+                Some(GuardOutcome::Redirect(target)) => {
+                    with_active(|router| redirect(router, &path, &target));
+                    continue;
+                }
+                None => {
+                    browser::warn(&format!("router: context unavailable for guarded {path}"));
+                    None
+                }
+            },
+            None => None
+        };
+        render(&path, handler);
+        return;
+    }
+    browser::warn("router: too many guard redirects");
+}
+
+/// Replaces the refused `path` with the guard's `target`.
+///
+/// When the browser already shows `path` (it was entered outside of
+/// [`navigate`]), its entry is overwritten or stepped back from so the
+/// browser history keeps matching the router's.
+fn redirect(router: &mut ActiveRouter, path: &str, target: &str) {
+    let depth = router.history.depth();
+    if !router.history.replace(normalize_path(target))
+        || browser::initial_path(router.mode) != path
+    {
+        return;
+    }
+    router.location = if router.history.depth() < depth {
+        LocationUpdate::Back
+    } else {
+        LocationUpdate::Replace
+    };
+}
+
+/// Everything [`render`] needs, collected while the router state is borrowed.
+struct RenderPlan {
+    mode:        RouterMode,
+    location:    LocationUpdate,
+    not_found:   Option<fn()>,
+    back_button: bool,
+    depth:       usize,
+    from:        Option<String>,
+    leave:       Option<fn()>,
+    enter:       Option<fn()>,
+    meta:        Option<RouteMeta>,
+    middleware:  Vec<Middleware>
+}
+
+/// Updates the location and BackButton, runs lifecycle hooks, middleware and
+/// route metadata, then calls the resolved handler or the `not_found`
+/// fallback.
+fn render(path: &str, handler: Option<Handler>) {
+    let Some(plan) = with_active(|router| {
+        let from = router.rendered.replace(path.to_owned());
+        let changed = from.as_deref() != Some(path);
+        let find =
+            |path: &str| find_route(&router.routes, path).map(|(idx, _)| &router.routes[idx]);
+        let target = handler.and_then(|_| find(path)).filter(|_| changed);
+        RenderPlan {
+            mode: router.mode,
+            location: std::mem::take(&mut router.location),
+            not_found: router.not_found,
+            back_button: router.back_button,
+            depth: router.history.depth(),
+            leave: from
+                .as_deref()
+                .filter(|_| changed)
+                .and_then(find)
+                .and_then(|route| route.on_leave),
+            enter: target.and_then(|route| route.on_enter),
+            meta: target.and_then(|route| route.meta),
+            middleware: router.middleware.clone(),
+            from
+        }
+    }) else {
+        return;
+    };
+
+    match plan.location {
+        LocationUpdate::Push => browser::set_location(plan.mode, path),
+        LocationUpdate::Replace => browser::replace_location(plan.mode, path),
+        LocationUpdate::Back => browser::go_back()
+    }
+    if plan.back_button {
+        browser::set_back_button_visible(plan.depth > 1);
+    }
+    if let Some(leave) = plan.leave {
+        leave();
+    }
+    if plan.from.as_deref() != Some(path) {
+        let transition = Transition {
+            from: plan.from.as_deref(),
+            to:   path
+        };
+        plan.middleware
+            .iter()
+            .for_each(|middleware| middleware(&transition));
+    }
+    if let Some(meta) = plan.meta {
+        browser::apply_meta(&meta);
+    }
+    if let Some(enter) = plan.enter {
+        enter();
+    }
+    match handler.or(plan.not_found.map(Handler::Sync)) {
+        Some(Handler::Sync(handler)) => handler(),
+        Some(Handler::Async(handler)) => spawn_page(handler()),
+        Some(Handler::Lazy(loader)) => {
+            let path = path.to_owned();
+            spawn_page(Box::pin(async move {
+                let handler = loader().await;
+                resolve_lazy(&path, handler);
+            }));
+        }
+        None => browser::warn(&format!("router: no page registered for {path}"))
+    }
+}
+
+/// Caches a loaded lazy handler and renders it if its route is still current.
+fn resolve_lazy(path: &str, handler: fn()) {
+    let current = with_active(|router| {
+        if let Some((idx, _)) = find_route(&router.routes, path) {
+            router.routes[idx].handler = Handler::Sync(handler);
+        }
+        router.history.current() == path
+    })
+    .unwrap_or(false);
+    if current {
+        handler();
+    }
+}
+
+/// Runs a page future to completion without blocking the caller.
+///
+/// On wasm targets the future is spawned on the browser event loop. Native
+/// builds poll it once, which completes futures that do not wait on I/O.
+/// Used by `telegram_page!` for `async fn` pages.
+pub fn spawn_page(future: PageFuture) {
+    browser::spawn(future);
+}
+
+/// Resolves the initial route from the context `start_param`.
+fn start_route(mapping: StartParamMapping) -> Option<String> {
+    let param = TelegramContext::get(|ctx| ctx.init_data.start_param.clone()).flatten()?;
+    let path = match mapping {
+        StartParamMapping::Direct => join_paths("", &param.replace('_', "/")),
+        StartParamMapping::Custom(map) => normalize_path(&map(&param)?)
+    };
+    Some(path)
+}
+
+/// Extracts the route path from a location hash such as `#/settings`.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+fn path_from_hash(hash: &str) -> String {
+    normalize_path(hash.trim_start_matches('#'))
+}
+
+/// Normalizes `path` to a leading-slash form without a trailing slash.
+fn normalize_path(path: &str) -> String {
+    join_paths("", path)
+}
+
+/// Joins `prefix` and `path` into a single normalized path.
+fn join_paths(prefix: &str, path: &str) -> String {
+    let segments: Vec<&str> = prefix
+        .split('/')
+        .chain(path.split('/'))
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    format!("/{}", segments.join("/"))
+}
+
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::{
+        cell::{Cell, RefCell},
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration
+    };
+
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
 
     use super::*;
+    use crate::utils::future::sleep;
+
+    wasm_bindgen_test_configure!(run_in_browser);
 
     fn noop() {}
 
     #[test]
     fn registers_pages() {
         let router = Router::new().register("/", noop);
-        assert_eq!(router.pages.len(), 1);
+        assert_eq!(router.routes.len(), 1);
     }
 
     static COUNT: AtomicUsize = AtomicUsize::new(0);
@@ -82,4 +1103,607 @@ mod tests {
         Router::new().register("/", handler).start();
         assert_eq!(COUNT.load(Ordering::SeqCst), 1);
     }
+
+    #[test]
+    fn mount_prefixes_nested_paths() {
+        let settings = Router::new().register("/", noop).register("/privacy", noop);
+        let router = Router::new()
+            .register("/", noop)
+            .mount("/settings", settings);
+        let paths: Vec<&str> = router.paths().collect();
+        assert_eq!(paths, ["/", "/settings", "/settings/privacy"]);
+    }
+
+    #[test]
+    fn mount_supports_multiple_levels() {
+        let security = Router::new().register("/2fa", noop);
+        let settings = Router::new().mount("/security/", security);
+        let router = Router::new().mount("settings", settings);
+        let paths: Vec<&str> = router.paths().collect();
+        assert_eq!(paths, ["/settings/security/2fa"]);
+    }
+
+    #[test]
+    fn join_paths_normalizes_slashes() {
+        assert_eq!(join_paths("/", "/"), "/");
+        assert_eq!(join_paths("/a/", "/b/"), "/a/b");
+        assert_eq!(join_paths("", "c"), "/c");
+    }
+
+    #[test]
+    fn path_from_hash_strips_marker() {
+        assert_eq!(path_from_hash(""), "/");
+        assert_eq!(path_from_hash("#"), "/");
+        assert_eq!(path_from_hash("#/settings/"), "/settings");
+    }
+
+    thread_local! {
+        static VISITS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn visit_index() {
+        VISITS.with(|v| v.borrow_mut().push("/"));
+    }
+
+    fn visit_settings() {
+        VISITS.with(|v| v.borrow_mut().push("/settings"));
+    }
+
+    #[test]
+    fn navigate_and_back_follow_history() {
+        Router::new()
+            .register("/", visit_index)
+            .register("/settings", visit_settings)
+            .with_back_button()
+            .start();
+        assert_eq!(history_depth(), 1);
+
+        navigate("/settings");
+        navigate("/settings");
+        assert_eq!(current_path().as_deref(), Some("/settings"));
+        assert_eq!(history_depth(), 2);
+
+        assert!(back());
+        assert!(!back());
+        assert_eq!(current_path().as_deref(), Some("/"));
+        VISITS.with(|v| assert_eq!(v.borrow().as_slice(), ["/", "/settings", "/"]));
+    }
+
+    thread_local! {
+        static MISSING: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn missing() {
+        MISSING.set(MISSING.get() + 1);
+    }
+
+    #[test]
+    fn unknown_path_renders_not_found() {
+        MISSING.set(0);
+        Router::new().register("/", noop).not_found(missing).start();
+        assert_eq!(MISSING.get(), 0);
+
+        navigate("/outdated/link");
+        assert_eq!(MISSING.get(), 1);
+        assert_eq!(current_path().as_deref(), Some("/outdated/link"));
+    }
+
+    #[test]
+    fn mount_keeps_outer_not_found() {
+        MISSING.set(0);
+        let nested = Router::new().register("/", noop).not_found(noop);
+        Router::new().not_found(missing).mount("/", nested).start();
+        navigate("/unknown");
+        assert_eq!(MISSING.get(), 1);
+
+        let nested = Router::new().register("/", noop).not_found(missing);
+        Router::new().mount("/", nested).start();
+        navigate("/unknown");
+        assert_eq!(MISSING.get(), 2);
+    }
+
+    fn init_context(start_param: Option<&str>) {
+        let init_data = crate::core::types::init_data::TelegramInitData {
+            query_id:       None,
+            user:           None,
+            receiver:       None,
+            chat:           None,
+            chat_type:      None,
+            chat_instance:  None,
+            start_param:    start_param.map(str::to_owned),
+            can_send_after: None,
+            auth_date:      0,
+            hash:           String::new(),
+            signature:      None
+        };
+        TelegramContext::init(init_data, Default::default(), String::new()).expect("init");
+    }
+
+    fn needs_start_param(ctx: &TelegramContext) -> GuardOutcome {
+        match ctx.init_data.start_param {
+            Some(_) => GuardOutcome::Allow,
+            None => GuardOutcome::redirect("/")
+        }
+    }
+
+    #[test]
+    fn guard_redirect_replaces_current_entry() {
+        init_context(None);
+        Router::new()
+            .register("/", noop)
+            .register("/a", noop)
+            .register_guarded("/deal", noop, needs_start_param)
+            .start();
+        navigate("/deal");
+        assert_eq!(current_path().as_deref(), Some("/"));
+        assert_eq!(history_depth(), 1);
+
+        navigate("/a");
+        navigate("/deal");
+        assert_eq!(current_path().as_deref(), Some("/"));
+        assert!(back());
+        assert_eq!(current_path().as_deref(), Some("/a"));
+    }
+
+    #[test]
+    fn guard_allows_route() {
+        init_context(Some("promo"));
+        Router::new()
+            .register("/", noop)
+            .register_guarded("/deal", noop, needs_start_param)
+            .start();
+        navigate("/deal");
+        assert_eq!(current_path().as_deref(), Some("/deal"));
+    }
+
+    #[test]
+    fn guarded_route_without_context_renders_not_found() {
+        MISSING.set(0);
+        Router::new()
+            .register("/", noop)
+            .register_guarded("/deal", visit_settings, needs_start_param)
+            .not_found(missing)
+            .start();
+        navigate("/deal");
+        assert_eq!(MISSING.get(), 1);
+        VISITS.with(|v| assert!(v.borrow().is_empty()));
+    }
+
+    thread_local! {
+        static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(event: &str) {
+        EVENTS.with(|e| e.borrow_mut().push(event.to_owned()));
+    }
+
+    fn enter_map() {
+        record("enter /map");
+    }
+
+    fn leave_map() {
+        record("leave /map");
+    }
+
+    fn render_map() {
+        record("render /map");
+    }
+
+    fn log_transition(transition: &Transition<'_>) {
+        record(&format!("{:?} -> {}", transition.from, transition.to));
+    }
+
+    #[test]
+    fn lifecycle_hooks_wrap_route_changes() {
+        Router::new()
+            .register("/", noop)
+            .register("/map", render_map)
+            .on_enter("/map", enter_map)
+            .on_leave("/map", leave_map)
+            .middleware(log_transition)
+            .start();
+        navigate("/map");
+        back();
+        EVENTS.with(|e| {
+            assert_eq!(
+                e.borrow().as_slice(),
+                [
+                    "None -> /",
+                    "Some(\"/\") -> /map",
+                    "enter /map",
+                    "render /map",
+                    "leave /map",
+                    "Some(\"/map\") -> /"
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn mounted_routes_keep_hooks() {
+        let nested = Router::new()
+            .register("/", render_map)
+            .on_enter("/", enter_map);
+        Router::new()
+            .register("/", noop)
+            .mount("/map", nested)
+            .start();
+        navigate("/map");
+        EVENTS.with(|e| assert_eq!(e.borrow().as_slice(), ["enter /map", "render /map"]));
+    }
+
+    #[test]
+    fn start_param_opens_mapped_route() {
+        init_context(Some("order_42"));
+        Router::new()
+            .register("/", noop)
+            .register("/order/42", visit_settings)
+            .with_start_param()
+            .start();
+        assert_eq!(current_path().as_deref(), Some("/order/42"));
+        assert_eq!(history_depth(), 2);
+        VISITS.with(|v| assert_eq!(v.borrow().as_slice(), ["/settings"]));
+    }
+
+    fn decode_order(param: &str) -> Option<String> {
+        param
+            .strip_prefix("order_")
+            .map(|id| format!("/orders/{id}"))
+    }
+
+    #[test]
+    fn start_param_uses_custom_mapping() {
+        init_context(Some("order_42"));
+        Router::new()
+            .register("/", noop)
+            .with_start_param_map(decode_order)
+            .start();
+        assert_eq!(current_path().as_deref(), Some("/orders/42"));
+    }
+
+    #[test]
+    fn start_param_ignored_without_context() {
+        Router::new().register("/", noop).with_start_param().start();
+        assert_eq!(current_path().as_deref(), Some("/"));
+    }
+
+    #[test]
+    fn with_meta_attaches_to_registered_route() {
+        let meta = RouteMeta {
+            title: Some("Map"),
+            ..RouteMeta::default()
+        };
+        let router = Router::new()
+            .register("/map", noop)
+            .with_meta("/map/", meta)
+            .with_meta("/unknown", RouteMeta::default());
+        assert_eq!(router.routes[0].meta, Some(meta));
+        assert_eq!(router.routes.len(), 1);
+    }
+
+    fn async_settings() -> PageFuture {
+        Box::pin(async { visit_settings() })
+    }
+
+    fn load_settings() -> PageFuture<fn()> {
+        Box::pin(async {
+            record("loaded");
+            visit_settings as fn()
+        })
+    }
+
+    #[test]
+    fn async_handler_runs_on_visit() {
+        Router::new()
+            .register("/", noop)
+            .register_async("/settings", async_settings)
+            .start();
+        navigate("/settings");
+        VISITS.with(|v| assert_eq!(v.borrow().as_slice(), ["/settings"]));
+    }
+
+    #[test]
+    fn lazy_handler_loads_once() {
+        Router::new()
+            .register("/", noop)
+            .register_lazy("/settings", load_settings)
+            .start();
+        navigate("/settings");
+        back();
+        navigate("/settings");
+        EVENTS.with(|e| assert_eq!(e.borrow().as_slice(), ["loaded"]));
+        VISITS.with(|v| assert_eq!(v.borrow().as_slice(), ["/settings", "/settings"]));
+    }
+
+    #[cfg(feature = "macros")]
+    mod page_macro {
+        use super::{EVENTS, MISSING, Router, log_transition, missing, navigate, noop, record};
+
+        crate::telegram_page!(
+            "/async-page",
+            async fn async_page() {
+                record("async page");
+            }
+        );
+
+        #[test]
+        fn telegram_page_accepts_async_fn() {
+            let page = crate::pages::iter()
+                .into_iter()
+                .find(|page| page.path == "/async-page")
+                .expect("registered");
+            (page.handler)();
+            EVENTS.with(|e| assert_eq!(e.borrow().as_slice(), ["async page"]));
+        }
+
+        #[crate::pages::telegram_page("/typed/:id")]
+        fn typed_page(id: u32) {
+            record(&format!("typed {id}"));
+        }
+
+        #[test]
+        fn telegram_page_attribute_parses_params() {
+            let page = crate::pages::iter()
+                .into_iter()
+                .find(|page| page.path == "/typed/:id")
+                .expect("registered");
+            MISSING.set(0);
+            Router::new()
+                .register("/", noop)
+                .register(page.path, page.handler)
+                .not_found(missing)
+                .start();
+            navigate("/typed/7");
+            navigate("/typed/seven");
+            EVENTS.with(|e| assert_eq!(e.borrow().as_slice(), ["typed 7"]));
+            assert_eq!(MISSING.get(), 1);
+        }
+
+        #[test]
+        fn telegram_router_applies_options() {
+            MISSING.set(0);
+            crate::telegram_router!(not_found = missing, middleware = [log_transition,]);
+            // No registered page serves the initial "/" either.
+            assert_eq!(MISSING.get(), 1);
+            navigate("/no-such-page");
+            assert_eq!(MISSING.get(), 2);
+            EVENTS.with(|e| {
+                assert_eq!(
+                    e.borrow().last().map(String::as_str),
+                    Some("Some(\"/\") -> /no-such-page")
+                );
+            });
+        }
+
+        struct CountingRouter(usize);
+
+        impl CountingRouter {
+            fn new() -> Self {
+                Self(0)
+            }
+
+            fn register(self, _path: &str, _handler: fn()) -> Self {
+                Self(self.0 + 1)
+            }
+
+            fn not_found(self, _handler: fn()) -> Self {
+                self
+            }
+
+            fn start(self) {
+                assert!(self.0 >= 2);
+            }
+        }
+
+        #[test]
+        fn telegram_router_accepts_custom_type_with_options() {
+            crate::telegram_router!(CountingRouter, not_found = missing);
+            crate::telegram_router!(CountingRouter);
+        }
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Order {
+        id: u64
+    }
+
+    thread_local! {
+        static SEEN: RefCell<Vec<Option<u64>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn order_page() {
+        let id = state::<Order>().expect("state").map(|order| order.id);
+        SEEN.with(|s| s.borrow_mut().push(id));
+    }
+
+    #[test]
+    fn state_is_passed_to_target_page() {
+        Router::new()
+            .register("/", noop)
+            .register("/order", order_page)
+            .start();
+        navigate_with_state(
+            "/order",
+            &Order {
+                id: 42
+            }
+        )
+        .expect("serialize");
+        navigate_with_state(
+            "/order",
+            &Order {
+                id: 7
+            }
+        )
+        .expect("serialize");
+        assert_eq!(history_depth(), 2);
+        back();
+        assert_eq!(state::<Order>().expect("state"), None);
+        navigate("/order");
+        SEEN.with(|s| assert_eq!(s.borrow().as_slice(), [Some(42), Some(7), None]));
+    }
+
+    #[test]
+    fn state_type_mismatch_is_an_error() {
+        Router::new().register("/", noop).start();
+        navigate_with_state(
+            "/",
+            &Order {
+                id: 1
+            }
+        )
+        .expect("serialize");
+        assert!(state::<Vec<u8>>().is_err());
+    }
+
+    thread_local! {
+        static ITEMS: RefCell<Vec<Option<String>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn item_page() {
+        ITEMS.with(|i| i.borrow_mut().push(param("id")));
+    }
+
+    #[test]
+    fn parameterized_routes_capture_segments() {
+        Router::new()
+            .register("/", noop)
+            .register("/item/:id", item_page)
+            .register("/item/new", visit_settings)
+            .start();
+        navigate("/item/42");
+        navigate("/item/new");
+        navigate("/item");
+        ITEMS.with(|i| assert_eq!(i.borrow().as_slice(), [Some("42".to_owned())]));
+        VISITS.with(|v| assert_eq!(v.borrow().as_slice(), ["/settings"]));
+        assert_eq!(param("id"), None);
+    }
+
+    #[test]
+    fn fallback_renders_not_found() {
+        MISSING.set(0);
+        Router::new().register("/", noop).not_found(missing).start();
+        fallback();
+        assert_eq!(MISSING.get(), 1);
+    }
+
+    #[test]
+    fn location_change_to_previous_entry_pops() {
+        Router::new()
+            .register("/", noop)
+            .register("/a", noop)
+            .start();
+        handle_location_change(RouterMode::Hash, "/a".into());
+        assert_eq!(history_depth(), 2);
+        handle_location_change(RouterMode::Hash, "/".into());
+        assert_eq!(history_depth(), 1);
+    }
+
+    fn history_length() -> u32 {
+        web_sys::window()
+            .and_then(|win| win.history().ok())
+            .and_then(|history| history.length().ok())
+            .unwrap_or_default()
+    }
+
+    #[wasm_bindgen_test]
+    async fn back_keeps_browser_history_in_step() {
+        let pause = || sleep(Duration::from_millis(50));
+        Router::new()
+            .register("/", noop)
+            .register("/a", noop)
+            .register("/b", noop)
+            .start();
+        navigate("/a");
+        pause().await;
+        let length = history_length();
+        assert!(back());
+        pause().await;
+        navigate("/b");
+        pause().await;
+        assert!(back());
+        pause().await;
+        assert_eq!(history_length(), length);
+        assert_eq!(current_path().as_deref(), Some("/"));
+        assert_eq!(history_depth(), 1);
+    }
+
+    fn pending_location() -> Option<LocationUpdate> {
+        with_active(|router| router.location)
+    }
+
+    #[test]
+    fn redirect_from_shown_path_replaces_location() {
+        Router::new().register("/", noop).start();
+        with_active(|router| redirect(router, "/", "/login"));
+        assert_eq!(pending_location(), Some(LocationUpdate::Replace));
+        assert_eq!(current_path().as_deref(), Some("/login"));
+        assert_eq!(history_depth(), 1);
+    }
+
+    #[test]
+    fn redirect_collapsing_into_previous_goes_back() {
+        Router::new().register("/", noop).start();
+        with_active(|router| {
+            router.history = History::new("/login".into());
+            router.history.push("/".into());
+            redirect(router, "/", "/login");
+        });
+        assert_eq!(pending_location(), Some(LocationUpdate::Back));
+        assert_eq!(history_depth(), 1);
+    }
+
+    #[test]
+    fn redirect_before_location_update_keeps_push() {
+        Router::new().register("/", noop).start();
+        with_active(|router| {
+            router.history.push("/premium".into());
+            redirect(router, "/premium", "/");
+        });
+        assert_eq!(pending_location(), Some(LocationUpdate::Push));
+        assert_eq!(current_path().as_deref(), Some("/"));
+    }
+
+    fn deny(_ctx: &TelegramContext) -> GuardOutcome {
+        GuardOutcome::redirect("/login")
+    }
+
+    fn location_hash() -> String {
+        web_sys::window()
+            .and_then(|win| win.location().hash().ok())
+            .unwrap_or_default()
+    }
+
+    #[wasm_bindgen_test]
+    async fn redirect_replaces_browser_entry() {
+        let pause = || sleep(Duration::from_millis(50));
+        if TelegramContext::get(|_| ()).is_none() {
+            init_context(None);
+        }
+        Router::new()
+            .register("/", noop)
+            .register("/login", noop)
+            .register_guarded("/premium", noop, deny)
+            .start();
+        let length = history_length();
+        if let Some(win) = web_sys::window() {
+            win.location().set_hash("#/premium").expect("set hash");
+        }
+        pause().await;
+        assert_eq!(current_path().as_deref(), Some("/login"));
+        assert_eq!(location_hash(), "#/login");
+        assert_eq!(history_length(), length + 1);
+    }
+
+    #[test]
+    fn location_change_from_other_mode_is_ignored() {
+        Router::new()
+            .register("/", noop)
+            .with_mode(RouterMode::History)
+            .start();
+        handle_location_change(RouterMode::Hash, "/a".into());
+        assert_eq!(history_depth(), 1);
+        handle_location_change(RouterMode::History, "/a".into());
+        assert_eq!(history_depth(), 2);
+    }
 }
@@ -0,0 +1,222 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Browser glue for the router.
+//!
+//! Everything touching `window` or `Telegram.WebApp` lives here so the routing
+//! logic itself stays target independent. Non-wasm builds get inert stubs,
+//! which keeps the router usable from native unit tests.
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use std::cell::Cell;
+
+    use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
+    use web_sys::window;
+
+    use crate::{
+        logger,
+        router::{PageFuture, RouteMeta, RouterMode, normalize_path, path_from_hash},
+        webapp::{EventHandle, TelegramWebApp}
+    };
+
+    thread_local! {
+        static HASH_LISTENING: Cell<bool> = const { Cell::new(false) };
+        static POPSTATE_LISTENING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Returns the path of the current location for `mode`.
+    pub(in crate::router) fn initial_path(mode: RouterMode) -> String {
+        let Some(location) = window().map(|w| w.location()) else {
+            return "/".to_owned();
+        };
+        match mode {
+            RouterMode::Hash => location.hash().ok().map(|hash| path_from_hash(&hash)),
+            RouterMode::History => location.pathname().ok().map(|path| normalize_path(&path))
+        }
+        .unwrap_or_else(|| "/".to_owned())
+    }
+
+    /// Writes `path` into the location if it differs from the current one.
+    pub(in crate::router) fn set_location(mode: RouterMode, path: &str) {
+        if initial_path(mode) == path {
+            return;
+        }
+        let Some(win) = window() else {
+            return;
+        };
+        let result = match mode {
+            RouterMode::Hash => win.location().set_hash(&format!("#{path}")),
+            RouterMode::History => win
+                .history()
+                .and_then(|history| history.push_state_with_url(&JsValue::NULL, "", Some(path)))
+        };
+        if let Err(err) = result {
+            logger::error(&format!("router: failed to update location: {err:?}"));
+        }
+    }
+
+    /// Overwrites the current location entry with `path`.
+    ///
+    /// Used when the router replaced a path the browser already shows, so no
+    /// stale entry is left for the back gesture to return to.
+    pub(in crate::router) fn replace_location(mode: RouterMode, path: &str) {
+        if initial_path(mode) == path {
+            return;
+        }
+        let Some(win) = window() else {
+            return;
+        };
+        let result = match mode {
+            RouterMode::Hash => win.location().replace(&format!("#{path}")),
+            RouterMode::History => win
+                .history()
+                .and_then(|history| history.replace_state_with_url(&JsValue::NULL, "", Some(path)))
+        };
+        if let Err(err) = result {
+            logger::error(&format!("router: failed to replace location: {err:?}"));
+        }
+    }
+
+    /// Steps the browser history back one entry to mirror a router pop.
+    ///
+    /// Writing the previous path instead would push a new entry, letting the
+    /// browser history grow away from the router's.
+    pub(in crate::router) fn go_back() {
+        let Some(win) = window() else {
+            return;
+        };
+        if let Err(err) = win.history().and_then(|history| history.back()) {
+            logger::error(&format!("router: failed to go back: {err:?}"));
+        }
+    }
+
+    /// Installs a `hashchange` or `popstate` listener forwarding the new path
+    /// to `on_change`. Only the first call per mode has an effect.
+    pub(in crate::router) fn listen(mode: RouterMode, on_change: fn(RouterMode, String)) {
+        let (flag, event) = match mode {
+            RouterMode::Hash => (&HASH_LISTENING, "hashchange"),
+            RouterMode::History => (&POPSTATE_LISTENING, "popstate")
+        };
+        if flag.with(|flag| flag.replace(true)) {
+            return;
+        }
+        let Some(win) = window() else {
+            return;
+        };
+        let closure = Closure::<dyn FnMut()>::new(move || {
+            on_change(mode, initial_path(mode));
+        });
+        if let Err(err) =
+            win.add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+        {
+            logger::error(&format!("router: failed to listen for {event}: {err:?}"));
+        }
+        // The router lives for the whole session.
+        closure.forget();
+    }
+
+    /// Routes native back button clicks to `on_click`.
+    pub(in crate::router) fn bind_back_button(on_click: fn()) -> Option<EventHandle<dyn FnMut()>> {
+        let app = TelegramWebApp::instance()?;
+        app.set_back_button_callback(on_click)
+            .inspect_err(|err| {
+                logger::error(&format!("router: back button binding failed: {err:?}"))
+            })
+            .ok()
+    }
+
+    /// Shows or hides the native back button.
+    pub(in crate::router) fn set_back_button_visible(visible: bool) {
+        let Some(app) = TelegramWebApp::instance() else {
+            return;
+        };
+        let result = if visible {
+            app.show_back_button()
+        } else {
+            app.hide_back_button()
+        };
+        if let Err(err) = result {
+            logger::error(&format!("router: back button toggle failed: {err:?}"));
+        }
+    }
+
+    /// Applies the fields set in `meta` to the document and the Mini App.
+    pub(in crate::router) fn apply_meta(meta: &RouteMeta) {
+        if let Some(title) = meta.title
+            && let Some(document) = window().and_then(|w| w.document())
+        {
+            document.set_title(title);
+        }
+        let Some(app) = TelegramWebApp::instance() else {
+            return;
+        };
+        if let Some(color) = meta.header_color
+            && let Err(err) = app.set_header_color(color)
+        {
+            logger::error(&format!("router: failed to set header color: {err:?}"));
+        }
+        let result = match meta.closing_confirmation {
+            Some(true) => app.enable_closing_confirmation(),
+            Some(false) => app.disable_closing_confirmation(),
+            None => Ok(())
+        };
+        if let Err(err) = result {
+            logger::error(&format!(
+                "router: failed to toggle closing confirmation: {err:?}"
+            ));
+        }
+    }
+
+    /// Spawns `future` on the browser event loop.
+    pub(in crate::router) fn spawn(future: PageFuture) {
+        wasm_bindgen_futures::spawn_local(future);
+    }
+
+    /// Logs a router warning to the console.
+    pub(in crate::router) fn warn(msg: &str) {
+        logger::warn(msg);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod imp {
+    use std::task::{Context, Waker};
+
+    use crate::{
+        router::{PageFuture, RouteMeta, RouterMode},
+        webapp::EventHandle
+    };
+
+    pub(in crate::router) fn initial_path(_mode: RouterMode) -> String {
+        "/".to_owned()
+    }
+
+    pub(in crate::router) fn set_location(_mode: RouterMode, _path: &str) {}
+
+    pub(in crate::router) fn replace_location(_mode: RouterMode, _path: &str) {}
+
+    pub(in crate::router) fn go_back() {}
+
+    pub(in crate::router) fn listen(_mode: RouterMode, _on_change: fn(RouterMode, String)) {}
+
+    pub(in crate::router) fn bind_back_button(
+        _on_click: fn()
+    ) -> Option<EventHandle<dyn FnMut()>> {
+        None
+    }
+
+    pub(in crate::router) fn set_back_button_visible(_visible: bool) {}
+
+    pub(in crate::router) fn apply_meta(_meta: &RouteMeta) {}
+
+    pub(in crate::router) fn spawn(mut future: PageFuture) {
+        let _ = future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()));
+    }
+
+    pub(in crate::router) fn warn(_msg: &str) {}
+}
+
+pub(super) use imp::*;
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use serde_json::Value;
+
+/// Single visited location with the state it was opened with.
+#[derive(Debug)]
+struct Entry {
+    path:  String,
+    state: Option<Value>
+}
+
+impl Entry {
+    fn new(path: String) -> Self {
+        Self {
+            path,
+            state: None
+        }
+    }
+}
+
+/// Stack of visited paths maintained by the active router.
+///
+/// The bottom entry is the route the router was started on and is never
+/// popped, so the stack always describes a valid current location.
+#[derive(Debug)]
+pub(super) struct History {
+    entries: Vec<Entry>
+}
+
+impl History {
+    /// Creates a history rooted at `path`.
+    pub(super) fn new(path: String) -> Self {
+        Self {
+            entries: vec![Entry::new(path)]
+        }
+    }
+
+    /// Returns the path on top of the stack.
+    pub(super) fn current(&self) -> &str {
+        self.entries
+            .last()
+            .map(|entry| entry.path.as_str())
+            .unwrap_or("/")
+    }
+
+    /// Returns the state attached to the current entry.
+    pub(super) fn state(&self) -> Option<&Value> {
+        self.entries.last().and_then(|entry| entry.state.as_ref())
+    }
+
+    /// Returns the path directly below the current one, if any.
+    pub(super) fn previous(&self) -> Option<&str> {
+        self.entries
+            .len()
+            .checked_sub(2)
+            .and_then(|idx| self.entries.get(idx))
+            .map(|entry| entry.path.as_str())
+    }
+
+    /// Returns the number of entries on the stack.
+    pub(super) fn depth(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Pushes `path` unless it is already the current entry.
+    ///
+    /// Returns `true` when the stack changed.
+    pub(super) fn push(&mut self, path: String) -> bool {
+        if self.current() == path {
+            return false;
+        }
+        self.entries.push(Entry::new(path));
+        true
+    }
+
+    /// Pushes `path` carrying `state`.
+    ///
+    /// When `path` is already current its state is replaced instead. Always
+    /// changes the stack.
+    pub(super) fn push_with_state(&mut self, path: String, state: Value) {
+        if self.current() != path {
+            self.entries.push(Entry::new(path));
+        }
+        if let Some(entry) = self.entries.last_mut() {
+            entry.state = Some(state);
+        }
+    }
+
+    /// Replaces the current entry with `path`.
+    ///
+    /// When `path` equals the previous entry the current one is popped
+    /// instead, so redirects never leave duplicate neighbours behind. Returns
+    /// `true` when the stack changed.
+    pub(super) fn replace(&mut self, path: String) -> bool {
+        if self.current() == path {
+            return false;
+        }
+        if self.previous() == Some(path.as_str()) {
+            return self.pop();
+        }
+        if let Some(last) = self.entries.last_mut() {
+            *last = Entry::new(path);
+        }
+        true
+    }
+
+    /// Pops the current entry unless it is the root.
+    ///
+    /// Returns `true` when the stack changed.
+    pub(super) fn pop(&mut self) -> bool {
+        if self.entries.len() <= 1 {
+            return false;
+        }
+        self.entries.pop();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::History;
+
+    #[test]
+    fn push_ignores_current_path() {
+        let mut history = History::new("/".into());
+        assert!(!history.push("/".into()));
+        assert!(history.push("/a".into()));
+        assert_eq!(history.depth(), 2);
+        assert_eq!(history.current(), "/a");
+        assert_eq!(history.previous(), Some("/"));
+    }
+
+    #[test]
+    fn replace_collapses_into_previous() {
+        let mut history = History::new("/".into());
+        history.push("/a".into());
+        assert!(history.replace("/b".into()));
+        assert_eq!(history.current(), "/b");
+        assert_eq!(history.depth(), 2);
+        assert!(history.replace("/".into()));
+        assert_eq!(history.depth(), 1);
+    }
+
+    #[test]
+    fn state_follows_entries() {
+        let mut history = History::new("/".into());
+        history.push_with_state("/a".into(), Value::from(1));
+        history.push_with_state("/a".into(), Value::from(2));
+        assert_eq!(history.depth(), 2);
+        assert_eq!(history.state(), Some(&Value::from(2)));
+        history.push("/b".into());
+        assert_eq!(history.state(), None);
+        history.pop();
+        assert_eq!(history.state(), Some(&Value::from(2)));
+    }
+
+    #[test]
+    fn pop_keeps_root() {
+        let mut history = History::new("/".into());
+        history.push("/a".into());
+        assert!(history.pop());
+        assert!(!history.pop());
+        assert_eq!(history.current(), "/");
+        assert_eq!(history.previous(), None);
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Short-lived session tokens for backends.
+//!
+//! Validating init data on every request means recomputing its signature
+//! against the bot token. Instead, a backend can validate the init data once
+//! (e.g. with [`init-data-rs`](https://docs.rs/init-data-rs)), issue a token
+//! with [`derive_token`] and check later requests with [`verify_token`].
+//!
+//! A token has the form `<user_id>.<exp>.<signature>`, where the signature is
+//! the URL-safe base64 HMAC-SHA256 of `<user_id>.<exp>` keyed by the server
+//! secret.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::core::types::{init_data_internal::TelegramInitDataInternal, user::TelegramUser};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Claims carried by a session token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SessionClaims {
+    /// Telegram id of the user the token was issued to.
+    pub user_id: u64,
+    /// Unix timestamp (in seconds) after which the token is rejected.
+    pub exp:     u64
+}
+
+/// Errors returned by [`derive_token`] and [`verify_token`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionError {
+    /// The init data is malformed or carries no user.
+    InvalidInitData(String),
+    /// The token is not of the form `<user_id>.<exp>.<signature>`.
+    Malformed,
+    /// The signature does not match the secret.
+    BadSignature,
+    /// The token has expired.
+    Expired
+}
+
+impl std::fmt::Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidInitData(msg) => write!(f, "Invalid init data: {msg}"),
+            Self::Malformed => write!(f, "Malformed session token"),
+            Self::BadSignature => write!(f, "Session token signature mismatch"),
+            Self::Expired => write!(f, "Session token expired")
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+/// Issues a token for the user in `init_data`, valid for `ttl`.
+///
+/// `init_data` is the raw URL-encoded init data. It is **not** validated
+/// here; validate it against the bot token before issuing a session.
+///
+/// # Errors
+///
+/// Returns [`SessionError::InvalidInitData`] when `init_data` cannot be
+/// parsed or carries no user.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::session::{derive_token, verify_token};
+///
+/// let init_data = "user=%7B%22id%22%3A42%2C%22first_name%22%3A%22A%22%7D&auth_date=1&hash=h";
+/// let token = derive_token(init_data, b"server-secret", Duration::from_secs(3600)).unwrap();
+/// assert_eq!(verify_token(&token, b"server-secret").unwrap().user_id, 42);
+/// ```
+pub fn derive_token(
+    init_data: &str,
+    secret: &[u8],
+    ttl: Duration
+) -> Result<String, SessionError> {
+    derive_token_at(init_data, secret, ttl, now())
+}
+
+/// Verifies `token` against `secret` and returns its claims.
+///
+/// # Errors
+///
+/// Returns [`SessionError::Malformed`], [`SessionError::BadSignature`] or
+/// [`SessionError::Expired`].
+pub fn verify_token(token: &str, secret: &[u8]) -> Result<SessionClaims, SessionError> {
+    verify_token_at(token, secret, now())
+}
+
+fn derive_token_at(
+    init_data: &str,
+    secret: &[u8],
+    ttl: Duration,
+    now: u64
+) -> Result<String, SessionError> {
+    let raw: TelegramInitDataInternal = serde_urlencoded::from_str(init_data)
+        .map_err(|e| SessionError::InvalidInitData(e.to_string()))?;
+    let user = raw
+        .user
+        .ok_or_else(|| SessionError::InvalidInitData("missing user".to_owned()))?;
+    let user: TelegramUser = serde_json::from_str(&user)
+        .map_err(|e| SessionError::InvalidInitData(format!("Failed to parse user: {e}")))?;
+    let payload = format!("{}.{}", user.id, now.saturating_add(ttl.as_secs()));
+    let signature = URL_SAFE_NO_PAD.encode(mac(secret, &payload).finalize().into_bytes());
+    Ok(format!("{payload}.{signature}"))
+}
+
+fn verify_token_at(token: &str, secret: &[u8], now: u64) -> Result<SessionClaims, SessionError> {
+    let (payload, signature) = token.rsplit_once('.').ok_or(SessionError::Malformed)?;
+    let (user_id, exp) = payload.split_once('.').ok_or(SessionError::Malformed)?;
+    let claims = SessionClaims {
+        user_id: user_id.parse().map_err(|_| SessionError::Malformed)?,
+        exp:     exp.parse().map_err(|_| SessionError::Malformed)?
+    };
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| SessionError::Malformed)?;
+    mac(secret, payload)
+        .verify_slice(&signature)
+        .map_err(|_| SessionError::BadSignature)?;
+    if claims.exp <= now {
+        return Err(SessionError::Expired);
+    }
+    Ok(claims)
+}
+
+fn mac(secret: &[u8], payload: &str) -> HmacSha256 {
+    // HMAC accepts keys of any length.
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC key of any size");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INIT_DATA: &str =
+        "user=%7B%22id%22%3A42%2C%22first_name%22%3A%22A%22%7D&auth_date=1&hash=h";
+    const SECRET: &[u8] = b"secret";
+
+    #[test]
+    fn round_trip() {
+        let token = derive_token_at(INIT_DATA, SECRET, Duration::from_secs(60), 1000).unwrap();
+        assert!(token.starts_with("42.1060."));
+        assert_eq!(
+            verify_token_at(&token, SECRET, 1059),
+            Ok(SessionClaims {
+                user_id: 42,
+                exp:     1060
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_expired_and_tampered_tokens() {
+        let token = derive_token_at(INIT_DATA, SECRET, Duration::from_secs(60), 1000).unwrap();
+        assert_eq!(
+            verify_token_at(&token, SECRET, 1060),
+            Err(SessionError::Expired)
+        );
+        assert_eq!(
+            verify_token_at(&token, b"other", 1000),
+            Err(SessionError::BadSignature)
+        );
+        let forged = token.replacen("42.", "43.", 1);
+        assert_eq!(
+            verify_token_at(&forged, SECRET, 1000),
+            Err(SessionError::BadSignature)
+        );
+        assert_eq!(
+            verify_token_at("garbage", SECRET, 1000),
+            Err(SessionError::Malformed)
+        );
+    }
+
+    #[test]
+    fn requires_user() {
+        assert!(matches!(
+            derive_token_at("auth_date=1&hash=h", SECRET, Duration::ZERO, 0),
+            Err(SessionError::InvalidInitData(_))
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Sharing prepared inline messages.
+//!
+//! The flow spans both halves of a Mini App: the client asks its backend for
+//! a message, the backend calls the Bot API `savePreparedInlineMessage` and
+//! answers with a [`PreparedMessage`], and the client passes its id to
+//! `WebApp.shareMessage`. [`share_prepared`] runs the client half. The serde
+//! types are shared with the backend, where the `teloxide` feature provides
+//! `teloxide::prepare_message` for the Bot API call.
+
+use std::{cell::RefCell, future::Future, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::webapp::TelegramWebApp;
+
+/// Prepared inline message returned by the backend, mirroring the Bot API
+/// `PreparedInlineMessage`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreparedMessage {
+    /// Identifier passed to `WebApp.shareMessage`.
+    pub id:              String,
+    /// Unix timestamp after which the message can no longer be shared.
+    pub expiration_date: u64
+}
+
+/// Chat types the user may pick when sharing, mirroring the `allow_*_chats`
+/// parameters of `savePreparedInlineMessage`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareTargets {
+    /// Private chats with users.
+    #[serde(default)]
+    pub allow_user_chats:    bool,
+    /// Private chats with bots.
+    #[serde(default)]
+    pub allow_bot_chats:     bool,
+    /// Groups and supergroups.
+    #[serde(default)]
+    pub allow_group_chats:   bool,
+    /// Channels.
+    #[serde(default)]
+    pub allow_channel_chats: bool
+}
+
+impl ShareTargets {
+    /// Allows every chat type.
+    pub const ALL: Self = Self {
+        allow_user_chats:    true,
+        allow_bot_chats:     true,
+        allow_group_chats:   true,
+        allow_channel_chats: true
+    };
+}
+
+/// Errors produced by [`share_prepared`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShareError {
+    /// The client does not support `shareMessage` (Bot API 8.0+).
+    Unsupported,
+    /// The backend failed to prepare the message.
+    Prepare(String),
+    /// The prepared message has expired.
+    Expired,
+    /// Telegram failed to send the message.
+    SendFailed,
+    /// The user closed the share dialog.
+    Declined,
+    /// Telegram reported another error code.
+    Unknown(String),
+    /// The underlying JS call failed.
+    Js(String)
+}
+
+impl ShareError {
+    /// Maps the `error` of a `shareMessageFailed` event.
+    fn from_code(code: &str) -> Self {
+        match code {
+            "UNSUPPORTED" => Self::Unsupported,
+            "MESSAGE_EXPIRED" => Self::Expired,
+            "MESSAGE_SEND_FAILED" => Self::SendFailed,
+            "USER_DECLINED" => Self::Declined,
+            other => Self::Unknown(other.to_owned())
+        }
+    }
+}
+
+impl std::fmt::Display for ShareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "Sharing messages is not supported"),
+            Self::Prepare(msg) => write!(f, "Failed to prepare message: {msg}"),
+            Self::Expired => write!(f, "Prepared message has expired"),
+            Self::SendFailed => write!(f, "Failed to send message"),
+            Self::Declined => write!(f, "User declined to share"),
+            Self::Unknown(code) => write!(f, "Sharing failed: {code}"),
+            Self::Js(msg) => write!(f, "Sharing failed: {msg}")
+        }
+    }
+}
+
+impl std::error::Error for ShareError {}
+
+impl From<ShareError> for JsValue {
+    fn from(err: ShareError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Asks the backend for a prepared message with `prepare` and shares it.
+///
+/// `prepare` usually posts to the backend, e.g. with
+/// [`TelegramFetch`](crate::http::TelegramFetch). The future resolves once the
+/// user has sent the message or closed the dialog.
+///
+/// # Errors
+///
+/// Returns [`ShareError::Unsupported`] outside Telegram or on clients older
+/// than Bot API 8.0, [`ShareError::Prepare`] when `prepare` fails and the
+/// error reported by Telegram otherwise.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::{
+///     http::TelegramFetch,
+///     share::{PreparedMessage, ShareError, ShareTargets, share_prepared}
+/// };
+///
+/// # async fn run() -> Result<(), ShareError> {
+/// let api = TelegramFetch::new("https://api.example.com");
+/// share_prepared(|| async {
+///     api.post::<_, PreparedMessage>("/share/score", &ShareTargets::ALL)
+///         .await
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn share_prepared<F, Fut, E>(prepare: F) -> Result<(), ShareError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<PreparedMessage, E>>,
+    E: std::fmt::Display
+{
+    let app = TelegramWebApp::instance().ok_or(ShareError::Unsupported)?;
+    if !app.is_version_at_least("8.0").unwrap_or(false) {
+        return Err(ShareError::Unsupported);
+    }
+    let message = prepare()
+        .await
+        .map_err(|err| ShareError::Prepare(err.to_string()))?;
+
+    let failure = Rc::new(RefCell::new(None));
+    let recorded = Rc::clone(&failure);
+    let _handle = app
+        .on_event("shareMessageFailed", move |payload: JsValue| {
+            let code = js_sys::Reflect::get(&payload, &"error".into())
+                .ok()
+                .and_then(|v| v.as_string())
+                .unwrap_or_default();
+            *recorded.borrow_mut() = Some(code);
+        })
+        .map_err(js_error)?;
+    let sent = app.share_message(&message.id).await.map_err(js_error)?;
+    if sent {
+        return Ok(());
+    }
+    Err(failure
+        .borrow_mut()
+        .take()
+        .map_or(ShareError::Declined, |code| ShareError::from_code(&code)))
+}
+
+fn js_error(err: JsValue) -> ShareError {
+    ShareError::Js(err.as_string().unwrap_or_else(|| format!("{err:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_map_to_variants() {
+        assert_eq!(ShareError::from_code("USER_DECLINED"), ShareError::Declined);
+        assert_eq!(
+            ShareError::from_code("MESSAGE_EXPIRED"),
+            ShareError::Expired
+        );
+        assert_eq!(
+            ShareError::from_code("NEW_CODE"),
+            ShareError::Unknown("NEW_CODE".into())
+        );
+    }
+
+    #[test]
+    fn shared_types_match_bot_api() {
+        let message: PreparedMessage =
+            serde_json::from_str(r#"{"id":"abc","expiration_date":1700000000}"#).unwrap();
+        assert_eq!(message.id, "abc");
+        assert_eq!(
+            serde_json::from_str::<ShareTargets>(r#"{"allow_user_chats":true}"#).unwrap(),
+            ShareTargets {
+                allow_user_chats: true,
+                ..ShareTargets::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+/// [`bottom_button::BottomButton`] component driving the main/secondary button.
+pub mod bottom_button;
+/// [`theme::use_theme`] hook exposing Telegram theme parameters reactively.
+pub mod theme;
+/// [`viewport::use_viewport`] hook exposing viewport size and state reactively.
+pub mod viewport;
+
+use std::rc::Rc;
+
+pub use bottom_button::{BottomButton, BottomButtonProps};
+use sycamore::prelude::{provide_context, try_use_context};
+pub use theme::{ThemeState, use_theme};
+pub use viewport::{ViewportState, use_viewport};
+use wasm_bindgen::JsValue;
+
+use crate::core::{context::TelegramContext, safe_context::get_shared_context};
+
+/// Provides the [`TelegramContext`] to the current reactive scope.
+///
+/// # Errors
+///
+/// Returns an error if the global context has not been initialized with
+/// [`TelegramContext::init`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use telegram_webapp_sdk::sycamore::{provide_telegram_context, use_shared_telegram_context};
+///
+/// #[component]
+/// fn App() -> View {
+///     provide_telegram_context().expect("context");
+///     let ctx = use_shared_telegram_context().expect("context");
+///     view! { span { (ctx.init_data.auth_date) } }
+/// }
+/// ```
+pub fn provide_telegram_context() -> Result<(), JsValue> {
+    provide_context(get_shared_context()?);
+    Ok(())
+}
+
+/// Returns a copy of the [`TelegramContext`] provided by an ancestor scope,
+/// falling back to the global context.
+///
+/// Prefer [`use_shared_telegram_context`], which avoids the copy.
+///
+/// # Errors
+///
+/// Returns an error if no ancestor called [`provide_telegram_context`] and
+/// the global context has not been initialized.
+pub fn use_telegram_context() -> Result<TelegramContext, JsValue> {
+    use_shared_telegram_context().map(|ctx| (*ctx).clone())
+}
+
+/// Returns a shared handle to the [`TelegramContext`] provided by
+/// an ancestor scope, falling back to the global context.
+///
+/// # Errors
+///
+/// Returns an error if no ancestor called [`provide_telegram_context`] and
+/// the global context has not been initialized.
+pub fn use_shared_telegram_context() -> Result<Rc<TelegramContext>, JsValue> {
+    match try_use_context::<Rc<TelegramContext>>() {
+        Some(ctx) => Ok(ctx),
+        None => get_shared_context()
+    }
+}
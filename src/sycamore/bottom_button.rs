@@ -0,0 +1,196 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::borrow::Cow;
+
+use sycamore::prelude::*;
+
+use crate::{
+    logger,
+    webapp::{BottomButton as WebBottomButton, BottomButtonParams, TelegramWebApp}
+};
+
+/// Props for [`BottomButton`].
+#[derive(Props)]
+pub struct BottomButtonProps {
+    /// Text label displayed on the button.
+    #[prop(setter(into))]
+    pub text:       MaybeDyn<Cow<'static, str>>,
+    /// Optional background color as a `#RRGGBB` hex string.
+    #[prop(setter(into))]
+    pub color:      Option<MaybeDyn<Cow<'static, str>>>,
+    /// Optional text color as a `#RRGGBB` hex string.
+    #[prop(setter(into))]
+    pub text_color: Option<MaybeDyn<Cow<'static, str>>>,
+    /// Optional flag showing the loading indicator; the button is inactive
+    /// while it is shown.
+    #[prop(setter(into))]
+    pub progress:   Option<MaybeDyn<bool>>,
+    /// Optional flag; `false` disables the button.
+    #[prop(setter(into))]
+    pub enabled:    Option<MaybeDyn<bool>>,
+    /// Optional flag enabling the shine animation.
+    #[prop(setter(into))]
+    pub shine:      Option<MaybeDyn<bool>>,
+    /// Optional callback invoked when the button is clicked.
+    #[prop(setter(transform = |f: impl Fn() + 'static| Some(Box::new(f) as Box<dyn Fn()>)))]
+    pub on_click:   Option<Box<dyn Fn()>>,
+    /// Which bottom button to control; defaults to the main button.
+    #[prop(default = WebBottomButton::Main)]
+    pub button:     WebBottomButton
+}
+
+/// Sycamore component that controls a Telegram bottom button.
+///
+/// The component shows the selected bottom button and keeps its text, colors
+/// and state in sync with the provided values, which may be static or
+/// reactive. The click callback is removed and the button hidden when the
+/// reactive scope is disposed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use telegram_webapp_sdk::{
+///     sycamore::BottomButton,
+///     webapp::{BottomButton as Btn, TelegramWebApp}
+/// };
+///
+/// #[component]
+/// fn App() -> View {
+///     let sending = create_signal(false);
+///     view! {
+///         BottomButton(
+///             button=Btn::Main,
+///             text="Send",
+///             progress=sending,
+///             on_click=move || {
+///                 sending.set(true);
+///                 if let Some(app) = TelegramWebApp::instance() {
+///                     let _ = app.send_data("clicked");
+///                 }
+///             }
+///         )
+///     }
+/// }
+/// ```
+#[component]
+pub fn BottomButton(props: BottomButtonProps) -> View {
+    let BottomButtonProps {
+        text,
+        color,
+        text_color,
+        progress,
+        enabled,
+        shine,
+        on_click,
+        button
+    } = props;
+
+    let Some(app) = TelegramWebApp::instance() else {
+        logger::error("TelegramWebApp instance not available");
+        return View::default();
+    };
+
+    if let Err(err) = app.show_bottom_button(button) {
+        logger::error(&format!("show_bottom_button failed: {err:?}"));
+    }
+
+    // Update text when it changes.
+    {
+        let app = app.clone();
+        create_effect(move || {
+            if let Err(err) = app.set_bottom_button_text(button, &text.get_clone()) {
+                logger::error(&format!("set_bottom_button_text failed: {err:?}"));
+            }
+        });
+    }
+
+    // Update button color.
+    if let Some(color) = color {
+        let app = app.clone();
+        create_effect(move || {
+            if let Err(err) = app.set_bottom_button_color(button, &color.get_clone()) {
+                logger::error(&format!("set_bottom_button_color failed: {err:?}"));
+            }
+        });
+    }
+
+    // Update text color.
+    if let Some(text_color) = text_color {
+        let app = app.clone();
+        create_effect(move || {
+            if let Err(err) = app.set_bottom_button_text_color(button, &text_color.get_clone()) {
+                logger::error(&format!("set_bottom_button_text_color failed: {err:?}"));
+            }
+        });
+    }
+
+    // Toggle the loading indicator.
+    if let Some(progress) = progress {
+        let app = app.clone();
+        create_effect(move || {
+            let result = if progress.get() {
+                app.show_bottom_button_progress(button, false)
+            } else {
+                app.hide_bottom_button_progress(button)
+            };
+            if let Err(err) = result {
+                logger::error(&format!("bottom button progress update failed: {err:?}"));
+            }
+        });
+    }
+
+    // Enable or disable the button.
+    if let Some(enabled) = enabled {
+        let app = app.clone();
+        create_effect(move || {
+            let result = if enabled.get() {
+                app.enable_bottom_button(button)
+            } else {
+                app.disable_bottom_button(button)
+            };
+            if let Err(err) = result {
+                logger::error(&format!("bottom button enable/disable failed: {err:?}"));
+            }
+        });
+    }
+
+    // Update the shine effect.
+    if let Some(shine) = shine {
+        let app = app.clone();
+        create_effect(move || {
+            let params = BottomButtonParams {
+                has_shine_effect: Some(shine.get()),
+                ..Default::default()
+            };
+            if let Err(err) = app.set_bottom_button_params(button, &params) {
+                logger::error(&format!("set_bottom_button_params failed: {err:?}"));
+            }
+        });
+    }
+
+    // Register click callback if provided and keep handle for cleanup.
+    let handle = on_click.and_then(|cb| {
+        app.set_bottom_button_callback(button, cb)
+            .inspect_err(|err| {
+                logger::error(&format!("set_bottom_button_callback failed: {err:?}"));
+            })
+            .ok()
+    });
+
+    // Cleanup: remove callback and hide button when the scope is disposed.
+    on_cleanup(move || {
+        if let Some(handle) = handle
+            && let Err(err) = app.remove_bottom_button_callback(handle)
+        {
+            logger::error(&format!("remove_bottom_button_callback failed: {err:?}"));
+        }
+        if let Err(err) = app.hide_bottom_button(button) {
+            logger::error(&format!("hide_bottom_button failed: {err:?}"));
+        }
+    });
+
+    // Component renders no DOM nodes.
+    View::default()
+}
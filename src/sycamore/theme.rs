@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use sycamore::prelude::*;
+
+use crate::{
+    api::theme::get_theme_params, core::types::theme_params::TelegramThemeParams,
+    webapp::TelegramWebApp
+};
+
+/// Snapshot of `Telegram.WebApp` theme state.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ThemeState {
+    /// `"light"` or `"dark"`.
+    pub color_scheme: Option<String>,
+    /// Parsed theme palette.
+    pub params:       TelegramThemeParams
+}
+
+impl ThemeState {
+    fn snapshot(app: Option<&TelegramWebApp>) -> Self {
+        let color_scheme = app.and_then(|a| a.color_scheme());
+        let params = app
+            .and_then(|_| get_theme_params().ok())
+            .unwrap_or_default();
+        Self {
+            color_scheme,
+            params
+        }
+    }
+}
+
+/// Sycamore reactive hook over `Telegram.WebApp` theme state.
+///
+/// Updates on `themeChanged`. The subscription is removed when the reactive
+/// scope is disposed.
+///
+/// # Examples
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use telegram_webapp_sdk::sycamore::use_theme;
+///
+/// #[component]
+/// fn ThemeBadge() -> View {
+///     let theme = use_theme();
+///     view! { span { (theme.with(|t| t.color_scheme.clone().unwrap_or_default())) } }
+/// }
+/// ```
+pub fn use_theme() -> ReadSignal<ThemeState> {
+    let app = TelegramWebApp::instance();
+    let signal = create_signal(ThemeState::snapshot(app.as_ref()));
+
+    if let Some(app) = app {
+        let app_for_handler = app.clone();
+        if let Ok(handle) = app.on_theme_changed(move || {
+            signal.set(ThemeState::snapshot(Some(&app_for_handler)));
+        }) {
+            on_cleanup(move || drop(handle));
+        }
+    }
+
+    *signal
+}
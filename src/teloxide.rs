@@ -0,0 +1,371 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Bot-side helpers built on `teloxide-core`.
+//!
+//! Decodes `web_app_data` with the same [`WebAppPayload`] or
+//! [`WebAppMessage`] type the mini app sends, builds `web_app` keyboard
+//! buttons pointing at router paths and answers Web App queries, so the bot and
+//! the mini app share one crate.
+
+use serde::de::DeserializeOwned;
+use teloxide_core::{
+    payloads::SavePreparedInlineMessageSetters,
+    requests::{Request, Requester},
+    types::{
+        InlineKeyboardButton, InlineKeyboardMarkup, InlineQueryResult, InlineQueryResultArticle,
+        InputMessageContent, InputMessageContentText, Message, SentWebAppMessage, UserId,
+        WebAppInfo
+    }
+};
+use url::Url;
+
+use crate::{
+    core::payload::{PayloadError, WebAppPayload},
+    messages::{self, MessageError, WebAppMessage},
+    router::RouterMode,
+    share::{PreparedMessage, ShareTargets}
+};
+
+/// Id of the article sent by [`answer_web_app_query_text`].
+const ANSWER_ID: &str = "web_app_answer";
+
+/// Decodes the `web_app_data` carried by `message` into `T`.
+///
+/// Returns `None` when the message carries no Web App data.
+///
+/// # Errors
+///
+/// The inner result is [`PayloadError`] when the data exceeds
+/// [`WebAppPayload::MAX_BYTES`], is not valid JSON for `T` or fails
+/// validation.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::{core::payload::WebAppPayload, teloxide::web_app_data};
+/// use teloxide_core::types::Message;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct OrderData {
+///     id:   u32,
+///     name: String
+/// }
+///
+/// impl WebAppPayload for OrderData {}
+///
+/// fn handle(message: &Message) {
+///     if let Some(Ok(order)) = web_app_data::<OrderData>(message) {
+///         println!("Order #{}: {}", order.id, order.name);
+///     }
+/// }
+/// ```
+pub fn web_app_data<T>(message: &Message) -> Option<Result<T, PayloadError>>
+where
+    T: WebAppPayload + DeserializeOwned
+{
+    message
+        .web_app_data()
+        .map(|data| T::from_send_data(&data.data))
+}
+
+/// Decodes the versioned [`WebAppMessage`] carried by `message`, as sent with
+/// [`messages::send_to_bot`].
+///
+/// Returns `None` when the message carries no Web App data.
+///
+/// # Errors
+///
+/// The inner result is [`MessageError`] when the schema version differs or
+/// the data is not a valid `T`.
+pub fn web_app_message<T: WebAppMessage>(message: &Message) -> Option<Result<T, MessageError>> {
+    message
+        .web_app_data()
+        .map(|data| messages::decode(&data.data))
+}
+
+/// Builds links into a mini app for keyboard buttons.
+///
+/// Paths are placed where the mini app's [`Router`](crate::router::Router)
+/// reads them, according to its [`RouterMode`].
+///
+/// # Examples
+///
+/// ```
+/// use telegram_webapp_sdk::teloxide::WebAppLinks;
+/// use url::Url;
+///
+/// let base = Url::parse("https://example.com/index.html").unwrap();
+/// let links = WebAppLinks::new(base);
+/// assert_eq!(
+///     links.url("/burger-king").as_str(),
+///     "https://example.com/index.html#/burger-king"
+/// );
+/// let keyboard = links.keyboard([("Menu", "/burger-king"), ("Init data", "/init-data")]);
+/// assert_eq!(keyboard.inline_keyboard.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebAppLinks {
+    base: Url,
+    mode: RouterMode
+}
+
+impl WebAppLinks {
+    /// Creates links into the mini app served at `base`, using
+    /// [`RouterMode::Hash`].
+    pub fn new(base: Url) -> Self {
+        Self {
+            base,
+            mode: RouterMode::Hash
+        }
+    }
+
+    /// Sets how the mini app's router stores paths.
+    pub fn mode(mut self, mode: RouterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the URL opening `path` in the mini app.
+    pub fn url(&self, path: &str) -> Url {
+        let path = format!("/{}", path.trim_start_matches('/'));
+        let mut url = self.base.clone();
+        match self.mode {
+            RouterMode::Hash => url.set_fragment(Some(&path)),
+            RouterMode::History => {
+                url.set_path(&path);
+                url.set_fragment(None);
+            }
+        }
+        url
+    }
+
+    /// Returns a `web_app` button opening `path`.
+    pub fn button(&self, text: impl Into<String>, path: &str) -> InlineKeyboardButton {
+        InlineKeyboardButton::web_app(
+            text,
+            WebAppInfo {
+                url: self.url(path)
+            }
+        )
+    }
+
+    /// Returns a keyboard with one `web_app` button per row, given as
+    /// `(text, path)` pairs.
+    pub fn keyboard<'a, T>(
+        &self,
+        buttons: impl IntoIterator<Item = (T, &'a str)>
+    ) -> InlineKeyboardMarkup
+    where
+        T: Into<String>
+    {
+        InlineKeyboardMarkup::new(
+            buttons
+                .into_iter()
+                .map(|(text, path)| vec![self.button(text, path)])
+        )
+    }
+}
+
+/// Answers the Web App query `query_id` with `result`.
+///
+/// `query_id` is the `query_id` from the mini app's init data, forwarded to
+/// the bot by the mini app.
+///
+/// # Errors
+///
+/// Returns the requester's error when the request fails.
+pub async fn answer_web_app_query<R>(
+    bot: &R,
+    query_id: impl Into<String>,
+    result: InlineQueryResult
+) -> Result<SentWebAppMessage, R::Err>
+where
+    R: Requester
+{
+    bot.answer_web_app_query(query_id, result).send().await
+}
+
+/// Answers the Web App query `query_id` with a text message titled `title`.
+///
+/// # Errors
+///
+/// Returns the requester's error when the request fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::teloxide::answer_web_app_query_text;
+/// use teloxide_core::Bot;
+///
+/// # async fn example(bot: Bot, query_id: String) -> Result<(), teloxide_core::RequestError> {
+/// answer_web_app_query_text(&bot, query_id, "Order", "Order #42 placed").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn answer_web_app_query_text<R>(
+    bot: &R,
+    query_id: impl Into<String>,
+    title: impl Into<String>,
+    text: impl Into<String>
+) -> Result<SentWebAppMessage, R::Err>
+where
+    R: Requester
+{
+    let article = InlineQueryResultArticle::new(
+        ANSWER_ID,
+        title,
+        InputMessageContent::Text(InputMessageContentText::new(text))
+    );
+    answer_web_app_query(bot, query_id, InlineQueryResult::Article(article)).await
+}
+
+/// Saves `result` as a prepared message that `user_id` can share to
+/// `targets`, ready to be returned to
+/// [`share_prepared`](crate::share::share_prepared).
+///
+/// # Errors
+///
+/// Returns the requester's error when the request fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::{share::ShareTargets, teloxide::prepare_message};
+/// use teloxide_core::{
+///     Bot,
+///     types::{
+///         InlineQueryResult, InlineQueryResultArticle, InputMessageContent,
+///         InputMessageContentText, UserId
+///     }
+/// };
+///
+/// # async fn example(bot: Bot, user_id: UserId) -> Result<(), teloxide_core::RequestError> {
+/// let result = InlineQueryResult::Article(InlineQueryResultArticle::new(
+///     "score",
+///     "My score",
+///     InputMessageContent::Text(InputMessageContentText::new("I scored 42!"))
+/// ));
+/// let prepared = prepare_message(&bot, user_id, result, ShareTargets::ALL).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn prepare_message<R>(
+    bot: &R,
+    user_id: UserId,
+    result: InlineQueryResult,
+    targets: ShareTargets
+) -> Result<PreparedMessage, R::Err>
+where
+    R: Requester
+{
+    let message = bot
+        .save_prepared_inline_message(user_id, result)
+        .allow_user_chats(targets.allow_user_chats)
+        .allow_bot_chats(targets.allow_bot_chats)
+        .allow_group_chats(targets.allow_group_chats)
+        .allow_channel_chats(targets.allow_channel_chats)
+        .send()
+        .await?;
+    Ok(PreparedMessage {
+        id:              message.id,
+        expiration_date: u64::try_from(message.expiration_date.timestamp()).unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use teloxide_core::types::InlineKeyboardButtonKind;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u32
+    }
+
+    impl WebAppPayload for Order {}
+
+    fn message(web_app_data: Option<&str>) -> Message {
+        let mut json = serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": 1, "type": "private", "first_name": "A" },
+            "text": "hi"
+        });
+        if let Some(data) = web_app_data {
+            let object = json.as_object_mut().unwrap();
+            object.remove("text");
+            object.insert(
+                "web_app_data".into(),
+                serde_json::json!({ "data": data, "button_text": "Order" })
+            );
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn web_app_data_decodes_payload() {
+        assert_eq!(
+            web_app_data::<Order>(&message(Some(r#"{"id":7}"#))),
+            Some(Ok(Order {
+                id: 7
+            }))
+        );
+        assert!(matches!(
+            web_app_data::<Order>(&message(Some("nope"))),
+            Some(Err(PayloadError::Json(_)))
+        ));
+        assert_eq!(web_app_data::<Order>(&message(None)), None);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Command {
+        Ping
+    }
+
+    impl WebAppMessage for Command {}
+
+    #[test]
+    fn web_app_message_decodes_envelope() {
+        let data = messages::encode(&Command::Ping).unwrap();
+        assert_eq!(
+            web_app_message::<Command>(&message(Some(&data))),
+            Some(Ok(Command::Ping))
+        );
+        assert!(matches!(
+            web_app_message::<Command>(&message(Some(r#""Ping""#))),
+            Some(Err(MessageError::Payload(_)))
+        ));
+    }
+
+    #[test]
+    fn links_follow_router_mode() {
+        let base = Url::parse("https://example.com/app/index.html#old").unwrap();
+        let hash = WebAppLinks::new(base.clone());
+        assert_eq!(
+            hash.url("settings").as_str(),
+            "https://example.com/app/index.html#/settings"
+        );
+        let history = WebAppLinks::new(base).mode(RouterMode::History);
+        assert_eq!(
+            history.url("/item/42").as_str(),
+            "https://example.com/item/42"
+        );
+    }
+
+    #[test]
+    fn keyboard_has_one_web_app_button_per_row() {
+        let links = WebAppLinks::new(Url::parse("https://example.com/").unwrap());
+        let keyboard = links.keyboard([("Menu", "/menu"), ("Cart", "/cart")]);
+        assert_eq!(keyboard.inline_keyboard.len(), 2);
+        let button = &keyboard.inline_keyboard[1][0];
+        assert_eq!(button.text, "Cart");
+        assert!(matches!(
+            &button.kind,
+            InlineKeyboardButtonKind::WebApp(info) if info.url.as_str() == "https://example.com/#/cart"
+        ));
+    }
+}
@@ -3,3 +3,11 @@
 
 /// Detection of the Telegram WebApp runtime environment.
 pub mod check_env;
+/// Wall-clock timestamps shared by the analytics and error reporters.
+#[cfg(any(feature = "analytics", feature = "error-report"))]
+pub(crate) mod clock;
+/// Inline styles shared by the Yew and Leptos form components.
+#[cfg(any(feature = "yew", feature = "leptos"))]
+pub(crate) mod form_style;
+/// `sleep`, `with_timeout` and `retry` helpers for JS-backed futures.
+pub mod future;
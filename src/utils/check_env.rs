@@ -1,9 +1,12 @@
-// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
 use js_sys::Reflect;
+use wasm_bindgen::JsValue;
 use web_sys::window;
 
+use crate::core::{init::parse_init_data, types::init_data::TelegramInitData};
+
 /// Checks if the code is running inside Telegram Mini App.
 pub fn is_telegram_env() -> bool {
     let win = match window() {
@@ -24,6 +27,128 @@ pub fn is_telegram_env() -> bool {
     true
 }
 
+/// How the Mini App was opened, derived from the init data.
+///
+/// Telegram does not report the launch method directly, so it is inferred
+/// from which init data fields are present and may be [`Self::Unknown`] for
+/// launch methods added later.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LaunchSource {
+    /// Reply keyboard `web_app` button; the only launch where
+    /// `WebApp.sendData` works.
+    KeyboardButton,
+    /// Inline keyboard `web_app` button attached to a message.
+    InlineButton,
+    /// Attachment menu of a chat.
+    AttachmentMenu,
+    /// Bot menu button or main Mini App of the bot.
+    MenuButton,
+    /// Direct link (`t.me/bot/app?startapp=...`).
+    DirectLink,
+    /// Outside Telegram or no init data.
+    Unknown
+}
+
+impl LaunchSource {
+    /// Infers the launch source from parsed init data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use telegram_webapp_sdk::{core::init::parse_init_data, utils::check_env::LaunchSource};
+    ///
+    /// let data = parse_init_data("auth_date=1700000000&hash=abc").unwrap();
+    /// assert_eq!(
+    ///     LaunchSource::from_init_data(&data),
+    ///     LaunchSource::KeyboardButton
+    /// );
+    /// ```
+    pub fn from_init_data(data: &TelegramInitData) -> Self {
+        if data.start_param.is_some() {
+            Self::DirectLink
+        } else if data.receiver.is_some() || data.chat.is_some() {
+            Self::AttachmentMenu
+        } else if data.query_id.is_some() {
+            Self::MenuButton
+        } else if data.chat_instance.is_some() {
+            Self::InlineButton
+        } else {
+            Self::KeyboardButton
+        }
+    }
+
+    /// Returns whether `WebApp.sendData` is available for this launch.
+    pub const fn can_send_data(self) -> bool {
+        matches!(self, Self::KeyboardButton)
+    }
+}
+
+/// How and where the Mini App is embedded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LaunchContext {
+    /// Whether `Telegram.WebApp` is present.
+    pub in_telegram: bool,
+    /// `WebApp.platform`, e.g. `"ios"` or `"tdesktop"`; `None` when Telegram
+    /// reports `"unknown"` or is absent.
+    pub platform:    Option<String>,
+    /// How the app was opened.
+    pub source:      LaunchSource,
+    /// `start_param` of a direct link launch.
+    pub start_param: Option<String>,
+    /// Type of the chat the app was opened from, e.g. `"private"`.
+    pub chat_type:   Option<String>
+}
+
+/// Reports how the app is embedded so it can branch on the launch method.
+///
+/// Reads `Telegram.WebApp` directly, so it works before the SDK is
+/// initialized and never fails; outside Telegram it returns a context with
+/// `in_telegram` unset and [`LaunchSource::Unknown`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::utils::check_env::launch_context;
+///
+/// let context = launch_context();
+/// if context.source.can_send_data() {
+///     // offer the "Send to chat" button
+/// }
+/// ```
+pub fn launch_context() -> LaunchContext {
+    let webapp = window()
+        .and_then(|win| Reflect::get(&win, &"Telegram".into()).ok())
+        .filter(|telegram| !telegram.is_undefined())
+        .and_then(|telegram| Reflect::get(&telegram, &"WebApp".into()).ok())
+        .filter(|webapp| !webapp.is_undefined());
+    let Some(webapp) = webapp else {
+        return LaunchContext {
+            in_telegram: false,
+            platform:    None,
+            source:      LaunchSource::Unknown,
+            start_param: None,
+            chat_type:   None
+        };
+    };
+    let string = |key: &str| {
+        Reflect::get(&webapp, &JsValue::from_str(key))
+            .ok()?
+            .as_string()
+    };
+    let data = string("initData")
+        .filter(|raw| !raw.is_empty())
+        .and_then(|raw| parse_init_data(&raw).ok());
+    LaunchContext {
+        in_telegram: true,
+        platform:    string("platform").filter(|platform| platform != "unknown"),
+        source:      data
+            .as_ref()
+            .map_or(LaunchSource::Unknown, LaunchSource::from_init_data),
+        start_param: data.as_ref().and_then(|data| data.start_param.clone()),
+        chat_type:   data.and_then(|data| data.chat_type)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use js_sys::{Object, Reflect};
@@ -68,4 +193,54 @@ mod tests {
         let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
         assert!(is_telegram_env());
     }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code)]
+    fn launch_context_reads_platform_and_init_data() {
+        cleanup();
+        assert_eq!(launch_context().source, LaunchSource::Unknown);
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let _ = Reflect::set(&webapp, &"platform".into(), &"ios".into());
+        let _ = Reflect::set(
+            &webapp,
+            &"initData".into(),
+            &"auth_date=1&hash=abc&start_param=promo".into()
+        );
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let context = launch_context();
+        assert!(context.in_telegram);
+        assert_eq!(context.platform.as_deref(), Some("ios"));
+        assert_eq!(context.source, LaunchSource::DirectLink);
+        assert_eq!(context.start_param.as_deref(), Some("promo"));
+        cleanup();
+    }
+
+    #[test]
+    fn launch_source_follows_init_data_fields() {
+        let source = |raw: &str| LaunchSource::from_init_data(&parse_init_data(raw).unwrap());
+        assert_eq!(source("auth_date=1&hash=a"), LaunchSource::KeyboardButton);
+        assert_eq!(
+            source("auth_date=1&hash=a&chat_instance=1&chat_type=group"),
+            LaunchSource::InlineButton
+        );
+        assert_eq!(
+            source("auth_date=1&hash=a&query_id=q"),
+            LaunchSource::MenuButton
+        );
+        assert_eq!(
+            source(
+                "auth_date=1&hash=a&query_id=q&chat=%7B%22id%22%3A1%2C%22type%22%3A%22group%22%2C%22title%22%3A%22t%22%7D"
+            ),
+            LaunchSource::AttachmentMenu
+        );
+        assert_eq!(
+            source("auth_date=1&hash=a&start_param=x"),
+            LaunchSource::DirectLink
+        );
+        assert!(LaunchSource::KeyboardButton.can_send_data());
+        assert!(!LaunchSource::InlineButton.can_send_data());
+    }
 }
@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+/// Returns the current Unix time in milliseconds.
+///
+/// Reads `Date.now()` on wasm targets and the system clock elsewhere, so the
+/// callers stay testable natively.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn now_ms() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+/// Returns the current Unix time in milliseconds.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
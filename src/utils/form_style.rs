@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::cell::Cell;
+
+/// Rules that cannot be expressed with inline styles.
+const STYLESHEET: &str = ".tg-text-input::placeholder { color: var(--tg-theme-hint-color); }";
+
+thread_local! {
+    static STYLE_INJECTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Appends [`STYLESHEET`] to the document head once.
+pub(crate) fn inject_stylesheet() {
+    if STYLE_INJECTED.with(Cell::get) {
+        return;
+    }
+    let Some(document) = web_sys::window().and_then(|win| win.document()) else {
+        return;
+    };
+    if let (Some(head), Ok(style)) = (document.head(), document.create_element("style")) {
+        style.set_text_content(Some(STYLESHEET));
+        if head.append_child(&style).is_ok() {
+            STYLE_INJECTED.with(|injected| injected.set(true));
+        }
+    }
+}
+
+/// Returns the inline style shared by text inputs and selects.
+pub(crate) fn field_style(destructive: bool, disabled: bool) -> String {
+    let border = if destructive {
+        "var(--tg-theme-destructive-text-color)"
+    } else {
+        "var(--tg-theme-section-separator-color, var(--tg-theme-hint-color))"
+    };
+    let opacity = if disabled { 0.5 } else { 1.0 };
+    format!(
+        "box-sizing: border-box; width: 100%; padding: 10px 12px; \
+         border: 1px solid {border}; border-radius: 10px; \
+         background: var(--tg-theme-secondary-bg-color); \
+         color: var(--tg-theme-text-color); font: inherit; opacity: {opacity};"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::field_style;
+
+    #[test]
+    fn field_style_reflects_state() {
+        let normal = field_style(false, false);
+        assert!(normal.contains("--tg-theme-section-separator-color"));
+        assert!(normal.contains("opacity: 1;"));
+
+        let error = field_style(true, true);
+        assert!(error.contains("--tg-theme-destructive-text-color"));
+        assert!(error.contains("opacity: 0.5;"));
+    }
+}
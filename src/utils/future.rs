@@ -0,0 +1,287 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Timers, timeouts and retries for JS-backed futures.
+//!
+//! Promises from `CloudStorage`, invoices or `fetch` can hang on flaky mobile
+//! connections. These helpers bound and retry them on top of `gloo-timers`,
+//! so they work on the single-threaded wasm executor of
+//! `wasm-bindgen-futures`.
+
+use std::{
+    future::{Future, poll_fn},
+    pin::{Pin, pin},
+    task::{Context, Poll},
+    time::Duration
+};
+
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen::JsValue;
+
+/// Error returned by [`with_timeout`] when the deadline passes first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(pub Duration);
+
+impl std::fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Timed out after {} ms", self.0.as_millis())
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+impl From<Elapsed> for JsValue {
+    fn from(err: Elapsed) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Future completing after a delay; see [`sleep`].
+///
+/// Dropping it clears the underlying timer.
+#[must_use = "futures do nothing unless awaited"]
+pub struct Sleep {
+    timer: TimeoutFuture
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.timer).poll(cx)
+    }
+}
+
+/// Completes after `duration`, using a `gloo-timers` timeout.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::utils::future::sleep;
+///
+/// # async fn run() {
+/// sleep(Duration::from_millis(300)).await;
+/// # }
+/// ```
+pub fn sleep(duration: Duration) -> Sleep {
+    let millis = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+    Sleep {
+        timer: TimeoutFuture::new(millis)
+    }
+}
+
+/// Awaits `future`, giving up after `duration`.
+///
+/// The future is dropped when the deadline passes; the JS operation behind
+/// it may still complete, but its result is discarded.
+///
+/// # Errors
+///
+/// Returns [`Elapsed`] if `future` is not ready within `duration`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::{api::cloud_storage::get_item, utils::future::with_timeout};
+/// use wasm_bindgen_futures::JsFuture;
+///
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let value = with_timeout(JsFuture::from(get_item("cart")?), Duration::from_secs(5)).await??;
+/// # let _ = value;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_timeout<F: Future>(future: F, duration: Duration) -> Result<F::Output, Elapsed> {
+    let mut future = pin!(future);
+    let mut deadline = sleep(duration);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        Pin::new(&mut deadline)
+            .poll(cx)
+            .map(|()| Err(Elapsed(duration)))
+    })
+    .await
+}
+
+/// Exponential backoff schedule for [`retry`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::utils::future::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(4).initial_delay(Duration::from_millis(100));
+/// assert_eq!(policy.delay(1), Duration::from_millis(100));
+/// assert_eq!(policy.delay(3), Duration::from_millis(400));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts:  u32,
+    initial_delay: Duration,
+    max_delay:     Duration,
+    multiplier:    f64
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, waiting 200 ms and then 400 ms.
+    fn default() -> Self {
+        Self {
+            max_attempts:  3,
+            initial_delay: Duration::from_millis(200),
+            max_delay:     Duration::from_secs(5),
+            multiplier:    2.0
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Makes at most `max_attempts` attempts; `0` is treated as `1`.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the wait after the first failure.
+    pub fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Caps the wait between attempts.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets the factor applied to the wait after each failure.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Returns the maximum number of attempts.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the wait after failed attempt number `attempt`, starting at 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(delay.max(0.0))
+        } else {
+            self.max_delay
+        }
+    }
+}
+
+/// Runs `operation` until it succeeds or `policy` runs out of attempts,
+/// sleeping between attempts.
+///
+/// Combine it with [`with_timeout`] to also retry attempts that hang.
+///
+/// # Errors
+///
+/// Returns the error of the last attempt.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use telegram_webapp_sdk::{
+///     api::cloud_storage::get_item,
+///     utils::future::{RetryPolicy, retry, with_timeout}
+/// };
+/// use wasm_bindgen_futures::JsFuture;
+///
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let value = retry(RetryPolicy::new(3), || async {
+///     with_timeout(JsFuture::from(get_item("cart")?), Duration::from_secs(5)).await?
+/// })
+/// .await?;
+/// # let _ = value;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn retry<T, E, F, Fut>(policy: RetryPolicy, mut operation: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>
+{
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt >= policy.max_attempts => return Err(err),
+            Err(_) => {
+                sleep(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, future::pending};
+
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn delay_grows_and_is_capped() {
+        let policy = RetryPolicy::new(5)
+            .initial_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300));
+        let delays: Vec<_> = (1..=4).map(|attempt| policy.delay(attempt)).collect();
+        assert_eq!(delays, [100, 200, 300, 300].map(Duration::from_millis));
+        assert_eq!(RetryPolicy::new(0).max_attempts(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn times_out_pending_future() {
+        let result = with_timeout(pending::<()>(), Duration::from_millis(10)).await;
+        assert_eq!(result, Err(Elapsed(Duration::from_millis(10))));
+        let ready = with_timeout(async { 7 }, Duration::from_millis(10)).await;
+        assert_eq!(ready, Ok(7));
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn retries_until_success() {
+        let attempts = Cell::new(0);
+        let policy = RetryPolicy::new(3).initial_delay(Duration::from_millis(1));
+        let result = retry(policy, || {
+            attempts.set(attempts.get() + 1);
+            let attempt = attempts.get();
+            async move {
+                if attempt < 3 {
+                    Err(attempt)
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result, Ok(3));
+
+        let failed: Result<(), u32> = retry(policy, || async { Err(1) }).await;
+        assert_eq!(failed, Err(1));
+    }
+}
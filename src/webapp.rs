@@ -1,16 +1,25 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::rc::Rc;
+
 use js_sys::Object;
 
 // Module declarations
+mod bindings;
 mod buttons;
 mod core;
+pub(crate) mod debounce;
+/// [`debug::DebugSnapshot`] of the WebApp state for bug reports.
+pub mod debug;
+#[cfg(feature = "popups")]
 mod dialogs;
 mod events;
 mod lifecycle;
 mod navigation;
+mod nested;
 mod permissions;
+mod scope;
 mod theme;
 /// Public data types shared across the WebApp bindings: button descriptors,
 /// button parameters, link/close options and event handles.
@@ -18,15 +27,21 @@ pub mod types;
 mod viewport;
 
 // Re-export public types
+pub use scope::EventScope;
 pub use types::{
-    BackgroundEvent, BottomButton, BottomButtonParams, CloseOptions, EventHandle, OpenLinkOptions,
-    SafeAreaInset, SecondaryButtonParams, SecondaryButtonPosition
+    BackgroundEvent, BackgroundEventData, BottomButton, BottomButtonParams,
+    BottomButtonParamsBuilder, ButtonParamsError, CloseOptions, ContactRequestResult,
+    EmojiStatusError, EventHandle, FileDownloadStatus, FullscreenError, InvoiceStatus,
+    MAX_BUTTON_TEXT, OpenLinkOptions, ParsePositionError, PopupButton, PopupButtonKind,
+    PopupParams, RequestedContact, SafeAreaInset, SecondaryButtonParams, SecondaryButtonPosition,
+    WriteAccessResult
 };
 
 /// Safe wrapper around `window.Telegram.WebApp`
 #[derive(Clone)]
 pub struct TelegramWebApp {
-    pub(super) inner: Object
+    pub(super) inner:  Object,
+    pub(super) nested: Rc<nested::NestedObjects>
 }
 
 #[cfg(test)]
@@ -37,6 +52,7 @@ mod tests {
     };
 
     use js_sys::{Function, Object, Reflect};
+    use serde::Serialize;
     use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
     use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
     use web_sys::window;
@@ -326,6 +342,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn secondary_button_position_round_trips() {
+        for position in [
+            SecondaryButtonPosition::Top,
+            SecondaryButtonPosition::Left,
+            SecondaryButtonPosition::Bottom,
+            SecondaryButtonPosition::Right
+        ] {
+            assert_eq!(position.to_string().parse(), Ok(position));
+            let json = serde_json::to_string(&position).unwrap();
+            assert_eq!(
+                serde_json::from_str::<SecondaryButtonPosition>(&json).unwrap(),
+                position
+            );
+        }
+        assert_eq!(
+            "Top".parse::<SecondaryButtonPosition>(),
+            Err(ParsePositionError("Top".into()))
+        );
+    }
+
+    fn as_json(value: &JsValue) -> serde_json::Value {
+        serde_wasm_bindgen::from_value(value.clone()).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn button_params_to_js_matches_serde() {
+        let full = SecondaryButtonParams {
+            common:   BottomButtonParams {
+                text:                 Some("Send"),
+                color:                Some("#2481cc"),
+                text_color:           Some("#ffffff"),
+                is_active:            Some(false),
+                is_visible:           Some(true),
+                has_shine_effect:     Some(true),
+                icon_custom_emoji_id: Some("123")
+            },
+            position: Some(SecondaryButtonPosition::Left)
+        };
+        let empty = SecondaryButtonParams::default();
+        // `flatten` would otherwise serialize into a JS `Map`.
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        for params in [&full, &empty] {
+            let serde = params.serialize(&serializer).unwrap();
+            assert_eq!(as_json(&params.to_js()), as_json(&serde));
+            let serde = params.common.serialize(&serializer).unwrap();
+            assert_eq!(as_json(&params.common.to_js()), as_json(&serde));
+        }
+    }
+
+    #[test]
+    fn button_params_builder_validates() {
+        let params = BottomButtonParams::builder()
+            .text("Next")
+            .text_color("#ffffff")
+            .position(SecondaryButtonPosition::Top)
+            .build_secondary()
+            .unwrap();
+        assert_eq!(params.common.text, Some("Next"));
+        assert_eq!(params.position, Some(SecondaryButtonPosition::Top));
+
+        let long = "x".repeat(MAX_BUTTON_TEXT + 1);
+        let errors = [
+            BottomButtonParams::builder().text("  ").build(),
+            BottomButtonParams::builder().text(&long).build(),
+            BottomButtonParams::builder().color("#12345").build(),
+            BottomButtonParams::builder()
+                .position(SecondaryButtonPosition::Left)
+                .build()
+        ]
+        .map(|result| result.unwrap_err());
+        assert_eq!(
+            errors,
+            [
+                ButtonParamsError::EmptyText,
+                ButtonParamsError::TextTooLong {
+                    len: MAX_BUTTON_TEXT + 1
+                },
+                ButtonParamsError::InvalidColor("#12345".into()),
+                ButtonParamsError::PositionRequiresSecondary
+            ]
+        );
+    }
+
     #[wasm_bindgen_test]
     #[allow(dead_code, clippy::unused_unit)]
     fn set_secondary_button_params_serializes_position() {
@@ -1333,15 +1434,43 @@ mod tests {
         let _ = Reflect::set(&webapp, &"requestWriteAccess".into(), &request);
 
         let app = TelegramWebApp::instance().unwrap();
-        let granted = Rc::new(Cell::new(false));
+        let granted = Rc::new(Cell::new(None));
         let granted_clone = Rc::clone(&granted);
 
-        let res = app.request_write_access_with_callback(move |g| {
-            granted_clone.set(g);
+        let res = app.request_write_access_with_callback(move |result| {
+            granted_clone.set(Some(result));
         });
         assert!(res.is_ok());
 
-        assert!(granted.get());
+        assert_eq!(granted.get(), Some(WriteAccessResult::Allowed));
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn request_contact_parses_shared_contact() {
+        let webapp = setup_webapp();
+        let request = Function::new_with_args(
+            "cb",
+            "cb(true, { status: 'sent', response: 'contact=signed', responseUnsafe: { contact: \
+             { phone_number: '+100', first_name: 'Ann', user_id: 7 } } });"
+        );
+        let _ = Reflect::set(&webapp, &"requestContact".into(), &request);
+
+        let app = TelegramWebApp::instance().unwrap();
+        let result = Rc::new(RefCell::new(None));
+        let result_clone = Rc::clone(&result);
+        app.request_contact_with_callback(move |res| {
+            *result_clone.borrow_mut() = Some(res);
+        })
+        .unwrap();
+
+        let Some(Ok(ContactRequestResult::Sent(contact))) = result.borrow_mut().take() else {
+            panic!("contact not shared");
+        };
+        assert_eq!(contact.phone_number, "+100");
+        assert_eq!(contact.user_id, 7);
+        assert_eq!(contact.last_name, None);
+        assert_eq!(contact.response, "contact=signed");
     }
 
     #[wasm_bindgen_test]
@@ -1453,6 +1582,7 @@ mod tests {
         assert_eq!(id.as_deref(), Some("321"));
     }
 
+    #[cfg(feature = "popups")]
     #[wasm_bindgen_test]
     #[allow(dead_code, clippy::unused_unit)]
     fn show_popup_invokes_callback() {
@@ -1491,6 +1621,7 @@ mod tests {
         assert_eq!(text.borrow().as_str(), "clip");
     }
 
+    #[cfg(feature = "popups")]
     #[wasm_bindgen_test]
     #[allow(dead_code, clippy::unused_unit)]
     fn scan_qr_popup_invokes_callback_and_close() {
@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Generated bindings for frequently accessed `Telegram.WebApp` members.
+//!
+//! Getters polled by reactive layers (viewport, activity, button state) and
+//! event registration compile to direct property accesses and method calls
+//! instead of a `Reflect.get` with a freshly allocated key on every call.
+//! Properties are typed as `JsValue` so missing members still read as
+//! `undefined` and callers keep their `Option` semantics.
+
+use js_sys::{Function, Object};
+use wasm_bindgen::{JsCast, JsValue, prelude::wasm_bindgen};
+
+use super::{TelegramWebApp, types::BottomButton};
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Telegram.WebApp`.
+    #[wasm_bindgen(extends = Object)]
+    pub(super) type WebAppObject;
+
+    #[wasm_bindgen(method, getter, js_name = viewportHeight)]
+    pub(super) fn viewport_height(this: &WebAppObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = viewportWidth)]
+    pub(super) fn viewport_width(this: &WebAppObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = viewportStableHeight)]
+    pub(super) fn viewport_stable_height(this: &WebAppObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = isActive)]
+    pub(super) fn is_active(this: &WebAppObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = isExpanded)]
+    pub(super) fn is_expanded(this: &WebAppObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = colorScheme)]
+    pub(super) fn color_scheme(this: &WebAppObject) -> JsValue;
+
+    #[wasm_bindgen(method, catch, js_name = onEvent)]
+    pub(super) fn on_event(
+        this: &WebAppObject,
+        event: &str,
+        callback: &Function
+    ) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = offEvent)]
+    pub(super) fn off_event(
+        this: &WebAppObject,
+        event: &str,
+        callback: &Function
+    ) -> Result<(), JsValue>;
+
+    /// `WebApp.MainButton` or `WebApp.SecondaryButton`.
+    #[wasm_bindgen(extends = Object)]
+    pub(super) type ButtonObject;
+
+    #[wasm_bindgen(method, getter, js_name = isVisible)]
+    pub(super) fn is_visible(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = isActive)]
+    pub(super) fn is_active(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = isProgressVisible)]
+    pub(super) fn is_progress_visible(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn text(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn color(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = textColor)]
+    pub(super) fn text_color(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = iconCustomEmojiId)]
+    pub(super) fn icon_custom_emoji_id(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = hasShineEffect)]
+    pub(super) fn has_shine_effect(this: &ButtonObject) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn position(this: &ButtonObject) -> JsValue;
+}
+
+impl TelegramWebApp {
+    /// Returns the wrapped object with the generated bindings.
+    pub(super) fn js(&self) -> &WebAppObject {
+        self.inner.unchecked_ref()
+    }
+
+    /// Returns the button object, or `None` if it is missing.
+    pub(super) fn button_js(&self, button: BottomButton) -> Option<ButtonObject> {
+        self.nested_object(button.js_name())
+            .map(JsCast::unchecked_into)
+    }
+}
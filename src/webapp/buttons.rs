@@ -2,7 +2,6 @@
 // SPDX-License-Identifier: MIT
 
 use js_sys::{Function, Object, Reflect};
-use serde_wasm_bindgen::to_value;
 use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
 
 use crate::{
@@ -21,10 +20,10 @@ impl TelegramWebApp {
 
     pub(super) fn bottom_button_object(&self, button: BottomButton) -> Result<Object, JsValue> {
         let name = button.js_name();
-        Reflect::get(&self.inner, &name.into())
-            .inspect_err(|_| logger::error(&format!("{name} not available")))?
-            .dyn_into::<Object>()
-            .inspect_err(|_| logger::error(&format!("{name} is not an object")))
+        self.nested_object(name).ok_or_else(|| {
+            logger::error(&format!("{name} not available"));
+            JsValue::from_str(&format!("{name} not available"))
+        })
     }
 
     pub(super) fn bottom_button_method(
@@ -49,16 +48,6 @@ impl TelegramWebApp {
         Ok(())
     }
 
-    pub(super) fn bottom_button_property(
-        &self,
-        button: BottomButton,
-        property: &str
-    ) -> Option<JsValue> {
-        self.bottom_button_object(button)
-            .ok()
-            .and_then(|object| Reflect::get(&object, &property.into()).ok())
-    }
-
     // === Bottom button operations ===
 
     /// Call `WebApp.MainButton.show()` or `WebApp.SecondaryButton.show()`.
@@ -223,7 +212,8 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn is_bottom_button_visible(&self, button: BottomButton) -> bool {
-        self.bottom_button_property(button, "isVisible")
+        self.button_js(button)
+            .map(|b| b.is_visible())
             .and_then(|v| v.as_bool())
             .unwrap_or(false)
     }
@@ -239,7 +229,8 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn is_bottom_button_active(&self, button: BottomButton) -> bool {
-        self.bottom_button_property(button, "isActive")
+        self.button_js(button)
+            .map(|b| b.is_active())
             .and_then(|v| v.as_bool())
             .unwrap_or(false)
     }
@@ -255,7 +246,8 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn is_bottom_button_progress_visible(&self, button: BottomButton) -> bool {
-        self.bottom_button_property(button, "isProgressVisible")
+        self.button_js(button)
+            .map(|b| b.is_progress_visible())
             .and_then(|v| v.as_bool())
             .unwrap_or(false)
     }
@@ -271,7 +263,7 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn bottom_button_text(&self, button: BottomButton) -> Option<String> {
-        self.bottom_button_property(button, "text")?.as_string()
+        self.button_js(button)?.text().as_string()
     }
 
     /// Returns the current custom emoji icon ID of the button (Bot API 9.5+).
@@ -290,8 +282,7 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn bottom_button_icon_custom_emoji_id(&self, button: BottomButton) -> Option<String> {
-        self.bottom_button_property(button, "iconCustomEmojiId")?
-            .as_string()
+        self.button_js(button)?.icon_custom_emoji_id().as_string()
     }
 
     /// Returns the current text color of the button.
@@ -305,8 +296,7 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn bottom_button_text_color(&self, button: BottomButton) -> Option<String> {
-        self.bottom_button_property(button, "textColor")?
-            .as_string()
+        self.button_js(button)?.text_color().as_string()
     }
 
     /// Returns the current background color of the button.
@@ -320,7 +310,7 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn bottom_button_color(&self, button: BottomButton) -> Option<String> {
-        self.bottom_button_property(button, "color")?.as_string()
+        self.button_js(button)?.color().as_string()
     }
 
     /// Returns whether the shine effect is enabled on the button.
@@ -334,7 +324,8 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn bottom_button_has_shine_effect(&self, button: BottomButton) -> bool {
-        self.bottom_button_property(button, "hasShineEffect")
+        self.button_js(button)
+            .map(|b| b.has_shine_effect())
             .and_then(|v| v.as_bool())
             .unwrap_or(false)
     }
@@ -358,8 +349,7 @@ impl TelegramWebApp {
         button: BottomButton,
         params: &BottomButtonParams<'_>
     ) -> Result<(), JsValue> {
-        let value = to_value(params).map_err(|err| JsValue::from_str(&err.to_string()))?;
-        self.bottom_button_method(button, "setParams", Some(&value))
+        self.bottom_button_method(button, "setParams", Some(&params.to_js()))
     }
 
     /// Update secondary button state via `setParams`, including position.
@@ -382,8 +372,7 @@ impl TelegramWebApp {
         &self,
         params: &SecondaryButtonParams<'_>
     ) -> Result<(), JsValue> {
-        let value = to_value(params).map_err(|err| JsValue::from_str(&err.to_string()))?;
-        self.bottom_button_method(BottomButton::Secondary, "setParams", Some(&value))
+        self.bottom_button_method(BottomButton::Secondary, "setParams", Some(&params.to_js()))
     }
 
     /// Returns the configured position of the secondary button, if available.
@@ -397,8 +386,8 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn secondary_button_position(&self) -> Option<SecondaryButtonPosition> {
-        self.bottom_button_property(BottomButton::Secondary, "position")
-            .and_then(SecondaryButtonPosition::from_js_value)
+        self.button_js(BottomButton::Secondary)
+            .and_then(|b| SecondaryButtonPosition::from_js_value(b.position()))
     }
 
     /// Set callback for `onClick()` on a bottom button.
@@ -415,8 +404,7 @@ impl TelegramWebApp {
     where
         F: 'static + Fn()
     {
-        let btn_val = Reflect::get(&self.inner, &button.js_name().into())?;
-        let btn = btn_val.dyn_into::<Object>()?;
+        let btn = self.bottom_button_object(button)?;
         let cb = Closure::<dyn FnMut()>::new(callback);
         let f = Reflect::get(&btn, &"onClick".into())?;
         let func = f
@@ -477,8 +465,9 @@ impl TelegramWebApp {
     where
         F: 'static + Fn()
     {
-        let back_button_val = Reflect::get(&self.inner, &"BackButton".into())?;
-        let back_button = back_button_val.dyn_into::<Object>()?;
+        let back_button = self
+            .nested_object("BackButton")
+            .ok_or_else(|| JsValue::from_str("BackButton not available"))?;
         let cb = Closure::<dyn FnMut()>::new(callback);
         let f = Reflect::get(&back_button, &"onClick".into())?;
         let func = f
@@ -508,8 +497,7 @@ impl TelegramWebApp {
     /// let _ = app.is_back_button_visible();
     /// ```
     pub fn is_back_button_visible(&self) -> bool {
-        Reflect::get(&self.inner, &"BackButton".into())
-            .ok()
+        self.nested_object("BackButton")
             .and_then(|bb| Reflect::get(&bb, &"isVisible".into()).ok())
             .and_then(|v| v.as_bool())
             .unwrap_or(false)
@@ -547,8 +535,9 @@ impl TelegramWebApp {
     where
         F: 'static + Fn()
     {
-        let button_val = Reflect::get(&self.inner, &"SettingsButton".into())?;
-        let button = button_val.dyn_into::<Object>()?;
+        let button = self
+            .nested_object("SettingsButton")
+            .ok_or_else(|| JsValue::from_str("SettingsButton not available"))?;
         let cb = Closure::<dyn FnMut()>::new(callback);
         let f = Reflect::get(&button, &"onClick".into())?;
         let func = f
@@ -571,8 +560,7 @@ impl TelegramWebApp {
 
     /// Returns whether the native settings button is visible.
     pub fn is_settings_button_visible(&self) -> bool {
-        Reflect::get(&self.inner, &"SettingsButton".into())
-            .ok()
+        self.nested_object("SettingsButton")
             .and_then(|bb| Reflect::get(&bb, &"isVisible".into()).ok())
             .and_then(|v| v.as_bool())
             .unwrap_or(false)
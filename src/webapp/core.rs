@@ -1,12 +1,17 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::rc::Rc;
+
 use js_sys::{Function, Object, Promise, Reflect};
 use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
 use wasm_bindgen_futures::JsFuture;
 use web_sys::window;
 
-use crate::{core::context::TelegramContext, webapp::TelegramWebApp};
+use crate::{
+    core::{context::TelegramContext, payload::WebAppPayload},
+    webapp::TelegramWebApp
+};
 
 /// Build a `Promise` whose executor invokes `f` synchronously with the
 /// `resolve` and `reject` callables. If `f` returns `Err`, the promise is
@@ -33,12 +38,19 @@ pub(super) async fn await_one_shot(promise: Promise) -> Result<JsValue, JsValue>
 
 impl TelegramWebApp {
     /// Get instance of `Telegram.WebApp` or `None` if not present
+    ///
+    /// Always `None` on non-`wasm32` targets, e.g. during server-side
+    /// rendering.
     pub fn instance() -> Option<Self> {
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
         let win = window()?;
         let tg = Reflect::get(&win, &"Telegram".into()).ok()?;
         let webapp = Reflect::get(&tg, &"WebApp".into()).ok()?;
         webapp.dyn_into::<Object>().ok().map(|inner| Self {
-            inner
+            inner,
+            nested: Rc::default()
         })
     }
 
@@ -46,14 +58,18 @@ impl TelegramWebApp {
     ///
     /// # Errors
     /// Returns [`JsValue`] if the `Telegram.WebApp` object is missing or
-    /// malformed.
+    /// malformed, or on non-`wasm32` targets.
     pub fn try_instance() -> Result<Self, JsValue> {
+        if !cfg!(target_arch = "wasm32") {
+            return Err(JsValue::from_str("window not available"));
+        }
         let win = window().ok_or_else(|| JsValue::from_str("window not available"))?;
         let tg = Reflect::get(&win, &"Telegram".into())?;
         let webapp = Reflect::get(&tg, &"WebApp".into())?;
         let inner = webapp.dyn_into::<Object>()?;
         Ok(Self {
-            inner
+            inner,
+            nested: Rc::default()
         })
     }
 
@@ -92,6 +108,15 @@ impl TelegramWebApp {
         self.call1("sendData", &data.into())
     }
 
+    /// Encode `payload` and send it with `WebApp.sendData`.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the payload fails validation, exceeds its size
+    /// limit, or the underlying JS call fails.
+    pub fn send_payload<P: WebAppPayload>(&self, payload: &P) -> Result<(), JsValue> {
+        self.send_data(&payload.to_send_data()?)
+    }
+
     /// Returns whether the WebApp version is at least the provided value.
     ///
     /// # Examples
@@ -224,8 +249,10 @@ impl TelegramWebApp {
         Ok(())
     }
 
-    pub(super) fn call_nested0(&self, field: &str, method: &str) -> Result<(), JsValue> {
-        let obj = Reflect::get(&self.inner, &field.into())?;
+    pub(super) fn call_nested0(&self, field: &'static str, method: &str) -> Result<(), JsValue> {
+        let obj = self
+            .nested_object(field)
+            .ok_or_else(|| JsValue::from_str(&format!("{field} not available")))?;
         let f = Reflect::get(&obj, &method.into())?;
         let func = f
             .dyn_ref::<Function>()
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
+use web_sys::Window;
+
+/// Trailing-edge debouncer backed by a single reusable JS timer.
+///
+/// Every [`trigger`](Self::trigger) restarts the timer; the callback runs once
+/// the events have been quiet for the configured delay. Dropping the debouncer
+/// cancels a pending call, so it is safe to capture in an event closure owned
+/// by an [`EventHandle`](super::types::EventHandle).
+pub(crate) struct Debouncer {
+    window: Window,
+    delay:  i32,
+    timer:  Rc<Cell<Option<i32>>>,
+    fire:   Closure<dyn FnMut()>
+}
+
+impl Debouncer {
+    /// Creates a debouncer calling `callback` after `delay` of inactivity.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `window` is not available.
+    pub(crate) fn new<F>(delay: Duration, callback: F) -> Result<Self, JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let window = web_sys::window().ok_or_else(|| JsValue::from_str("window not available"))?;
+        let timer = Rc::new(Cell::new(None));
+        let pending = Rc::clone(&timer);
+        let fire = Closure::<dyn FnMut()>::new(move || {
+            pending.set(None);
+            callback();
+        });
+        Ok(Self {
+            window,
+            delay: i32::try_from(delay.as_millis()).unwrap_or(i32::MAX),
+            timer,
+            fire
+        })
+    }
+
+    /// Restarts the timer.
+    pub(crate) fn trigger(&self) {
+        self.cancel();
+        let id = self
+            .window
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                self.fire.as_ref().unchecked_ref(),
+                self.delay
+            )
+            .ok();
+        self.timer.set(id);
+    }
+
+    /// Cancels a pending call.
+    pub(crate) fn cancel(&self) {
+        if let Some(id) = self.timer.take() {
+            self.window.clear_timeout_with_handle(id);
+        }
+    }
+}
+
+impl Drop for Debouncer {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::collections::BTreeMap;
+
+use js_sys::Reflect;
+use serde::Serialize;
+
+use crate::webapp::{
+    TelegramWebApp,
+    types::{BottomButton, SecondaryButtonPosition, registered_events}
+};
+
+/// State of a bottom button captured by [`DebugSnapshot`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ButtonSnapshot {
+    /// Whether the button is shown.
+    pub visible:          bool,
+    /// Whether the button accepts clicks.
+    pub active:           bool,
+    /// Whether the loading indicator is shown.
+    pub progress_visible: bool,
+    /// Button label.
+    pub text:             Option<String>,
+    /// Background color.
+    pub color:            Option<String>,
+    /// Label color.
+    pub text_color:       Option<String>
+}
+
+/// Viewport dimensions and flags captured by [`DebugSnapshot`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ViewportSnapshot {
+    /// `WebApp.viewportWidth` in pixels.
+    pub width:         Option<f64>,
+    /// `WebApp.viewportHeight` in pixels.
+    pub height:        Option<f64>,
+    /// `WebApp.viewportStableHeight` in pixels.
+    pub stable_height: Option<f64>,
+    /// Whether the app is expanded to full height.
+    pub expanded:      bool,
+    /// Whether the app is in fullscreen mode.
+    pub fullscreen:    bool
+}
+
+/// Point-in-time state of the WebApp for bug reports.
+///
+/// Serializes to a single JSON object that can be attached to a support
+/// ticket or shown in a dev overlay.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DebugSnapshot {
+    /// `WebApp.version`.
+    pub version:              Option<String>,
+    /// `WebApp.platform`.
+    pub platform:             Option<String>,
+    /// `WebApp.colorScheme`.
+    pub color_scheme:         Option<String>,
+    /// `WebApp.themeParams` keyed by Telegram's parameter names.
+    pub theme_params:         BTreeMap<String, String>,
+    /// Viewport dimensions and flags.
+    pub viewport:             ViewportSnapshot,
+    /// Main bottom button state.
+    pub main_button:          ButtonSnapshot,
+    /// Secondary bottom button state.
+    pub secondary_button:     ButtonSnapshot,
+    /// Position of the secondary button relative to the main one.
+    pub secondary_position:   Option<SecondaryButtonPosition>,
+    /// Whether the back button is shown.
+    pub back_button_visible:  bool,
+    /// Whether the app is active, i.e. not minimized.
+    pub active:               bool,
+    /// Whether the orientation is locked.
+    pub orientation_locked:   bool,
+    /// Whether closing asks the user for confirmation.
+    pub closing_confirmation: bool,
+    /// Whether vertical swipes may close the app.
+    pub vertical_swipes:      bool,
+    /// Sorted names of events with a live handler registered through this
+    /// crate.
+    pub registered_events:    Vec<String>
+}
+
+impl TelegramWebApp {
+    /// Collects version, platform, theme, viewport, button states and
+    /// registered event names into a [`DebugSnapshot`].
+    ///
+    /// Only handlers registered through this crate's [`EventHandle`]s are
+    /// listed; handlers attached from JavaScript are not visible.
+    ///
+    /// [`EventHandle`]: crate::webapp::EventHandle
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use telegram_webapp_sdk::webapp::TelegramWebApp;
+    /// # let app = TelegramWebApp::instance().unwrap();
+    /// let report = serde_json::to_string(&app.debug_snapshot()).unwrap();
+    /// # let _ = report;
+    /// ```
+    pub fn debug_snapshot(&self) -> DebugSnapshot {
+        let theme_params = Reflect::get(&self.inner, &"themeParams".into())
+            .ok()
+            .and_then(|value| serde_wasm_bindgen::from_value(value).ok())
+            .unwrap_or_default();
+        DebugSnapshot {
+            version: self.raw_version(),
+            platform: self.platform(),
+            color_scheme: self.color_scheme(),
+            theme_params,
+            viewport: ViewportSnapshot {
+                width:         self.viewport_width(),
+                height:        self.viewport_height(),
+                stable_height: self.viewport_stable_height(),
+                expanded:      self.is_expanded(),
+                fullscreen:    self.is_fullscreen()
+            },
+            main_button: self.button_snapshot(BottomButton::Main),
+            secondary_button: self.button_snapshot(BottomButton::Secondary),
+            secondary_position: self.secondary_button_position(),
+            back_button_visible: self.is_back_button_visible(),
+            active: self.is_active(),
+            orientation_locked: self.is_orientation_locked(),
+            closing_confirmation: self.is_closing_confirmation_enabled(),
+            vertical_swipes: self.is_vertical_swipes_enabled(),
+            registered_events: registered_events()
+        }
+    }
+
+    fn button_snapshot(&self, button: BottomButton) -> ButtonSnapshot {
+        ButtonSnapshot {
+            visible:          self.is_bottom_button_visible(button),
+            active:           self.is_bottom_button_active(button),
+            progress_visible: self.is_bottom_button_progress_visible(button),
+            text:             self.bottom_button_text(button),
+            color:            self.bottom_button_color(button),
+            text_color:       self.bottom_button_text_color(button)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Function, Object};
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::window;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Installs a mock `Telegram.WebApp` whose `onEvent` stores the callback
+    /// under the event name, and returns it.
+    fn install_webapp() -> Object {
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let theme = Object::new();
+        let main = Object::new();
+        let _ = Reflect::set(&theme, &"bg_color".into(), &"#ffffff".into());
+        let _ = Reflect::set(&main, &"isVisible".into(), &JsValue::TRUE);
+        let _ = Reflect::set(&main, &"text".into(), &"Pay".into());
+        let _ = Reflect::set(&webapp, &"version".into(), &"9.1".into());
+        let _ = Reflect::set(&webapp, &"platform".into(), &"ios".into());
+        let _ = Reflect::set(&webapp, &"themeParams".into(), &theme);
+        let _ = Reflect::set(&webapp, &"viewportWidth".into(), &390.into());
+        let _ = Reflect::set(&webapp, &"MainButton".into(), &main);
+        let on_event = Function::new_with_args("name, cb", "this[name] = cb;");
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &Function::new_no_args(""));
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+
+        webapp
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn snapshot_collects_state_and_events() {
+        install_webapp();
+
+        let app = TelegramWebApp::instance().unwrap();
+        let handle = app.on_theme_changed(|| {}).unwrap();
+        let snapshot = app.debug_snapshot();
+        assert_eq!(snapshot.version.as_deref(), Some("9.1"));
+        assert_eq!(snapshot.platform.as_deref(), Some("ios"));
+        assert_eq!(snapshot.theme_params["bg_color"], "#ffffff");
+        assert_eq!(snapshot.viewport.width, Some(390.0));
+        assert!(snapshot.main_button.visible);
+        assert_eq!(snapshot.main_button.text.as_deref(), Some("Pay"));
+        assert!(
+            snapshot
+                .registered_events
+                .contains(&"themeChanged".to_string())
+        );
+
+        drop(handle);
+        assert!(
+            !app.debug_snapshot()
+                .registered_events
+                .contains(&"themeChanged".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn delivered_one_shot_is_not_registered() {
+        let webapp = install_webapp();
+        let app = TelegramWebApp::instance().unwrap();
+        let registered =
+            || app.debug_snapshot().registered_events.contains(&"invoiceClosed".to_string());
+
+        let once = app.once_event("invoiceClosed", |_| {}).unwrap();
+        assert!(registered());
+        let deliver: Function = Reflect::get(&webapp, &"invoiceClosed".into())
+            .unwrap()
+            .unchecked_into();
+        let _ = deliver.call1(&JsValue::NULL, &JsValue::UNDEFINED);
+        assert!(!registered());
+
+        let persistent = app.on_event("invoiceClosed", |_| {}).unwrap();
+        drop(once);
+        assert!(registered());
+
+        drop(persistent);
+        assert!(!registered());
+    }
+}
@@ -18,6 +18,22 @@ impl TelegramWebApp {
         self.call1("showAlert", &msg.into())
     }
 
+    /// Callback variant of [`Self::show_alert`], invoked once the alert is
+    /// closed.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn show_alert_with_callback<F>(&self, msg: &str, on_close: F) -> Result<(), JsValue>
+    where
+        F: 'static + FnOnce()
+    {
+        let cb = Closure::once_into_js(on_close);
+        Reflect::get(&self.inner, &"showAlert".into())?
+            .dyn_into::<Function>()?
+            .call2(&self.inner, &msg.into(), &cb)?;
+        Ok(())
+    }
+
     /// Callback variant of [`Self::show_confirm`].
     ///
     /// # Errors
@@ -188,7 +204,7 @@ mod tests {
     use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
     use web_sys::window;
 
-    use crate::webapp::TelegramWebApp;
+    use crate::webapp::{PopupButton, PopupButtonKind, PopupParams, TelegramWebApp};
 
     wasm_bindgen_test_configure!(run_in_browser);
 
@@ -239,6 +255,54 @@ mod tests {
         );
     }
 
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn show_alert_with_callback_notifies_on_close() {
+        let webapp = setup_webapp();
+        let invoke = Function::new_with_args("msg, cb", "cb();");
+        let _ = Reflect::set(&webapp, &"showAlert".into(), &invoke);
+
+        let app = TelegramWebApp::instance().expect("instance");
+        let closed = std::rc::Rc::new(std::cell::Cell::new(false));
+        let closed_ref = closed.clone();
+        app.show_alert_with_callback("Saved", move || closed_ref.set(true))
+            .expect("ok");
+        assert!(closed.get());
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn popup_params_serialize_to_show_popup_shape() {
+        let params = PopupParams {
+            title:   None,
+            message: "Delete?".into(),
+            buttons: vec![
+                PopupButton::new("delete", "Delete").kind(PopupButtonKind::Destructive),
+                PopupButton::default().kind(PopupButtonKind::Cancel),
+            ]
+        }
+        .to_js()
+        .expect("serialize");
+
+        assert!(
+            Reflect::get(&params, &"title".into())
+                .unwrap()
+                .is_undefined()
+        );
+        let buttons = js_sys::Array::from(&Reflect::get(&params, &"buttons".into()).unwrap());
+        let delete = buttons.get(0);
+        assert_eq!(
+            Reflect::get(&delete, &"type".into()).unwrap().as_string(),
+            Some("destructive".to_owned())
+        );
+        let cancel = buttons.get(1);
+        assert!(
+            Reflect::get(&cancel, &"text".into())
+                .unwrap()
+                .is_undefined()
+        );
+    }
+
     #[wasm_bindgen_test]
     #[allow(dead_code, clippy::unused_unit)]
     fn show_confirm_passes_message_and_routes_boolean_back() {
@@ -1,12 +1,24 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::{
+    cell::{Cell, OnceCell},
+    rc::Rc,
+    time::Duration
+};
+
 use js_sys::{Function, Reflect};
 use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
 
+use super::debounce::Debouncer;
+#[cfg(feature = "location")]
+use crate::api::location_manager::LocationData;
 use crate::webapp::{
     TelegramWebApp,
-    types::{BackgroundEvent, EventHandle}
+    types::{
+        BackgroundEvent, BackgroundEventData, EmojiStatusError, EventHandle, FileDownloadStatus,
+        FullscreenError, InvoiceStatus, release_event
+    }
 };
 
 impl TelegramWebApp {
@@ -26,11 +38,7 @@ impl TelegramWebApp {
         F: 'static + Fn(JsValue)
     {
         let cb = Closure::<dyn FnMut(JsValue)>::new(callback);
-        let f = Reflect::get(&self.inner, &"onEvent".into())?;
-        let func = f
-            .dyn_ref::<Function>()
-            .ok_or_else(|| JsValue::from_str("onEvent is not a function"))?;
-        func.call2(&self.inner, &event.into(), cb.as_ref().unchecked_ref())?;
+        self.js().on_event(event, cb.as_ref().unchecked_ref())?;
         Ok(EventHandle::new(
             self.inner.clone(),
             "offEvent",
@@ -55,15 +63,8 @@ impl TelegramWebApp {
         F: 'static + Fn(JsValue)
     {
         let cb = Closure::<dyn FnMut(JsValue)>::new(callback);
-        let f = Reflect::get(&self.inner, &"onEvent".into())?;
-        let func = f
-            .dyn_ref::<Function>()
-            .ok_or_else(|| JsValue::from_str("onEvent is not a function"))?;
-        func.call2(
-            &self.inner,
-            &event.as_str().into(),
-            cb.as_ref().unchecked_ref()
-        )?;
+        self.js()
+            .on_event(event.as_str(), cb.as_ref().unchecked_ref())?;
         Ok(EventHandle::new(
             self.inner.clone(),
             "offEvent",
@@ -72,6 +73,44 @@ impl TelegramWebApp {
         ))
     }
 
+    /// Register a callback for a background event with a decoded payload.
+    ///
+    /// Returns an [`EventHandle`] that can be passed to
+    /// [`off_event`](Self::off_event).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use telegram_webapp_sdk::webapp::{BackgroundEvent, BackgroundEventData, TelegramWebApp};
+    ///
+    /// # let app = TelegramWebApp::instance().unwrap();
+    /// let handle = app
+    ///     .on_background_event_typed(BackgroundEvent::PopupClosed, |data| {
+    ///         if let BackgroundEventData::PopupClosed {
+    ///             button_id: Some(id)
+    ///         } = data
+    ///         {
+    ///             let _ = id;
+    ///         }
+    ///     })
+    ///     .unwrap();
+    /// app.off_event(handle).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_background_event_typed<F>(
+        &self,
+        event: BackgroundEvent,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + Fn(BackgroundEventData)
+    {
+        self.on_background_event(event, move |payload| {
+            callback(BackgroundEventData::from_js(event, &payload));
+        })
+    }
+
     /// Deregister a previously registered event handler.
     ///
     /// # Errors
@@ -80,6 +119,139 @@ impl TelegramWebApp {
         handle.unregister()
     }
 
+    /// Register event handler that is removed after the first delivery.
+    ///
+    /// The returned [`EventHandle`] can still be passed to
+    /// [`off_event`](Self::off_event) to cancel before the event arrives;
+    /// dropping it also cancels a pending delivery. Once delivered, the
+    /// handler no longer counts as registered.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use telegram_webapp_sdk::webapp::TelegramWebApp;
+    /// # let app = TelegramWebApp::instance().unwrap();
+    /// let handle = app
+    ///     .once_event("writeAccessRequested", |payload| {
+    ///         let _ = payload;
+    ///     })
+    ///     .unwrap();
+    /// # drop(handle);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn once_event<F>(
+        &self,
+        event: &str,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + FnOnce(JsValue)
+    {
+        let registered: Rc<OnceCell<Function>> = Rc::default();
+        let released: Rc<Cell<bool>> = Rc::default();
+        let cb = {
+            let app = self.clone();
+            let name = event.to_owned();
+            let registered = Rc::clone(&registered);
+            let released = Rc::clone(&released);
+            let mut callback = Some(callback);
+            Closure::<dyn FnMut(JsValue)>::new(move |payload: JsValue| {
+                let Some(callback) = callback.take() else {
+                    return;
+                };
+                if let Some(func) = registered.get() {
+                    let _ = app.js().off_event(&name, func);
+                }
+                release_event(&name, &released);
+                callback(payload);
+            })
+        };
+        let func: &Function = cb.as_ref().unchecked_ref();
+        let _ = registered.set(func.clone());
+        self.js().on_event(event, func)?;
+        Ok(EventHandle::new(
+            self.inner.clone(),
+            "offEvent",
+            Some(event.to_owned()),
+            cb
+        )
+        .released_by(released))
+    }
+
+    /// Register a one-shot callback for a background event with a decoded
+    /// payload.
+    ///
+    /// See [`once_event`](Self::once_event) for the handle semantics.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn once_background_event<F>(
+        &self,
+        event: BackgroundEvent,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + FnOnce(BackgroundEventData)
+    {
+        self.once_event(event.as_str(), move |payload| {
+            callback(BackgroundEventData::from_js(event, &payload));
+        })
+    }
+
+    /// Register a one-shot callback for the next invoice result.
+    ///
+    /// The status is `None` when Telegram reports a value unknown to this
+    /// crate. See [`once_event`](Self::once_event) for the handle semantics.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use telegram_webapp_sdk::webapp::{InvoiceStatus, TelegramWebApp};
+    /// # let app = TelegramWebApp::instance().unwrap();
+    /// let _handle = app
+    ///     .once_invoice_closed(|status| {
+    ///         if status == Some(InvoiceStatus::Paid) { /* deliver */ }
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn once_invoice_closed<F>(
+        &self,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + FnOnce(Option<InvoiceStatus>)
+    {
+        self.once_event(BackgroundEvent::InvoiceClosed.as_str(), move |payload| {
+            callback(InvoiceStatus::from_event(&payload));
+        })
+    }
+
+    /// Register a one-shot callback for the next scanned QR text.
+    ///
+    /// See [`once_event`](Self::once_event) for the handle semantics.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn once_qr_text_received<F>(
+        &self,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + FnOnce(String)
+    {
+        self.once_background_event(BackgroundEvent::QrTextReceived, move |data| {
+            if let BackgroundEventData::QrTextReceived {
+                data
+            } = data
+            {
+                callback(data);
+            }
+        })
+    }
+
     /// Register a callback for theme changes.
     ///
     /// Returns an [`EventHandle`] that can be passed to
@@ -199,6 +371,76 @@ impl TelegramWebApp {
         ))
     }
 
+    /// Register a debounced callback for viewport changes.
+    ///
+    /// The callback runs once `delay` has passed without another
+    /// `viewportChanged` event, so layout is not recomputed on every frame of
+    /// a keyboard or expand animation. A single timer is reused and a pending
+    /// call is cancelled when the handle is dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use telegram_webapp_sdk::webapp::TelegramWebApp;
+    /// # let app = TelegramWebApp::instance().unwrap();
+    /// let _handle = app
+    ///     .on_viewport_changed_debounced(Duration::from_millis(100), || {})
+    ///     .expect("subscribe");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `window` is unavailable or the underlying JS
+    /// call fails.
+    pub fn on_viewport_changed_debounced<F>(
+        &self,
+        delay: Duration,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut()>, JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let debouncer = Debouncer::new(delay, callback)?;
+        self.on_viewport_changed(move || debouncer.trigger())
+    }
+
+    /// Register a debounced callback for safe area changes.
+    ///
+    /// See [`on_viewport_changed_debounced`](Self::on_viewport_changed_debounced).
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `window` is unavailable or the underlying JS
+    /// call fails.
+    pub fn on_safe_area_changed_debounced<F>(
+        &self,
+        delay: Duration,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut()>, JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let debouncer = Debouncer::new(delay, callback)?;
+        self.on_safe_area_changed(move || debouncer.trigger())
+    }
+
+    /// Register a debounced callback for content safe area changes.
+    ///
+    /// See [`on_viewport_changed_debounced`](Self::on_viewport_changed_debounced).
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `window` is unavailable or the underlying JS
+    /// call fails.
+    pub fn on_content_safe_area_changed_debounced<F>(
+        &self,
+        delay: Duration,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut()>, JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let debouncer = Debouncer::new(delay, callback)?;
+        self.on_content_safe_area_changed(move || debouncer.trigger())
+    }
+
     /// Register a callback for received clipboard text.
     ///
     /// Returns an [`EventHandle`] that can be passed to
@@ -276,15 +518,122 @@ impl TelegramWebApp {
             cb
         ))
     }
+
+    /// Register a callback for failed fullscreen requests.
+    ///
+    /// Returns an [`EventHandle`] that can be passed to
+    /// [`off_event`](Self::off_event).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use telegram_webapp_sdk::webapp::{FullscreenError, TelegramWebApp};
+    /// # let app = TelegramWebApp::instance().unwrap();
+    /// let handle = app
+    ///     .on_fullscreen_failed(|error| {
+    ///         if error == FullscreenError::Unsupported { /* keep the compact layout */ }
+    ///     })
+    ///     .unwrap();
+    /// app.off_event(handle).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_fullscreen_failed<F>(
+        &self,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + Fn(FullscreenError)
+    {
+        self.on_event("fullscreenFailed", move |payload| {
+            if let Some(error) = FullscreenError::from_event(&payload) {
+                callback(error);
+            }
+        })
+    }
+
+    /// Register a callback for failed `setEmojiStatus` requests.
+    ///
+    /// Returns an [`EventHandle`] that can be passed to
+    /// [`off_event`](Self::off_event).
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_emoji_status_failed<F>(
+        &self,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + Fn(EmojiStatusError)
+    {
+        self.on_event("emojiStatusFailed", move |payload| {
+            if let Some(error) = EmojiStatusError::from_event(&payload) {
+                callback(error);
+            }
+        })
+    }
+
+    /// Register a callback for answers to `downloadFile` requests.
+    ///
+    /// Returns an [`EventHandle`] that can be passed to
+    /// [`off_event`](Self::off_event).
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_file_download_requested<F>(
+        &self,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + Fn(FileDownloadStatus)
+    {
+        self.on_event("fileDownloadRequested", move |payload| {
+            if let Some(status) = FileDownloadStatus::from_event(&payload) {
+                callback(status);
+            }
+        })
+    }
+
+    /// Register a callback for answers to location requests.
+    ///
+    /// The callback receives `None` when the user denied access.
+    ///
+    /// Returns an [`EventHandle`] that can be passed to
+    /// [`off_event`](Self::off_event).
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    #[cfg(feature = "location")]
+    pub fn on_location_requested<F>(
+        &self,
+        callback: F
+    ) -> Result<EventHandle<dyn FnMut(JsValue)>, JsValue>
+    where
+        F: 'static + Fn(Option<LocationData>)
+    {
+        self.on_event("locationRequested", move |payload| {
+            callback(LocationData::from_event(&payload));
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use js_sys::{Function, Object, Reflect};
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        time::Duration
+    };
+
+    use js_sys::{Function, Object, Promise, Reflect};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
     use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
     use web_sys::window;
 
-    use crate::webapp::TelegramWebApp;
+    use crate::webapp::{
+        BackgroundEvent, BackgroundEventData, FullscreenError, InvoiceStatus, TelegramWebApp
+    };
 
     wasm_bindgen_test_configure!(run_in_browser);
 
@@ -319,4 +668,136 @@ mod tests {
             "callback should be removed"
         );
     }
+
+    async fn sleep(millis: i32) {
+        let promise = Promise::new(&mut |resolve, _| {
+            let _ = window()
+                .expect("window")
+                .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis);
+        });
+        let _ = JsFuture::from(promise).await;
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn viewport_changed_debounced_fires_once() {
+        let webapp = setup_webapp();
+        let app = TelegramWebApp::instance().expect("instance");
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+
+        let _handle = app
+            .on_viewport_changed_debounced(Duration::from_millis(20), move || {
+                counter.set(counter.get() + 1);
+            })
+            .expect("subscribe");
+        let emit = Reflect::get(&webapp, &"viewportChanged".into())
+            .expect("registered")
+            .dyn_into::<Function>()
+            .expect("function");
+        for _ in 0..5 {
+            let _ = emit.call0(&webapp);
+        }
+        assert_eq!(calls.get(), 0);
+
+        sleep(60).await;
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn dropping_debounced_handle_cancels_pending_call() {
+        let webapp = setup_webapp();
+        let app = TelegramWebApp::instance().expect("instance");
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+
+        let handle = app
+            .on_safe_area_changed_debounced(Duration::from_millis(20), move || {
+                counter.set(counter.get() + 1);
+            })
+            .expect("subscribe");
+        let emit = Reflect::get(&webapp, &"safeAreaChanged".into())
+            .expect("registered")
+            .dyn_into::<Function>()
+            .expect("function");
+        let _ = emit.call0(&webapp);
+        drop(handle);
+
+        sleep(60).await;
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn fullscreen_failed_is_typed() {
+        let webapp = setup_webapp();
+        let app = TelegramWebApp::instance().expect("instance");
+        let seen = Rc::new(RefCell::new(None));
+        let sink = Rc::clone(&seen);
+
+        let _handle = app
+            .on_fullscreen_failed(move |error| *sink.borrow_mut() = Some(error))
+            .expect("subscribe");
+        let emit = Reflect::get(&webapp, &"fullscreenFailed".into())
+            .expect("registered")
+            .dyn_into::<Function>()
+            .expect("function");
+        let payload = Object::new();
+        let _ = Reflect::set(&payload, &"error".into(), &"ALREADY_FULLSCREEN".into());
+        let _ = emit.call1(&webapp, &payload);
+        assert_eq!(*seen.borrow(), Some(FullscreenError::AlreadyFullscreen));
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn once_invoice_closed_unregisters_after_first_delivery() {
+        let webapp = setup_webapp();
+        let app = TelegramWebApp::instance().expect("instance");
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&seen);
+
+        let _handle = app
+            .once_invoice_closed(move |status| sink.borrow_mut().push(status))
+            .expect("subscribe");
+        let emit = Reflect::get(&webapp, &"invoiceClosed".into())
+            .expect("registered")
+            .dyn_into::<Function>()
+            .expect("function");
+        let payload = Object::new();
+        let _ = Reflect::set(&payload, &"status".into(), &"paid".into());
+        let _ = emit.call1(&webapp, &payload);
+        let _ = emit.call1(&webapp, &payload);
+
+        assert_eq!(*seen.borrow(), [Some(InvoiceStatus::Paid)]);
+        assert!(!Reflect::has(&webapp, &"invoiceClosed".into()).unwrap_or(true));
+    }
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn background_event_payload_is_typed() {
+        let webapp = setup_webapp();
+        let app = TelegramWebApp::instance().expect("instance");
+        let seen = Rc::new(RefCell::new(None));
+        let sink = Rc::clone(&seen);
+
+        let _handle = app
+            .on_background_event_typed(BackgroundEvent::QrTextReceived, move |data| {
+                *sink.borrow_mut() = Some(data);
+            })
+            .expect("subscribe");
+        let emit = Reflect::get(&webapp, &"qrTextReceived".into())
+            .expect("registered")
+            .dyn_into::<Function>()
+            .expect("function");
+        let payload = Object::new();
+        let _ = Reflect::set(&payload, &"data".into(), &"hello".into());
+        let _ = emit.call1(&webapp, &payload);
+        assert_eq!(
+            *seen.borrow(),
+            Some(BackgroundEventData::QrTextReceived {
+                data: "hello".into()
+            })
+        );
+    }
 }
@@ -248,10 +248,7 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn is_active(&self) -> bool {
-        Reflect::get(&self.inner, &"isActive".into())
-            .ok()
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false)
+        self.js().is_active().as_bool().unwrap_or(false)
     }
 
     /// Returns whether the mini app is expanded to its maximum available
@@ -266,10 +263,7 @@ impl TelegramWebApp {
     /// }
     /// ```
     pub fn is_expanded(&self) -> bool {
-        Reflect::get(&self.inner, &"isExpanded".into())
-            .ok()
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false)
+        self.js().is_expanded().as_bool().unwrap_or(false)
     }
 }
 
@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Cache of nested `Telegram.WebApp` objects.
+//!
+//! `MainButton`, `BackButton` and friends are created once by
+//! `telegram-web-app.js` and never replaced, so each wrapper looks them up
+//! on first use and reuses the handle afterwards. Clones share the cache;
+//! [`TelegramWebApp::instance`] starts with an empty one, so re-initializing
+//! the WebApp drops stale handles.
+
+use std::cell::RefCell;
+
+use js_sys::{Object, Reflect};
+use wasm_bindgen::JsCast;
+
+use super::TelegramWebApp;
+
+/// Nested objects fetched so far, keyed by property name.
+#[derive(Default)]
+pub(crate) struct NestedObjects {
+    objects: RefCell<Vec<(&'static str, Object)>>
+}
+
+impl TelegramWebApp {
+    /// Returns `WebApp[name]`, or `None` if it is missing or not an object.
+    ///
+    /// Missing objects are not cached, so they are picked up once the client
+    /// provides them.
+    pub(super) fn nested_object(&self, name: &'static str) -> Option<Object> {
+        let mut objects = self.nested.objects.borrow_mut();
+        if let Some((_, obj)) = objects.iter().find(|(key, _)| *key == name) {
+            return Some(obj.clone());
+        }
+        let obj = Reflect::get(&self.inner, &name.into())
+            .ok()?
+            .dyn_into::<Object>()
+            .ok()?;
+        objects.push((name, obj.clone()));
+        Some(obj)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Object, Reflect};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::window;
+
+    use crate::webapp::TelegramWebApp;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn nested_objects_are_cached_per_instance() {
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+
+        let app = TelegramWebApp::instance().unwrap();
+        assert!(app.nested_object("BackButton").is_none());
+
+        let first = Object::new();
+        let _ = Reflect::set(&webapp, &"BackButton".into(), &first);
+        assert!(Object::is(
+            &app.nested_object("BackButton").unwrap(),
+            &first
+        ));
+
+        let second = Object::new();
+        let _ = Reflect::set(&webapp, &"BackButton".into(), &second);
+        assert!(Object::is(
+            &app.clone().nested_object("BackButton").unwrap(),
+            &first
+        ));
+
+        let fresh = TelegramWebApp::instance().unwrap();
+        assert!(Object::is(
+            &fresh.nested_object("BackButton").unwrap(),
+            &second
+        ));
+    }
+}
@@ -8,7 +8,7 @@ use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
 use crate::{
     core::types::download_file_params::DownloadFileParams,
     webapp::{
-        TelegramWebApp,
+        ContactRequestResult, TelegramWebApp, WriteAccessResult,
         core::{await_one_shot, one_shot_promise}
     }
 };
@@ -20,33 +20,36 @@ impl TelegramWebApp {
     /// Returns [`JsValue`] if the underlying JS call fails.
     pub fn request_write_access_with_callback<F>(&self, callback: F) -> Result<(), JsValue>
     where
-        F: 'static + FnOnce(bool)
+        F: 'static + FnOnce(WriteAccessResult)
     {
         let cb = Closure::once_into_js(move |v: JsValue| {
-            callback(v.as_bool().unwrap_or(false));
+            callback(WriteAccessResult::from_granted(
+                v.as_bool().unwrap_or(false)
+            ));
         });
         self.call1("requestWriteAccess", &cb)
     }
 
     /// Async wrapper over `WebApp.requestWriteAccess`.
     ///
-    /// Resolves with `true` when the user grants permission to receive
-    /// messages from the bot.
+    /// Resolves with [`WriteAccessResult::Allowed`] when the user grants
+    /// permission to receive messages from the bot.
     ///
     /// # Examples
     /// ```no_run
-    /// # use telegram_webapp_sdk::webapp::TelegramWebApp;
+    /// # use telegram_webapp_sdk::webapp::{TelegramWebApp, WriteAccessResult};
     /// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
     /// let app = TelegramWebApp::try_instance()?;
-    /// let granted: bool = app.request_write_access().await?;
-    /// let _ = granted;
+    /// if app.request_write_access().await? == WriteAccessResult::Allowed {
+    ///     // the bot can now message the user
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     /// Returns [`JsValue`] if the underlying JS call fails.
-    pub async fn request_write_access(&self) -> Result<bool, JsValue> {
+    pub async fn request_write_access(&self) -> Result<WriteAccessResult, JsValue> {
         let webapp = self.inner.clone();
         let promise = one_shot_promise(move |resolve, _reject| {
             let cb = Closure::once_into_js(move |granted: JsValue| {
@@ -60,7 +63,61 @@ impl TelegramWebApp {
             Ok(())
         });
         let value = await_one_shot(promise).await?;
-        Ok(value.as_bool().unwrap_or(false))
+        Ok(WriteAccessResult::from_granted(
+            value.as_bool().unwrap_or(false)
+        ))
+    }
+
+    /// Callback variant of [`Self::request_contact`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn request_contact_with_callback<F>(&self, callback: F) -> Result<(), JsValue>
+    where
+        F: 'static + FnOnce(Result<ContactRequestResult, JsValue>)
+    {
+        let cb = Closure::once_into_js(move |shared: JsValue, payload: JsValue| {
+            callback(contact_result(&contact_payload(shared, payload)));
+        });
+        self.call1("requestContact", &cb)
+    }
+
+    /// Async wrapper over `WebApp.requestContact`.
+    ///
+    /// Resolves with the shared contact, or [`ContactRequestResult::Cancelled`]
+    /// when the user declines.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use telegram_webapp_sdk::webapp::{ContactRequestResult, TelegramWebApp};
+    /// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+    /// let app = TelegramWebApp::try_instance()?;
+    /// if let ContactRequestResult::Sent(contact) = app.request_contact().await? {
+    ///     // send `contact.response` to the backend for validation
+    ///     let _ = contact.phone_number;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails or the shared
+    /// contact cannot be read.
+    pub async fn request_contact(&self) -> Result<ContactRequestResult, JsValue> {
+        let webapp = self.inner.clone();
+        let promise = one_shot_promise(move |resolve, _reject| {
+            let cb = Closure::once_into_js(move |shared: JsValue, payload: JsValue| {
+                let _ = resolve.call1(&JsValue::NULL, &contact_payload(shared, payload));
+            });
+            let f = Reflect::get(&webapp, &"requestContact".into())?;
+            let func = f
+                .dyn_ref::<Function>()
+                .ok_or_else(|| JsValue::from_str("requestContact is not a function"))?;
+            func.call1(&webapp, &cb)?;
+            Ok(())
+        });
+        let value = await_one_shot(promise).await?;
+        contact_result(&value)
     }
 
     /// Callback variant of [`Self::request_emoji_status_access`].
@@ -270,3 +327,17 @@ impl TelegramWebApp {
         Ok(value.as_string().unwrap_or_default())
     }
 }
+
+/// Picks the `requestContact` callback argument to parse: the response
+/// object on current clients, the bare `shared` flag on older ones.
+fn contact_payload(shared: JsValue, payload: JsValue) -> JsValue {
+    if payload.is_object() { payload } else { shared }
+}
+
+fn contact_result(payload: &JsValue) -> Result<ContactRequestResult, JsValue> {
+    if payload.as_bool() == Some(false) {
+        return Ok(ContactRequestResult::Cancelled);
+    }
+    ContactRequestResult::from_event(payload)
+        .ok_or_else(|| JsValue::from_str("requestContact returned no readable contact"))
+}
@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::any::Any;
+
+use wasm_bindgen::JsValue;
+
+use crate::webapp::{
+    TelegramWebApp,
+    types::{BackgroundEvent, EventHandle}
+};
+
+/// Owner of any number of [`EventHandle`]s, unregistered together when the
+/// scope is dropped.
+///
+/// Pages and components that register many listeners keep a single scope
+/// instead of a field per handle. Handles are released in reverse
+/// registration order.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::webapp::{EventScope, TelegramWebApp};
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let app = TelegramWebApp::try_instance()?;
+/// let mut scope = EventScope::new(&app);
+/// scope.on_viewport_changed(|| { /* relayout */ })?;
+/// scope.on_theme_changed(|| { /* restyle */ })?;
+/// scope.add(app.on_invoice_closed(|_| {})?);
+/// // every listener above is removed
+/// drop(scope);
+/// # Ok(())
+/// # }
+/// ```
+pub struct EventScope {
+    app:     TelegramWebApp,
+    handles: Vec<Box<dyn Any>>
+}
+
+impl EventScope {
+    /// Creates an empty scope registering listeners on `app`.
+    pub fn new(app: &TelegramWebApp) -> Self {
+        Self {
+            app:     app.clone(),
+            handles: Vec::new()
+        }
+    }
+
+    /// Takes ownership of `handle`.
+    pub fn add<T: ?Sized + 'static>(&mut self, handle: EventHandle<T>) -> &mut Self {
+        self.handles.push(Box::new(handle));
+        self
+    }
+
+    /// Returns the number of owned handles.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns whether the scope owns no handles.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Unregisters every owned handle, keeping the scope usable.
+    pub fn clear(&mut self) {
+        while let Some(handle) = self.handles.pop() {
+            drop(handle);
+        }
+    }
+
+    /// Registers an event handler, see [`TelegramWebApp::on_event`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_event<F>(&mut self, event: &str, callback: F) -> Result<(), JsValue>
+    where
+        F: 'static + Fn(JsValue)
+    {
+        let handle = self.app.on_event(event, callback)?;
+        self.add(handle);
+        Ok(())
+    }
+
+    /// Registers a background event handler, see
+    /// [`TelegramWebApp::on_background_event`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_background_event<F>(
+        &mut self,
+        event: BackgroundEvent,
+        callback: F
+    ) -> Result<(), JsValue>
+    where
+        F: 'static + Fn(JsValue)
+    {
+        let handle = self.app.on_background_event(event, callback)?;
+        self.add(handle);
+        Ok(())
+    }
+
+    /// Registers a theme change handler, see
+    /// [`TelegramWebApp::on_theme_changed`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_theme_changed<F>(&mut self, callback: F) -> Result<(), JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let handle = self.app.on_theme_changed(callback)?;
+        self.add(handle);
+        Ok(())
+    }
+
+    /// Registers a viewport change handler, see
+    /// [`TelegramWebApp::on_viewport_changed`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_viewport_changed<F>(&mut self, callback: F) -> Result<(), JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let handle = self.app.on_viewport_changed(callback)?;
+        self.add(handle);
+        Ok(())
+    }
+
+    /// Registers a safe area change handler, see
+    /// [`TelegramWebApp::on_safe_area_changed`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_safe_area_changed<F>(&mut self, callback: F) -> Result<(), JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let handle = self.app.on_safe_area_changed(callback)?;
+        self.add(handle);
+        Ok(())
+    }
+
+    /// Registers a content safe area change handler, see
+    /// [`TelegramWebApp::on_content_safe_area_changed`].
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if the underlying JS call fails.
+    pub fn on_content_safe_area_changed<F>(&mut self, callback: F) -> Result<(), JsValue>
+    where
+        F: 'static + Fn()
+    {
+        let handle = self.app.on_content_safe_area_changed(callback)?;
+        self.add(handle);
+        Ok(())
+    }
+}
+
+impl Drop for EventScope {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl TelegramWebApp {
+    /// Creates an [`EventScope`] registering listeners on this instance.
+    pub fn event_scope(&self) -> EventScope {
+        EventScope::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Function, Object, Reflect};
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::window;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    fn dropping_scope_unregisters_all_handles() {
+        let win = window().unwrap();
+        let telegram = Object::new();
+        let webapp = Object::new();
+        let on_event = Function::new_with_args("name, cb", "this[name] = cb;");
+        let off_event = Function::new_with_args("name", "delete this[name];");
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &off_event);
+        let _ = Reflect::set(&telegram, &"WebApp".into(), &webapp);
+        let _ = Reflect::set(&win, &"Telegram".into(), &telegram);
+
+        let app = TelegramWebApp::instance().unwrap();
+        let mut scope = app.event_scope();
+        scope.on_viewport_changed(|| {}).unwrap();
+        scope.on_theme_changed(|| {}).unwrap();
+        scope.add(app.on_invoice_closed(|_| {}).unwrap());
+        assert_eq!(scope.len(), 3);
+        assert!(Reflect::has(&webapp, &"viewportChanged".into()).unwrap());
+
+        drop(scope);
+        for event in ["viewportChanged", "themeChanged", "invoiceClosed"] {
+            assert!(!Reflect::has(&webapp, &event.into()).unwrap());
+        }
+    }
+}
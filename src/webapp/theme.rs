@@ -9,9 +9,7 @@ use crate::webapp::TelegramWebApp;
 impl TelegramWebApp {
     /// Returns `WebApp.colorScheme` — `"light"` or `"dark"`.
     pub fn color_scheme(&self) -> Option<String> {
-        Reflect::get(&self.inner, &"colorScheme".into())
-            .ok()
-            .and_then(|v| v.as_string())
+        self.js().color_scheme().as_string()
     }
 
     /// Returns the current `WebApp.headerColor` value.
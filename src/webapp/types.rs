@@ -1,12 +1,48 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    rc::Rc
+};
+
 use js_sys::{Function, Object, Reflect};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
 
 use crate::logger;
 
+thread_local! {
+    /// Number of live [`EventHandle`]s per `WebApp.onEvent` event name.
+    static REGISTERED_EVENTS: RefCell<BTreeMap<String, usize>> = const {
+        RefCell::new(BTreeMap::new())
+    };
+}
+
+/// Returns the sorted names of events with at least one live handler.
+pub(crate) fn registered_events() -> Vec<String> {
+    REGISTERED_EVENTS.with(|events| events.borrow().keys().cloned().collect())
+}
+
+/// Stops counting a handler for `event` unless `released` is already set.
+///
+/// Shared by [`EventHandle`] and one-shot callbacks removing themselves.
+pub(super) fn release_event(event: &str, released: &Cell<bool>) {
+    if released.replace(true) {
+        return;
+    }
+    let _ = REGISTERED_EVENTS.try_with(|events| {
+        let mut events = events.borrow_mut();
+        if let Some(count) = events.get_mut(event) {
+            *count -= 1;
+            if *count == 0 {
+                events.remove(event);
+            }
+        }
+    });
+}
+
 /// Handle returned when registering callbacks.
 ///
 /// Automatically unregisters the callback when dropped, implementing RAII
@@ -33,7 +69,10 @@ pub struct EventHandle<T: ?Sized> {
     pub(super) method:       &'static str,
     pub(super) event:        Option<String>,
     pub(super) callback:     Closure<T>,
-    pub(super) unregistered: bool
+    pub(super) unregistered: bool,
+    /// Set once the handler is no longer registered, either through this
+    /// handle or by a one-shot callback removing itself.
+    pub(super) released:     Rc<Cell<bool>>
 }
 
 impl<T: ?Sized> EventHandle<T> {
@@ -43,17 +82,29 @@ impl<T: ?Sized> EventHandle<T> {
         event: Option<String>,
         callback: Closure<T>
     ) -> Self {
+        if let Some(event) = &event {
+            REGISTERED_EVENTS
+                .with(|events| *events.borrow_mut().entry(event.clone()).or_default() += 1);
+        }
         Self {
             target,
             method,
             event,
             callback,
-            unregistered: false
+            unregistered: false,
+            released: Rc::default()
         }
     }
 
+    /// Shares `released` with a callback that unregisters itself, so the
+    /// handle does not unregister or uncount it a second time.
+    pub(super) fn released_by(mut self, released: Rc<Cell<bool>>) -> Self {
+        self.released = released;
+        self
+    }
+
     pub(crate) fn unregister(mut self) -> Result<(), JsValue> {
-        if self.unregistered {
+        if self.unregistered || self.released.get() {
             return Ok(());
         }
 
@@ -82,6 +133,12 @@ impl<T: ?Sized> Drop for EventHandle<T> {
     /// properly cleaned up even if the user forgets to manually unregister.
     /// Errors during unregistration are logged but do not panic.
     fn drop(&mut self) {
+        if self.released.get() {
+            return;
+        }
+        if let Some(event) = &self.event {
+            release_event(event, &self.released);
+        }
         if self.unregistered {
             return;
         }
@@ -150,7 +207,18 @@ impl BottomButton {
 ///     }
 /// }
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+///
+/// Positions round-trip through strings and serde:
+///
+/// ```
+/// use telegram_webapp_sdk::webapp::SecondaryButtonPosition;
+///
+/// let position: SecondaryButtonPosition = "left".parse().unwrap();
+/// assert_eq!(position, SecondaryButtonPosition::Left);
+/// assert_eq!(position.to_string(), "left");
+/// assert!("middle".parse::<SecondaryButtonPosition>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SecondaryButtonPosition {
     /// Displayed above the main button.
@@ -164,9 +232,19 @@ pub enum SecondaryButtonPosition {
 }
 
 impl SecondaryButtonPosition {
-    pub(super) fn from_js_value(value: JsValue) -> Option<Self> {
-        let as_str = value.as_string()?;
-        match as_str.as_str() {
+    /// Returns the position name used by Telegram.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Left => "left",
+            Self::Bottom => "bottom",
+            Self::Right => "right"
+        }
+    }
+
+    /// Parses a position name reported by Telegram.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
             "top" => Some(Self::Top),
             "left" => Some(Self::Left),
             "bottom" => Some(Self::Bottom),
@@ -174,6 +252,402 @@ impl SecondaryButtonPosition {
             _ => None
         }
     }
+
+    /// Parses a JS string value, returning `None` for anything else.
+    pub fn from_js_value(value: JsValue) -> Option<Self> {
+        Self::from_name(&value.as_string()?)
+    }
+}
+
+impl std::fmt::Display for SecondaryButtonPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for SecondaryButtonPosition {
+    type Err = ParsePositionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| ParsePositionError(s.to_owned()))
+    }
+}
+
+/// Error returned when parsing an unknown [`SecondaryButtonPosition`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePositionError(pub String);
+
+impl std::fmt::Display for ParsePositionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown button position `{}`, expected top, left, bottom or right",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParsePositionError {}
+
+/// Outcome of an invoice opened with `WebApp.openInvoice`.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::webapp::InvoiceStatus;
+///
+/// assert_eq!(
+///     InvoiceStatus::from_status("paid"),
+///     Some(InvoiceStatus::Paid)
+/// );
+/// assert_eq!(InvoiceStatus::Cancelled.as_str(), "cancelled");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InvoiceStatus {
+    /// The invoice was paid successfully.
+    Paid,
+    /// The user closed the invoice without paying.
+    Cancelled,
+    /// The payment failed.
+    Failed,
+    /// The payment is being processed.
+    Pending
+}
+
+impl InvoiceStatus {
+    /// Parses the status string reported by Telegram.
+    pub fn from_status(status: &str) -> Option<Self> {
+        match status {
+            "paid" => Some(Self::Paid),
+            "cancelled" => Some(Self::Cancelled),
+            "failed" => Some(Self::Failed),
+            "pending" => Some(Self::Pending),
+            _ => None
+        }
+    }
+
+    /// Returns the status string used by Telegram.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Paid => "paid",
+            Self::Cancelled => "cancelled",
+            Self::Failed => "failed",
+            Self::Pending => "pending"
+        }
+    }
+
+    /// Extracts the status from an `invoiceClosed` payload.
+    ///
+    /// Accepts both the `{ url, status }` event object and a bare status
+    /// string.
+    pub fn from_event(payload: &JsValue) -> Option<Self> {
+        let status = match payload.as_string() {
+            Some(status) => status,
+            None => Reflect::get(payload, &"status".into()).ok()?.as_string()?
+        };
+        Self::from_status(&status)
+    }
+}
+
+/// Outcome of `WebApp.requestWriteAccess`.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::webapp::WriteAccessResult;
+///
+/// assert_eq!(
+///     WriteAccessResult::from_status("allowed"),
+///     Some(WriteAccessResult::Allowed)
+/// );
+/// assert!(!WriteAccessResult::from_granted(false).is_allowed());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WriteAccessResult {
+    /// The user allowed the bot to message them.
+    Allowed,
+    /// The user declined the request.
+    Cancelled
+}
+
+impl WriteAccessResult {
+    /// Parses the status string of a `writeAccessRequested` event.
+    pub fn from_status(status: &str) -> Option<Self> {
+        match status {
+            "allowed" => Some(Self::Allowed),
+            "cancelled" => Some(Self::Cancelled),
+            _ => None
+        }
+    }
+
+    /// Maps the flag passed to the `requestWriteAccess` callback.
+    pub const fn from_granted(granted: bool) -> Self {
+        if granted {
+            Self::Allowed
+        } else {
+            Self::Cancelled
+        }
+    }
+
+    /// Returns whether access was granted.
+    pub const fn is_allowed(self) -> bool {
+        matches!(self, Self::Allowed)
+    }
+
+    /// Extracts the result from a `writeAccessRequested` payload.
+    ///
+    /// Accepts the `{ status }` event object, a bare status string and the
+    /// boolean passed to the `requestWriteAccess` callback.
+    pub fn from_event(payload: &JsValue) -> Option<Self> {
+        if let Some(granted) = payload.as_bool() {
+            return Some(Self::from_granted(granted));
+        }
+        let status = match payload.as_string() {
+            Some(status) => status,
+            None => Reflect::get(payload, &"status".into()).ok()?.as_string()?
+        };
+        Self::from_status(&status)
+    }
+}
+
+/// Contact shared by the user through `WebApp.requestContact`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestedContact {
+    /// Phone number of the user.
+    pub phone_number: String,
+    /// First name of the user.
+    pub first_name:   String,
+    /// Last name of the user.
+    #[serde(default)]
+    pub last_name:    Option<String>,
+    /// Telegram identifier of the user.
+    pub user_id:      u64,
+    /// Signed, URL-encoded `response` string; validate it on the backend like
+    /// init data before trusting the contact.
+    #[serde(default)]
+    pub response:     String
+}
+
+/// Outcome of `WebApp.requestContact`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContactRequestResult {
+    /// The user shared their contact.
+    Sent(RequestedContact),
+    /// The user declined the request.
+    Cancelled
+}
+
+impl ContactRequestResult {
+    /// Extracts the result from a `contactRequested` payload or the object
+    /// passed to the `requestContact` callback.
+    ///
+    /// Returns `None` for unknown statuses and for `"sent"` payloads without
+    /// a readable contact.
+    pub fn from_event(payload: &JsValue) -> Option<Self> {
+        let status = Reflect::get(payload, &"status".into()).ok()?.as_string()?;
+        match status.as_str() {
+            "sent" => {
+                let unsafe_data = Reflect::get(payload, &"responseUnsafe".into()).ok()?;
+                let contact = Reflect::get(&unsafe_data, &"contact".into()).ok()?;
+                let mut contact: RequestedContact =
+                    serde_wasm_bindgen::from_value(contact).ok()?;
+                contact.response = Reflect::get(payload, &"response".into())
+                    .ok()
+                    .and_then(|response| response.as_string())
+                    .unwrap_or_default();
+                Some(Self::Sent(contact))
+            }
+            "cancelled" => Some(Self::Cancelled),
+            _ => None
+        }
+    }
+}
+
+/// Reads `key` from an event payload, or the payload itself when it is a
+/// bare string.
+fn payload_field(payload: &JsValue, key: &str) -> Option<String> {
+    match payload.as_string() {
+        Some(value) => Some(value),
+        None => Reflect::get(payload, &key.into()).ok()?.as_string()
+    }
+}
+
+/// Error reported by the `fullscreenFailed` event.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::webapp::FullscreenError;
+///
+/// assert_eq!(
+///     FullscreenError::from_code("UNSUPPORTED"),
+///     FullscreenError::Unsupported
+/// );
+/// assert_eq!(
+///     FullscreenError::AlreadyFullscreen.as_str(),
+///     "ALREADY_FULLSCREEN"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FullscreenError {
+    /// The client does not support fullscreen mode.
+    Unsupported,
+    /// The app is already displayed in fullscreen.
+    AlreadyFullscreen,
+    /// Error code not known to this crate.
+    Other(String)
+}
+
+impl FullscreenError {
+    /// Maps the error code reported by Telegram.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "UNSUPPORTED" => Self::Unsupported,
+            "ALREADY_FULLSCREEN" => Self::AlreadyFullscreen,
+            other => Self::Other(other.to_owned())
+        }
+    }
+
+    /// Returns the error code used by Telegram.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Unsupported => "UNSUPPORTED",
+            Self::AlreadyFullscreen => "ALREADY_FULLSCREEN",
+            Self::Other(code) => code
+        }
+    }
+
+    /// Extracts the error from a `fullscreenFailed` payload.
+    ///
+    /// Accepts both the `{ error }` event object and a bare error code.
+    pub fn from_event(payload: &JsValue) -> Option<Self> {
+        payload_field(payload, "error").map(|code| Self::from_code(&code))
+    }
+}
+
+impl std::fmt::Display for FullscreenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Fullscreen request failed: {}", self.as_str())
+    }
+}
+
+impl std::error::Error for FullscreenError {}
+
+/// Error reported by the `emojiStatusFailed` event.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::webapp::EmojiStatusError;
+///
+/// assert_eq!(
+///     EmojiStatusError::from_code("USER_DECLINED"),
+///     EmojiStatusError::UserDeclined
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EmojiStatusError {
+    /// The client does not support emoji statuses.
+    Unsupported,
+    /// The emoji is not one of the bot's suggested statuses.
+    SuggestedEmojiInvalid,
+    /// The requested duration is invalid.
+    DurationInvalid,
+    /// The user declined to set the status.
+    UserDeclined,
+    /// The status could not be saved on the server.
+    ServerError,
+    /// Telegram reported an unknown error.
+    UnknownError,
+    /// Error code not known to this crate.
+    Other(String)
+}
+
+impl EmojiStatusError {
+    /// Maps the error code reported by Telegram.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "UNSUPPORTED" => Self::Unsupported,
+            "SUGGESTED_EMOJI_INVALID" => Self::SuggestedEmojiInvalid,
+            "DURATION_INVALID" => Self::DurationInvalid,
+            "USER_DECLINED" => Self::UserDeclined,
+            "SERVER_ERROR" => Self::ServerError,
+            "UNKNOWN_ERROR" => Self::UnknownError,
+            other => Self::Other(other.to_owned())
+        }
+    }
+
+    /// Returns the error code used by Telegram.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Unsupported => "UNSUPPORTED",
+            Self::SuggestedEmojiInvalid => "SUGGESTED_EMOJI_INVALID",
+            Self::DurationInvalid => "DURATION_INVALID",
+            Self::UserDeclined => "USER_DECLINED",
+            Self::ServerError => "SERVER_ERROR",
+            Self::UnknownError => "UNKNOWN_ERROR",
+            Self::Other(code) => code
+        }
+    }
+
+    /// Extracts the error from an `emojiStatusFailed` payload.
+    ///
+    /// Accepts both the `{ error }` event object and a bare error code.
+    pub fn from_event(payload: &JsValue) -> Option<Self> {
+        payload_field(payload, "error").map(|code| Self::from_code(&code))
+    }
+}
+
+impl std::fmt::Display for EmojiStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Emoji status request failed: {}", self.as_str())
+    }
+}
+
+impl std::error::Error for EmojiStatusError {}
+
+/// Outcome of `WebApp.downloadFile`, reported by `fileDownloadRequested`.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::webapp::FileDownloadStatus;
+///
+/// assert_eq!(
+///     FileDownloadStatus::from_status("downloading"),
+///     Some(FileDownloadStatus::Downloading)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileDownloadStatus {
+    /// The user accepted and the download started.
+    Downloading,
+    /// The user declined the download.
+    Cancelled
+}
+
+impl FileDownloadStatus {
+    /// Parses the status string reported by Telegram.
+    pub fn from_status(status: &str) -> Option<Self> {
+        match status {
+            "downloading" => Some(Self::Downloading),
+            "cancelled" => Some(Self::Cancelled),
+            _ => None
+        }
+    }
+
+    /// Returns the status string used by Telegram.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Downloading => "downloading",
+            Self::Cancelled => "cancelled"
+        }
+    }
+
+    /// Extracts the status from a `fileDownloadRequested` payload.
+    ///
+    /// Accepts both the `{ status }` event object and a bare status string.
+    pub fn from_event(payload: &JsValue) -> Option<Self> {
+        Self::from_status(&payload_field(payload, "status")?)
+    }
 }
 
 /// Safe area insets reported by Telegram.
@@ -193,7 +667,7 @@ impl SecondaryButtonPosition {
 ///     }
 /// }
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct SafeAreaInset {
     /// Distance from the top edge in CSS pixels.
     pub top:    f64,
@@ -219,6 +693,55 @@ impl SafeAreaInset {
             right
         })
     }
+
+    /// Adds `other` to every side, e.g. to stack the content safe area on
+    /// top of the device safe area.
+    ///
+    /// # Examples
+    /// ```
+    /// use telegram_webapp_sdk::webapp::SafeAreaInset;
+    ///
+    /// let device = SafeAreaInset {
+    ///     top:    20.0,
+    ///     bottom: 10.0,
+    ///     left:   0.0,
+    ///     right:  0.0
+    /// };
+    /// let content = SafeAreaInset {
+    ///     top: 40.0,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(device.combine(content).top, 60.0);
+    /// ```
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            top:    self.top + other.top,
+            bottom: self.bottom + other.bottom,
+            left:   self.left + other.left,
+            right:  self.right + other.right
+        }
+    }
+
+    /// Formats the inset as a CSS `padding` declaration.
+    ///
+    /// # Examples
+    /// ```
+    /// use telegram_webapp_sdk::webapp::SafeAreaInset;
+    ///
+    /// let inset = SafeAreaInset {
+    ///     top:    1.0,
+    ///     right:  2.0,
+    ///     bottom: 3.0,
+    ///     left:   4.0
+    /// };
+    /// assert_eq!(inset.to_padding_css(), "padding: 1px 2px 3px 4px;");
+    /// ```
+    pub fn to_padding_css(&self) -> String {
+        format!(
+            "padding: {}px {}px {}px {}px;",
+            self.top, self.right, self.bottom, self.left
+        )
+    }
 }
 
 /// Parameters accepted by bottom buttons when updating state via `setParams`.
@@ -278,6 +801,56 @@ pub struct BottomButtonParams<'a> {
     pub icon_custom_emoji_id: Option<&'a str>
 }
 
+impl<'a> BottomButtonParams<'a> {
+    /// Starts a [`BottomButtonParamsBuilder`] that validates the values.
+    ///
+    /// # Examples
+    /// ```
+    /// use telegram_webapp_sdk::webapp::BottomButtonParams;
+    ///
+    /// let params = BottomButtonParams::builder()
+    ///     .text("Pay")
+    ///     .color("#2481CC")
+    ///     .has_shine_effect(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(params.text, Some("Pay"));
+    /// assert!(BottomButtonParams::builder().color("blue").build().is_err());
+    /// ```
+    pub fn builder() -> BottomButtonParamsBuilder<'a> {
+        BottomButtonParamsBuilder::default()
+    }
+}
+
+impl BottomButtonParams<'_> {
+    /// Builds the `setParams` argument without going through serde.
+    pub(super) fn to_js(&self) -> Object {
+        let obj = Object::new();
+        let strings = [
+            ("text", self.text),
+            ("color", self.color),
+            ("text_color", self.text_color),
+            ("icon_custom_emoji_id", self.icon_custom_emoji_id)
+        ];
+        for (key, value) in strings {
+            if let Some(value) = value {
+                let _ = Reflect::set(&obj, &key.into(), &value.into());
+            }
+        }
+        let flags = [
+            ("is_active", self.is_active),
+            ("is_visible", self.is_visible),
+            ("has_shine_effect", self.has_shine_effect)
+        ];
+        for (key, value) in flags {
+            if let Some(value) = value {
+                let _ = Reflect::set(&obj, &key.into(), &value.into());
+            }
+        }
+        obj
+    }
+}
+
 /// Additional parameters supported by the secondary button.
 ///
 /// # Examples
@@ -304,6 +877,208 @@ pub struct SecondaryButtonParams<'a> {
     pub position: Option<SecondaryButtonPosition>
 }
 
+impl SecondaryButtonParams<'_> {
+    /// Builds the `setParams` argument without going through serde.
+    pub(super) fn to_js(&self) -> Object {
+        let obj = self.common.to_js();
+        if let Some(position) = self.position {
+            let _ = Reflect::set(&obj, &"position".into(), &position.as_str().into());
+        }
+        obj
+    }
+}
+
+/// Maximum length of a bottom button label in characters.
+pub const MAX_BUTTON_TEXT: usize = 64;
+
+/// Errors produced by [`BottomButtonParamsBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ButtonParamsError {
+    /// The label is empty or blank.
+    EmptyText,
+    /// The label is longer than [`MAX_BUTTON_TEXT`] characters.
+    TextTooLong {
+        /// Length of the label in characters.
+        len: usize
+    },
+    /// A color is not a `#RRGGBB` hex string.
+    InvalidColor(String),
+    /// A position was set, but only the secondary button has one.
+    PositionRequiresSecondary
+}
+
+impl std::fmt::Display for ButtonParamsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyText => write!(f, "Button text is empty"),
+            Self::TextTooLong {
+                len
+            } => write!(
+                f,
+                "Button text is {len} characters, at most {MAX_BUTTON_TEXT} allowed"
+            ),
+            Self::InvalidColor(color) => write!(f, "Invalid button color: {color}"),
+            Self::PositionRequiresSecondary => {
+                write!(f, "Only the secondary button has a position")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ButtonParamsError {}
+
+impl From<ButtonParamsError> for JsValue {
+    fn from(err: ButtonParamsError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Validating builder for [`BottomButtonParams`] and
+/// [`SecondaryButtonParams`].
+///
+/// Telegram silently ignores malformed values, so the builder rejects them
+/// up front: labels must be non-blank and at most [`MAX_BUTTON_TEXT`]
+/// characters, colors must be `#RRGGBB`.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::webapp::{
+///     BottomButtonParams, SecondaryButtonPosition, TelegramWebApp
+/// };
+///
+/// # fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let app = TelegramWebApp::try_instance()?;
+/// let params = BottomButtonParams::builder()
+///     .text("Later")
+///     .position(SecondaryButtonPosition::Left)
+///     .build_secondary()?;
+/// app.set_secondary_button_params(&params)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct BottomButtonParamsBuilder<'a> {
+    text:                 Option<&'a str>,
+    color:                Option<&'a str>,
+    text_color:           Option<&'a str>,
+    is_active:            Option<bool>,
+    is_visible:           Option<bool>,
+    has_shine_effect:     Option<bool>,
+    icon_custom_emoji_id: Option<&'a str>,
+    position:             Option<SecondaryButtonPosition>
+}
+
+impl<'a> BottomButtonParamsBuilder<'a> {
+    /// Sets the label.
+    pub fn text(mut self, text: &'a str) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Sets the background color, `#RRGGBB`.
+    pub fn color(mut self, color: &'a str) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the label color, `#RRGGBB`.
+    pub fn text_color(mut self, color: &'a str) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    /// Sets whether the button is tappable.
+    pub fn is_active(mut self, active: bool) -> Self {
+        self.is_active = Some(active);
+        self
+    }
+
+    /// Sets whether the button is shown.
+    pub fn is_visible(mut self, visible: bool) -> Self {
+        self.is_visible = Some(visible);
+        self
+    }
+
+    /// Sets whether the button plays the shine animation.
+    pub fn has_shine_effect(mut self, shine: bool) -> Self {
+        self.has_shine_effect = Some(shine);
+        self
+    }
+
+    /// Sets the custom emoji shown as the icon.
+    pub fn icon_custom_emoji_id(mut self, id: &'a str) -> Self {
+        self.icon_custom_emoji_id = Some(id);
+        self
+    }
+
+    /// Sets the secondary button position; requires
+    /// [`build_secondary`](Self::build_secondary).
+    pub fn position(mut self, position: SecondaryButtonPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Validates and returns parameters for any bottom button.
+    ///
+    /// # Errors
+    /// Returns [`ButtonParamsError`] for a blank or too long label, a
+    /// malformed color or a position set on non-secondary parameters.
+    pub fn build(self) -> Result<BottomButtonParams<'a>, ButtonParamsError> {
+        if self.position.is_some() {
+            return Err(ButtonParamsError::PositionRequiresSecondary);
+        }
+        self.validate()
+    }
+
+    /// Validates and returns parameters for the secondary button.
+    ///
+    /// # Errors
+    /// Returns [`ButtonParamsError`] for a blank or too long label or a
+    /// malformed color.
+    pub fn build_secondary(self) -> Result<SecondaryButtonParams<'a>, ButtonParamsError> {
+        let position = self.position;
+        Ok(SecondaryButtonParams {
+            common: self.validate()?,
+            position
+        })
+    }
+
+    fn validate(self) -> Result<BottomButtonParams<'a>, ButtonParamsError> {
+        if let Some(text) = self.text {
+            if text.trim().is_empty() {
+                return Err(ButtonParamsError::EmptyText);
+            }
+            let len = text.chars().count();
+            if len > MAX_BUTTON_TEXT {
+                return Err(ButtonParamsError::TextTooLong {
+                    len
+                });
+            }
+        }
+        for color in [self.color, self.text_color].into_iter().flatten() {
+            if !is_hex_color(color) {
+                return Err(ButtonParamsError::InvalidColor(color.to_owned()));
+            }
+        }
+        Ok(BottomButtonParams {
+            text:                 self.text,
+            color:                self.color,
+            text_color:           self.text_color,
+            is_active:            self.is_active,
+            is_visible:           self.is_visible,
+            has_shine_effect:     self.has_shine_effect,
+            icon_custom_emoji_id: self.icon_custom_emoji_id
+        })
+    }
+}
+
+/// Checks the `#RRGGBB` form.
+fn is_hex_color(color: &str) -> bool {
+    color
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
 /// Options supported by [`crate::webapp::TelegramWebApp::open_link`].
 ///
 /// # Examples
@@ -350,6 +1125,106 @@ pub struct CloseOptions {
     pub return_back: Option<bool>
 }
 
+/// Style of a [`PopupButton`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PopupButtonKind {
+    /// Regular button with custom text.
+    #[default]
+    Default,
+    /// Localized "OK" button; the text is ignored.
+    Ok,
+    /// Localized "Close" button; the text is ignored.
+    Close,
+    /// Localized "Cancel" button; the text is ignored.
+    Cancel,
+    /// Button with custom text styled as a destructive action.
+    Destructive
+}
+
+/// Button shown in a native popup.
+///
+/// # Examples
+/// ```
+/// use telegram_webapp_sdk::webapp::{PopupButton, PopupButtonKind};
+///
+/// let delete = PopupButton::new("delete", "Delete").kind(PopupButtonKind::Destructive);
+/// assert_eq!(delete.id, "delete");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct PopupButton {
+    /// Identifier reported when the button is pressed.
+    pub id:   String,
+    /// Button style.
+    #[serde(rename = "type")]
+    pub kind: PopupButtonKind,
+    /// Button label for [`PopupButtonKind::Default`] and
+    /// [`PopupButtonKind::Destructive`].
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub text: String
+}
+
+impl PopupButton {
+    /// Creates a default-styled button.
+    pub fn new(id: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            id:   id.into(),
+            kind: PopupButtonKind::Default,
+            text: text.into()
+        }
+    }
+
+    /// Sets the button style.
+    pub fn kind(mut self, kind: PopupButtonKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// Parameters for [`crate::webapp::TelegramWebApp::show_popup`].
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::webapp::{PopupButton, PopupButtonKind, PopupParams, TelegramWebApp};
+///
+/// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+/// let params = PopupParams {
+///     title:   Some("Delete order".into()),
+///     message: "This cannot be undone".into(),
+///     buttons: vec![
+///         PopupButton::new("delete", "Delete").kind(PopupButtonKind::Destructive),
+///         PopupButton::default().kind(PopupButtonKind::Cancel),
+///     ]
+/// };
+/// let app = TelegramWebApp::try_instance()?;
+/// let pressed = app.show_popup(&params.to_js()?).await?;
+/// # let _ = pressed;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct PopupParams {
+    /// Popup title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title:   Option<String>,
+    /// Popup message.
+    pub message: String,
+    /// Up to three buttons; Telegram shows a single "Close" button when
+    /// empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub buttons: Vec<PopupButton>
+}
+
+impl PopupParams {
+    /// Converts the parameters into the object expected by `showPopup`.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if serialization fails.
+    pub fn to_js(&self) -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(self).map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+}
+
 /// Background events delivered by Telegram when the Mini App runs in the
 /// background.
 #[derive(Clone, Copy, Debug)]
@@ -397,3 +1272,85 @@ impl BackgroundEvent {
         }
     }
 }
+
+/// Decoded payload of a [`BackgroundEvent`], delivered by
+/// [`on_background_event_typed`](crate::webapp::TelegramWebApp::on_background_event_typed).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BackgroundEventData {
+    /// The main button was clicked.
+    MainButtonClicked,
+    /// The back button was clicked.
+    BackButtonClicked,
+    /// The settings button was clicked.
+    SettingsButtonClicked,
+    /// User responded to a write access request.
+    WriteAccessRequested {
+        /// The answer; `None` for an unrecognized payload.
+        result: Option<WriteAccessResult>
+    },
+    /// User responded to a contact request.
+    ContactRequested {
+        /// The answer; `None` for an unrecognized payload.
+        result: Option<ContactRequestResult>
+    },
+    /// An invoice was closed.
+    InvoiceClosed {
+        /// Final status; `None` for an unknown status.
+        status: Option<InvoiceStatus>
+    },
+    /// A popup was closed.
+    PopupClosed {
+        /// Id of the pressed button; `None` when dismissed.
+        button_id: Option<String>
+    },
+    /// Text was received from the QR scanner.
+    QrTextReceived {
+        /// Scanned text.
+        data: String
+    },
+    /// Text was read from the clipboard.
+    ClipboardTextReceived {
+        /// Clipboard text; `None` without clipboard access.
+        data: Option<String>
+    },
+    /// User picked a chat in response to `WebApp.requestChat`.
+    RequestedChatSent,
+    /// `WebApp.requestChat` failed.
+    RequestedChatFailed {
+        /// Error reported by Telegram.
+        error: String
+    }
+}
+
+impl BackgroundEventData {
+    /// Decodes the payload delivered with `event`.
+    pub fn from_js(event: BackgroundEvent, payload: &JsValue) -> Self {
+        match event {
+            BackgroundEvent::MainButtonClicked => Self::MainButtonClicked,
+            BackgroundEvent::BackButtonClicked => Self::BackButtonClicked,
+            BackgroundEvent::SettingsButtonClicked => Self::SettingsButtonClicked,
+            BackgroundEvent::WriteAccessRequested => Self::WriteAccessRequested {
+                result: WriteAccessResult::from_event(payload)
+            },
+            BackgroundEvent::ContactRequested => Self::ContactRequested {
+                result: ContactRequestResult::from_event(payload)
+            },
+            BackgroundEvent::InvoiceClosed => Self::InvoiceClosed {
+                status: InvoiceStatus::from_event(payload)
+            },
+            BackgroundEvent::PopupClosed => Self::PopupClosed {
+                button_id: payload_field(payload, "button_id").filter(|id| !id.is_empty())
+            },
+            BackgroundEvent::QrTextReceived => Self::QrTextReceived {
+                data: payload_field(payload, "data").unwrap_or_default()
+            },
+            BackgroundEvent::ClipboardTextReceived => Self::ClipboardTextReceived {
+                data: payload_field(payload, "data")
+            },
+            BackgroundEvent::RequestedChatSent => Self::RequestedChatSent,
+            BackgroundEvent::RequestedChatFailed => Self::RequestedChatFailed {
+                error: payload_field(payload, "error").unwrap_or_default()
+            }
+        }
+    }
+}
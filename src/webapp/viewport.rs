@@ -1,10 +1,16 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::{cell::RefCell, rc::Rc};
+
 use js_sys::Reflect;
 use wasm_bindgen::JsValue;
 
-use crate::webapp::{TelegramWebApp, types::SafeAreaInset};
+use crate::webapp::{
+    TelegramWebApp,
+    core::{await_one_shot, one_shot_promise},
+    types::SafeAreaInset
+};
 
 impl TelegramWebApp {
     /// Returns the current viewport height in pixels.
@@ -16,9 +22,7 @@ impl TelegramWebApp {
     /// let _ = app.viewport_height();
     /// ```
     pub fn viewport_height(&self) -> Option<f64> {
-        Reflect::get(&self.inner, &"viewportHeight".into())
-            .ok()?
-            .as_f64()
+        self.js().viewport_height().as_f64()
     }
 
     /// Returns the current viewport width in pixels.
@@ -30,9 +34,7 @@ impl TelegramWebApp {
     /// let _ = app.viewport_width();
     /// ```
     pub fn viewport_width(&self) -> Option<f64> {
-        Reflect::get(&self.inner, &"viewportWidth".into())
-            .ok()?
-            .as_f64()
+        self.js().viewport_width().as_f64()
     }
 
     /// Returns the stable viewport height in pixels.
@@ -44,9 +46,7 @@ impl TelegramWebApp {
     /// let _ = app.viewport_stable_height();
     /// ```
     pub fn viewport_stable_height(&self) -> Option<f64> {
-        Reflect::get(&self.inner, &"viewportStableHeight".into())
-            .ok()?
-            .as_f64()
+        self.js().viewport_stable_height().as_f64()
     }
 
     /// Call `WebApp.expand()` to expand the viewport.
@@ -57,6 +57,54 @@ impl TelegramWebApp {
         self.call0("expand")
     }
 
+    /// Expands the viewport and waits for the expand animation to finish.
+    ///
+    /// Resolves with the stable viewport height once a `viewportChanged`
+    /// event reports `isStateStable` while the app is expanded, so layout code
+    /// can run after the animation instead of guessing with timeouts.
+    /// Resolves immediately when the app is already expanded.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use telegram_webapp_sdk::webapp::TelegramWebApp;
+    /// # async fn run() -> Result<(), wasm_bindgen::JsValue> {
+    /// let app = TelegramWebApp::try_instance()?;
+    /// let height = app.expand_and_wait().await?;
+    /// let _ = height;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if subscribing to `viewportChanged` or calling
+    /// `WebApp.expand()` fails.
+    pub async fn expand_and_wait(&self) -> Result<f64, JsValue> {
+        if self.is_expanded() {
+            return Ok(self.viewport_stable_height().unwrap_or_default());
+        }
+        let app = self.clone();
+        let handle = Rc::new(RefCell::new(None));
+        let slot = Rc::clone(&handle);
+        let promise = one_shot_promise(move |resolve, _reject| {
+            let webapp = app.clone();
+            let registered = app.on_event("viewportChanged", move |event| {
+                let stable = Reflect::get(&event, &"isStateStable".into())
+                    .ok()
+                    .and_then(|stable| stable.as_bool())
+                    .unwrap_or(false);
+                if stable && webapp.is_expanded() {
+                    let height = webapp.viewport_stable_height().unwrap_or_default();
+                    let _ = resolve.call1(&JsValue::NULL, &height.into());
+                }
+            })?;
+            *slot.borrow_mut() = Some(registered);
+            app.expand()
+        });
+        let value = await_one_shot(promise).await;
+        drop(handle.borrow_mut().take());
+        Ok(value?.as_f64().unwrap_or_default())
+    }
+
     pub(super) fn safe_area_from_property(&self, property: &str) -> Option<SafeAreaInset> {
         let value = Reflect::get(&self.inner, &property.into()).ok()?;
         SafeAreaInset::from_js(value)
@@ -169,6 +217,28 @@ mod tests {
         assert!(called.get());
     }
 
+    #[wasm_bindgen_test]
+    #[allow(dead_code, clippy::unused_unit)]
+    async fn expand_and_wait_resolves_on_stable_viewport() {
+        let webapp = setup_webapp();
+        let _ = Reflect::set(&webapp, &"isExpanded".into(), &false.into());
+        let on_event = js_sys::Function::new_with_args("name, cb", "this.handler = cb;");
+        let off_event = js_sys::Function::new_with_args("name, cb", "this.unsubscribed = true;");
+        let expand = js_sys::Function::new_no_args(
+            "this.handler.call(this, { isStateStable: false }); \
+             this.isExpanded = true; this.viewportStableHeight = 720; \
+             setTimeout(() => this.handler.call(this, { isStateStable: true }), 0);"
+        );
+        let _ = Reflect::set(&webapp, &"onEvent".into(), &on_event);
+        let _ = Reflect::set(&webapp, &"offEvent".into(), &off_event);
+        let _ = Reflect::set(&webapp, &"expand".into(), &expand);
+
+        let app = TelegramWebApp::instance().expect("instance");
+        assert_eq!(app.expand_and_wait().await.expect("expanded"), 720.0);
+        let unsubscribed = Reflect::get(&webapp, &"unsubscribed".into()).unwrap();
+        assert_eq!(unsubscribed.as_bool(), Some(true));
+    }
+
     #[wasm_bindgen_test]
     #[allow(dead_code, clippy::unused_unit)]
     fn expand_viewport_errors_when_method_missing() {
@@ -0,0 +1,427 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{CloseEvent, Event, MessageEvent, WebSocket};
+
+use crate::{
+    core::context::TelegramContext,
+    webapp::{TelegramWebApp, types::EventHandle}
+};
+
+/// Delay before the first reconnect attempt by default.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound of the reconnect delay by default.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Errors produced by [`TelegramSocket`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SocketError {
+    /// The SDK is not initialized and no init data was set explicitly.
+    InitDataUnavailable,
+    /// The socket is not open.
+    NotConnected,
+    /// A message could not be encoded or decoded.
+    Json(String),
+    /// The underlying `WebSocket` call failed.
+    Js(String)
+}
+
+impl std::fmt::Display for SocketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InitDataUnavailable => write!(f, "Init data is not available"),
+            Self::NotConnected => write!(f, "Socket is not connected"),
+            Self::Json(msg) => write!(f, "Invalid JSON: {msg}"),
+            Self::Js(msg) => write!(f, "WebSocket error: {msg}")
+        }
+    }
+}
+
+impl std::error::Error for SocketError {}
+
+impl From<SocketError> for JsValue {
+    fn from(err: SocketError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+impl From<JsValue> for SocketError {
+    fn from(err: JsValue) -> Self {
+        Self::Js(err.as_string().unwrap_or_else(|| format!("{err:?}")))
+    }
+}
+
+/// First frame sent on every connection.
+#[derive(Serialize)]
+struct AuthFrame<'a> {
+    #[serde(rename = "type")]
+    kind:      &'static str,
+    init_data: &'a str
+}
+
+/// Connection settings for [`TelegramSocket`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SocketOptions {
+    url:             String,
+    init_data:       Option<String>,
+    initial_backoff: Duration,
+    max_backoff:     Duration
+}
+
+impl SocketOptions {
+    /// Creates settings for the socket at `url` (`wss://…`).
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url:             url.into(),
+            init_data:       None,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff:     DEFAULT_MAX_BACKOFF
+        }
+    }
+
+    /// Uses `raw` instead of the init data captured by
+    /// [`TelegramContext::init`].
+    pub fn init_data(mut self, raw: impl Into<String>) -> Self {
+        self.init_data = Some(raw.into());
+        self
+    }
+
+    /// Sets the first reconnect delay and its upper bound; the delay doubles
+    /// after each failed attempt.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+}
+
+/// Open `WebSocket` together with the closures bound to it.
+struct Connection {
+    socket:    WebSocket,
+    _on_open:  Closure<dyn FnMut(Event)>,
+    _on_msg:   Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>
+}
+
+impl Connection {
+    /// Unbinds the closures, which are dropped with `self`, and closes the
+    /// socket.
+    fn detach(self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+/// Callback receiving decoded incoming messages.
+type Handler<In> = Box<dyn FnMut(Result<In, SocketError>)>;
+
+struct State<In> {
+    options:    SocketOptions,
+    auth:       String,
+    connection: RefCell<Option<Connection>>,
+    on_message: RefCell<Handler<In>>,
+    attempts:   Cell<u32>,
+    timer:      Cell<Option<i32>>,
+    active:     Cell<bool>,
+    closed:     Cell<bool>
+}
+
+/// `WebSocket` client that authenticates with the raw init data and
+/// reconnects on its own.
+///
+/// On every connection the first frame is
+/// `{"type":"auth","init_data":"<initData>"}`, which the server validates
+/// against the bot token before accepting other frames. Messages are JSON in
+/// both directions: [`send`](Self::send) encodes any `Serialize` value and
+/// incoming frames are decoded into `In` and passed to the handler.
+///
+/// When the connection drops it is re-opened with exponential backoff. While
+/// the Mini App is in the background, reconnects are paused; on `activated`
+/// a dropped connection is re-opened right away. The socket is closed when
+/// the value is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::{Deserialize, Serialize};
+/// use telegram_webapp_sdk::ws::{SocketError, SocketOptions, TelegramSocket};
+///
+/// #[derive(Serialize)]
+/// struct Bid {
+///     amount: u64
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Outbid {
+///     amount: u64
+/// }
+///
+/// # fn run() -> Result<(), SocketError> {
+/// let socket = TelegramSocket::connect(
+///     SocketOptions::new("wss://api.example.com/auction"),
+///     |event: Result<Outbid, SocketError>| {
+///         if let Ok(outbid) = event {
+///             let _ = outbid.amount;
+///         }
+///     }
+/// )?;
+/// socket.send(&Bid {
+///     amount: 100
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TelegramSocket<In: 'static> {
+    state:    Rc<State<In>>,
+    _handles: Vec<EventHandle<dyn FnMut(JsValue)>>
+}
+
+impl<In: DeserializeOwned + 'static> TelegramSocket<In> {
+    /// Opens the socket and routes decoded incoming messages to
+    /// `on_message`.
+    ///
+    /// # Errors
+    /// Returns [`SocketError::InitDataUnavailable`] without init data and
+    /// [`SocketError::Js`] if the socket cannot be created.
+    pub fn connect<F>(options: SocketOptions, on_message: F) -> Result<Self, SocketError>
+    where
+        F: FnMut(Result<In, SocketError>) + 'static
+    {
+        let raw = match &options.init_data {
+            Some(raw) => raw.clone(),
+            None => TelegramContext::get_raw_init_data()
+                .map_err(|_| SocketError::InitDataUnavailable)?
+        };
+        let state = Rc::new(State {
+            options,
+            auth: auth_frame(&raw)?,
+            connection: RefCell::new(None),
+            on_message: RefCell::new(Box::new(on_message)),
+            attempts: Cell::new(0),
+            timer: Cell::new(None),
+            active: Cell::new(true),
+            closed: Cell::new(false)
+        });
+        open(&state)?;
+
+        let mut handles = Vec::new();
+        if let Some(app) = TelegramWebApp::instance() {
+            let weak = Rc::downgrade(&state);
+            handles.push(app.on_event("activated", move |_| {
+                if let Some(state) = weak.upgrade() {
+                    state.active.set(true);
+                    if !state.closed.get() && !is_alive(&state) {
+                        cancel_timer(&state);
+                        state.attempts.set(0);
+                        let _ = open(&state);
+                    }
+                }
+            })?);
+            let weak = Rc::downgrade(&state);
+            handles.push(app.on_event("deactivated", move |_| {
+                if let Some(state) = weak.upgrade() {
+                    state.active.set(false);
+                }
+            })?);
+        }
+        Ok(Self {
+            state,
+            _handles: handles
+        })
+    }
+
+    /// Encodes `msg` as JSON and sends it.
+    ///
+    /// # Errors
+    /// Returns [`SocketError::NotConnected`] while the socket is not open,
+    /// [`SocketError::Json`] if encoding fails and [`SocketError::Js`] if
+    /// sending fails.
+    pub fn send<T: Serialize>(&self, msg: &T) -> Result<(), SocketError> {
+        let text = serde_json::to_string(msg).map_err(|err| SocketError::Json(err.to_string()))?;
+        let connection = self.state.connection.borrow();
+        match connection.as_ref() {
+            Some(conn) if conn.socket.ready_state() == WebSocket::OPEN => {
+                Ok(conn.socket.send_with_str(&text)?)
+            }
+            _ => Err(SocketError::NotConnected)
+        }
+    }
+
+    /// Returns whether the socket is open.
+    pub fn is_open(&self) -> bool {
+        self.state
+            .connection
+            .borrow()
+            .as_ref()
+            .is_some_and(|conn| conn.socket.ready_state() == WebSocket::OPEN)
+    }
+
+    /// Closes the socket and stops reconnecting.
+    pub fn close(&self) {
+        shutdown(&self.state);
+    }
+}
+
+impl<In: 'static> Drop for TelegramSocket<In> {
+    fn drop(&mut self) {
+        shutdown(&self.state);
+    }
+}
+
+/// Opens a new connection and stores it in `state`.
+fn open<In: DeserializeOwned + 'static>(state: &Rc<State<In>>) -> Result<(), SocketError> {
+    let socket = WebSocket::new(&state.options.url)?;
+
+    let weak = Rc::downgrade(state);
+    let auth_socket = socket.clone();
+    let on_open = Closure::<dyn FnMut(Event)>::new(move |_| {
+        if let Some(state) = weak.upgrade() {
+            state.attempts.set(0);
+            let _ = auth_socket.send_with_str(&state.auth);
+        }
+    });
+
+    let weak = Rc::downgrade(state);
+    let on_msg = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        let Some(state) = weak.upgrade() else {
+            return;
+        };
+        let decoded = event
+            .data()
+            .as_string()
+            .ok_or_else(|| SocketError::Json("binary frames are not supported".to_owned()))
+            .and_then(|text| {
+                serde_json::from_str(&text).map_err(|err| SocketError::Json(err.to_string()))
+            });
+        (state.on_message.borrow_mut())(decoded);
+    });
+
+    let weak = Rc::downgrade(state);
+    let on_close = Closure::<dyn FnMut(CloseEvent)>::new(move |_| {
+        if let Some(state) = weak.upgrade() {
+            schedule_reconnect(&state);
+        }
+    });
+
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(on_msg.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+    if let Some(old) = state.connection.replace(Some(Connection {
+        socket,
+        _on_open: on_open,
+        _on_msg: on_msg,
+        _on_close: on_close
+    })) {
+        old.detach();
+    }
+    Ok(())
+}
+
+/// Closes the connection for good and cancels a pending reconnect.
+fn shutdown<In>(state: &State<In>) {
+    state.closed.set(true);
+    cancel_timer(state);
+    if let Some(conn) = state.connection.borrow_mut().take() {
+        conn.detach();
+    }
+}
+
+/// Re-opens the connection after the current backoff delay, unless the
+/// socket was closed or the app is in the background.
+fn schedule_reconnect<In: DeserializeOwned + 'static>(state: &Rc<State<In>>) {
+    if state.closed.get() || !state.active.get() || state.timer.get().is_some() {
+        return;
+    }
+    let attempt = state.attempts.get();
+    state.attempts.set(attempt.saturating_add(1));
+    let delay = backoff(
+        attempt,
+        state.options.initial_backoff,
+        state.options.max_backoff
+    );
+
+    let weak = Rc::downgrade(state);
+    let retry = Closure::once_into_js(move || {
+        if let Some(state) = weak.upgrade() {
+            state.timer.set(None);
+            if !state.closed.get() && open(&state).is_err() {
+                schedule_reconnect(&state);
+            }
+        }
+    });
+    let timeout = i32::try_from(delay.as_millis()).unwrap_or(i32::MAX);
+    if let Some(id) = web_sys::window().and_then(|win| {
+        win.set_timeout_with_callback_and_timeout_and_arguments_0(retry.unchecked_ref(), timeout)
+            .ok()
+    }) {
+        state.timer.set(Some(id));
+    }
+}
+
+fn cancel_timer<In>(state: &State<In>) {
+    if let (Some(id), Some(win)) = (state.timer.take(), web_sys::window()) {
+        win.clear_timeout_with_handle(id);
+    }
+}
+
+/// Returns whether the current connection is open or still connecting.
+fn is_alive<In>(state: &State<In>) -> bool {
+    state.connection.borrow().as_ref().is_some_and(|conn| {
+        matches!(
+            conn.socket.ready_state(),
+            WebSocket::CONNECTING | WebSocket::OPEN
+        )
+    })
+}
+
+fn auth_frame(init_data: &str) -> Result<String, SocketError> {
+    serde_json::to_string(&AuthFrame {
+        kind: "auth",
+        init_data
+    })
+    .map_err(|err| SocketError::Json(err.to_string()))
+}
+
+/// Delay before reconnect `attempt` (starting at 0).
+fn backoff(attempt: u32, initial: Duration, max: Duration) -> Duration {
+    initial
+        .checked_mul(2u32.saturating_pow(attempt))
+        .map_or(max, |delay| delay.min(max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let initial = Duration::from_millis(500);
+        let max = Duration::from_secs(4);
+        let delays: Vec<_> = (0..6).map(|n| backoff(n, initial, max)).collect();
+        assert_eq!(
+            delays,
+            [500, 1000, 2000, 4000, 4000, 4000].map(Duration::from_millis)
+        );
+        assert_eq!(backoff(u32::MAX, initial, max), max);
+    }
+
+    #[test]
+    fn auth_frame_carries_init_data() {
+        assert_eq!(
+            auth_frame("query_id=1&hash=x").unwrap(),
+            r#"{"type":"auth","init_data":"query_id=1&hash=x"}"#
+        );
+    }
+}
@@ -6,14 +6,44 @@ use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen::{JsCast, JsValue, closure::Closure};
 use yew::prelude::{hook, use_effect, use_state};
 
-use crate::core::{context::TelegramContext, safe_context::get_context};
+use crate::core::{context::TelegramContext, safe_context::context_missing};
 
+/// [`active::use_is_active`] hook tracking the active/background state.
+pub mod active;
 /// [`back_button::BackButton`] component driving `WebApp.BackButton`.
 pub mod back_button;
+/// [`biometric::use_biometric`] hook over `WebApp.BiometricManager`.
+#[cfg(feature = "biometric")]
+pub mod biometric;
 /// [`bottom_button::BottomButton`] component driving the main/secondary button.
 pub mod bottom_button;
+/// [`dialogs::TelegramConfirm`] and other components driving native popups.
+#[cfg(feature = "popups")]
+pub mod dialogs;
+/// [`error_boundary::TelegramErrorBoundary`] component reporting failures
+/// through a native popup.
+pub mod error_boundary;
+/// [`form::TextInput`], [`form::Select`], [`form::Switch`] and
+/// [`form::Section`] components styled from the Telegram theme.
+pub mod form;
+/// [`haptic::use_haptic`] hook returning haptic feedback callbacks.
+pub mod haptic;
+/// [`invoice::use_invoice`] hook opening invoices and tracking their status.
+pub mod invoice;
+/// [`location::use_location`] hook over `WebApp.LocationManager`.
+#[cfg(feature = "location")]
+pub mod location;
+/// [`qr_scanner::QrScanner`] component driving the native QR scanner.
+#[cfg(feature = "popups")]
+pub mod qr_scanner;
+/// [`router::PageRoute`] bridging registered pages to `yew-router`.
+#[cfg(feature = "yew-router")]
+pub mod router;
 /// [`safe_area::use_safe_area`] hook exposing safe-area insets reactively.
 pub mod safe_area;
+/// [`safe_area_view::SafeAreaView`] component padding its children by the
+/// safe-area insets.
+pub mod safe_area_view;
 /// [`settings_button::SettingsButton`] component driving
 /// `WebApp.SettingsButton`.
 pub mod settings_button;
@@ -22,9 +52,23 @@ pub mod theme;
 /// [`viewport::use_viewport`] hook exposing viewport size and state reactively.
 pub mod viewport;
 
+pub use active::use_is_active;
 pub use back_button::BackButton;
+#[cfg(feature = "biometric")]
+pub use biometric::{BiometricHandle, use_biometric};
 pub use bottom_button::BottomButton;
+#[cfg(feature = "popups")]
+pub use dialogs::{TelegramAlert, TelegramConfirm, TelegramPopup};
+pub use error_boundary::{ErrorReporter, TelegramErrorBoundary, use_error_reporter};
+pub use form::{Section, Select, Switch, TextInput};
+pub use haptic::{Haptic, use_haptic};
+pub use invoice::{InvoiceState, use_invoice};
+#[cfg(feature = "location")]
+pub use location::{LocationHandle, use_location};
+#[cfg(feature = "popups")]
+pub use qr_scanner::QrScanner;
 pub use safe_area::{SafeAreaState, use_safe_area};
+pub use safe_area_view::SafeAreaView;
 pub use settings_button::SettingsButton;
 pub use theme::{ThemeState, use_theme};
 pub use viewport::{ViewportState, use_viewport};
@@ -61,7 +105,37 @@ type ClosureCell = Rc<RefCell<Option<Closure<dyn FnMut()>>>>;
 /// ```
 #[hook]
 pub fn use_telegram_context() -> Result<TelegramContext, JsValue> {
-    let context_state = use_state(|| get_context(|c| c.clone()));
+    use_shared_telegram_context().map(|ctx| (*ctx).clone())
+}
+
+/// Like [`use_telegram_context`], but returns a shared handle instead of a
+/// copy of the context.
+///
+/// Re-renders only clone the [`Rc`], so prefer this hook in components that
+/// render often.
+///
+/// # Errors
+///
+/// Returns an error until the context has been initialized with
+/// [`TelegramContext::init`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use telegram_webapp_sdk::yew::use_shared_telegram_context;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn App() -> Html {
+///     match use_shared_telegram_context() {
+///         Ok(ctx) => html! { <span>{ ctx.init_data.auth_date }</span> },
+///         Err(_) => html! { <div>{"Loading Telegram context..."}</div> }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_shared_telegram_context() -> Result<Rc<TelegramContext>, JsValue> {
+    let context_state = use_state(TelegramContext::shared);
 
     {
         let context_state = context_state.clone();
@@ -69,7 +143,7 @@ pub fn use_telegram_context() -> Result<TelegramContext, JsValue> {
             let handle: Rc<RefCell<Option<i32>>> = Rc::new(RefCell::new(None));
             let closure: ClosureCell = Rc::new(RefCell::new(None));
 
-            if context_state.is_err()
+            if context_state.is_none()
                 && let Some(win) = web_sys::window()
             {
                 let handle_clone = handle.clone();
@@ -77,8 +151,8 @@ pub fn use_telegram_context() -> Result<TelegramContext, JsValue> {
                 let ctx_state = context_state.clone();
 
                 let check_fn = Closure::wrap(Box::new(move || {
-                    if let Ok(ctx) = get_context(|c| c.clone()) {
-                        ctx_state.set(Ok(ctx));
+                    if let Some(ctx) = TelegramContext::shared() {
+                        ctx_state.set(Some(ctx));
                         if let Some(id) = handle_clone.borrow_mut().take()
                             && let Some(w) = web_sys::window()
                         {
@@ -113,7 +187,7 @@ pub fn use_telegram_context() -> Result<TelegramContext, JsValue> {
         });
     }
 
-    (*context_state).clone()
+    (*context_state).clone().ok_or_else(context_missing)
 }
 
 #[cfg(test)]
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::JsValue;
+use yew::prelude::{hook, use_effect_with, use_state};
+
+use crate::webapp::{EventHandle, TelegramWebApp};
+
+type HandleList = Rc<RefCell<Vec<EventHandle<dyn FnMut(JsValue)>>>>;
+
+/// Reads `WebApp.isActive`.
+///
+/// Clients older than Bot API 8.0 and non-Telegram environments have no
+/// background state and are reported as active.
+fn snapshot(app: Option<&TelegramWebApp>) -> bool {
+    app.is_none_or(|app| app.is_active() || !app.is_version_at_least("8.0").unwrap_or(false))
+}
+
+/// Yew hook reporting whether the Mini App is active.
+///
+/// Updates on `activated` and `deactivated`, so components can pause
+/// polling, animations and timers while the app is in the background. The
+/// subscriptions are removed on unmount.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::use_is_active;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Ticker() -> Html {
+///     let active = use_is_active();
+///     html! { <span>{ if active { "live" } else { "paused" } }</span> }
+/// }
+/// ```
+#[hook]
+pub fn use_is_active() -> bool {
+    let active = use_state(|| snapshot(TelegramWebApp::instance().as_ref()));
+
+    {
+        let active = active.clone();
+        use_effect_with((), move |_| {
+            let stash: HandleList = Rc::new(RefCell::new(Vec::new()));
+            if let Some(app) = TelegramWebApp::instance() {
+                for (event, value) in [("activated", true), ("deactivated", false)] {
+                    let active = active.clone();
+                    if let Ok(handle) = app.on_event(event, move |_| active.set(value)) {
+                        stash.borrow_mut().push(handle);
+                    }
+                }
+            }
+            move || {
+                stash.borrow_mut().clear();
+            }
+        });
+    }
+
+    *active
+}
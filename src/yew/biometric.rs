@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::JsValue;
+use yew::prelude::{Callback, hook, use_callback, use_effect_with, use_state};
+
+use crate::{
+    api::biometric::{self, BiometricAuthResult, BiometricStatus},
+    logger,
+    webapp::{EventHandle, TelegramWebApp}
+};
+
+type HandleSlot = Rc<RefCell<Vec<EventHandle<dyn FnMut(JsValue)>>>>;
+
+/// `BiometricManager` events that change its flags.
+const EVENTS: [&str; 3] = [
+    "biometricManagerUpdated",
+    "biometricAuthRequested",
+    "biometricTokenUpdated"
+];
+
+/// State returned by [`use_biometric`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BiometricHandle {
+    /// Current `BiometricManager` flags.
+    pub status: BiometricStatus,
+    refresh:    Callback<()>
+}
+
+impl BiometricHandle {
+    /// Prompts the user for biometric authentication.
+    ///
+    /// [`BiometricHandle::status`] is refreshed once the prompt closes.
+    ///
+    /// # Errors
+    /// The future fails if `BiometricManager` is unavailable or the call
+    /// fails.
+    pub fn authenticate(
+        &self,
+        reason: Option<&str>
+    ) -> impl Future<Output = Result<BiometricAuthResult, JsValue>> + 'static {
+        let reason = reason.map(str::to_owned);
+        let refresh = self.refresh.clone();
+        async move {
+            let result = biometric::authenticate_user(reason.as_deref()).await;
+            refresh.emit(());
+            result
+        }
+    }
+
+    /// Opens the biometric settings, e.g. after access was denied.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `BiometricManager` is unavailable or the call
+    /// fails.
+    pub fn open_settings(&self) -> Result<(), JsValue> {
+        biometric::open_settings()
+    }
+}
+
+/// Yew hook over `Telegram.WebApp.BiometricManager`.
+///
+/// Initializes the manager on mount and updates on
+/// `biometricManagerUpdated`, `biometricAuthRequested` and
+/// `biometricTokenUpdated`. The subscriptions are removed on unmount.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::use_biometric;
+/// use wasm_bindgen_futures::spawn_local;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Unlock() -> Html {
+///     let biometric = use_biometric();
+///     let onclick = {
+///         let biometric = biometric.clone();
+///         Callback::from(move |_| {
+///             let unlock = biometric.authenticate(Some("Unlock your wallet"));
+///             spawn_local(async move {
+///                 let _ = unlock.await;
+///             });
+///         })
+///     };
+///     html! {
+///         <button {onclick} disabled={!biometric.status.available}>{ "Unlock" }</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_biometric() -> BiometricHandle {
+    let status = use_state(|| {
+        TelegramWebApp::instance()
+            .map(|_| BiometricStatus::current())
+            .unwrap_or_default()
+    });
+
+    let refresh = {
+        let status = status.clone();
+        use_callback((), move |(), _| status.set(BiometricStatus::current()))
+    };
+
+    {
+        let refresh = refresh.clone();
+        use_effect_with((), move |_| {
+            let stash: HandleSlot = Rc::new(RefCell::new(Vec::new()));
+            if let Some(app) = TelegramWebApp::instance() {
+                for event in EVENTS {
+                    let refresh = refresh.clone();
+                    match app.on_event(event, move |_| refresh.emit(())) {
+                        Ok(handle) => stash.borrow_mut().push(handle),
+                        Err(err) => logger::error(&format!("{event} subscription failed: {err:?}"))
+                    }
+                }
+                if !biometric::is_inited().unwrap_or(true)
+                    && let Err(err) = biometric::init()
+                {
+                    logger::error(&format!("BiometricManager.init failed: {err:?}"));
+                }
+            }
+            move || {
+                stash.borrow_mut().clear();
+            }
+        });
+    }
+
+    BiometricHandle {
+        status: *status,
+        refresh
+    }
+}
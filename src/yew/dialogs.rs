@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use yew::prelude::{AttrValue, Callback, Html, Properties, function_component, use_effect_with};
+
+use crate::{
+    logger,
+    webapp::{PopupParams, TelegramWebApp}
+};
+
+/// Props for [`TelegramAlert`].
+#[derive(Properties, PartialEq)]
+pub struct TelegramAlertProps {
+    /// Shows the alert when it becomes `true`.
+    pub open:     bool,
+    /// Alert message.
+    pub message:  AttrValue,
+    /// Called once the alert is closed.
+    #[prop_or_default]
+    pub on_close: Callback<()>
+}
+
+/// Yew component showing `WebApp.showAlert` while `open` is `true`.
+///
+/// The alert is shown each time `open` switches to `true`; reset it from
+/// `on_close`.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::TelegramAlert;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Saved() -> Html {
+///     let open = use_state(|| true);
+///     let on_close = {
+///         let open = open.clone();
+///         Callback::from(move |()| open.set(false))
+///     };
+///     html! { <TelegramAlert open={*open} message="Saved" {on_close} /> }
+/// }
+/// ```
+#[function_component(TelegramAlert)]
+pub fn telegram_alert(props: &TelegramAlertProps) -> Html {
+    let message = props.message.clone();
+    let on_close = props.on_close.clone();
+    use_effect_with(props.open, move |&open| {
+        if open
+            && let Some(app) = TelegramWebApp::instance()
+            && let Err(err) = app.show_alert_with_callback(&message, move || on_close.emit(()))
+        {
+            logger::error(&format!("showAlert failed: {err:?}"));
+        }
+        || ()
+    });
+    Html::default()
+}
+
+/// Props for [`TelegramConfirm`].
+#[derive(Properties, PartialEq)]
+pub struct TelegramConfirmProps {
+    /// Shows the confirmation when it becomes `true`.
+    pub open:      bool,
+    /// Question shown to the user.
+    pub message:   AttrValue,
+    /// Called with the user's answer.
+    #[prop_or_default]
+    pub on_result: Callback<bool>
+}
+
+/// Yew component showing `WebApp.showConfirm` while `open` is `true`.
+///
+/// The confirmation is shown each time `open` switches to `true`; reset it
+/// from `on_result`.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::TelegramConfirm;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Logout() -> Html {
+///     let open = use_state(|| false);
+///     let onclick = {
+///         let open = open.clone();
+///         Callback::from(move |_| open.set(true))
+///     };
+///     let on_result = {
+///         let open = open.clone();
+///         Callback::from(move |confirmed: bool| {
+///             open.set(false);
+///             if confirmed { /* log out */ }
+///         })
+///     };
+///     html! {
+///         <>
+///             <button {onclick}>{ "Log out" }</button>
+///             <TelegramConfirm open={*open} message="Log out?" {on_result} />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(TelegramConfirm)]
+pub fn telegram_confirm(props: &TelegramConfirmProps) -> Html {
+    let message = props.message.clone();
+    let on_result = props.on_result.clone();
+    use_effect_with(props.open, move |&open| {
+        if open
+            && let Some(app) = TelegramWebApp::instance()
+            && let Err(err) =
+                app.show_confirm_with_callback(&message, move |ok| on_result.emit(ok))
+        {
+            logger::error(&format!("showConfirm failed: {err:?}"));
+        }
+        || ()
+    });
+    Html::default()
+}
+
+/// Props for [`TelegramPopup`].
+#[derive(Properties, PartialEq)]
+pub struct TelegramPopupProps {
+    /// Shows the popup when it becomes `true`.
+    pub open:      bool,
+    /// Title, message and buttons of the popup.
+    pub params:    PopupParams,
+    /// Called with the id of the pressed button, or `None` if the popup was
+    /// dismissed.
+    #[prop_or_default]
+    pub on_result: Callback<Option<String>>
+}
+
+/// Yew component showing `WebApp.showPopup` while `open` is `true`.
+///
+/// The popup is shown each time `open` switches to `true`; reset it from
+/// `on_result`.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::{
+///     webapp::{PopupButton, PopupButtonKind, PopupParams},
+///     yew::TelegramPopup
+/// };
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn DeleteOrder() -> Html {
+///     let open = use_state(|| true);
+///     let params = PopupParams {
+///         title:   Some("Delete order".into()),
+///         message: "This cannot be undone".into(),
+///         buttons: vec![
+///             PopupButton::new("delete", "Delete").kind(PopupButtonKind::Destructive),
+///             PopupButton::default().kind(PopupButtonKind::Cancel),
+///         ]
+///     };
+///     let on_result = {
+///         let open = open.clone();
+///         Callback::from(move |id: Option<String>| {
+///             open.set(false);
+///             if id.as_deref() == Some("delete") { /* delete */ }
+///         })
+///     };
+///     html! { <TelegramPopup open={*open} {params} {on_result} /> }
+/// }
+/// ```
+#[function_component(TelegramPopup)]
+pub fn telegram_popup(props: &TelegramPopupProps) -> Html {
+    let params = props.params.clone();
+    let on_result = props.on_result.clone();
+    use_effect_with(props.open, move |&open| {
+        if open && let Some(app) = TelegramWebApp::instance() {
+            let result = params.to_js().and_then(|params| {
+                app.show_popup_with_callback(&params, move |id| {
+                    on_result.emit(Some(id).filter(|id| !id.is_empty()));
+                })
+            });
+            if let Err(err) = result {
+                logger::error(&format!("showPopup failed: {err:?}"));
+            }
+        }
+        || ()
+    });
+    Html::default()
+}
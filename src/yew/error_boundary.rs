@@ -0,0 +1,230 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    cell::{Cell, RefCell},
+    panic
+};
+
+use js_sys::{Function, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use yew::prelude::{
+    AttrValue, Callback, Children, ContextProvider, Html, Properties, function_component, hook,
+    html, use_context, use_effect_with, use_state
+};
+
+use crate::{
+    logger,
+    webapp::{PopupButton, PopupButtonKind, PopupParams}
+};
+
+/// Id of the popup button that retries after an error.
+const RETRY_ID: &str = "retry";
+
+thread_local! {
+    static PANIC_HOOK_INSTALLED: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Reports an error to the nearest [`TelegramErrorBoundary`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorReporter(Callback<String>);
+
+impl ErrorReporter {
+    /// Switches the boundary to its fallback and logs `error`.
+    pub fn report(&self, error: impl Into<String>) {
+        self.0.emit(error.into());
+    }
+}
+
+/// Returns the reporter of the nearest [`TelegramErrorBoundary`].
+///
+/// Outside a boundary the reporter only logs the error.
+#[hook]
+pub fn use_error_reporter() -> ErrorReporter {
+    use_context::<ErrorReporter>().unwrap_or_else(|| {
+        ErrorReporter(Callback::from(|error: String| {
+            logger::error(&format!("Unhandled error: {error}"));
+        }))
+    })
+}
+
+/// Props for [`TelegramErrorBoundary`].
+#[derive(Properties, PartialEq)]
+pub struct TelegramErrorBoundaryProps {
+    /// Content guarded by the boundary.
+    #[prop_or_default]
+    pub children:   Children,
+    /// Message shown in the fallback and the native alert.
+    #[prop_or(AttrValue::Static("Something went wrong"))]
+    pub message:    AttrValue,
+    /// Whether to show a native popup with a retry button.
+    #[prop_or(true)]
+    pub show_alert: bool,
+    /// Rendered instead of the default fallback after an error.
+    #[prop_or_default]
+    pub fallback:   Option<Html>,
+    /// Called with every reported error.
+    #[prop_or_default]
+    pub on_error:   Callback<String>
+}
+
+/// Yew component turning failures into a visible, recoverable state.
+///
+/// Errors reported through [`use_error_reporter`] are logged through
+/// [`logger`], replace the children with a fallback and, with `show_alert`,
+/// open a native popup whose retry button re-mounts the children.
+///
+/// Panics cannot be caught on `wasm32`, so the boundary also installs a
+/// panic hook that logs the panic and shows the same popup; retrying reloads
+/// the page because the WebAssembly instance is unusable afterwards.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::{TelegramErrorBoundary, use_error_reporter};
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Orders() -> Html {
+///     let reporter = use_error_reporter();
+///     let onclick = Callback::from(move |_| reporter.report("Failed to load orders"));
+///     html! { <button {onclick}>{ "Load" }</button> }
+/// }
+///
+/// #[component]
+/// fn App() -> Html {
+///     html! {
+///         <TelegramErrorBoundary>
+///             <Orders />
+///         </TelegramErrorBoundary>
+///     }
+/// }
+/// ```
+#[function_component(TelegramErrorBoundary)]
+pub fn telegram_error_boundary(props: &TelegramErrorBoundaryProps) -> Html {
+    let error = use_state(|| None::<String>);
+    let generation = use_state(|| 0_u32);
+
+    {
+        let message = props.message.to_string();
+        let show_alert = props.show_alert;
+        use_effect_with((), move |()| {
+            install_panic_hook(message, show_alert);
+        });
+    }
+
+    let retry = {
+        let error = error.clone();
+        let generation = generation.clone();
+        Callback::from(move |()| {
+            error.set(None);
+            generation.set(generation.wrapping_add(1));
+        })
+    };
+
+    let reporter = {
+        let error = error.clone();
+        let on_error = props.on_error.clone();
+        let message = props.message.clone();
+        let show_alert = props.show_alert;
+        let retry = retry.clone();
+        ErrorReporter(Callback::from(move |reported: String| {
+            logger::error(&format!("TelegramErrorBoundary caught: {reported}"));
+            on_error.emit(reported.clone());
+            error.set(Some(reported));
+            if show_alert {
+                show_retry_popup(&message, retry.clone());
+            }
+        }))
+    };
+
+    if error.is_some() {
+        if let Some(fallback) = &props.fallback {
+            return fallback.clone();
+        }
+        let onclick = retry.reform(|_| ());
+        return html! {
+            <div style="padding: 24px; text-align: center; color: var(--tg-theme-text-color);">
+                <p>{ props.message.clone() }</p>
+                <button {onclick} style="padding: 10px 20px; border: none; border-radius: 10px; \
+                                         background: var(--tg-theme-button-color); \
+                                         color: var(--tg-theme-button-text-color);">
+                    { "Retry" }
+                </button>
+            </div>
+        };
+    }
+
+    html! {
+        <ContextProvider<ErrorReporter> context={reporter}>
+            <div key={*generation}>
+                { props.children.clone() }
+            </div>
+        </ContextProvider<ErrorReporter>>
+    }
+}
+
+/// Popup offering to retry after an error.
+fn retry_params(message: &str) -> PopupParams {
+    PopupParams {
+        title:   None,
+        message: message.to_owned(),
+        buttons: vec![
+            PopupButton::new(RETRY_ID, "Retry"),
+            PopupButton::default().kind(PopupButtonKind::Close),
+        ]
+    }
+}
+
+/// Shows the retry popup, invoking `retry` when the user accepts.
+#[cfg(feature = "popups")]
+fn show_retry_popup(message: &str, retry: Callback<()>) {
+    let Some(app) = crate::webapp::TelegramWebApp::instance() else {
+        return;
+    };
+    let result = retry_params(message).to_js().and_then(|params| {
+        app.show_popup_with_callback(&params, move |id| {
+            if id == RETRY_ID {
+                retry.emit(());
+            }
+        })
+    });
+    if let Err(err) = result {
+        logger::error(&format!("showPopup failed: {err:?}"));
+    }
+}
+
+/// Without the `popups` feature only the fallback is rendered.
+#[cfg(not(feature = "popups"))]
+fn show_retry_popup(_message: &str, _retry: Callback<()>) {}
+
+/// Installs a panic hook, once, that logs the panic and offers a reload.
+fn install_panic_hook(message: String, show_alert: bool) {
+    PANIC_MESSAGE.with(|slot| *slot.borrow_mut() = show_alert.then_some(message));
+    if PANIC_HOOK_INSTALLED.with(|installed| installed.replace(true)) {
+        return;
+    }
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        logger::error(&format!("Panic: {info}"));
+        if let Some(message) = PANIC_MESSAGE.with(|slot| slot.borrow().clone()) {
+            let _ = show_reload_popup(&message);
+        }
+        previous(info);
+    }));
+}
+
+/// Shows the retry popup with a plain JavaScript callback that reloads the
+/// page, since Rust code cannot run safely after a panic.
+fn show_reload_popup(message: &str) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("no window"))?;
+    let telegram = Reflect::get(&window, &"Telegram".into())?;
+    let webapp = Reflect::get(&telegram, &"WebApp".into())?;
+    let show_popup = Reflect::get(&webapp, &"showPopup".into())?.dyn_into::<Function>()?;
+    let reload = Function::new_with_args(
+        "id",
+        &format!("if (id === '{RETRY_ID}') {{ window.location.reload(); }}")
+    );
+    show_popup.call2(&webapp, &retry_params(message).to_js()?, &reload)?;
+    Ok(())
+}
@@ -0,0 +1,246 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::{
+    AttrValue, Callback, Children, Classes, Event, Html, InputEvent, Properties, TargetCast,
+    classes, function_component, html, use_effect_with
+};
+
+use crate::utils::form_style::{field_style, inject_stylesheet};
+
+/// Props for [`TextInput`].
+#[derive(Properties, PartialEq)]
+pub struct TextInputProps {
+    /// Current value.
+    #[prop_or_default]
+    pub value:       AttrValue,
+    /// Placeholder rendered in the theme hint color.
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+    /// HTML input type, e.g. `"email"` or `"number"`.
+    #[prop_or(AttrValue::Static("text"))]
+    pub input_type:  AttrValue,
+    /// Called with the new value on every edit.
+    #[prop_or_default]
+    pub on_input:    Callback<String>,
+    /// Highlights the field with the destructive color, e.g. on validation
+    /// errors.
+    #[prop_or_default]
+    pub destructive: bool,
+    /// Whether the field is disabled.
+    #[prop_or_default]
+    pub disabled:    bool,
+    /// Extra classes for the `input`.
+    #[prop_or_default]
+    pub class:       Classes
+}
+
+/// Text field styled from the Telegram theme.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::TextInput;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Name() -> Html {
+///     let name = use_state(String::new);
+///     let on_input = {
+///         let name = name.clone();
+///         Callback::from(move |value| name.set(value))
+///     };
+///     html! {
+///         <TextInput value={(*name).clone()} placeholder="Your name"
+///             destructive={name.is_empty()} {on_input} />
+///     }
+/// }
+/// ```
+#[function_component(TextInput)]
+pub fn text_input(props: &TextInputProps) -> Html {
+    use_effect_with((), |()| inject_stylesheet());
+    let on_input = props.on_input.clone();
+    let oninput = Callback::from(move |event: InputEvent| {
+        on_input.emit(event.target_unchecked_into::<HtmlInputElement>().value());
+    });
+    html! {
+        <input
+            class={classes!("tg-text-input", props.class.clone())}
+            type={props.input_type.clone()}
+            value={props.value.clone()}
+            placeholder={props.placeholder.clone()}
+            disabled={props.disabled}
+            style={field_style(props.destructive, props.disabled)}
+            {oninput}
+        />
+    }
+}
+
+/// Props for [`Select`].
+#[derive(Properties, PartialEq)]
+pub struct SelectProps {
+    /// Options as `(value, label)` pairs.
+    pub options:     Vec<(AttrValue, AttrValue)>,
+    /// Value of the selected option.
+    #[prop_or_default]
+    pub value:       AttrValue,
+    /// Called with the value of the newly selected option.
+    #[prop_or_default]
+    pub on_change:   Callback<String>,
+    /// Highlights the field with the destructive color.
+    #[prop_or_default]
+    pub destructive: bool,
+    /// Whether the field is disabled.
+    #[prop_or_default]
+    pub disabled:    bool,
+    /// Extra classes for the `select`.
+    #[prop_or_default]
+    pub class:       Classes
+}
+
+/// Drop-down styled from the Telegram theme.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::Select;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Currency() -> Html {
+///     let options = vec![
+///         ("usd".into(), "US Dollar".into()),
+///         ("eur".into(), "Euro".into()),
+///     ];
+///     html! { <Select {options} value="usd" /> }
+/// }
+/// ```
+#[function_component(Select)]
+pub fn select(props: &SelectProps) -> Html {
+    let on_change = props.on_change.clone();
+    let onchange = Callback::from(move |event: Event| {
+        on_change.emit(event.target_unchecked_into::<HtmlSelectElement>().value());
+    });
+    html! {
+        <select
+            class={props.class.clone()}
+            disabled={props.disabled}
+            style={field_style(props.destructive, props.disabled)}
+            {onchange}
+        >
+            { for props.options.iter().map(|(value, label)| html! {
+                <option value={value.clone()} selected={*value == props.value}>
+                    { label.clone() }
+                </option>
+            }) }
+        </select>
+    }
+}
+
+/// Props for [`Switch`].
+#[derive(Properties, PartialEq)]
+pub struct SwitchProps {
+    /// Whether the switch is on.
+    pub checked:   bool,
+    /// Label shown next to the switch.
+    #[prop_or_default]
+    pub label:     AttrValue,
+    /// Called with the new state when toggled.
+    #[prop_or_default]
+    pub on_toggle: Callback<bool>,
+    /// Whether the switch is disabled.
+    #[prop_or_default]
+    pub disabled:  bool
+}
+
+/// Labelled toggle tinted with the theme button color.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::Switch;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Notifications() -> Html {
+///     let enabled = use_state(|| true);
+///     let on_toggle = {
+///         let enabled = enabled.clone();
+///         Callback::from(move |value| enabled.set(value))
+///     };
+///     html! { <Switch checked={*enabled} label="Notifications" {on_toggle} /> }
+/// }
+/// ```
+#[function_component(Switch)]
+pub fn switch(props: &SwitchProps) -> Html {
+    let on_toggle = props.on_toggle.clone();
+    let onchange = Callback::from(move |event: Event| {
+        on_toggle.emit(event.target_unchecked_into::<HtmlInputElement>().checked());
+    });
+    html! {
+        <label style="display: flex; align-items: center; justify-content: space-between; \
+                      gap: 12px; padding: 10px 0; color: var(--tg-theme-text-color);">
+            <span>{ props.label.clone() }</span>
+            <input
+                type="checkbox"
+                role="switch"
+                checked={props.checked}
+                disabled={props.disabled}
+                style="width: 20px; height: 20px; accent-color: var(--tg-theme-button-color);"
+                {onchange}
+            />
+        </label>
+    }
+}
+
+/// Props for [`Section`].
+#[derive(Properties, PartialEq)]
+pub struct SectionProps {
+    /// Header shown above the section.
+    #[prop_or_default]
+    pub title:    Option<AttrValue>,
+    /// Hint shown below the section.
+    #[prop_or_default]
+    pub footer:   Option<AttrValue>,
+    /// Section content.
+    #[prop_or_default]
+    pub children: Children
+}
+
+/// Settings-style group with a header and footer in theme colors.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::{Section, Switch};
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Settings() -> Html {
+///     html! {
+///         <Section title="Privacy" footer="Visible to your contacts only.">
+///             <Switch checked={true} label="Show phone number" />
+///         </Section>
+///     }
+/// }
+/// ```
+#[function_component(Section)]
+pub fn section(props: &SectionProps) -> Html {
+    html! {
+        <section style="margin: 12px 0;">
+            if let Some(title) = &props.title {
+                <h3 style="margin: 0 16px 6px; font-size: 13px; text-transform: uppercase; \
+                           color: var(--tg-theme-section-header-text-color);">
+                    { title.clone() }
+                </h3>
+            }
+            <div style="padding: 4px 16px; border-radius: 12px; \
+                        background: var(--tg-theme-section-bg-color, var(--tg-theme-bg-color));">
+                { props.children.clone() }
+            </div>
+            if let Some(footer) = &props.footer {
+                <p style="margin: 6px 16px 0; font-size: 13px; \
+                          color: var(--tg-theme-hint-color);">
+                    { footer.clone() }
+                </p>
+            }
+        </section>
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use yew::prelude::{Callback, hook, use_memo};
+
+use crate::api::haptic::{
+    HapticImpactStyle, HapticNotificationType, impact_occurred, notification_occurred,
+    selection_changed
+};
+
+/// Haptic feedback callbacks returned by [`use_haptic`].
+///
+/// The callbacks are no-ops outside Telegram.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Haptic {
+    /// Triggers an impact of the given style.
+    pub impact:    Callback<HapticImpactStyle>,
+    /// Triggers a notification of the given type.
+    pub notify:    Callback<HapticNotificationType>,
+    /// Signals a selection change.
+    pub selection: Callback<()>
+}
+
+/// Yew hook returning memoized haptic feedback callbacks.
+///
+/// The callbacks keep their identity across renders, so passing them as
+/// props does not re-render children.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::{api::haptic::HapticImpactStyle, yew::use_haptic};
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Tap() -> Html {
+///     let haptic = use_haptic();
+///     let onclick = Callback::from(move |_| haptic.impact.emit(HapticImpactStyle::Light));
+///     html! { <button {onclick}>{ "Tap" }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_haptic() -> Haptic {
+    let haptic = use_memo((), |()| Haptic {
+        impact:    Callback::from(|style| {
+            let _ = impact_occurred(style);
+        }),
+        notify:    Callback::from(|ty| {
+            let _ = notification_occurred(ty);
+        }),
+        selection: Callback::from(|()| {
+            let _ = selection_changed();
+        })
+    });
+    (*haptic).clone()
+}
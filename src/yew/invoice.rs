@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::JsValue;
+use yew::prelude::{Callback, hook, use_callback, use_effect_with, use_state};
+
+use crate::{
+    logger,
+    webapp::{EventHandle, InvoiceStatus, TelegramWebApp}
+};
+
+type HandleSlot = Rc<RefCell<Option<EventHandle<dyn FnMut(JsValue)>>>>;
+
+/// State returned by [`use_invoice`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvoiceState {
+    /// Status of the last closed invoice; `None` until one is closed.
+    pub status:  Option<InvoiceStatus>,
+    /// Whether an invoice opened with [`InvoiceState::open`] is still shown.
+    pub pending: bool,
+    open:        Callback<String>
+}
+
+impl InvoiceState {
+    /// Opens the invoice at `url` and clears the previous status.
+    pub fn open(&self, url: impl Into<String>) {
+        self.open.emit(url.into());
+    }
+}
+
+/// Yew hook opening invoices and tracking their outcome.
+///
+/// The status updates on `invoiceClosed`. The subscription is removed on
+/// unmount. Opening is a no-op outside Telegram.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::{webapp::InvoiceStatus, yew::use_invoice};
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Checkout() -> Html {
+///     let invoice = use_invoice();
+///     let onclick = {
+///         let invoice = invoice.clone();
+///         Callback::from(move |_| invoice.open("https://t.me/$invoice"))
+///     };
+///     html! {
+///         <>
+///             <button {onclick} disabled={invoice.pending}>{ "Pay" }</button>
+///             if invoice.status == Some(InvoiceStatus::Paid) {
+///                 <p>{ "Thank you!" }</p>
+///             }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_invoice() -> InvoiceState {
+    let status = use_state(|| None);
+    let pending = use_state(|| false);
+
+    {
+        let status = status.clone();
+        let pending = pending.clone();
+        use_effect_with((), move |_| {
+            let stash: HandleSlot = Rc::new(RefCell::new(None));
+            if let Some(app) = TelegramWebApp::instance() {
+                match app.on_event("invoiceClosed", move |payload| {
+                    status.set(InvoiceStatus::from_event(&payload));
+                    pending.set(false);
+                }) {
+                    Ok(handle) => *stash.borrow_mut() = Some(handle),
+                    Err(err) => {
+                        logger::error(&format!("invoiceClosed subscription failed: {err:?}"))
+                    }
+                }
+            }
+            move || {
+                stash.borrow_mut().take();
+            }
+        });
+    }
+
+    let open = {
+        let status = status.clone();
+        let pending = pending.clone();
+        use_callback((), move |url: String, _| {
+            let Some(app) = TelegramWebApp::instance() else {
+                return;
+            };
+            status.set(None);
+            pending.set(true);
+            if let Err(err) = app.open_invoice_with_callback(&url, |_| {}) {
+                logger::error(&format!("openInvoice failed: {err:?}"));
+                pending.set(false);
+            }
+        })
+    };
+
+    InvoiceState {
+        status: *status,
+        pending: *pending,
+        open
+    }
+}
@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::{Callback, hook, use_callback, use_effect_with, use_state};
+
+use crate::{
+    api::location_manager::{self, LocationData, LocationStatus},
+    logger,
+    webapp::{EventHandle, TelegramWebApp}
+};
+
+type HandleSlot = Rc<RefCell<Vec<EventHandle<dyn FnMut(JsValue)>>>>;
+
+/// State returned by [`use_location`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocationHandle {
+    /// Current `LocationManager` flags.
+    pub status:   LocationStatus,
+    /// Latest reported location.
+    pub location: Option<LocationData>,
+    request:      Callback<()>
+}
+
+impl LocationHandle {
+    /// Asks Telegram for the current location.
+    ///
+    /// [`LocationHandle::location`] updates once the location arrives; it
+    /// becomes `None` if the user denies access.
+    pub fn request(&self) {
+        self.request.emit(());
+    }
+
+    /// Opens the location settings, e.g. after access was denied.
+    ///
+    /// # Errors
+    /// Returns [`JsValue`] if `LocationManager` is unavailable or the call
+    /// fails.
+    pub fn open_settings(&self) -> Result<(), JsValue> {
+        location_manager::open_settings()
+    }
+}
+
+/// Yew hook over `Telegram.WebApp.LocationManager`.
+///
+/// Initializes the manager on mount, refreshes the flags on
+/// `locationManagerUpdated` and stores locations delivered by
+/// `locationRequested`. The subscriptions are removed on unmount.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::use_location;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Nearby() -> Html {
+///     let location = use_location();
+///     let onclick = {
+///         let location = location.clone();
+///         Callback::from(move |_| location.request())
+///     };
+///     html! {
+///         <>
+///             <button {onclick} disabled={!location.status.available}>{ "Locate me" }</button>
+///             if let Some(data) = &location.location {
+///                 <p>{ format!("{}, {}", data.latitude, data.longitude) }</p>
+///             }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_location() -> LocationHandle {
+    let status = use_state(|| {
+        TelegramWebApp::instance()
+            .map(|_| LocationStatus::current())
+            .unwrap_or_default()
+    });
+    let location = use_state(|| None);
+
+    {
+        let status = status.clone();
+        let location = location.clone();
+        use_effect_with((), move |_| {
+            let stash: HandleSlot = Rc::new(RefCell::new(Vec::new()));
+            if let Some(app) = TelegramWebApp::instance() {
+                let updated = {
+                    let status = status.clone();
+                    app.on_event("locationManagerUpdated", move |_| {
+                        status.set(LocationStatus::current());
+                    })
+                };
+                let requested = app.on_event("locationRequested", move |payload| {
+                    let data = LocationData::from_event(&payload);
+                    location.set(data);
+                    status.set(LocationStatus::current());
+                });
+                for subscription in [updated, requested] {
+                    match subscription {
+                        Ok(handle) => stash.borrow_mut().push(handle),
+                        Err(err) => {
+                            logger::error(&format!("LocationManager subscription failed: {err:?}"))
+                        }
+                    }
+                }
+                if !LocationStatus::current().inited
+                    && let Err(err) = location_manager::init()
+                {
+                    logger::error(&format!("LocationManager.init failed: {err:?}"));
+                }
+            }
+            move || {
+                stash.borrow_mut().clear();
+            }
+        });
+    }
+
+    let request = {
+        let status = status.clone();
+        let location = location.clone();
+        use_callback((), move |(), _| {
+            let status = status.clone();
+            let location = location.clone();
+            spawn_local(async move {
+                match location_manager::request_location().await {
+                    Ok(data) => location.set(data),
+                    Err(err) => logger::error(&format!("getLocation failed: {err:?}"))
+                }
+                status.set(LocationStatus::current());
+            });
+        })
+    };
+
+    LocationHandle {
+        status: *status,
+        location: (*location).clone(),
+        request
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use yew::prelude::{AttrValue, Callback, Html, Properties, function_component, use_effect_with};
+
+use crate::{logger, webapp::TelegramWebApp};
+
+/// Props for [`QrScanner`].
+#[derive(Properties, PartialEq)]
+pub struct QrScannerProps {
+    /// Whether the scanner is shown.
+    pub open:     bool,
+    /// Caption shown above the scanner viewport.
+    #[prop_or_default]
+    pub text:     AttrValue,
+    /// Called with the scanned text.
+    pub on_code:  Callback<String>,
+    /// Called whenever the scanner closes, including after a scan.
+    #[prop_or_default]
+    pub on_close: Callback<()>
+}
+
+/// Yew component driving `WebApp.showScanQrPopup`.
+///
+/// The scanner opens when `open` becomes `true` and closes after the first
+/// scanned code, when `open` becomes `false` or when the component unmounts.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::QrScanner;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Scan() -> Html {
+///     let open = use_state(|| false);
+///     let onclick = {
+///         let open = open.clone();
+///         Callback::from(move |_| open.set(true))
+///     };
+///     let on_code = Callback::from(|code: String| {
+///         web_sys::console::log_1(&code.into());
+///     });
+///     let on_close = {
+///         let open = open.clone();
+///         Callback::from(move |()| open.set(false))
+///     };
+///     html! {
+///         <>
+///             <button {onclick}>{ "Scan" }</button>
+///             <QrScanner open={*open} text="Point at a QR code" {on_code} {on_close} />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(QrScanner)]
+pub fn qr_scanner(props: &QrScannerProps) -> Html {
+    let text = props.text.clone();
+    let on_code = props.on_code.clone();
+    let on_close = props.on_close.clone();
+    use_effect_with(props.open, move |&open| {
+        let mut closed_handle = None;
+        if open && let Some(app) = TelegramWebApp::instance() {
+            match app.on_event("scanQrPopupClosed", move |_| on_close.emit(())) {
+                Ok(handle) => closed_handle = Some(handle),
+                Err(err) => {
+                    logger::error(&format!("scanQrPopupClosed subscription failed: {err:?}"))
+                }
+            }
+            let scanner = app.clone();
+            if let Err(err) = app.show_scan_qr_popup_with_callback(&text, move |code| {
+                on_code.emit(code);
+                let _ = scanner.close_scan_qr_popup();
+            }) {
+                logger::error(&format!("showScanQrPopup failed: {err:?}"));
+            }
+        }
+        move || {
+            if open && let Some(app) = TelegramWebApp::instance() {
+                let _ = app.close_scan_qr_popup();
+            }
+            drop(closed_handle);
+        }
+    });
+    Html::default()
+}
@@ -0,0 +1,229 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::collections::HashMap;
+
+use yew::prelude::{AttrValue, Callback, Html, Properties, function_component, html};
+use yew_router::{
+    Routable,
+    hooks::{use_location, use_navigator}
+};
+
+use crate::{pages, router::match_pattern, yew::BackButton};
+
+/// Path used by [`PageRoute::NotFound`].
+const NOT_FOUND_PATH: &str = "/404";
+
+/// [`Routable`] route over the pages registered with `telegram_page!` or
+/// `#[telegram_page]`.
+///
+/// Lets Yew apps drive the registered page table with `yew-router`: use it
+/// with `<Switch<PageRoute>>`, `Link<PageRoute>` and the navigator. Static
+/// paths take precedence over parameterized ones, as in the SDK router.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::router::{PageRoute, RouterBackButton};
+/// use yew::prelude::*;
+/// use yew_router::prelude::*;
+///
+/// fn switch(route: PageRoute) -> Html {
+///     match route.path() {
+///         Some("/") => html! { <h1>{ "Home" }</h1> },
+///         Some("/item/:id") => html! { <p>{ route.param("id") }</p> },
+///         _ => html! { <p>{ "Not found" }</p> }
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> Html {
+///     html! {
+///         <HashRouter>
+///             <RouterBackButton />
+///             <Switch<PageRoute> render={switch} />
+///         </HashRouter>
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PageRoute {
+    /// A registered page matched with its path parameters.
+    Page {
+        /// Registered path pattern, e.g. `/item/:id`.
+        path:   &'static str,
+        /// Parameters captured from the location.
+        params: Vec<(String, String)>
+    },
+    /// No registered page matches the location.
+    NotFound
+}
+
+impl PageRoute {
+    /// Returns the registered path pattern, or `None` for
+    /// [`PageRoute::NotFound`].
+    pub fn path(&self) -> Option<&'static str> {
+        match self {
+            Self::Page {
+                path, ..
+            } => Some(path),
+            Self::NotFound => None
+        }
+    }
+
+    /// Returns the value of path parameter `name`.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        match self {
+            Self::Page {
+                params, ..
+            } => params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str()),
+            Self::NotFound => None
+        }
+    }
+
+    /// Returns the handler registered for this page.
+    ///
+    /// Useful for pages that render imperatively rather than through Yew.
+    pub fn handler(&self) -> Option<fn()> {
+        let path = self.path()?;
+        pages::iter()
+            .into_iter()
+            .find(|page| page.path == path)
+            .map(|page| page.handler)
+    }
+}
+
+impl Routable for PageRoute {
+    fn from_path(path: &str, params: &HashMap<&str, &str>) -> Option<Self> {
+        let page = pages::iter().into_iter().find(|page| page.path == path)?;
+        let params = page
+            .path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix(':'))
+            .map(|name| Some((name.to_owned(), (*params.get(name)?).to_owned())))
+            .collect::<Option<_>>()?;
+        Some(Self::Page {
+            path: page.path,
+            params
+        })
+    }
+
+    fn to_path(&self) -> String {
+        match self {
+            Self::Page {
+                path, ..
+            } => path
+                .split('/')
+                .map(|segment| match segment.strip_prefix(':') {
+                    Some(name) => self.param(name).unwrap_or_default(),
+                    None => segment
+                })
+                .collect::<Vec<_>>()
+                .join("/"),
+            Self::NotFound => NOT_FOUND_PATH.to_owned()
+        }
+    }
+
+    fn routes() -> Vec<&'static str> {
+        pages::iter().into_iter().map(|page| page.path).collect()
+    }
+
+    fn not_found_route() -> Option<Self> {
+        Some(Self::NotFound)
+    }
+
+    fn recognize(pathname: &str) -> Option<Self> {
+        let pages = || pages::iter().into_iter();
+        let exact = pages().find(|page| !page.path.contains(':') && page.path == pathname);
+        let matched = exact.map(|page| (page.path, Vec::new())).or_else(|| {
+            pages().find_map(|page| {
+                match_pattern(page.path, pathname).map(|params| (page.path, params))
+            })
+        });
+        Some(match matched {
+            Some((path, params)) => Self::Page {
+                path,
+                params
+            },
+            None => Self::NotFound
+        })
+    }
+}
+
+/// Props for [`RouterBackButton`].
+#[derive(Properties, PartialEq)]
+pub struct RouterBackButtonProps {
+    /// Path on which the back button is hidden.
+    #[prop_or(AttrValue::Static("/"))]
+    pub root: AttrValue
+}
+
+/// Keeps `WebApp.BackButton` in sync with the `yew-router` history.
+///
+/// Must be rendered inside a `yew-router` router. The button is shown on
+/// every location except `root` and navigates back in the Yew history when
+/// pressed.
+#[function_component(RouterBackButton)]
+pub fn router_back_button(props: &RouterBackButtonProps) -> Html {
+    let location = use_location();
+    let navigator = use_navigator();
+    let visible = location.is_some_and(|location| location.path() != props.root.as_str());
+    let on_click = Callback::from(move |()| {
+        if let Some(navigator) = &navigator {
+            navigator.back();
+        }
+    });
+    html! { <BackButton {visible} {on_click} /> }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use yew_router::Routable;
+
+    use super::PageRoute;
+    use crate::pages::telegram_page;
+
+    #[telegram_page("/bridge")]
+    fn bridge_home() {}
+
+    #[telegram_page("/bridge/:id/tab/:tab")]
+    fn bridge_item(id: u32, tab: String) {
+        let _ = (id, tab);
+    }
+
+    #[telegram_page("/bridge/new/tab/info")]
+    fn bridge_new() {}
+
+    #[test]
+    fn recognizes_registered_pages() {
+        assert_eq!(
+            PageRoute::recognize("/bridge").unwrap().path(),
+            Some("/bridge")
+        );
+        let item = PageRoute::recognize("/bridge/7/tab/info").unwrap();
+        assert_eq!(item.path(), Some("/bridge/:id/tab/:tab"));
+        assert_eq!(item.param("id"), Some("7"));
+        assert_eq!(item.param("tab"), Some("info"));
+        assert!(item.handler().is_some());
+        assert_eq!(item.to_path(), "/bridge/7/tab/info");
+        assert_eq!(
+            PageRoute::recognize("/bridge/new/tab/info").unwrap().path(),
+            Some("/bridge/new/tab/info")
+        );
+        assert_eq!(PageRoute::recognize("/missing"), Some(PageRoute::NotFound));
+        assert!(PageRoute::routes().contains(&"/bridge/:id/tab/:tab"));
+    }
+
+    #[test]
+    fn builds_route_from_pattern_and_params() {
+        let params = HashMap::from([("id", "3"), ("tab", "main")]);
+        let route = PageRoute::from_path("/bridge/:id/tab/:tab", &params).unwrap();
+        assert_eq!(route.to_path(), "/bridge/3/tab/main");
+        assert!(PageRoute::from_path("/bridge/:id/tab/:tab", &HashMap::new()).is_none());
+        assert_eq!(PageRoute::NotFound.to_path(), "/404");
+    }
+}
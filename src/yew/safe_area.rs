@@ -19,6 +19,14 @@ pub struct SafeAreaState {
 }
 
 impl SafeAreaState {
+    /// Returns the combined device and content insets, treating missing ones
+    /// as zero.
+    pub fn total(&self) -> SafeAreaInset {
+        self.area
+            .unwrap_or_default()
+            .combine(self.content.unwrap_or_default())
+    }
+
     fn snapshot(app: Option<&TelegramWebApp>) -> Self {
         match app {
             Some(app) => Self {
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use yew::prelude::{Children, Classes, Html, Properties, function_component, html};
+
+use crate::yew::use_safe_area;
+
+/// Props for [`SafeAreaView`].
+#[derive(Properties, PartialEq)]
+pub struct SafeAreaViewProps {
+    /// Content rendered inside the safe area.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra classes for the wrapping `div`.
+    #[prop_or_default]
+    pub class:    Classes
+}
+
+/// Yew component padding its children by the safe-area insets.
+///
+/// The padding is the sum of `WebApp.safeAreaInset` and
+/// `WebApp.contentSafeAreaInset` and follows `safeAreaChanged` and
+/// `contentSafeAreaChanged`.
+///
+/// # Examples
+/// ```no_run
+/// use telegram_webapp_sdk::yew::SafeAreaView;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Screen() -> Html {
+///     html! {
+///         <SafeAreaView class="screen">
+///             <h1>{ "Settings" }</h1>
+///         </SafeAreaView>
+///     }
+/// }
+/// ```
+#[function_component(SafeAreaView)]
+pub fn safe_area_view(props: &SafeAreaViewProps) -> Html {
+    let style = use_safe_area().total().to_padding_css();
+    html! {
+        <div class={props.class.clone()} {style}>
+            { props.children.clone() }
+        </div>
+    }
+}
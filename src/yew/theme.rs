@@ -25,7 +25,9 @@ pub struct ThemeState {
 impl ThemeState {
     fn snapshot(app: Option<&TelegramWebApp>) -> Self {
         let color_scheme = app.and_then(|a| a.color_scheme());
-        let params = get_theme_params().unwrap_or_default();
+        let params = app
+            .and_then(|_| get_theme_params().ok())
+            .unwrap_or_default();
         Self {
             color_scheme,
             params
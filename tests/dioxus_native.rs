@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+#![cfg(all(feature = "dioxus", not(target_arch = "wasm32")))]
+
+use std::cell::RefCell;
+
+use dioxus::prelude::*;
+use telegram_webapp_sdk::dioxus::{
+    BottomButton, SafeAreaState, ThemeState, ViewportState, provide_telegram_context,
+    use_safe_area, use_telegram_context, use_theme, use_viewport
+};
+
+thread_local! {
+    static SEEN: RefCell<Option<(ThemeState, ViewportState, SafeAreaState, bool)>> =
+        const { RefCell::new(None) };
+}
+
+#[component]
+fn App() -> Element {
+    let provided = provide_telegram_context().is_ok();
+    let theme = use_theme();
+    let viewport = use_viewport();
+    let safe_area = use_safe_area();
+    let context = use_telegram_context().is_ok();
+    SEEN.with(|seen| {
+        *seen.borrow_mut() = Some((
+            theme.read().clone(),
+            viewport.read().clone(),
+            safe_area.read().clone(),
+            provided || context
+        ));
+    });
+    let progress = use_signal(|| true);
+    rsx! {
+        BottomButton { text: "Pay", progress: progress(), on_click: |_| {} }
+    }
+}
+
+#[test]
+fn mounts_without_telegram() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    drop(dom);
+
+    let (theme, viewport, safe_area, has_context) = SEEN.with(|seen| seen.take()).unwrap();
+    assert_eq!(theme, ThemeState::default());
+    assert_eq!(viewport, ViewportState::default());
+    assert_eq!(safe_area, SafeAreaState::default());
+    assert!(!has_context);
+}
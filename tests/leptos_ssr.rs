@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+#![cfg(all(
+    feature = "leptos",
+    feature = "biometric",
+    feature = "cloud-storage",
+    feature = "location",
+    feature = "popups",
+    not(target_arch = "wasm32")
+))]
+
+use leptos::prelude::*;
+use telegram_webapp_sdk::leptos::{
+    BackButton, BottomButton, SafeAreaView, Section, Select, SettingsButton, Switch,
+    TelegramConfirm, TextInput, ThemeProvider, provide_telegram_context, use_biometric,
+    use_cloud_storage, use_invoice, use_is_active, use_location, use_safe_area,
+    use_telegram_event, use_theme, use_theme_params, use_viewport
+};
+
+#[component]
+fn Status() -> impl IntoView {
+    let theme = use_theme();
+    let params = use_theme_params();
+    let viewport = use_viewport();
+    let safe_area = use_safe_area();
+    let biometric = use_biometric();
+    let location = use_location();
+    let invoice = use_invoice();
+    let counter = use_cloud_storage("counter", 7_u32);
+    let event = use_telegram_event("themeChanged");
+    let active = use_is_active();
+    view! {
+        <p>{move || theme.get().color_scheme.unwrap_or_else(|| "none".to_owned())}</p>
+        <p>{move || params.get().bg_color.is_none()}</p>
+        <p>{move || viewport.get().height}</p>
+        <p>{move || safe_area.get().area.is_none()}</p>
+        <p>{move || biometric.available.get()}</p>
+        <p>{move || location.location.get().is_none()}</p>
+        <p>{move || invoice.pending.get()}</p>
+        <p>{move || counter.value.get()}</p>
+        <p>{move || counter.loading.get()}</p>
+        <p>{move || event.with(Option::is_none)}</p>
+        <p>{move || active.get()}</p>
+    }
+}
+
+#[test]
+fn renders_on_the_server() {
+    let html = Owner::new().with(|| {
+        assert!(provide_telegram_context().is_err());
+        let open = RwSignal::new(true);
+        view! {
+            <ThemeProvider>
+                <SafeAreaView class="screen">
+                    <BackButton visible=open on_click=|| {} />
+                    <SettingsButton visible=open on_click=|| {} />
+                    <BottomButton text="Pay" progress=open on_click=|| {} />
+                    <TelegramConfirm open message="Sure?" />
+                    <Status />
+                </SafeAreaView>
+            </ThemeProvider>
+        }
+        .to_html()
+    });
+    assert!(html.contains("class=\"screen\""));
+    assert!(html.contains("<p>none</p>"));
+    assert!(html.contains("<p>7</p>"));
+}
+
+#[test]
+fn renders_form_components_on_the_server() {
+    let html = Owner::new().with(|| {
+        let options = vec![
+            ("usd".to_owned(), "US Dollar".to_owned()),
+            ("eur".to_owned(), "Euro".to_owned()),
+        ];
+        view! {
+            <Section title="Payment" footer="Charged once.">
+                <TextInput placeholder="Card holder" destructive=true />
+                <Select options value="eur" />
+                <Switch checked=true label="Save card" />
+            </Section>
+        }
+        .to_html()
+    });
+    assert!(html.contains("Payment"));
+    assert!(html.contains("Charged once."));
+    assert!(html.contains("tg-text-input"));
+    assert!(html.contains("--tg-theme-destructive-text-color"));
+    assert!(html.contains("Euro"));
+    assert!(html.contains("Save card"));
+}
@@ -47,3 +47,42 @@ fn telegram_image_creates_image() -> Result<(), JsValue> {
     assert_eq!(alt, "Logo");
     Ok(())
 }
+
+#[wasm_bindgen_test]
+fn telegram_input_uses_theme_colors() -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let input = telegram_webapp_sdk::telegram_input!(document, "Name", "type" = "email")?;
+    assert_eq!(input.tag_name(), "INPUT");
+    assert_eq!(input.placeholder(), "Name");
+    assert_eq!(input.type_(), "email");
+    let style = input
+        .get_attribute("style")
+        .ok_or_else(|| JsValue::from_str("missing style"))?;
+    assert!(style.contains("--tg-theme-secondary-bg-color"));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+fn telegram_containers_append_children() -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let list = telegram_webapp_sdk::telegram_list!(document, ["a".to_owned(), "b".into()])?;
+    assert_eq!(list.tag_name(), "UL");
+    assert_eq!(list.child_element_count(), 2);
+
+    let card = telegram_webapp_sdk::telegram_card!(document, [list], class = "card")?;
+    assert_eq!(card.class_name(), "card");
+    assert_eq!(card.child_element_count(), 1);
+
+    let section = telegram_webapp_sdk::telegram_section!(document, "Order", [card])?;
+    assert_eq!(section.tag_name(), "SECTION");
+    let header = section
+        .first_element_child()
+        .ok_or_else(|| JsValue::from_str("missing header"))?;
+    assert_eq!(header.text_content().as_deref(), Some("Order"));
+    assert_eq!(section.child_element_count(), 2);
+    Ok(())
+}
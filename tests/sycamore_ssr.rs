@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+#![cfg(all(feature = "sycamore", not(target_arch = "wasm32")))]
+
+use sycamore::prelude::*;
+use telegram_webapp_sdk::sycamore::{
+    BottomButton, provide_telegram_context, use_telegram_context, use_theme, use_viewport
+};
+
+#[component]
+fn App() -> View {
+    let provided = provide_telegram_context().is_ok();
+    let context = use_telegram_context().is_ok();
+    let theme = use_theme();
+    let viewport = use_viewport();
+    let progress = create_signal(true);
+    let scheme = theme.with(|theme| theme.color_scheme.clone().unwrap_or_else(|| "none".into()));
+    view! {
+        BottomButton(text="Pay", progress=progress, on_click=|| {})
+        p { (scheme) }
+        p { (viewport.with(|viewport| viewport.height)) }
+        p { ((provided || context).to_string()) }
+    }
+}
+
+#[test]
+fn renders_on_the_server() {
+    let html = sycamore::render_to_string(App);
+    assert!(html.contains("none"));
+    assert!(html.contains(">0<"));
+    assert!(html.contains("false"));
+}
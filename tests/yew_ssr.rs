@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025-2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+#![cfg(all(
+    feature = "yew",
+    feature = "biometric",
+    feature = "location",
+    not(target_arch = "wasm32")
+))]
+
+use telegram_webapp_sdk::yew::{
+    BackButton, BottomButton, SafeAreaView, Section, TelegramErrorBoundary, TextInput,
+    use_biometric, use_haptic, use_invoice, use_is_active, use_location, use_safe_area,
+    use_telegram_context, use_theme, use_viewport
+};
+use yew::{LocalServerRenderer, prelude::*};
+
+#[function_component(App)]
+fn app() -> Html {
+    let theme = use_theme();
+    let viewport = use_viewport();
+    let safe_area = use_safe_area();
+    let active = use_is_active();
+    let biometric = use_biometric();
+    let location = use_location();
+    let invoice = use_invoice();
+    let _haptic = use_haptic();
+    let context = use_telegram_context();
+    html! {
+        <TelegramErrorBoundary>
+            <SafeAreaView>
+                <BackButton />
+                <BottomButton text="Pay" on_click={Callback::noop()} />
+                <Section title="State">
+                    <TextInput placeholder="Name" />
+                    <p>{ format!("{:?}", theme.color_scheme) }</p>
+                    <p>{ viewport.height }</p>
+                    <p>{ safe_area.area.is_some() }</p>
+                    <p>{ active }</p>
+                    <p>{ biometric.status.available }</p>
+                    <p>{ location.location.is_some() }</p>
+                    <p>{ invoice.pending }</p>
+                    <p>{ context.is_ok() }</p>
+                </Section>
+            </SafeAreaView>
+        </TelegramErrorBoundary>
+    }
+}
+
+#[tokio::test]
+async fn renders_on_the_server() {
+    let html = LocalServerRenderer::<App>::new().render().await;
+    assert!(html.contains("tg-text-input"));
+    assert!(html.contains("<p>true</p>"));
+}